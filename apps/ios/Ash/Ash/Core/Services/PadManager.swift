@@ -186,7 +186,7 @@ actor PadManager: PadManagerProtocol {
 
     func updatePeerConsumption(peerRole: Role, consumed: UInt64, for conversationId: String) async throws {
         let pad = try await loadPad(for: conversationId)
-        pad.updatePeerConsumption(peerRole: peerRole, newConsumed: consumed)
+        try pad.updatePeerConsumption(peerRole: peerRole, newConsumed: consumed)
 
         // Persist updated state
         try savePadState(pad: pad, for: conversationId)
@@ -250,6 +250,71 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_CONSUMPTIONJOURNAL
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_CONSUMPTIONJOURNAL
+void*_Nonnull uniffi_ash_bindings_fn_clone_consumptionjournal(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_CONSUMPTIONJOURNAL
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_CONSUMPTIONJOURNAL
+void uniffi_ash_bindings_fn_free_consumptionjournal(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_CONSUMPTIONJOURNAL_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_CONSUMPTIONJOURNAL_DECODE
+void*_Nonnull uniffi_ash_bindings_fn_constructor_consumptionjournal_decode(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_CONSUMPTIONJOURNAL_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_CONSUMPTIONJOURNAL_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_consumptionjournal_new(RustBuffer role, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CHECK
+void uniffi_ash_bindings_fn_method_consumptionjournal_check(void*_Nonnull ptr, void*_Nonnull pad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CHECK_SEND
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CHECK_SEND
+void uniffi_ash_bindings_fn_method_consumptionjournal_check_send(void*_Nonnull ptr, void*_Nonnull pad, uint32_t n, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CONSUME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_CONSUME
+void*_Nonnull uniffi_ash_bindings_fn_method_consumptionjournal_consume(void*_Nonnull ptr, void*_Nonnull pad, uint32_t n, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_ENCODE
+RustBuffer uniffi_ash_bindings_fn_method_consumptionjournal_encode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_HEAD
+RustBuffer uniffi_ash_bindings_fn_method_consumptionjournal_head(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_OWN_CONSUMED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_OWN_CONSUMED
+uint64_t uniffi_ash_bindings_fn_method_consumptionjournal_own_consumed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_PEER_CONSUMED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_PEER_CONSUMED
+uint64_t uniffi_ash_bindings_fn_method_consumptionjournal_peer_consumed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_RECORD_RECEIVED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_RECORD_RECEIVED
+void uniffi_ash_bindings_fn_method_consumptionjournal_record_received(void*_Nonnull ptr, void*_Nonnull pad, uint64_t offset, uint64_t len, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_ROLE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_CONSUMPTIONJOURNAL_ROLE
+RustBuffer uniffi_ash_bindings_fn_method_consumptionjournal_role(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_FOUNTAINFRAMEGENERATOR
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_FOUNTAINFRAMEGENERATOR
@@ -341,6 +406,86 @@ uint32_t uniffi_ash_bindings_fn_method_fountainframereceiver_source_count(void*_
 uint32_t uniffi_ash_bindings_fn_method_fountainframereceiver_unique_blocks_received(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_INITIATORCEREMONY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_INITIATORCEREMONY
+void*_Nonnull uniffi_ash_bindings_fn_clone_initiatorceremony(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_INITIATORCEREMONY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_INITIATORCEREMONY
+void uniffi_ash_bindings_fn_free_initiatorceremony(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_INITIATORCEREMONY_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_INITIATORCEREMONY_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_initiatorceremony_new(RustBuffer pad_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_ABORT
+void uniffi_ash_bindings_fn_method_initiatorceremony_abort(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_CONFIRM_MNEMONIC
+void uniffi_ash_bindings_fn_method_initiatorceremony_confirm_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_FINISH
+RustBuffer uniffi_ash_bindings_fn_method_initiatorceremony_finish(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_FINISH_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_FINISH_TRANSFER
+void uniffi_ash_bindings_fn_method_initiatorceremony_finish_transfer(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_MNEMONIC
+RustBuffer uniffi_ash_bindings_fn_method_initiatorceremony_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_NEXT_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_NEXT_FRAME
+RustBuffer uniffi_ash_bindings_fn_method_initiatorceremony_next_frame(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_REJECT_MNEMONIC
+void uniffi_ash_bindings_fn_method_initiatorceremony_reject_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_BLOCK_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_BLOCK_SIZE
+void uniffi_ash_bindings_fn_method_initiatorceremony_set_block_size(void*_Nonnull ptr, uint32_t block_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_METADATA
+void uniffi_ash_bindings_fn_method_initiatorceremony_set_metadata(void*_Nonnull ptr, RustBuffer metadata, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SET_PASSPHRASE
+void uniffi_ash_bindings_fn_method_initiatorceremony_set_passphrase(void*_Nonnull ptr, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SOURCE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_SOURCE_COUNT
+uint32_t uniffi_ash_bindings_fn_method_initiatorceremony_source_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_START_TRANSFER
+void uniffi_ash_bindings_fn_method_initiatorceremony_start_transfer(void*_Nonnull ptr, RustBuffer entropy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_INITIATORCEREMONY_STATE
+RustBuffer uniffi_ash_bindings_fn_method_initiatorceremony_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_PAD
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_PAD
 void*_Nonnull uniffi_ash_bindings_fn_clone_pad(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -371,6 +516,11 @@ void*_Nonnull uniffi_ash_bindings_fn_constructor_pad_from_entropy(RustBuffer ent
 RustBuffer uniffi_ash_bindings_fn_method_pad_as_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_AS_SECRET_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_AS_SECRET_BYTES
+void*_Nonnull uniffi_ash_bindings_fn_method_pad_as_secret_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_AVAILABLE_FOR_SENDING
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_AVAILABLE_FOR_SENDING
 uint64_t uniffi_ash_bindings_fn_method_pad_available_for_sending(void*_Nonnull ptr, RustBuffer role, RustCallStatus *_Nonnull out_status
@@ -381,11 +531,21 @@ uint64_t uniffi_ash_bindings_fn_method_pad_available_for_sending(void*_Nonnull p
 int8_t uniffi_ash_bindings_fn_method_pad_can_send(void*_Nonnull ptr, uint32_t length, RustBuffer role, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CAN_SEND_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CAN_SEND_TEXT
+int8_t uniffi_ash_bindings_fn_method_pad_can_send_text(void*_Nonnull ptr, uint32_t plaintext_len, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUME
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUME
 RustBuffer uniffi_ash_bindings_fn_method_pad_consume(void*_Nonnull ptr, uint32_t n, RustBuffer role, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUME_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUME_SECRET
+void*_Nonnull uniffi_ash_bindings_fn_method_pad_consume_secret(void*_Nonnull ptr, uint32_t n, RustBuffer role, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUMED
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_CONSUMED
 uint64_t uniffi_ash_bindings_fn_method_pad_consumed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -401,11 +561,21 @@ uint64_t uniffi_ash_bindings_fn_method_pad_consumed_back(void*_Nonnull ptr, Rust
 uint64_t uniffi_ash_bindings_fn_method_pad_consumed_front(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_FORECAST
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_FORECAST
+RustBuffer uniffi_ash_bindings_fn_method_pad_forecast(void*_Nonnull ptr, RustBuffer role, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_IS_EXHAUSTED
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_IS_EXHAUSTED
 int8_t uniffi_ash_bindings_fn_method_pad_is_exhausted(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_KEY_AT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_KEY_AT
+void*_Nonnull uniffi_ash_bindings_fn_method_pad_key_at(void*_Nonnull ptr, uint64_t offset, uint64_t len, RustBuffer peer_role, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_NEXT_SEND_OFFSET
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_NEXT_SEND_OFFSET
 uint64_t uniffi_ash_bindings_fn_method_pad_next_send_offset(void*_Nonnull ptr, RustBuffer role, RustCallStatus *_Nonnull out_status
@@ -416,6 +586,11 @@ uint64_t uniffi_ash_bindings_fn_method_pad_next_send_offset(void*_Nonnull ptr, R
 uint64_t uniffi_ash_bindings_fn_method_pad_remaining(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_SEND_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_SEND_POSITION
+uint64_t uniffi_ash_bindings_fn_method_pad_send_position(void*_Nonnull ptr, RustBuffer role, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_TOTAL_SIZE
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_PAD_TOTAL_SIZE
 uint64_t uniffi_ash_bindings_fn_method_pad_total_size(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -431,6 +606,377 @@ void uniffi_ash_bindings_fn_method_pad_update_peer_consumption(void*_Nonnull ptr
 int8_t uniffi_ash_bindings_fn_method_pad_zero_bytes_at(void*_Nonnull ptr, uint64_t offset, uint64_t length, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RECEIVEWINDOW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RECEIVEWINDOW
+void*_Nonnull uniffi_ash_bindings_fn_clone_receivewindow(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RECEIVEWINDOW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RECEIVEWINDOW
+void uniffi_ash_bindings_fn_free_receivewindow(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RECEIVEWINDOW_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RECEIVEWINDOW_DECODE
+void*_Nonnull uniffi_ash_bindings_fn_constructor_receivewindow_decode(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RECEIVEWINDOW_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RECEIVEWINDOW_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_receivewindow_new(RustBuffer role, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ACCEPT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ACCEPT
+void uniffi_ash_bindings_fn_method_receivewindow_accept(void*_Nonnull ptr, void*_Nonnull pad, uint64_t offset, uint64_t len, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_CHECK
+void uniffi_ash_bindings_fn_method_receivewindow_check(void*_Nonnull ptr, void*_Nonnull pad, uint64_t offset, uint64_t len, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ENCODE
+RustBuffer uniffi_ash_bindings_fn_method_receivewindow_encode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_GAPS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_GAPS
+RustBuffer uniffi_ash_bindings_fn_method_receivewindow_gaps(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_HIGHEST
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_HIGHEST
+uint64_t uniffi_ash_bindings_fn_method_receivewindow_highest(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_RECEIVE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_RECEIVE
+RustBuffer uniffi_ash_bindings_fn_method_receivewindow_receive(void*_Nonnull ptr, void*_Nonnull pad, RustBuffer encoded_frame, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ROLE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RECEIVEWINDOW_ROLE
+RustBuffer uniffi_ash_bindings_fn_method_receivewindow_role(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RESPONDERCEREMONY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RESPONDERCEREMONY
+void*_Nonnull uniffi_ash_bindings_fn_clone_responderceremony(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RESPONDERCEREMONY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RESPONDERCEREMONY
+void uniffi_ash_bindings_fn_free_responderceremony(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RESPONDERCEREMONY_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RESPONDERCEREMONY_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_responderceremony_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_ABORT
+void uniffi_ash_bindings_fn_method_responderceremony_abort(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_ADD_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_ADD_FRAME
+int8_t uniffi_ash_bindings_fn_method_responderceremony_add_frame(void*_Nonnull ptr, RustBuffer frame_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_CONFIRM_MNEMONIC
+void uniffi_ash_bindings_fn_method_responderceremony_confirm_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_FINISH
+RustBuffer uniffi_ash_bindings_fn_method_responderceremony_finish(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_METADATA
+RustBuffer uniffi_ash_bindings_fn_method_responderceremony_metadata(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_MNEMONIC
+RustBuffer uniffi_ash_bindings_fn_method_responderceremony_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_PROGRESS
+double uniffi_ash_bindings_fn_method_responderceremony_progress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_REJECT_MNEMONIC
+void uniffi_ash_bindings_fn_method_responderceremony_reject_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_SET_PASSPHRASE
+void uniffi_ash_bindings_fn_method_responderceremony_set_passphrase(void*_Nonnull ptr, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_START_TRANSFER
+void uniffi_ash_bindings_fn_method_responderceremony_start_transfer(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RESPONDERCEREMONY_STATE
+RustBuffer uniffi_ash_bindings_fn_method_responderceremony_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RETENTIONMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_RETENTIONMANAGER
+void*_Nonnull uniffi_ash_bindings_fn_clone_retentionmanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RETENTIONMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_RETENTIONMANAGER
+void uniffi_ash_bindings_fn_free_retentionmanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RETENTIONMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_RETENTIONMANAGER_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_retentionmanager_new(RustBuffer role, RustBuffer policy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_MARK_DELIVERED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_MARK_DELIVERED
+int8_t uniffi_ash_bindings_fn_method_retentionmanager_mark_delivered(void*_Nonnull ptr, uint64_t id, uint64_t now, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_MARK_VIEWED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_MARK_VIEWED
+int8_t uniffi_ash_bindings_fn_method_retentionmanager_mark_viewed(void*_Nonnull ptr, uint64_t id, uint64_t now, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_NEXT_DUE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_NEXT_DUE
+RustBuffer uniffi_ash_bindings_fn_method_retentionmanager_next_due(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_POLICY
+RustBuffer uniffi_ash_bindings_fn_method_retentionmanager_policy(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_RECORD_RECEIVED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_RECORD_RECEIVED
+void uniffi_ash_bindings_fn_method_retentionmanager_record_received(void*_Nonnull ptr, void*_Nonnull pad, uint64_t id, uint64_t offset, uint64_t len, uint64_t now, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_RECORD_SENT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_RECORD_SENT
+void uniffi_ash_bindings_fn_method_retentionmanager_record_sent(void*_Nonnull ptr, void*_Nonnull pad, uint64_t id, uint64_t offset, uint64_t len, uint64_t now, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SET_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SET_POLICY
+void uniffi_ash_bindings_fn_method_retentionmanager_set_policy(void*_Nonnull ptr, RustBuffer policy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SUMMARY
+RustBuffer uniffi_ash_bindings_fn_method_retentionmanager_summary(void*_Nonnull ptr, void*_Nonnull pad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SWEEP
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_SWEEP
+RustBuffer uniffi_ash_bindings_fn_method_retentionmanager_sweep(void*_Nonnull ptr, void*_Nonnull pad, uint64_t now, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_WIPE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_RETENTIONMANAGER_WIPE
+int8_t uniffi_ash_bindings_fn_method_retentionmanager_wipe(void*_Nonnull ptr, void*_Nonnull pad, uint64_t id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_SECRETBYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_SECRETBYTES
+void*_Nonnull uniffi_ash_bindings_fn_clone_secretbytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_SECRETBYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_SECRETBYTES
+void uniffi_ash_bindings_fn_free_secretbytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_SECRETBYTES_FROM_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_SECRETBYTES_FROM_BYTES
+void*_Nonnull uniffi_ash_bindings_fn_constructor_secretbytes_from_bytes(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_COPY_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_COPY_BYTES
+RustBuffer uniffi_ash_bindings_fn_method_secretbytes_copy_bytes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_IS_EMPTY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_IS_EMPTY
+int8_t uniffi_ash_bindings_fn_method_secretbytes_is_empty(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_LEN
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_LEN
+uint64_t uniffi_ash_bindings_fn_method_secretbytes_len(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_SLICE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_SLICE
+void*_Nonnull uniffi_ash_bindings_fn_method_secretbytes_slice(void*_Nonnull ptr, uint64_t offset, uint64_t len, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_WIPE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_SECRETBYTES_WIPE
+void uniffi_ash_bindings_fn_method_secretbytes_wipe(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_TOPUPRECEIVER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_TOPUPRECEIVER
+void*_Nonnull uniffi_ash_bindings_fn_clone_topupreceiver(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_TOPUPRECEIVER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_TOPUPRECEIVER
+void uniffi_ash_bindings_fn_free_topupreceiver(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_TOPUPRECEIVER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_TOPUPRECEIVER_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_topupreceiver_new(RustBuffer role, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_ABORT
+void uniffi_ash_bindings_fn_method_topupreceiver_abort(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_ADD_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_ADD_FRAME
+int8_t uniffi_ash_bindings_fn_method_topupreceiver_add_frame(void*_Nonnull ptr, RustBuffer frame_bytes, void*_Nonnull pad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_CONFIRM_MNEMONIC
+void uniffi_ash_bindings_fn_method_topupreceiver_confirm_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_FINISH
+RustBuffer uniffi_ash_bindings_fn_method_topupreceiver_finish(void*_Nonnull ptr, void*_Nonnull pad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_MNEMONIC
+RustBuffer uniffi_ash_bindings_fn_method_topupreceiver_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_PROGRESS
+double uniffi_ash_bindings_fn_method_topupreceiver_progress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_REJECT_MNEMONIC
+void uniffi_ash_bindings_fn_method_topupreceiver_reject_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_SET_PASSPHRASE
+void uniffi_ash_bindings_fn_method_topupreceiver_set_passphrase(void*_Nonnull ptr, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_START_TRANSFER
+void uniffi_ash_bindings_fn_method_topupreceiver_start_transfer(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPRECEIVER_STATE
+RustBuffer uniffi_ash_bindings_fn_method_topupreceiver_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_TOPUPSENDER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CLONE_TOPUPSENDER
+void*_Nonnull uniffi_ash_bindings_fn_clone_topupsender(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_TOPUPSENDER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FREE_TOPUPSENDER
+void uniffi_ash_bindings_fn_free_topupsender(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_TOPUPSENDER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_CONSTRUCTOR_TOPUPSENDER_NEW
+void*_Nonnull uniffi_ash_bindings_fn_constructor_topupsender_new(RustBuffer role, RustBuffer segment_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_ABORT
+void uniffi_ash_bindings_fn_method_topupsender_abort(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_CONFIRM_MNEMONIC
+void uniffi_ash_bindings_fn_method_topupsender_confirm_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_FINISH
+RustBuffer uniffi_ash_bindings_fn_method_topupsender_finish(void*_Nonnull ptr, void*_Nonnull pad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_FINISH_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_FINISH_TRANSFER
+void uniffi_ash_bindings_fn_method_topupsender_finish_transfer(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_MNEMONIC
+RustBuffer uniffi_ash_bindings_fn_method_topupsender_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_NEXT_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_NEXT_FRAME
+RustBuffer uniffi_ash_bindings_fn_method_topupsender_next_frame(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_REJECT_MNEMONIC
+void uniffi_ash_bindings_fn_method_topupsender_reject_mnemonic(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_BLOCK_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_BLOCK_SIZE
+void uniffi_ash_bindings_fn_method_topupsender_set_block_size(void*_Nonnull ptr, uint32_t block_size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_PASSPHRASE
+void uniffi_ash_bindings_fn_method_topupsender_set_passphrase(void*_Nonnull ptr, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_TRANSFER_METHOD
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_SET_TRANSFER_METHOD
+void uniffi_ash_bindings_fn_method_topupsender_set_transfer_method(void*_Nonnull ptr, RustBuffer method, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_START_TRANSFER
+void uniffi_ash_bindings_fn_method_topupsender_start_transfer(void*_Nonnull ptr, void*_Nonnull pad, RustBuffer entropy, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_METHOD_TOPUPSENDER_STATE
+RustBuffer uniffi_ash_bindings_fn_method_topupsender_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_CALCULATE_EXPECTED_FRAMES
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_CALCULATE_EXPECTED_FRAMES
 uint32_t uniffi_ash_bindings_fn_func_calculate_expected_frames(uint64_t pad_bytes, uint32_t block_size, RustBuffer method, RustCallStatus *_Nonnull out_status
@@ -471,6 +1017,11 @@ RustBuffer uniffi_ash_bindings_fn_func_decrypt(RustBuffer key, RustBuffer cipher
 RustBuffer uniffi_ash_bindings_fn_func_decrypt_authenticated(RustBuffer auth_key, RustBuffer encryption_key, RustBuffer encoded_frame, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DECRYPT_AUTHENTICATED_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DECRYPT_AUTHENTICATED_SECRET
+RustBuffer uniffi_ash_bindings_fn_func_decrypt_authenticated_secret(void*_Nonnull auth_key, void*_Nonnull encryption_key, RustBuffer encoded_frame, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_ALL_TOKENS
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_ALL_TOKENS
 RustBuffer uniffi_ash_bindings_fn_func_derive_all_tokens(RustBuffer pad_bytes, RustCallStatus *_Nonnull out_status
@@ -491,6 +1042,16 @@ RustBuffer uniffi_ash_bindings_fn_func_derive_burn_token(RustBuffer pad_bytes, R
 RustBuffer uniffi_ash_bindings_fn_func_derive_conversation_id(RustBuffer pad_bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_REGISTERED_CONVERSATION_ID
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_REGISTERED_CONVERSATION_ID
+RustBuffer uniffi_ash_bindings_fn_func_derive_registered_conversation_id(RustBuffer pad_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_REGISTRATION_PROOF
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_DERIVE_REGISTRATION_PROOF
+RustBuffer uniffi_ash_bindings_fn_func_derive_registration_proof(RustBuffer pad_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT
 RustBuffer uniffi_ash_bindings_fn_func_encrypt(RustBuffer key, RustBuffer plaintext, RustCallStatus *_Nonnull out_status
@@ -501,6 +1062,16 @@ RustBuffer uniffi_ash_bindings_fn_func_encrypt(RustBuffer key, RustBuffer plaint
 RustBuffer uniffi_ash_bindings_fn_func_encrypt_authenticated(RustBuffer auth_key, RustBuffer encryption_key, RustBuffer plaintext, uint8_t msg_type, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT_AUTHENTICATED_AT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT_AUTHENTICATED_AT
+RustBuffer uniffi_ash_bindings_fn_func_encrypt_authenticated_at(uint64_t offset, void*_Nonnull auth_key, void*_Nonnull encryption_key, RustBuffer plaintext, uint8_t msg_type, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT_AUTHENTICATED_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_ENCRYPT_AUTHENTICATED_SECRET
+RustBuffer uniffi_ash_bindings_fn_func_encrypt_authenticated_secret(void*_Nonnull auth_key, void*_Nonnull encryption_key, RustBuffer plaintext, uint8_t msg_type, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_GENERATE_MNEMONIC
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_GENERATE_MNEMONIC
 RustBuffer uniffi_ash_bindings_fn_func_generate_mnemonic(RustBuffer pad_bytes, RustCallStatus *_Nonnull out_status
@@ -546,6 +1117,26 @@ uint32_t uniffi_ash_bindings_fn_func_get_min_passphrase_length(RustCallStatus *_
 uint64_t uniffi_ash_bindings_fn_func_get_pad_size_bytes(RustBuffer size, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_HASH_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_HASH_TOKEN
+RustBuffer uniffi_ash_bindings_fn_func_hash_token(RustBuffer token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_OPEN_PAD_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_OPEN_PAD_STATE
+RustBuffer uniffi_ash_bindings_fn_func_open_pad_state(RustBuffer sealed, RustBuffer wrapping_key, uint64_t min_generation, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_RETENTION_POLICY_FROM_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_RETENTION_POLICY_FROM_METADATA
+RustBuffer uniffi_ash_bindings_fn_func_retention_policy_from_metadata(RustBuffer metadata, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_SEAL_PAD_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_SEAL_PAD_STATE
+RustBuffer uniffi_ash_bindings_fn_func_seal_pad_state(void*_Nonnull pad, RustBuffer role, uint64_t generation, RustBuffer wrapping_key, RustBuffer nonce, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_SECURE_ZERO_BYTES
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_FN_FUNC_SECURE_ZERO_BYTES
 void uniffi_ash_bindings_fn_func_secure_zero_bytes(RustBuffer data, RustCallStatus *_Nonnull out_status
@@ -882,6 +1473,12 @@ uint16_t uniffi_ash_bindings_checksum_func_decrypt(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DECRYPT_AUTHENTICATED
 uint16_t uniffi_ash_bindings_checksum_func_decrypt_authenticated(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DECRYPT_AUTHENTICATED_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DECRYPT_AUTHENTICATED_SECRET
+uint16_t uniffi_ash_bindings_checksum_func_decrypt_authenticated_secret(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_ALL_TOKENS
@@ -906,6 +1503,18 @@ uint16_t uniffi_ash_bindings_checksum_func_derive_burn_token(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_CONVERSATION_ID
 uint16_t uniffi_ash_bindings_checksum_func_derive_conversation_id(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_REGISTERED_CONVERSATION_ID
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_REGISTERED_CONVERSATION_ID
+uint16_t uniffi_ash_bindings_checksum_func_derive_registered_conversation_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_REGISTRATION_PROOF
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_DERIVE_REGISTRATION_PROOF
+uint16_t uniffi_ash_bindings_checksum_func_derive_registration_proof(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT
@@ -918,6 +1527,18 @@ uint16_t uniffi_ash_bindings_checksum_func_encrypt(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT_AUTHENTICATED
 uint16_t uniffi_ash_bindings_checksum_func_encrypt_authenticated(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT_AUTHENTICATED_AT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT_AUTHENTICATED_AT
+uint16_t uniffi_ash_bindings_checksum_func_encrypt_authenticated_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT_AUTHENTICATED_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_ENCRYPT_AUTHENTICATED_SECRET
+uint16_t uniffi_ash_bindings_checksum_func_encrypt_authenticated_secret(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_GENERATE_MNEMONIC
@@ -968,16 +1589,94 @@ uint16_t uniffi_ash_bindings_checksum_func_get_pad_size_bytes(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SECURE_ZERO_BYTES
-#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SECURE_ZERO_BYTES
-uint16_t uniffi_ash_bindings_checksum_func_secure_zero_bytes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_HASH_TOKEN
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_HASH_TOKEN
+uint16_t uniffi_ash_bindings_checksum_func_hash_token(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_VALIDATE_PASSPHRASE
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_OPEN_PAD_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_OPEN_PAD_STATE
+uint16_t uniffi_ash_bindings_checksum_func_open_pad_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_RETENTION_POLICY_FROM_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_RETENTION_POLICY_FROM_METADATA
+uint16_t uniffi_ash_bindings_checksum_func_retention_policy_from_metadata(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SEAL_PAD_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SEAL_PAD_STATE
+uint16_t uniffi_ash_bindings_checksum_func_seal_pad_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SECURE_ZERO_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_SECURE_ZERO_BYTES
+uint16_t uniffi_ash_bindings_checksum_func_secure_zero_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_VALIDATE_PASSPHRASE
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_FUNC_VALIDATE_PASSPHRASE
 uint16_t uniffi_ash_bindings_checksum_func_validate_passphrase(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CHECK
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_check(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CHECK_SEND
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CHECK_SEND
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_check_send(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CONSUME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_CONSUME
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_consume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_ENCODE
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_encode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_HEAD
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_HEAD
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_head(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_OWN_CONSUMED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_OWN_CONSUMED
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_own_consumed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_PEER_CONSUMED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_PEER_CONSUMED
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_peer_consumed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_RECORD_RECEIVED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_RECORD_RECEIVED
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_record_received(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_ROLE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_CONSUMPTIONJOURNAL_ROLE
+uint16_t uniffi_ash_bindings_checksum_method_consumptionjournal_role(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_FOUNTAINFRAMEGENERATOR_BLOCK_SIZE
@@ -1056,12 +1755,96 @@ uint16_t uniffi_ash_bindings_checksum_method_fountainframereceiver_source_count(
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_FOUNTAINFRAMERECEIVER_UNIQUE_BLOCKS_RECEIVED
 uint16_t uniffi_ash_bindings_checksum_method_fountainframereceiver_unique_blocks_received(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_ABORT
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_abort(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_CONFIRM_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_confirm_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_FINISH
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_finish(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_FINISH_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_FINISH_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_finish_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_NEXT_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_NEXT_FRAME
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_next_frame(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_REJECT_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_reject_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_BLOCK_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_BLOCK_SIZE
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_set_block_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_METADATA
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_set_metadata(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SET_PASSPHRASE
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_set_passphrase(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SOURCE_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_SOURCE_COUNT
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_source_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_START_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_start_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_INITIATORCEREMONY_STATE
+uint16_t uniffi_ash_bindings_checksum_method_initiatorceremony_state(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_AS_BYTES
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_AS_BYTES
 uint16_t uniffi_ash_bindings_checksum_method_pad_as_bytes(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_AS_SECRET_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_AS_SECRET_BYTES
+uint16_t uniffi_ash_bindings_checksum_method_pad_as_secret_bytes(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_AVAILABLE_FOR_SENDING
@@ -1074,12 +1857,24 @@ uint16_t uniffi_ash_bindings_checksum_method_pad_available_for_sending(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CAN_SEND
 uint16_t uniffi_ash_bindings_checksum_method_pad_can_send(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CAN_SEND_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CAN_SEND_TEXT
+uint16_t uniffi_ash_bindings_checksum_method_pad_can_send_text(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUME
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUME
 uint16_t uniffi_ash_bindings_checksum_method_pad_consume(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUME_SECRET
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUME_SECRET
+uint16_t uniffi_ash_bindings_checksum_method_pad_consume_secret(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUMED
@@ -1098,12 +1893,24 @@ uint16_t uniffi_ash_bindings_checksum_method_pad_consumed_back(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_CONSUMED_FRONT
 uint16_t uniffi_ash_bindings_checksum_method_pad_consumed_front(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_FORECAST
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_FORECAST
+uint16_t uniffi_ash_bindings_checksum_method_pad_forecast(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_IS_EXHAUSTED
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_IS_EXHAUSTED
 uint16_t uniffi_ash_bindings_checksum_method_pad_is_exhausted(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_KEY_AT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_KEY_AT
+uint16_t uniffi_ash_bindings_checksum_method_pad_key_at(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_NEXT_SEND_OFFSET
@@ -1116,6 +1923,12 @@ uint16_t uniffi_ash_bindings_checksum_method_pad_next_send_offset(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_REMAINING
 uint16_t uniffi_ash_bindings_checksum_method_pad_remaining(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_SEND_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_SEND_POSITION
+uint16_t uniffi_ash_bindings_checksum_method_pad_send_position(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_TOTAL_SIZE
@@ -1134,12 +1947,360 @@ uint16_t uniffi_ash_bindings_checksum_method_pad_update_peer_consumption(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_PAD_ZERO_BYTES_AT
 uint16_t uniffi_ash_bindings_checksum_method_pad_zero_bytes_at(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ACCEPT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ACCEPT
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_accept(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_CHECK
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_check(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ENCODE
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_encode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_GAPS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_GAPS
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_gaps(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_HIGHEST
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_HIGHEST
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_highest(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_RECEIVE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_RECEIVE
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_receive(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ROLE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RECEIVEWINDOW_ROLE
+uint16_t uniffi_ash_bindings_checksum_method_receivewindow_role(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_ABORT
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_abort(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_ADD_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_ADD_FRAME
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_add_frame(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_CONFIRM_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_confirm_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_FINISH
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_finish(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_METADATA
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_METADATA
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_metadata(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_PROGRESS
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_REJECT_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_reject_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_SET_PASSPHRASE
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_set_passphrase(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_START_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_start_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RESPONDERCEREMONY_STATE
+uint16_t uniffi_ash_bindings_checksum_method_responderceremony_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_MARK_DELIVERED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_MARK_DELIVERED
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_mark_delivered(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_MARK_VIEWED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_MARK_VIEWED
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_mark_viewed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_NEXT_DUE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_NEXT_DUE
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_next_due(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_POLICY
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_policy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_RECORD_RECEIVED
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_RECORD_RECEIVED
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_record_received(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_RECORD_SENT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_RECORD_SENT
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_record_sent(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SET_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SET_POLICY
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_set_policy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SUMMARY
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_summary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SWEEP
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_SWEEP
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_sweep(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_WIPE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_RETENTIONMANAGER_WIPE
+uint16_t uniffi_ash_bindings_checksum_method_retentionmanager_wipe(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_COPY_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_COPY_BYTES
+uint16_t uniffi_ash_bindings_checksum_method_secretbytes_copy_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_IS_EMPTY
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_IS_EMPTY
+uint16_t uniffi_ash_bindings_checksum_method_secretbytes_is_empty(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_LEN
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_LEN
+uint16_t uniffi_ash_bindings_checksum_method_secretbytes_len(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_SLICE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_SLICE
+uint16_t uniffi_ash_bindings_checksum_method_secretbytes_slice(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_WIPE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_SECRETBYTES_WIPE
+uint16_t uniffi_ash_bindings_checksum_method_secretbytes_wipe(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_ABORT
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_abort(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_ADD_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_ADD_FRAME
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_add_frame(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_CONFIRM_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_confirm_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_FINISH
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_finish(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_PROGRESS
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_PROGRESS
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_progress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_REJECT_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_reject_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_SET_PASSPHRASE
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_set_passphrase(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_START_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_start_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPRECEIVER_STATE
+uint16_t uniffi_ash_bindings_checksum_method_topupreceiver_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_ABORT
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_ABORT
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_abort(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_CONFIRM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_CONFIRM_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_confirm_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_FINISH
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_FINISH
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_finish(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_FINISH_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_FINISH_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_finish_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_NEXT_FRAME
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_NEXT_FRAME
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_next_frame(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_REJECT_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_REJECT_MNEMONIC
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_reject_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_BLOCK_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_BLOCK_SIZE
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_set_block_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_PASSPHRASE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_PASSPHRASE
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_set_passphrase(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_TRANSFER_METHOD
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_SET_TRANSFER_METHOD
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_set_transfer_method(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_START_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_START_TRANSFER
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_start_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_METHOD_TOPUPSENDER_STATE
+uint16_t uniffi_ash_bindings_checksum_method_topupsender_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_CONSUMPTIONJOURNAL_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_CONSUMPTIONJOURNAL_DECODE
+uint16_t uniffi_ash_bindings_checksum_constructor_consumptionjournal_decode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_CONSUMPTIONJOURNAL_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_CONSUMPTIONJOURNAL_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_consumptionjournal_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_FOUNTAINFRAMERECEIVER_NEW
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_FOUNTAINFRAMERECEIVER_NEW
 uint16_t uniffi_ash_bindings_checksum_constructor_fountainframereceiver_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_INITIATORCEREMONY_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_INITIATORCEREMONY_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_initiatorceremony_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_PAD_FROM_BYTES
@@ -1158,6 +2319,48 @@ uint16_t uniffi_ash_bindings_checksum_constructor_pad_from_bytes_with_state(void
 #define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_PAD_FROM_ENTROPY
 uint16_t uniffi_ash_bindings_checksum_constructor_pad_from_entropy(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RECEIVEWINDOW_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RECEIVEWINDOW_DECODE
+uint16_t uniffi_ash_bindings_checksum_constructor_receivewindow_decode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RECEIVEWINDOW_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RECEIVEWINDOW_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_receivewindow_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RESPONDERCEREMONY_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RESPONDERCEREMONY_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_responderceremony_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RETENTIONMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_RETENTIONMANAGER_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_retentionmanager_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_SECRETBYTES_FROM_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_SECRETBYTES_FROM_BYTES
+uint16_t uniffi_ash_bindings_checksum_constructor_secretbytes_from_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_TOPUPRECEIVER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_TOPUPRECEIVER_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_topupreceiver_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_TOPUPSENDER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_ASH_BINDINGS_CHECKSUM_CONSTRUCTOR_TOPUPSENDER_NEW
+uint16_t uniffi_ash_bindings_checksum_constructor_topupsender_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_ASH_BINDINGS_UNIFFI_CONTRACT_VERSION
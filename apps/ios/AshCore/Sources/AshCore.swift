@@ -544,6 +544,293 @@ fileprivate struct FfiConverterString: FfiConverter {
 
 
 
+/**
+ * Hash-chained record of consumed pad ranges.
+ * Persist it next to the pad and call check() after every load to detect
+ * a pad restored from an older backup.
+ */
+public protocol ConsumptionJournalProtocol : AnyObject {
+    
+    /**
+     * Check that the pad has not been rolled back behind the journal.
+     */
+    func check(pad: Pad) throws 
+    
+    /**
+     * Check that sending n bytes would not reuse a recorded range.
+     */
+    func checkSend(pad: Pad, n: UInt32) throws 
+    
+    /**
+     * Consume pad bytes for an outgoing message and record the range.
+     * The key stays in Rust memory; see SecretBytes.
+     */
+    func consume(pad: Pad, n: UInt32) throws  -> SecretBytes
+    
+    /**
+     * Encode the journal for storage.
+     */
+    func encode()  -> [UInt8]
+    
+    /**
+     * Hash of the latest entry (32 bytes).
+     */
+    func head()  -> [UInt8]
+    
+    /**
+     * Bytes recorded as consumed in our direction.
+     */
+    func ownConsumed()  -> UInt64
+    
+    /**
+     * Bytes the peer has been observed consuming.
+     */
+    func peerConsumed()  -> UInt64
+    
+    /**
+     * Record the range of an authenticated message from the peer.
+     * offset is the peer's send_position when it sent the message.
+     */
+    func recordReceived(pad: Pad, offset: UInt64, len: UInt64) throws 
+    
+    /**
+     * Our role in the conversation.
+     */
+    func role()  -> Role
+    
+}
+
+/**
+ * Hash-chained record of consumed pad ranges.
+ * Persist it next to the pad and call check() after every load to detect
+ * a pad restored from an older backup.
+ */
+open class ConsumptionJournal:
+    ConsumptionJournalProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_consumptionjournal(self.pointer, $0) }
+    }
+    /**
+     * Create an empty journal for our role.
+     */
+public convenience init(role: Role) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_consumptionjournal_new(
+        FfiConverterTypeRole.lower(role),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_consumptionjournal(pointer, $0) }
+    }
+
+    
+    /**
+     * Decode a stored journal, verifying the whole hash chain.
+     */
+public static func decode(bytes: [UInt8])throws  -> ConsumptionJournal {
+    return try  FfiConverterTypeConsumptionJournal.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_constructor_consumptionjournal_decode(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Check that the pad has not been rolled back behind the journal.
+     */
+open func check(pad: Pad)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_consumptionjournal_check(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),$0
+    )
+}
+}
+    
+    /**
+     * Check that sending n bytes would not reuse a recorded range.
+     */
+open func checkSend(pad: Pad, n: UInt32)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_consumptionjournal_check_send(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt32.lower(n),$0
+    )
+}
+}
+    
+    /**
+     * Consume pad bytes for an outgoing message and record the range.
+     * The key stays in Rust memory; see SecretBytes.
+     */
+open func consume(pad: Pad, n: UInt32)throws  -> SecretBytes {
+    return try  FfiConverterTypeSecretBytes.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_consumptionjournal_consume(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt32.lower(n),$0
+    )
+})
+}
+    
+    /**
+     * Encode the journal for storage.
+     */
+open func encode() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_consumptionjournal_encode(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Hash of the latest entry (32 bytes).
+     */
+open func head() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_consumptionjournal_head(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Bytes recorded as consumed in our direction.
+     */
+open func ownConsumed() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_consumptionjournal_own_consumed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Bytes the peer has been observed consuming.
+     */
+open func peerConsumed() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_consumptionjournal_peer_consumed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Record the range of an authenticated message from the peer.
+     * offset is the peer's send_position when it sent the message.
+     */
+open func recordReceived(pad: Pad, offset: UInt64, len: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_consumptionjournal_record_received(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),$0
+    )
+}
+}
+    
+    /**
+     * Our role in the conversation.
+     */
+open func role() -> Role {
+    return try!  FfiConverterTypeRole.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_consumptionjournal_role(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConsumptionJournal: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ConsumptionJournal
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ConsumptionJournal {
+        return ConsumptionJournal(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ConsumptionJournal) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConsumptionJournal {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ConsumptionJournal, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeConsumptionJournal_lift(_ pointer: UnsafeMutableRawPointer) throws -> ConsumptionJournal {
+    return try FfiConverterTypeConsumptionJournal.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeConsumptionJournal_lower(_ value: ConsumptionJournal) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeConsumptionJournal.lower(value)
+}
+
+
+
+
 /**
  * Fountain frame generator for QR display.
  *
@@ -1040,101 +1327,89 @@ public nonisolated func FfiConverterTypeFountainFrameReceiver_lower(_ value: Fou
 
 
 /**
- * One-Time Pad with bidirectional consumption semantics
- *
- * The pad uses bidirectional consumption:
- * - Initiator consumes from the start of the pad
- * - Responder consumes from the end of the pad
+ * Ceremony driver for the initiator (creates the pad, displays QR codes).
  *
- * This allows both parties to send messages without coordination.
+ * Enforces step ordering and refuses to derive tokens before the
+ * mnemonic has been confirmed by both parties.
  */
-public protocol PadProtocol : AnyObject {
+public protocol InitiatorCeremonyProtocol : AnyObject {
     
     /**
-     * Get raw bytes for ceremony transfer
+     * Abort the ceremony and wipe the pad.
      */
-    func asBytes()  -> [UInt8]
+    func abort() 
     
     /**
-     * Get the number of bytes available for sending (dynamic allocation)
+     * Record that both parties saw the same mnemonic.
      */
-    func availableForSending(role: Role)  -> UInt64
+    func confirmMnemonic() throws 
     
     /**
-     * Check if we can send a message of the given length (dynamic allocation)
+     * Derive tokens and hand out the conversation bundle (Confirmed only).
      */
-    func canSend(length: UInt32, role: Role)  -> Bool
+    func finish() throws  -> ConversationBundle
     
     /**
-     * Consume next n bytes from pad based on role
-     * - Initiator consumes from the start (moving forward)
-     * - Responder consumes from the end (moving backward)
+     * Stop displaying frames and move on to mnemonic verification.
      */
-    func consume(n: UInt32, role: Role) throws  -> [UInt8]
+    func finishTransfer() throws 
     
     /**
-     * Get total consumed bytes count (both directions)
+     * Mnemonic to compare with the responder.
      */
-    func consumed()  -> UInt64
+    func mnemonic() throws  -> [String]
     
     /**
-     * Get bytes consumed from end (by Responder)
+     * Generate the next QR frame (Transferring only).
      */
-    func consumedBack()  -> UInt64
+    func nextFrame() throws  -> [UInt8]
     
     /**
-     * Get bytes consumed from start (by Initiator)
+     * Record that the mnemonics differ. Aborts the ceremony.
      */
-    func consumedFront()  -> UInt64
+    func rejectMnemonic() throws 
     
     /**
-     * Check if pad is exhausted
+     * Set the QR block size in bytes (Configuring only).
      */
-    func isExhausted()  -> Bool
+    func setBlockSize(blockSize: UInt32) throws 
     
     /**
-     * Get the offset for the next message we send
+     * Set the metadata transferred with the pad (Configuring only).
      */
-    func nextSendOffset(role: Role)  -> UInt64
+    func setMetadata(metadata: CeremonyMetadata) throws 
     
     /**
-     * Get remaining bytes count (available for either role)
+     * Set the passphrase used to encrypt QR frames (Configuring only).
      */
-    func remaining()  -> UInt64
+    func setPassphrase(passphrase: String) throws 
     
     /**
-     * Get total pad size
+     * Number of source blocks (0 unless Transferring).
      */
-    func totalSize()  -> UInt64
+    func sourceCount()  -> UInt32
     
     /**
-     * Update peer's consumption based on a received message
+     * Create the pad from entropy and start generating frames.
+     * The entropy length must match the pad size.
      */
-    func updatePeerConsumption(peerRole: Role, newConsumed: UInt64) 
+    func startTransfer(entropy: [UInt8]) throws 
     
     /**
-     * Securely zero bytes at a specific offset (for forward secrecy).
-     *
-     * When a message expires, this zeros the key material used to encrypt it,
-     * preventing future decryption even if the pad is compromised.
-     *
-     * Returns true if bytes were zeroed, false if offset/length is out of bounds.
+     * Current ceremony state.
      */
-    func zeroBytesAt(offset: UInt64, length: UInt64)  -> Bool
+    func state()  -> CeremonyState
     
 }
 
 /**
- * One-Time Pad with bidirectional consumption semantics
- *
- * The pad uses bidirectional consumption:
- * - Initiator consumes from the start of the pad
- * - Responder consumes from the end of the pad
+ * Ceremony driver for the initiator (creates the pad, displays QR codes).
  *
- * This allows both parties to send messages without coordination.
+ * Enforces step ordering and refuses to derive tokens before the
+ * mnemonic has been confirmed by both parties.
  */
-open class Pad:
-    PadProtocol {
+open class InitiatorCeremony:
+    InitiatorCeremonyProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1168,201 +1443,155 @@ open class Pad:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_ash_bindings_fn_clone_pad(self.pointer, $0) }
+        return try! rustCall { uniffi_ash_bindings_fn_clone_initiatorceremony(self.pointer, $0) }
     }
-    // No primary constructor declared for this class.
-
-    deinit {
-        guard let pointer = pointer else {
-            return
-        }
-
-        try! rustCall { uniffi_ash_bindings_fn_free_pad(pointer, $0) }
-    }
-
-    
-    /**
-     * Create pad from raw bytes (for reconstruction)
-     */
-public static func fromBytes(bytes: [UInt8]) -> Pad {
-    return try!  FfiConverterTypePad.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_constructor_pad_from_bytes(
-        FfiConverterSequenceUInt8.lower(bytes),$0
-    )
-})
-}
-    
     /**
-     * Create pad from raw bytes with pre-existing consumption state
-     * Used when restoring a pad from persistent storage.
+     * Create a new ceremony in the Configuring state.
      */
-public static func fromBytesWithState(bytes: [UInt8], consumedFront: UInt64, consumedBack: UInt64) -> Pad {
-    return try!  FfiConverterTypePad.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_constructor_pad_from_bytes_with_state(
-        FfiConverterSequenceUInt8.lower(bytes),
-        FfiConverterUInt64.lower(consumedFront),
-        FfiConverterUInt64.lower(consumedBack),$0
+public convenience init(padSize: PadSize) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_initiatorceremony_new(
+        FfiConverterTypePadSize.lower(padSize),$0
     )
-})
 }
-    
-    /**
-     * Create pad from entropy bytes with a specific size.
-     * The entropy length must match the specified pad size.
-     */
-public static func fromEntropy(entropy: [UInt8], size: PadSize)throws  -> Pad {
-    return try  FfiConverterTypePad.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
-    uniffi_ash_bindings_fn_constructor_pad_from_entropy(
-        FfiConverterSequenceUInt8.lower(entropy),
-        FfiConverterTypePadSize.lower(size),$0
-    )
-})
+    self.init(unsafeFromRawPointer: pointer)
 }
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_initiatorceremony(pointer, $0) }
+    }
+
     
 
     
     /**
-     * Get raw bytes for ceremony transfer
+     * Abort the ceremony and wipe the pad.
      */
-open func asBytes() -> [UInt8] {
-    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_as_bytes(self.uniffiClonePointer(),$0
+open func abort() {try! rustCall() {
+    uniffi_ash_bindings_fn_method_initiatorceremony_abort(self.uniffiClonePointer(),$0
     )
-})
+}
 }
     
     /**
-     * Get the number of bytes available for sending (dynamic allocation)
+     * Record that both parties saw the same mnemonic.
      */
-open func availableForSending(role: Role) -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_available_for_sending(self.uniffiClonePointer(),
-        FfiConverterTypeRole.lower(role),$0
+open func confirmMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_confirm_mnemonic(self.uniffiClonePointer(),$0
     )
-})
+}
 }
     
     /**
-     * Check if we can send a message of the given length (dynamic allocation)
+     * Derive tokens and hand out the conversation bundle (Confirmed only).
      */
-open func canSend(length: UInt32, role: Role) -> Bool {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_can_send(self.uniffiClonePointer(),
-        FfiConverterUInt32.lower(length),
-        FfiConverterTypeRole.lower(role),$0
+open func finish()throws  -> ConversationBundle {
+    return try  FfiConverterTypeConversationBundle.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_finish(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Consume next n bytes from pad based on role
-     * - Initiator consumes from the start (moving forward)
-     * - Responder consumes from the end (moving backward)
+     * Stop displaying frames and move on to mnemonic verification.
      */
-open func consume(n: UInt32, role: Role)throws  -> [UInt8] {
-    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
-    uniffi_ash_bindings_fn_method_pad_consume(self.uniffiClonePointer(),
-        FfiConverterUInt32.lower(n),
-        FfiConverterTypeRole.lower(role),$0
+open func finishTransfer()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_finish_transfer(self.uniffiClonePointer(),$0
     )
-})
+}
 }
     
     /**
-     * Get total consumed bytes count (both directions)
+     * Mnemonic to compare with the responder.
      */
-open func consumed() -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_consumed(self.uniffiClonePointer(),$0
+open func mnemonic()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_mnemonic(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Get bytes consumed from end (by Responder)
+     * Generate the next QR frame (Transferring only).
      */
-open func consumedBack() -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_consumed_back(self.uniffiClonePointer(),$0
+open func nextFrame()throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_next_frame(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Get bytes consumed from start (by Initiator)
+     * Record that the mnemonics differ. Aborts the ceremony.
      */
-open func consumedFront() -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_consumed_front(self.uniffiClonePointer(),$0
+open func rejectMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_reject_mnemonic(self.uniffiClonePointer(),$0
     )
-})
+}
 }
     
     /**
-     * Check if pad is exhausted
+     * Set the QR block size in bytes (Configuring only).
      */
-open func isExhausted() -> Bool {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_is_exhausted(self.uniffiClonePointer(),$0
+open func setBlockSize(blockSize: UInt32)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_set_block_size(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(blockSize),$0
     )
-})
+}
 }
     
     /**
-     * Get the offset for the next message we send
+     * Set the metadata transferred with the pad (Configuring only).
      */
-open func nextSendOffset(role: Role) -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_next_send_offset(self.uniffiClonePointer(),
-        FfiConverterTypeRole.lower(role),$0
+open func setMetadata(metadata: CeremonyMetadata)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_set_metadata(self.uniffiClonePointer(),
+        FfiConverterTypeCeremonyMetadata.lower(metadata),$0
     )
-})
+}
 }
     
     /**
-     * Get remaining bytes count (available for either role)
+     * Set the passphrase used to encrypt QR frames (Configuring only).
      */
-open func remaining() -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_remaining(self.uniffiClonePointer(),$0
+open func setPassphrase(passphrase: String)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_set_passphrase(self.uniffiClonePointer(),
+        FfiConverterString.lower(passphrase),$0
     )
-})
+}
 }
     
     /**
-     * Get total pad size
+     * Number of source blocks (0 unless Transferring).
      */
-open func totalSize() -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_total_size(self.uniffiClonePointer(),$0
+open func sourceCount() -> UInt32 {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_initiatorceremony_source_count(self.uniffiClonePointer(),$0
     )
 })
 }
     
     /**
-     * Update peer's consumption based on a received message
+     * Create the pad from entropy and start generating frames.
+     * The entropy length must match the pad size.
      */
-open func updatePeerConsumption(peerRole: Role, newConsumed: UInt64) {try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_update_peer_consumption(self.uniffiClonePointer(),
-        FfiConverterTypeRole.lower(peerRole),
-        FfiConverterUInt64.lower(newConsumed),$0
+open func startTransfer(entropy: [UInt8])throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_initiatorceremony_start_transfer(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(entropy),$0
     )
 }
 }
     
     /**
-     * Securely zero bytes at a specific offset (for forward secrecy).
-     *
-     * When a message expires, this zeros the key material used to encrypt it,
-     * preventing future decryption even if the pad is compromised.
-     *
-     * Returns true if bytes were zeroed, false if offset/length is out of bounds.
+     * Current ceremony state.
      */
-open func zeroBytesAt(offset: UInt64, length: UInt64) -> Bool {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_ash_bindings_fn_method_pad_zero_bytes_at(self.uniffiClonePointer(),
-        FfiConverterUInt64.lower(offset),
-        FfiConverterUInt64.lower(length),$0
+open func state() -> CeremonyState {
+    return try!  FfiConverterTypeCeremonyState.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_initiatorceremony_state(self.uniffiClonePointer(),$0
     )
 })
 }
@@ -1373,20 +1602,20 @@ open func zeroBytesAt(offset: UInt64, length: UInt64) -> Bool {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePad: FfiConverter {
+public struct FfiConverterTypeInitiatorCeremony: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = Pad
+    typealias SwiftType = InitiatorCeremony
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Pad {
-        return Pad(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> InitiatorCeremony {
+        return InitiatorCeremony(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: Pad) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: InitiatorCeremony) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Pad {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InitiatorCeremony {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1397,7 +1626,7 @@ public struct FfiConverterTypePad: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: Pad, into buf: inout [UInt8]) {
+    public static func write(_ value: InitiatorCeremony, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1410,73 +1639,3237 @@ public struct FfiConverterTypePad: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypePad_lift(_ pointer: UnsafeMutableRawPointer) throws -> Pad {
-    return try FfiConverterTypePad.lift(pointer)
+public nonisolated func FfiConverterTypeInitiatorCeremony_lift(_ pointer: UnsafeMutableRawPointer) throws -> InitiatorCeremony {
+    return try FfiConverterTypeInitiatorCeremony.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypePad_lower(_ value: Pad) -> UnsafeMutableRawPointer {
-    return FfiConverterTypePad.lower(value)
+public nonisolated func FfiConverterTypeInitiatorCeremony_lower(_ value: InitiatorCeremony) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeInitiatorCeremony.lower(value)
 }
 
 
+
+
 /**
- * Authorization tokens derived from pad during ceremony
+ * One-Time Pad with bidirectional consumption semantics
+ *
+ * The pad uses bidirectional consumption:
+ * - Initiator consumes from the start of the pad
+ * - Responder consumes from the end of the pad
+ *
+ * This allows both parties to send messages without coordination.
  */
-public struct AuthTokens {
+public protocol PadProtocol : AnyObject {
+    
     /**
-     * Conversation ID (hex-encoded, 64 chars)
+     * Get raw bytes for ceremony transfer
      */
-    public let conversationId: String
+    func asBytes()  -> [UInt8]
+    
     /**
-     * Auth token for API operations (hex-encoded, 64 chars)
+     * Get raw bytes like as_bytes, as a copy of the whole pad that stays
+     * in Rust memory. See SecretBytes.
      */
-    public let authToken: String
+    func asSecretBytes()  -> SecretBytes
+    
     /**
-     * Burn token for burn operations (hex-encoded, 64 chars)
+     * Get the number of bytes available for sending (dynamic allocation)
      */
-    public let burnToken: String
+    func availableForSending(role: Role)  -> UInt64
+    
+    /**
+     * Check if we can send a message of the given length (dynamic allocation)
+     */
+    func canSend(length: UInt32, role: Role)  -> Bool
+    
+    /**
+     * Check if a normal message fits without touching the burn/control reserve
+     */
+    func canSendText(plaintextLen: UInt32, config: ForecastConfig)  -> Bool
+    
+    /**
+     * Consume next n bytes from pad based on role
+     * - Initiator consumes from the start (moving forward)
+     * - Responder consumes from the end (moving backward)
+     */
+    func consume(n: UInt32, role: Role) throws  -> [UInt8]
+    
+    /**
+     * Consume next n bytes like consume, keeping the key in Rust memory.
+     * See SecretBytes.
+     */
+    func consumeSecret(n: UInt32, role: Role) throws  -> SecretBytes
+    
+    /**
+     * Get total consumed bytes count (both directions)
+     */
+    func consumed()  -> UInt64
+    
+    /**
+     * Get bytes consumed from end (by Responder)
+     */
+    func consumedBack()  -> UInt64
+    
+    /**
+     * Get bytes consumed from start (by Initiator)
+     */
+    func consumedFront()  -> UInt64
+    
+    /**
+     * Forecast remaining capacity using real per-message cost and the
+     * observed peer consumption
+     */
+    func forecast(role: Role, config: ForecastConfig)  -> CapacityForecast
+    
+    /**
+     * Check if pad is exhausted
+     */
+    func isExhausted()  -> Bool
+    
+    /**
+     * Read the key material of a peer message without consuming it.
+     * offset is the peer's send_position when it sent the message.
+     * The key stays in Rust memory; see SecretBytes.
+     */
+    func keyAt(offset: UInt64, len: UInt64, peerRole: Role) throws  -> SecretBytes
+    
+    /**
+     * Get the offset for the next message we send
+     */
+    func nextSendOffset(role: Role)  -> UInt64
+    
+    /**
+     * Get remaining bytes count (available for either role)
+     */
+    func remaining()  -> UInt64
+    
+    /**
+     * Get the position of the next message we send within our direction
+     * (bytes consumed from our end). Does not move when a top-up grows the
+     * pad; this is the offset to carry with a message.
+     */
+    func sendPosition(role: Role)  -> UInt64
+    
+    /**
+     * Get total pad size
+     */
+    func totalSize()  -> UInt64
+    
+    /**
+     * Update peer's consumption based on a received message
+     *
+     * Throws PadRangeReuse if the claim overlaps our own consumed bytes.
+     */
+    func updatePeerConsumption(peerRole: Role, newConsumed: UInt64) throws 
+    
+    /**
+     * Securely zero bytes at a specific offset (for forward secrecy).
+     *
+     * When a message expires, this zeros the key material used to encrypt it,
+     * preventing future decryption even if the pad is compromised.
+     *
+     * Returns true if bytes were zeroed, false if offset/length is out of bounds.
+     */
+    func zeroBytesAt(offset: UInt64, length: UInt64)  -> Bool
+    
+}
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Conversation ID (hex-encoded, 64 chars)
-         */conversationId: String, 
-        /**
-         * Auth token for API operations (hex-encoded, 64 chars)
+/**
+ * One-Time Pad with bidirectional consumption semantics
+ *
+ * The pad uses bidirectional consumption:
+ * - Initiator consumes from the start of the pad
+ * - Responder consumes from the end of the pad
+ *
+ * This allows both parties to send messages without coordination.
+ */
+open class Pad:
+    PadProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_pad(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_pad(pointer, $0) }
+    }
+
+    
+    /**
+     * Create pad from raw bytes (for reconstruction)
+     */
+public static func fromBytes(bytes: [UInt8]) -> Pad {
+    return try!  FfiConverterTypePad.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_pad_from_bytes(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
+    /**
+     * Create pad from raw bytes with pre-existing consumption state
+     * Used when restoring a pad from persistent storage.
+     */
+public static func fromBytesWithState(bytes: [UInt8], consumedFront: UInt64, consumedBack: UInt64) -> Pad {
+    return try!  FfiConverterTypePad.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_pad_from_bytes_with_state(
+        FfiConverterSequenceUInt8.lower(bytes),
+        FfiConverterUInt64.lower(consumedFront),
+        FfiConverterUInt64.lower(consumedBack),$0
+    )
+})
+}
+    
+    /**
+     * Create pad from entropy bytes with a specific size.
+     * The entropy length must match the specified pad size.
+     */
+public static func fromEntropy(entropy: [UInt8], size: PadSize)throws  -> Pad {
+    return try  FfiConverterTypePad.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_constructor_pad_from_entropy(
+        FfiConverterSequenceUInt8.lower(entropy),
+        FfiConverterTypePadSize.lower(size),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Get raw bytes for ceremony transfer
+     */
+open func asBytes() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_as_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get raw bytes like as_bytes, as a copy of the whole pad that stays
+     * in Rust memory. See SecretBytes.
+     */
+open func asSecretBytes() -> SecretBytes {
+    return try!  FfiConverterTypeSecretBytes.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_as_secret_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the number of bytes available for sending (dynamic allocation)
+     */
+open func availableForSending(role: Role) -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_available_for_sending(self.uniffiClonePointer(),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Check if we can send a message of the given length (dynamic allocation)
+     */
+open func canSend(length: UInt32, role: Role) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_can_send(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(length),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Check if a normal message fits without touching the burn/control reserve
+     */
+open func canSendText(plaintextLen: UInt32, config: ForecastConfig) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_can_send_text(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(plaintextLen),
+        FfiConverterTypeForecastConfig.lower(config),$0
+    )
+})
+}
+    
+    /**
+     * Consume next n bytes from pad based on role
+     * - Initiator consumes from the start (moving forward)
+     * - Responder consumes from the end (moving backward)
+     */
+open func consume(n: UInt32, role: Role)throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_pad_consume(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(n),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Consume next n bytes like consume, keeping the key in Rust memory.
+     * See SecretBytes.
+     */
+open func consumeSecret(n: UInt32, role: Role)throws  -> SecretBytes {
+    return try  FfiConverterTypeSecretBytes.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_pad_consume_secret(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(n),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Get total consumed bytes count (both directions)
+     */
+open func consumed() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_consumed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get bytes consumed from end (by Responder)
+     */
+open func consumedBack() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_consumed_back(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get bytes consumed from start (by Initiator)
+     */
+open func consumedFront() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_consumed_front(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Forecast remaining capacity using real per-message cost and the
+     * observed peer consumption
+     */
+open func forecast(role: Role, config: ForecastConfig) -> CapacityForecast {
+    return try!  FfiConverterTypeCapacityForecast.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_forecast(self.uniffiClonePointer(),
+        FfiConverterTypeRole.lower(role),
+        FfiConverterTypeForecastConfig.lower(config),$0
+    )
+})
+}
+    
+    /**
+     * Check if pad is exhausted
+     */
+open func isExhausted() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_is_exhausted(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Read the key material of a peer message without consuming it.
+     * offset is the peer's send_position when it sent the message.
+     * The key stays in Rust memory; see SecretBytes.
+     */
+open func keyAt(offset: UInt64, len: UInt64, peerRole: Role)throws  -> SecretBytes {
+    return try  FfiConverterTypeSecretBytes.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_pad_key_at(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),
+        FfiConverterTypeRole.lower(peerRole),$0
+    )
+})
+}
+    
+    /**
+     * Get the offset for the next message we send
+     */
+open func nextSendOffset(role: Role) -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_next_send_offset(self.uniffiClonePointer(),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Get remaining bytes count (available for either role)
+     */
+open func remaining() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_remaining(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Get the position of the next message we send within our direction
+     * (bytes consumed from our end). Does not move when a top-up grows the
+     * pad; this is the offset to carry with a message.
+     */
+open func sendPosition(role: Role) -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_send_position(self.uniffiClonePointer(),
+        FfiConverterTypeRole.lower(role),$0
+    )
+})
+}
+    
+    /**
+     * Get total pad size
+     */
+open func totalSize() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_total_size(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Update peer's consumption based on a received message
+     *
+     * Throws PadRangeReuse if the claim overlaps our own consumed bytes.
+     */
+open func updatePeerConsumption(peerRole: Role, newConsumed: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_pad_update_peer_consumption(self.uniffiClonePointer(),
+        FfiConverterTypeRole.lower(peerRole),
+        FfiConverterUInt64.lower(newConsumed),$0
+    )
+}
+}
+    
+    /**
+     * Securely zero bytes at a specific offset (for forward secrecy).
+     *
+     * When a message expires, this zeros the key material used to encrypt it,
+     * preventing future decryption even if the pad is compromised.
+     *
+     * Returns true if bytes were zeroed, false if offset/length is out of bounds.
+     */
+open func zeroBytesAt(offset: UInt64, length: UInt64) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_pad_zero_bytes_at(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(length),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePad: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = Pad
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Pad {
+        return Pad(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: Pad) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Pad {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: Pad, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePad_lift(_ pointer: UnsafeMutableRawPointer) throws -> Pad {
+    return try FfiConverterTypePad.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePad_lower(_ value: Pad) -> UnsafeMutableRawPointer {
+    return FfiConverterTypePad.lower(value)
+}
+
+
+
+
+/**
+ * Pad ranges of messages already accepted from the peer.
+ * Use receive() for the whole receive path, or call check() before
+ * decrypting and accept() once the tag verified. Persist encode() next to
+ * the pad state.
+ */
+public protocol ReceiveWindowProtocol : AnyObject {
+    
+    /**
+     * Record an authenticated message from the peer.
+     */
+    func accept(pad: Pad, offset: UInt64, len: UInt64) throws 
+    
+    /**
+     * Reject a message that overlaps an accepted one. Does not touch the pad.
+     * offset is the peer's send_position when it sent the message.
+     */
+    func check(pad: Pad, offset: UInt64, len: UInt64) throws 
+    
+    /**
+     * Encode the window for storage.
+     */
+    func encode()  -> [UInt8]
+    
+    /**
+     * Peer ranges below the furthest accepted message not yet received.
+     */
+    func gaps()  -> [ReceiveGap]
+    
+    /**
+     * End of the furthest accepted range, from the peer's origin.
+     */
+    func highest()  -> UInt64
+    
+    /**
+     * Verify and decrypt a peer message in any arrival order. Rejects
+     * replays before reading the pad, then accepts the message and advances
+     * the peer consumption. The frame must come from
+     * encrypt_authenticated_at, which carries the pad offset in its header.
+     */
+    func receive(pad: Pad, encodedFrame: [UInt8]) throws  -> DecryptedMessage
+    
+    /**
+     * Our role in the conversation.
+     */
+    func role()  -> Role
+    
+}
+
+/**
+ * Pad ranges of messages already accepted from the peer.
+ * Use receive() for the whole receive path, or call check() before
+ * decrypting and accept() once the tag verified. Persist encode() next to
+ * the pad state.
+ */
+open class ReceiveWindow:
+    ReceiveWindowProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_receivewindow(self.pointer, $0) }
+    }
+    /**
+     * Create an empty window for our role.
+     */
+public convenience init(role: Role) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_receivewindow_new(
+        FfiConverterTypeRole.lower(role),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_receivewindow(pointer, $0) }
+    }
+
+    
+    /**
+     * Decode a stored window.
+     */
+public static func decode(bytes: [UInt8])throws  -> ReceiveWindow {
+    return try  FfiConverterTypeReceiveWindow.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_constructor_receivewindow_decode(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Record an authenticated message from the peer.
+     */
+open func accept(pad: Pad, offset: UInt64, len: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_receivewindow_accept(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),$0
+    )
+}
+}
+    
+    /**
+     * Reject a message that overlaps an accepted one. Does not touch the pad.
+     * offset is the peer's send_position when it sent the message.
+     */
+open func check(pad: Pad, offset: UInt64, len: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_receivewindow_check(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),$0
+    )
+}
+}
+    
+    /**
+     * Encode the window for storage.
+     */
+open func encode() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_receivewindow_encode(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Peer ranges below the furthest accepted message not yet received.
+     */
+open func gaps() -> [ReceiveGap] {
+    return try!  FfiConverterSequenceTypeReceiveGap.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_receivewindow_gaps(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * End of the furthest accepted range, from the peer's origin.
+     */
+open func highest() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_receivewindow_highest(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Verify and decrypt a peer message in any arrival order. Rejects
+     * replays before reading the pad, then accepts the message and advances
+     * the peer consumption. The frame must come from
+     * encrypt_authenticated_at, which carries the pad offset in its header.
+     */
+open func receive(pad: Pad, encodedFrame: [UInt8])throws  -> DecryptedMessage {
+    return try  FfiConverterTypeDecryptedMessage.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_receivewindow_receive(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterSequenceUInt8.lower(encodedFrame),$0
+    )
+})
+}
+    
+    /**
+     * Our role in the conversation.
+     */
+open func role() -> Role {
+    return try!  FfiConverterTypeRole.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_receivewindow_role(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReceiveWindow: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ReceiveWindow
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ReceiveWindow {
+        return ReceiveWindow(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ReceiveWindow) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReceiveWindow {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ReceiveWindow, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeReceiveWindow_lift(_ pointer: UnsafeMutableRawPointer) throws -> ReceiveWindow {
+    return try FfiConverterTypeReceiveWindow.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeReceiveWindow_lower(_ value: ReceiveWindow) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeReceiveWindow.lower(value)
+}
+
+
+
+
+/**
+ * Ceremony driver for the responder (scans QR codes, receives the pad).
+ */
+public protocol ResponderCeremonyProtocol : AnyObject {
+    
+    /**
+     * Abort the ceremony and wipe the received pad.
+     */
+    func abort() 
+    
+    /**
+     * Add a scanned QR frame.
+     * Returns true once the pad is decoded (moves to VerifyingMnemonic).
+     */
+    func addFrame(frameBytes: [UInt8]) throws  -> Bool
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+    func confirmMnemonic() throws 
+    
+    /**
+     * Derive tokens and hand out the conversation bundle (Confirmed only).
+     */
+    func finish() throws  -> ConversationBundle
+    
+    /**
+     * Metadata received from the initiator (null until transfer completes).
+     */
+    func metadata()  -> CeremonyMetadata?
+    
+    /**
+     * Mnemonic to compare with the initiator.
+     */
+    func mnemonic() throws  -> [String]
+    
+    /**
+     * Decoding progress (0.0 to 1.0).
+     */
+    func progress()  -> Double
+    
+    /**
+     * Record that the mnemonics differ. Aborts the ceremony.
+     */
+    func rejectMnemonic() throws 
+    
+    /**
+     * Set the passphrase used to decrypt QR frames (Configuring only).
+     */
+    func setPassphrase(passphrase: String) throws 
+    
+    /**
+     * Start accepting scanned frames.
+     */
+    func startTransfer() throws 
+    
+    /**
+     * Current ceremony state.
+     */
+    func state()  -> CeremonyState
+    
+}
+
+/**
+ * Ceremony driver for the responder (scans QR codes, receives the pad).
+ */
+open class ResponderCeremony:
+    ResponderCeremonyProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_responderceremony(self.pointer, $0) }
+    }
+    /**
+     * Create a new ceremony in the Configuring state.
+     */
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_responderceremony_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_responderceremony(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Abort the ceremony and wipe the received pad.
+     */
+open func abort() {try! rustCall() {
+    uniffi_ash_bindings_fn_method_responderceremony_abort(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Add a scanned QR frame.
+     * Returns true once the pad is decoded (moves to VerifyingMnemonic).
+     */
+open func addFrame(frameBytes: [UInt8])throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_add_frame(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(frameBytes),$0
+    )
+})
+}
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+open func confirmMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_confirm_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Derive tokens and hand out the conversation bundle (Confirmed only).
+     */
+open func finish()throws  -> ConversationBundle {
+    return try  FfiConverterTypeConversationBundle.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_finish(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Metadata received from the initiator (null until transfer completes).
+     */
+open func metadata() -> CeremonyMetadata? {
+    return try!  FfiConverterOptionTypeCeremonyMetadata.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_responderceremony_metadata(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Mnemonic to compare with the initiator.
+     */
+open func mnemonic()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_mnemonic(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Decoding progress (0.0 to 1.0).
+     */
+open func progress() -> Double {
+    return try!  FfiConverterDouble.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_responderceremony_progress(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Record that the mnemonics differ. Aborts the ceremony.
+     */
+open func rejectMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_reject_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Set the passphrase used to decrypt QR frames (Configuring only).
+     */
+open func setPassphrase(passphrase: String)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_set_passphrase(self.uniffiClonePointer(),
+        FfiConverterString.lower(passphrase),$0
+    )
+}
+}
+    
+    /**
+     * Start accepting scanned frames.
+     */
+open func startTransfer()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_responderceremony_start_transfer(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Current ceremony state.
+     */
+open func state() -> CeremonyState {
+    return try!  FfiConverterTypeCeremonyState.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_responderceremony_state(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeResponderCeremony: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ResponderCeremony
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ResponderCeremony {
+        return ResponderCeremony(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ResponderCeremony) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ResponderCeremony {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ResponderCeremony, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeResponderCeremony_lift(_ pointer: UnsafeMutableRawPointer) throws -> ResponderCeremony {
+    return try FfiConverterTypeResponderCeremony.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeResponderCeremony_lower(_ value: ResponderCeremony) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeResponderCeremony.lower(value)
+}
+
+
+
+
+/**
+ * Records the pad range of every message and wipes it once the key is no
+ * longer needed (forward secrecy). Times are Unix seconds from the caller.
+ */
+public protocol RetentionManagerProtocol : AnyObject {
+    
+    /**
+     * Mark a message delivered. Returns false if the ID is unknown.
+     */
+    func markDelivered(id: UInt64, now: UInt64)  -> Bool
+    
+    /**
+     * Mark a message viewed, starting its disappearing timer.
+     * Returns false if the ID is unknown.
+     */
+    func markViewed(id: UInt64, now: UInt64)  -> Bool
+    
+    /**
+     * Earliest time a retained message becomes due, if any.
+     */
+    func nextDue()  -> UInt64?
+    
+    /**
+     * The active policy.
+     */
+    func policy()  -> RetentionPolicy
+    
+    /**
+     * Record a received message.
+     * offset is the peer's send_position when it sent the message.
+     */
+    func recordReceived(pad: Pad, id: UInt64, offset: UInt64, len: UInt64, now: UInt64) throws 
+    
+    /**
+     * Record a sent message.
+     * offset is our send_position before the message consumed its bytes.
+     */
+    func recordSent(pad: Pad, id: UInt64, offset: UInt64, len: UInt64, now: UInt64) throws 
+    
+    /**
+     * Replace the policy. Takes effect at the next sweep.
+     */
+    func setPolicy(policy: RetentionPolicy) 
+    
+    /**
+     * Summarize which key material is still recoverable from the pad.
+     */
+    func summary(pad: Pad)  -> RetentionSummary
+    
+    /**
+     * Wipe every message whose key is due at `now`.
+     */
+    func sweep(pad: Pad, now: UInt64)  -> [WipedMessage]
+    
+    /**
+     * Wipe a message's key now, regardless of policy.
+     * Returns false if the ID is unknown.
+     */
+    func wipe(pad: Pad, id: UInt64)  -> Bool
+    
+}
+
+/**
+ * Records the pad range of every message and wipes it once the key is no
+ * longer needed (forward secrecy). Times are Unix seconds from the caller.
+ */
+open class RetentionManager:
+    RetentionManagerProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_retentionmanager(self.pointer, $0) }
+    }
+    /**
+     * Create an empty manager for our role.
+     */
+public convenience init(role: Role, policy: RetentionPolicy) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_retentionmanager_new(
+        FfiConverterTypeRole.lower(role),
+        FfiConverterTypeRetentionPolicy.lower(policy),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_retentionmanager(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Mark a message delivered. Returns false if the ID is unknown.
+     */
+open func markDelivered(id: UInt64, now: UInt64) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_mark_delivered(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt64.lower(now),$0
+    )
+})
+}
+    
+    /**
+     * Mark a message viewed, starting its disappearing timer.
+     * Returns false if the ID is unknown.
+     */
+open func markViewed(id: UInt64, now: UInt64) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_mark_viewed(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt64.lower(now),$0
+    )
+})
+}
+    
+    /**
+     * Earliest time a retained message becomes due, if any.
+     */
+open func nextDue() -> UInt64? {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_next_due(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * The active policy.
+     */
+open func policy() -> RetentionPolicy {
+    return try!  FfiConverterTypeRetentionPolicy.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_policy(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Record a received message.
+     * offset is the peer's send_position when it sent the message.
+     */
+open func recordReceived(pad: Pad, id: UInt64, offset: UInt64, len: UInt64, now: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_retentionmanager_record_received(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),
+        FfiConverterUInt64.lower(now),$0
+    )
+}
+}
+    
+    /**
+     * Record a sent message.
+     * offset is our send_position before the message consumed its bytes.
+     */
+open func recordSent(pad: Pad, id: UInt64, offset: UInt64, len: UInt64, now: UInt64)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_retentionmanager_record_sent(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),
+        FfiConverterUInt64.lower(now),$0
+    )
+}
+}
+    
+    /**
+     * Replace the policy. Takes effect at the next sweep.
+     */
+open func setPolicy(policy: RetentionPolicy) {try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_set_policy(self.uniffiClonePointer(),
+        FfiConverterTypeRetentionPolicy.lower(policy),$0
+    )
+}
+}
+    
+    /**
+     * Summarize which key material is still recoverable from the pad.
+     */
+open func summary(pad: Pad) -> RetentionSummary {
+    return try!  FfiConverterTypeRetentionSummary.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_summary(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),$0
+    )
+})
+}
+    
+    /**
+     * Wipe every message whose key is due at `now`.
+     */
+open func sweep(pad: Pad, now: UInt64) -> [WipedMessage] {
+    return try!  FfiConverterSequenceTypeWipedMessage.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_sweep(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(now),$0
+    )
+})
+}
+    
+    /**
+     * Wipe a message's key now, regardless of policy.
+     * Returns false if the ID is unknown.
+     */
+open func wipe(pad: Pad, id: UInt64) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_retentionmanager_wipe(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetentionManager: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = RetentionManager
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> RetentionManager {
+        return RetentionManager(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: RetentionManager) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetentionManager {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: RetentionManager, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeRetentionManager_lift(_ pointer: UnsafeMutableRawPointer) throws -> RetentionManager {
+    return try FfiConverterTypeRetentionManager.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeRetentionManager_lower(_ value: RetentionManager) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRetentionManager.lower(value)
+}
+
+
+
+
+/**
+ * Key material kept in Rust memory.
+ *
+ * Returned by Pad.consume_secret, Pad.key_at and Pad.as_secret_bytes so pad
+ * bytes do not cross the FFI boundary as a plain sequence<u8>. The bytes are
+ * wiped by wipe() or when the handle is released.
+ */
+public protocol SecretBytesProtocol : AnyObject {
+    
+    /**
+     * Copy the bytes out as a plain sequence<u8>.
+     * The copy lives in foreign memory and is not wiped by Rust.
+     */
+    func copyBytes()  -> [UInt8]
+    
+    /**
+     * Check if there are no bytes (also true after wipe)
+     */
+    func isEmpty()  -> Bool
+    
+    /**
+     * Number of bytes
+     */
+    func len()  -> UInt64
+    
+    /**
+     * Copy len bytes at offset into a new handle
+     */
+    func slice(offset: UInt64, len: UInt64) throws  -> SecretBytes
+    
+    /**
+     * Wipe the bytes now instead of when the handle is released
+     */
+    func wipe() 
+    
+}
+
+/**
+ * Key material kept in Rust memory.
+ *
+ * Returned by Pad.consume_secret, Pad.key_at and Pad.as_secret_bytes so pad
+ * bytes do not cross the FFI boundary as a plain sequence<u8>. The bytes are
+ * wiped by wipe() or when the handle is released.
+ */
+open class SecretBytes:
+    SecretBytesProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_secretbytes(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_secretbytes(pointer, $0) }
+    }
+
+    
+    /**
+     * Take ownership of the copy passed across the FFI boundary.
+     * The caller's own array is not wiped.
+     */
+public static func fromBytes(bytes: [UInt8]) -> SecretBytes {
+    return try!  FfiConverterTypeSecretBytes.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_secretbytes_from_bytes(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
+
+    
+    /**
+     * Copy the bytes out as a plain sequence<u8>.
+     * The copy lives in foreign memory and is not wiped by Rust.
+     */
+open func copyBytes() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_secretbytes_copy_bytes(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Check if there are no bytes (also true after wipe)
+     */
+open func isEmpty() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_secretbytes_is_empty(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Number of bytes
+     */
+open func len() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_secretbytes_len(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Copy len bytes at offset into a new handle
+     */
+open func slice(offset: UInt64, len: UInt64)throws  -> SecretBytes {
+    return try  FfiConverterTypeSecretBytes.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_secretbytes_slice(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(offset),
+        FfiConverterUInt64.lower(len),$0
+    )
+})
+}
+    
+    /**
+     * Wipe the bytes now instead of when the handle is released
+     */
+open func wipe() {try! rustCall() {
+    uniffi_ash_bindings_fn_method_secretbytes_wipe(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSecretBytes: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = SecretBytes
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> SecretBytes {
+        return SecretBytes(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: SecretBytes) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SecretBytes {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: SecretBytes, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeSecretBytes_lift(_ pointer: UnsafeMutableRawPointer) throws -> SecretBytes {
+    return try FfiConverterTypeSecretBytes.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeSecretBytes_lower(_ value: SecretBytes) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeSecretBytes.lower(value)
+}
+
+
+
+
+/**
+ * Receiver side of a pad top-up (scans QR codes, verifies the segment).
+ */
+public protocol TopUpReceiverProtocol : AnyObject {
+    
+    /**
+     * Abort the top-up and wipe the received segment.
+     */
+    func abort() 
+    
+    /**
+     * Add a scanned QR frame.
+     * Returns true once the segment is decoded and verified against the pad.
+     */
+    func addFrame(frameBytes: [UInt8], pad: Pad) throws  -> Bool
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+    func confirmMnemonic() throws 
+    
+    /**
+     * Splice the segment into the pad (Confirmed only).
+     */
+    func finish(pad: Pad) throws  -> TopUpSummary
+    
+    /**
+     * Mnemonic to compare with the sender.
+     */
+    func mnemonic() throws  -> [String]
+    
+    /**
+     * Decoding progress (0.0 to 1.0).
+     */
+    func progress()  -> Double
+    
+    /**
+     * Record that the mnemonics differ. Aborts the top-up.
+     */
+    func rejectMnemonic() throws 
+    
+    /**
+     * Set the passphrase used to decrypt QR frames (Configuring only).
+     */
+    func setPassphrase(passphrase: String) throws 
+    
+    /**
+     * Start accepting scanned frames.
+     */
+    func startTransfer() throws 
+    
+    /**
+     * Current top-up state.
+     */
+    func state()  -> CeremonyState
+    
+}
+
+/**
+ * Receiver side of a pad top-up (scans QR codes, verifies the segment).
+ */
+open class TopUpReceiver:
+    TopUpReceiverProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_topupreceiver(self.pointer, $0) }
+    }
+    /**
+     * Create a new top-up in the Configuring state.
+     */
+public convenience init(role: Role) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_topupreceiver_new(
+        FfiConverterTypeRole.lower(role),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_topupreceiver(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Abort the top-up and wipe the received segment.
+     */
+open func abort() {try! rustCall() {
+    uniffi_ash_bindings_fn_method_topupreceiver_abort(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Add a scanned QR frame.
+     * Returns true once the segment is decoded and verified against the pad.
+     */
+open func addFrame(frameBytes: [UInt8], pad: Pad)throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_add_frame(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(frameBytes),
+        FfiConverterTypePad.lower(pad),$0
+    )
+})
+}
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+open func confirmMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_confirm_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Splice the segment into the pad (Confirmed only).
+     */
+open func finish(pad: Pad)throws  -> TopUpSummary {
+    return try  FfiConverterTypeTopUpSummary.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_finish(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),$0
+    )
+})
+}
+    
+    /**
+     * Mnemonic to compare with the sender.
+     */
+open func mnemonic()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_mnemonic(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Decoding progress (0.0 to 1.0).
+     */
+open func progress() -> Double {
+    return try!  FfiConverterDouble.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_topupreceiver_progress(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Record that the mnemonics differ. Aborts the top-up.
+     */
+open func rejectMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_reject_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Set the passphrase used to decrypt QR frames (Configuring only).
+     */
+open func setPassphrase(passphrase: String)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_set_passphrase(self.uniffiClonePointer(),
+        FfiConverterString.lower(passphrase),$0
+    )
+}
+}
+    
+    /**
+     * Start accepting scanned frames.
+     */
+open func startTransfer()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupreceiver_start_transfer(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Current top-up state.
+     */
+open func state() -> CeremonyState {
+    return try!  FfiConverterTypeCeremonyState.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_topupreceiver_state(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTopUpReceiver: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TopUpReceiver
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TopUpReceiver {
+        return TopUpReceiver(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TopUpReceiver) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TopUpReceiver {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TopUpReceiver, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeTopUpReceiver_lift(_ pointer: UnsafeMutableRawPointer) throws -> TopUpReceiver {
+    return try FfiConverterTypeTopUpReceiver.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeTopUpReceiver_lower(_ value: TopUpReceiver) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTopUpReceiver.lower(value)
+}
+
+
+
+
+/**
+ * Sender side of a pad top-up (creates the segment, displays QR codes).
+ * Binds the segment to the existing conversation with a MAC keyed from
+ * the current pad, so no new tokens or conversation ID are needed.
+ */
+public protocol TopUpSenderProtocol : AnyObject {
+    
+    /**
+     * Abort the top-up and wipe the segment.
+     */
+    func abort() 
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+    func confirmMnemonic() throws 
+    
+    /**
+     * Splice the segment into the pad (Confirmed only).
+     */
+    func finish(pad: Pad) throws  -> TopUpSummary
+    
+    /**
+     * Stop displaying frames once the receiver has decoded the segment.
+     */
+    func finishTransfer() throws 
+    
+    /**
+     * Mnemonic to compare with the receiver.
+     */
+    func mnemonic() throws  -> [String]
+    
+    /**
+     * Generate the next QR frame (Transferring only).
+     */
+    func nextFrame() throws  -> [UInt8]
+    
+    /**
+     * Record that the mnemonics differ. Aborts the top-up.
+     */
+    func rejectMnemonic() throws 
+    
+    /**
+     * Set the QR block size in bytes (Configuring only).
+     */
+    func setBlockSize(blockSize: UInt32) throws 
+    
+    /**
+     * Set the passphrase used to encrypt QR frames (Configuring only).
+     */
+    func setPassphrase(passphrase: String) throws 
+    
+    /**
+     * Set the QR transfer method (Configuring only).
+     */
+    func setTransferMethod(method: TransferMethod) throws 
+    
+    /**
+     * Consume the binding key from the pad and start generating frames.
+     * The entropy length must match the segment size.
+     */
+    func startTransfer(pad: Pad, entropy: [UInt8]) throws 
+    
+    /**
+     * Current top-up state.
+     */
+    func state()  -> CeremonyState
+    
+}
+
+/**
+ * Sender side of a pad top-up (creates the segment, displays QR codes).
+ * Binds the segment to the existing conversation with a MAC keyed from
+ * the current pad, so no new tokens or conversation ID are needed.
+ */
+open class TopUpSender:
+    TopUpSenderProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ash_bindings_fn_clone_topupsender(self.pointer, $0) }
+    }
+    /**
+     * Create a new top-up in the Configuring state.
+     */
+public convenience init(role: Role, segmentSize: PadSize) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ash_bindings_fn_constructor_topupsender_new(
+        FfiConverterTypeRole.lower(role),
+        FfiConverterTypePadSize.lower(segmentSize),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ash_bindings_fn_free_topupsender(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Abort the top-up and wipe the segment.
+     */
+open func abort() {try! rustCall() {
+    uniffi_ash_bindings_fn_method_topupsender_abort(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Record that both parties saw the same mnemonic.
+     */
+open func confirmMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_confirm_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Splice the segment into the pad (Confirmed only).
+     */
+open func finish(pad: Pad)throws  -> TopUpSummary {
+    return try  FfiConverterTypeTopUpSummary.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_finish(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),$0
+    )
+})
+}
+    
+    /**
+     * Stop displaying frames once the receiver has decoded the segment.
+     */
+open func finishTransfer()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_finish_transfer(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Mnemonic to compare with the receiver.
+     */
+open func mnemonic()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_mnemonic(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Generate the next QR frame (Transferring only).
+     */
+open func nextFrame()throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_next_frame(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Record that the mnemonics differ. Aborts the top-up.
+     */
+open func rejectMnemonic()throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_reject_mnemonic(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Set the QR block size in bytes (Configuring only).
+     */
+open func setBlockSize(blockSize: UInt32)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_set_block_size(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(blockSize),$0
+    )
+}
+}
+    
+    /**
+     * Set the passphrase used to encrypt QR frames (Configuring only).
+     */
+open func setPassphrase(passphrase: String)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_set_passphrase(self.uniffiClonePointer(),
+        FfiConverterString.lower(passphrase),$0
+    )
+}
+}
+    
+    /**
+     * Set the QR transfer method (Configuring only).
+     */
+open func setTransferMethod(method: TransferMethod)throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_set_transfer_method(self.uniffiClonePointer(),
+        FfiConverterTypeTransferMethod.lower(method),$0
+    )
+}
+}
+    
+    /**
+     * Consume the binding key from the pad and start generating frames.
+     * The entropy length must match the segment size.
+     */
+open func startTransfer(pad: Pad, entropy: [UInt8])throws  {try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_method_topupsender_start_transfer(self.uniffiClonePointer(),
+        FfiConverterTypePad.lower(pad),
+        FfiConverterSequenceUInt8.lower(entropy),$0
+    )
+}
+}
+    
+    /**
+     * Current top-up state.
+     */
+open func state() -> CeremonyState {
+    return try!  FfiConverterTypeCeremonyState.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_method_topupsender_state(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTopUpSender: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = TopUpSender
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> TopUpSender {
+        return TopUpSender(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: TopUpSender) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TopUpSender {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: TopUpSender, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeTopUpSender_lift(_ pointer: UnsafeMutableRawPointer) throws -> TopUpSender {
+    return try FfiConverterTypeTopUpSender.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeTopUpSender_lower(_ value: TopUpSender) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTopUpSender.lower(value)
+}
+
+
+/**
+ * Authorization tokens derived from pad during ceremony
+ */
+public struct AuthTokens {
+    /**
+     * Conversation ID (hex-encoded, 64 chars)
+     */
+    public let conversationId: String
+    /**
+     * Auth token for API operations (hex-encoded, 64 chars)
+     */
+    public let authToken: String
+    /**
+     * Burn token for burn operations (hex-encoded, 64 chars)
+     */
+    public let burnToken: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Conversation ID (hex-encoded, 64 chars)
+         */conversationId: String, 
+        /**
+         * Auth token for API operations (hex-encoded, 64 chars)
          */authToken: String, 
         /**
-         * Burn token for burn operations (hex-encoded, 64 chars)
-         */burnToken: String) {
-        self.conversationId = conversationId
-        self.authToken = authToken
-        self.burnToken = burnToken
+         * Burn token for burn operations (hex-encoded, 64 chars)
+         */burnToken: String) {
+        self.conversationId = conversationId
+        self.authToken = authToken
+        self.burnToken = burnToken
+    }
+}
+
+
+
+extension AuthTokens: Equatable, Hashable {
+    public static func ==(lhs: AuthTokens, rhs: AuthTokens) -> Bool {
+        if lhs.conversationId != rhs.conversationId {
+            return false
+        }
+        if lhs.authToken != rhs.authToken {
+            return false
+        }
+        if lhs.burnToken != rhs.burnToken {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(conversationId)
+        hasher.combine(authToken)
+        hasher.combine(burnToken)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAuthTokens: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AuthTokens {
+        return
+            try AuthTokens(
+                conversationId: FfiConverterString.read(from: &buf), 
+                authToken: FfiConverterString.read(from: &buf), 
+                burnToken: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AuthTokens, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.conversationId, into: &buf)
+        FfiConverterString.write(value.authToken, into: &buf)
+        FfiConverterString.write(value.burnToken, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeAuthTokens_lift(_ buf: RustBuffer) throws -> AuthTokens {
+    return try FfiConverterTypeAuthTokens.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeAuthTokens_lower(_ value: AuthTokens) -> RustBuffer {
+    return FfiConverterTypeAuthTokens.lower(value)
+}
+
+
+/**
+ * Capacity forecast for a pad in use.
+ * Per-direction counts are estimates based on the observed send ratio.
+ */
+public struct CapacityForecast {
+    /**
+     * Unconsumed bytes between the two consumption fronts
+     */
+    public let availableBytes: UInt64
+    /**
+     * Bytes held back for burn/control messages
+     */
+    public let reserveBytes: UInt64
+    /**
+     * Bytes normal messages may use
+     */
+    public let textBudgetBytes: UInt64
+    /**
+     * Messages we could send if the peer sent nothing more
+     */
+    public let maxMessagesOurs: UInt64
+    /**
+     * Estimated messages left in our direction
+     */
+    public let messagesLeftOurs: UInt64
+    /**
+     * Estimated messages left in the peer's direction
+     */
+    public let messagesLeftPeer: UInt64
+    /**
+     * Lowest warning threshold reached, if any
+     */
+    public let warning: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Unconsumed bytes between the two consumption fronts
+         */availableBytes: UInt64, 
+        /**
+         * Bytes held back for burn/control messages
+         */reserveBytes: UInt64, 
+        /**
+         * Bytes normal messages may use
+         */textBudgetBytes: UInt64, 
+        /**
+         * Messages we could send if the peer sent nothing more
+         */maxMessagesOurs: UInt64, 
+        /**
+         * Estimated messages left in our direction
+         */messagesLeftOurs: UInt64, 
+        /**
+         * Estimated messages left in the peer's direction
+         */messagesLeftPeer: UInt64, 
+        /**
+         * Lowest warning threshold reached, if any
+         */warning: UInt64?) {
+        self.availableBytes = availableBytes
+        self.reserveBytes = reserveBytes
+        self.textBudgetBytes = textBudgetBytes
+        self.maxMessagesOurs = maxMessagesOurs
+        self.messagesLeftOurs = messagesLeftOurs
+        self.messagesLeftPeer = messagesLeftPeer
+        self.warning = warning
+    }
+}
+
+
+
+extension CapacityForecast: Equatable, Hashable {
+    public static func ==(lhs: CapacityForecast, rhs: CapacityForecast) -> Bool {
+        if lhs.availableBytes != rhs.availableBytes {
+            return false
+        }
+        if lhs.reserveBytes != rhs.reserveBytes {
+            return false
+        }
+        if lhs.textBudgetBytes != rhs.textBudgetBytes {
+            return false
+        }
+        if lhs.maxMessagesOurs != rhs.maxMessagesOurs {
+            return false
+        }
+        if lhs.messagesLeftOurs != rhs.messagesLeftOurs {
+            return false
+        }
+        if lhs.messagesLeftPeer != rhs.messagesLeftPeer {
+            return false
+        }
+        if lhs.warning != rhs.warning {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(availableBytes)
+        hasher.combine(reserveBytes)
+        hasher.combine(textBudgetBytes)
+        hasher.combine(maxMessagesOurs)
+        hasher.combine(messagesLeftOurs)
+        hasher.combine(messagesLeftPeer)
+        hasher.combine(warning)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCapacityForecast: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CapacityForecast {
+        return
+            try CapacityForecast(
+                availableBytes: FfiConverterUInt64.read(from: &buf), 
+                reserveBytes: FfiConverterUInt64.read(from: &buf), 
+                textBudgetBytes: FfiConverterUInt64.read(from: &buf), 
+                maxMessagesOurs: FfiConverterUInt64.read(from: &buf), 
+                messagesLeftOurs: FfiConverterUInt64.read(from: &buf), 
+                messagesLeftPeer: FfiConverterUInt64.read(from: &buf), 
+                warning: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CapacityForecast, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.availableBytes, into: &buf)
+        FfiConverterUInt64.write(value.reserveBytes, into: &buf)
+        FfiConverterUInt64.write(value.textBudgetBytes, into: &buf)
+        FfiConverterUInt64.write(value.maxMessagesOurs, into: &buf)
+        FfiConverterUInt64.write(value.messagesLeftOurs, into: &buf)
+        FfiConverterUInt64.write(value.messagesLeftPeer, into: &buf)
+        FfiConverterOptionUInt64.write(value.warning, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCapacityForecast_lift(_ buf: RustBuffer) throws -> CapacityForecast {
+    return try FfiConverterTypeCapacityForecast.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCapacityForecast_lower(_ value: CapacityForecast) -> RustBuffer {
+    return FfiConverterTypeCapacityForecast.lower(value)
+}
+
+
+/**
+ * Ceremony metadata transferred via QR frame 0
+ * Contains settings agreed upon during ceremony
+ */
+public struct CeremonyMetadata {
+    /**
+     * Protocol version (always 1)
+     */
+    public let version: UInt8
+    /**
+     * Message TTL in seconds (default 300 = 5 minutes)
+     */
+    public let ttlSeconds: UInt64
+    /**
+     * Disappearing messages timeout in seconds (0 = off)
+     */
+    public let disappearingMessagesSeconds: UInt32
+    /**
+     * Notification flags (16-bit bitfield) encoding various settings:
+     *
+     * Notification flags (bits 0-7):
+     * - Bit 0: NOTIFY_NEW_MESSAGE (0x0001) - notify on new message
+     * - Bit 1: NOTIFY_MESSAGE_EXPIRING (0x0002) - notify before message expires
+     * - Bit 2: NOTIFY_MESSAGE_EXPIRED (0x0004) - notify when message expires
+     * - Bit 3: NOTIFY_DELIVERY_FAILED (0x0008) - notify sender if TTL expires unread
+     * - Bit 4: NOTIFY_MESSAGE_READ (0x0010) - reserved for read receipts
+     * - Bits 5-7: Reserved for future notification types
+     *
+     * Security flags (bits 8-11):
+     * - Bit 8: Persistence consent (local message storage)
+     * - Bits 9-11: Message padding settings (enabled + size)
+     *
+     * UI flags (bits 12-15):
+     * - Bits 12-15: Conversation accent color (16 colors)
+     *
+     * Default: 0x000B (new message + expiring + delivery failed)
+     */
+    public let notificationFlags: UInt16
+    /**
+     * Transfer method used for QR ceremony (Raptor, LT, or Sequential)
+     */
+    public let transferMethod: TransferMethod
+    /**
+     * Relay server URL
+     */
+    public let relayUrl: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Protocol version (always 1)
+         */version: UInt8, 
+        /**
+         * Message TTL in seconds (default 300 = 5 minutes)
+         */ttlSeconds: UInt64, 
+        /**
+         * Disappearing messages timeout in seconds (0 = off)
+         */disappearingMessagesSeconds: UInt32, 
+        /**
+         * Notification flags (16-bit bitfield) encoding various settings:
+         *
+         * Notification flags (bits 0-7):
+         * - Bit 0: NOTIFY_NEW_MESSAGE (0x0001) - notify on new message
+         * - Bit 1: NOTIFY_MESSAGE_EXPIRING (0x0002) - notify before message expires
+         * - Bit 2: NOTIFY_MESSAGE_EXPIRED (0x0004) - notify when message expires
+         * - Bit 3: NOTIFY_DELIVERY_FAILED (0x0008) - notify sender if TTL expires unread
+         * - Bit 4: NOTIFY_MESSAGE_READ (0x0010) - reserved for read receipts
+         * - Bits 5-7: Reserved for future notification types
+         *
+         * Security flags (bits 8-11):
+         * - Bit 8: Persistence consent (local message storage)
+         * - Bits 9-11: Message padding settings (enabled + size)
+         *
+         * UI flags (bits 12-15):
+         * - Bits 12-15: Conversation accent color (16 colors)
+         *
+         * Default: 0x000B (new message + expiring + delivery failed)
+         */notificationFlags: UInt16, 
+        /**
+         * Transfer method used for QR ceremony (Raptor, LT, or Sequential)
+         */transferMethod: TransferMethod, 
+        /**
+         * Relay server URL
+         */relayUrl: String) {
+        self.version = version
+        self.ttlSeconds = ttlSeconds
+        self.disappearingMessagesSeconds = disappearingMessagesSeconds
+        self.notificationFlags = notificationFlags
+        self.transferMethod = transferMethod
+        self.relayUrl = relayUrl
+    }
+}
+
+
+
+extension CeremonyMetadata: Equatable, Hashable {
+    public static func ==(lhs: CeremonyMetadata, rhs: CeremonyMetadata) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.ttlSeconds != rhs.ttlSeconds {
+            return false
+        }
+        if lhs.disappearingMessagesSeconds != rhs.disappearingMessagesSeconds {
+            return false
+        }
+        if lhs.notificationFlags != rhs.notificationFlags {
+            return false
+        }
+        if lhs.transferMethod != rhs.transferMethod {
+            return false
+        }
+        if lhs.relayUrl != rhs.relayUrl {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(ttlSeconds)
+        hasher.combine(disappearingMessagesSeconds)
+        hasher.combine(notificationFlags)
+        hasher.combine(transferMethod)
+        hasher.combine(relayUrl)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCeremonyMetadata: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CeremonyMetadata {
+        return
+            try CeremonyMetadata(
+                version: FfiConverterUInt8.read(from: &buf), 
+                ttlSeconds: FfiConverterUInt64.read(from: &buf), 
+                disappearingMessagesSeconds: FfiConverterUInt32.read(from: &buf), 
+                notificationFlags: FfiConverterUInt16.read(from: &buf), 
+                transferMethod: FfiConverterTypeTransferMethod.read(from: &buf), 
+                relayUrl: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CeremonyMetadata, into buf: inout [UInt8]) {
+        FfiConverterUInt8.write(value.version, into: &buf)
+        FfiConverterUInt64.write(value.ttlSeconds, into: &buf)
+        FfiConverterUInt32.write(value.disappearingMessagesSeconds, into: &buf)
+        FfiConverterUInt16.write(value.notificationFlags, into: &buf)
+        FfiConverterTypeTransferMethod.write(value.transferMethod, into: &buf)
+        FfiConverterString.write(value.relayUrl, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCeremonyMetadata_lift(_ buf: RustBuffer) throws -> CeremonyMetadata {
+    return try FfiConverterTypeCeremonyMetadata.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCeremonyMetadata_lower(_ value: CeremonyMetadata) -> RustBuffer {
+    return FfiConverterTypeCeremonyMetadata.lower(value)
+}
+
+
+/**
+ * Everything needed to start messaging after a completed ceremony
+ */
+public struct ConversationBundle {
+    /**
+     * Our role (determines pad consumption direction)
+     */
+    public let role: Role
+    /**
+     * The shared pad, with no bytes consumed yet
+     */
+    public let pad: Pad
+    /**
+     * Settings agreed during the ceremony
+     */
+    public let metadata: CeremonyMetadata
+    /**
+     * Relay credentials derived from the pad
+     */
+    public let tokens: AuthTokens
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Our role (determines pad consumption direction)
+         */role: Role, 
+        /**
+         * The shared pad, with no bytes consumed yet
+         */pad: Pad, 
+        /**
+         * Settings agreed during the ceremony
+         */metadata: CeremonyMetadata, 
+        /**
+         * Relay credentials derived from the pad
+         */tokens: AuthTokens) {
+        self.role = role
+        self.pad = pad
+        self.metadata = metadata
+        self.tokens = tokens
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConversationBundle: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConversationBundle {
+        return
+            try ConversationBundle(
+                role: FfiConverterTypeRole.read(from: &buf), 
+                pad: FfiConverterTypePad.read(from: &buf), 
+                metadata: FfiConverterTypeCeremonyMetadata.read(from: &buf), 
+                tokens: FfiConverterTypeAuthTokens.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ConversationBundle, into buf: inout [UInt8]) {
+        FfiConverterTypeRole.write(value.role, into: &buf)
+        FfiConverterTypePad.write(value.pad, into: &buf)
+        FfiConverterTypeCeremonyMetadata.write(value.metadata, into: &buf)
+        FfiConverterTypeAuthTokens.write(value.tokens, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeConversationBundle_lift(_ buf: RustBuffer) throws -> ConversationBundle {
+    return try FfiConverterTypeConversationBundle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeConversationBundle_lower(_ value: ConversationBundle) -> RustBuffer {
+    return FfiConverterTypeConversationBundle.lower(value)
+}
+
+
+/**
+ * Result of authenticated decryption
+ */
+public struct DecryptedMessage {
+    /**
+     * Decrypted plaintext
+     */
+    public let plaintext: [UInt8]
+    /**
+     * Message type (0x01 = text, 0x02 = location)
+     */
+    public let msgType: UInt8
+    /**
+     * Authentication tag (32 bytes) - can be shown in UI
+     */
+    public let tag: [UInt8]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Decrypted plaintext
+         */plaintext: [UInt8], 
+        /**
+         * Message type (0x01 = text, 0x02 = location)
+         */msgType: UInt8, 
+        /**
+         * Authentication tag (32 bytes) - can be shown in UI
+         */tag: [UInt8]) {
+        self.plaintext = plaintext
+        self.msgType = msgType
+        self.tag = tag
+    }
+}
+
+
+
+extension DecryptedMessage: Equatable, Hashable {
+    public static func ==(lhs: DecryptedMessage, rhs: DecryptedMessage) -> Bool {
+        if lhs.plaintext != rhs.plaintext {
+            return false
+        }
+        if lhs.msgType != rhs.msgType {
+            return false
+        }
+        if lhs.tag != rhs.tag {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(plaintext)
+        hasher.combine(msgType)
+        hasher.combine(tag)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecryptedMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecryptedMessage {
+        return
+            try DecryptedMessage(
+                plaintext: FfiConverterSequenceUInt8.read(from: &buf), 
+                msgType: FfiConverterUInt8.read(from: &buf), 
+                tag: FfiConverterSequenceUInt8.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DecryptedMessage, into buf: inout [UInt8]) {
+        FfiConverterSequenceUInt8.write(value.plaintext, into: &buf)
+        FfiConverterUInt8.write(value.msgType, into: &buf)
+        FfiConverterSequenceUInt8.write(value.tag, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeDecryptedMessage_lift(_ buf: RustBuffer) throws -> DecryptedMessage {
+    return try FfiConverterTypeDecryptedMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeDecryptedMessage_lower(_ value: DecryptedMessage) -> RustBuffer {
+    return FfiConverterTypeDecryptedMessage.lower(value)
+}
+
+
+/**
+ * Settings for forecasting the capacity of a pad in use
+ */
+public struct ForecastConfig {
+    /**
+     * Expected plaintext size of a normal message in bytes
+     */
+    public let avgMessageBytes: UInt32
+    /**
+     * Padding applied before encryption
+     */
+    public let padding: PaddingPolicy
+    /**
+     * Number of burn/control messages normal text may not eat into
+     */
+    public let reserveMessages: UInt32
+    /**
+     * Plaintext size of a single burn/control message in bytes
+     */
+    public let controlMessageBytes: UInt32
+    /**
+     * Warn when messages left in our direction drop to these values
+     */
+    public let warnThresholds: [UInt32]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Expected plaintext size of a normal message in bytes
+         */avgMessageBytes: UInt32, 
+        /**
+         * Padding applied before encryption
+         */padding: PaddingPolicy, 
+        /**
+         * Number of burn/control messages normal text may not eat into
+         */reserveMessages: UInt32, 
+        /**
+         * Plaintext size of a single burn/control message in bytes
+         */controlMessageBytes: UInt32, 
+        /**
+         * Warn when messages left in our direction drop to these values
+         */warnThresholds: [UInt32]) {
+        self.avgMessageBytes = avgMessageBytes
+        self.padding = padding
+        self.reserveMessages = reserveMessages
+        self.controlMessageBytes = controlMessageBytes
+        self.warnThresholds = warnThresholds
+    }
+}
+
+
+
+extension ForecastConfig: Equatable, Hashable {
+    public static func ==(lhs: ForecastConfig, rhs: ForecastConfig) -> Bool {
+        if lhs.avgMessageBytes != rhs.avgMessageBytes {
+            return false
+        }
+        if lhs.padding != rhs.padding {
+            return false
+        }
+        if lhs.reserveMessages != rhs.reserveMessages {
+            return false
+        }
+        if lhs.controlMessageBytes != rhs.controlMessageBytes {
+            return false
+        }
+        if lhs.warnThresholds != rhs.warnThresholds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(avgMessageBytes)
+        hasher.combine(padding)
+        hasher.combine(reserveMessages)
+        hasher.combine(controlMessageBytes)
+        hasher.combine(warnThresholds)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeForecastConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ForecastConfig {
+        return
+            try ForecastConfig(
+                avgMessageBytes: FfiConverterUInt32.read(from: &buf), 
+                padding: FfiConverterTypePaddingPolicy.read(from: &buf), 
+                reserveMessages: FfiConverterUInt32.read(from: &buf), 
+                controlMessageBytes: FfiConverterUInt32.read(from: &buf), 
+                warnThresholds: FfiConverterSequenceUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ForecastConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.avgMessageBytes, into: &buf)
+        FfiConverterTypePaddingPolicy.write(value.padding, into: &buf)
+        FfiConverterUInt32.write(value.reserveMessages, into: &buf)
+        FfiConverterUInt32.write(value.controlMessageBytes, into: &buf)
+        FfiConverterSequenceUInt32.write(value.warnThresholds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeForecastConfig_lift(_ buf: RustBuffer) throws -> ForecastConfig {
+    return try FfiConverterTypeForecastConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeForecastConfig_lower(_ value: ForecastConfig) -> RustBuffer {
+    return FfiConverterTypeForecastConfig.lower(value)
+}
+
+
+/**
+ * Result of fountain ceremony decoding
+ */
+public struct FountainCeremonyResult {
+    /**
+     * Ceremony metadata (settings)
+     */
+    public let metadata: CeremonyMetadata
+    /**
+     * Reconstructed pad bytes
+     */
+    public let pad: [UInt8]
+    /**
+     * Number of blocks used for decoding
+     */
+    public let blocksUsed: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Ceremony metadata (settings)
+         */metadata: CeremonyMetadata, 
+        /**
+         * Reconstructed pad bytes
+         */pad: [UInt8], 
+        /**
+         * Number of blocks used for decoding
+         */blocksUsed: UInt32) {
+        self.metadata = metadata
+        self.pad = pad
+        self.blocksUsed = blocksUsed
+    }
+}
+
+
+
+extension FountainCeremonyResult: Equatable, Hashable {
+    public static func ==(lhs: FountainCeremonyResult, rhs: FountainCeremonyResult) -> Bool {
+        if lhs.metadata != rhs.metadata {
+            return false
+        }
+        if lhs.pad != rhs.pad {
+            return false
+        }
+        if lhs.blocksUsed != rhs.blocksUsed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(metadata)
+        hasher.combine(pad)
+        hasher.combine(blocksUsed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFountainCeremonyResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FountainCeremonyResult {
+        return
+            try FountainCeremonyResult(
+                metadata: FfiConverterTypeCeremonyMetadata.read(from: &buf), 
+                pad: FfiConverterSequenceUInt8.read(from: &buf), 
+                blocksUsed: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FountainCeremonyResult, into buf: inout [UInt8]) {
+        FfiConverterTypeCeremonyMetadata.write(value.metadata, into: &buf)
+        FfiConverterSequenceUInt8.write(value.pad, into: &buf)
+        FfiConverterUInt32.write(value.blocksUsed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeFountainCeremonyResult_lift(_ buf: RustBuffer) throws -> FountainCeremonyResult {
+    return try FfiConverterTypeFountainCeremonyResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeFountainCeremonyResult_lower(_ value: FountainCeremonyResult) -> RustBuffer {
+    return FfiConverterTypeFountainCeremonyResult.lower(value)
+}
+
+
+/**
+ * Pad restored from a sealed container
+ */
+public struct PadState {
+    /**
+     * Our role in the conversation
+     */
+    public let role: Role
+    /**
+     * Generation the container was sealed with
+     */
+    public let generation: UInt64
+    /**
+     * The restored pad
+     */
+    public let pad: Pad
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Our role in the conversation
+         */role: Role, 
+        /**
+         * Generation the container was sealed with
+         */generation: UInt64, 
+        /**
+         * The restored pad
+         */pad: Pad) {
+        self.role = role
+        self.generation = generation
+        self.pad = pad
     }
 }
 
 
 
-extension AuthTokens: Equatable, Hashable {
-    public static func ==(lhs: AuthTokens, rhs: AuthTokens) -> Bool {
-        if lhs.conversationId != rhs.conversationId {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePadState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PadState {
+        return
+            try PadState(
+                role: FfiConverterTypeRole.read(from: &buf), 
+                generation: FfiConverterUInt64.read(from: &buf), 
+                pad: FfiConverterTypePad.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PadState, into buf: inout [UInt8]) {
+        FfiConverterTypeRole.write(value.role, into: &buf)
+        FfiConverterUInt64.write(value.generation, into: &buf)
+        FfiConverterTypePad.write(value.pad, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePadState_lift(_ buf: RustBuffer) throws -> PadState {
+    return try FfiConverterTypePadState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePadState_lower(_ value: PadState) -> RustBuffer {
+    return FfiConverterTypePadState.lower(value)
+}
+
+
+/**
+ * Peer pad range not yet received (lost or still in flight)
+ */
+public struct ReceiveGap {
+    /**
+     * Start, in bytes from the peer's origin
+     */
+    public let start: UInt64
+    /**
+     * End, in bytes from the peer's origin (exclusive)
+     */
+    public let end: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Start, in bytes from the peer's origin
+         */start: UInt64, 
+        /**
+         * End, in bytes from the peer's origin (exclusive)
+         */end: UInt64) {
+        self.start = start
+        self.end = end
+    }
+}
+
+
+
+extension ReceiveGap: Equatable, Hashable {
+    public static func ==(lhs: ReceiveGap, rhs: ReceiveGap) -> Bool {
+        if lhs.start != rhs.start {
             return false
         }
-        if lhs.authToken != rhs.authToken {
+        if lhs.end != rhs.end {
             return false
         }
-        if lhs.burnToken != rhs.burnToken {
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReceiveGap: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReceiveGap {
+        return
+            try ReceiveGap(
+                start: FfiConverterUInt64.read(from: &buf), 
+                end: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ReceiveGap, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.start, into: &buf)
+        FfiConverterUInt64.write(value.end, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeReceiveGap_lift(_ buf: RustBuffer) throws -> ReceiveGap {
+    return try FfiConverterTypeReceiveGap.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeReceiveGap_lower(_ value: ReceiveGap) -> RustBuffer {
+    return FfiConverterTypeReceiveGap.lower(value)
+}
+
+
+/**
+ * Pad range of a message whose key is still recoverable
+ */
+public struct RecoverableRange {
+    /**
+     * Caller-assigned message ID
+     */
+    public let id: UInt64
+    /**
+     * Direction the pad bytes were consumed in
+     */
+    public let direction: Role
+    /**
+     * Absolute start offset in the pad
+     */
+    public let start: UInt64
+    /**
+     * Absolute end offset in the pad (exclusive)
+     */
+    public let end: UInt64
+    /**
+     * When the key is due to be wiped, if scheduled
+     */
+    public let dueAt: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Caller-assigned message ID
+         */id: UInt64, 
+        /**
+         * Direction the pad bytes were consumed in
+         */direction: Role, 
+        /**
+         * Absolute start offset in the pad
+         */start: UInt64, 
+        /**
+         * Absolute end offset in the pad (exclusive)
+         */end: UInt64, 
+        /**
+         * When the key is due to be wiped, if scheduled
+         */dueAt: UInt64?) {
+        self.id = id
+        self.direction = direction
+        self.start = start
+        self.end = end
+        self.dueAt = dueAt
+    }
+}
+
+
+
+extension RecoverableRange: Equatable, Hashable {
+    public static func ==(lhs: RecoverableRange, rhs: RecoverableRange) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.direction != rhs.direction {
+            return false
+        }
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        if lhs.dueAt != rhs.dueAt {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(conversationId)
-        hasher.combine(authToken)
-        hasher.combine(burnToken)
+        hasher.combine(id)
+        hasher.combine(direction)
+        hasher.combine(start)
+        hasher.combine(end)
+        hasher.combine(dueAt)
     }
 }
 
@@ -1484,20 +4877,119 @@ extension AuthTokens: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeAuthTokens: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AuthTokens {
+public struct FfiConverterTypeRecoverableRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecoverableRange {
         return
-            try AuthTokens(
-                conversationId: FfiConverterString.read(from: &buf), 
-                authToken: FfiConverterString.read(from: &buf), 
-                burnToken: FfiConverterString.read(from: &buf)
+            try RecoverableRange(
+                id: FfiConverterUInt64.read(from: &buf), 
+                direction: FfiConverterTypeRole.read(from: &buf), 
+                start: FfiConverterUInt64.read(from: &buf), 
+                end: FfiConverterUInt64.read(from: &buf), 
+                dueAt: FfiConverterOptionUInt64.read(from: &buf)
         )
     }
 
-    public static func write(_ value: AuthTokens, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.conversationId, into: &buf)
-        FfiConverterString.write(value.authToken, into: &buf)
-        FfiConverterString.write(value.burnToken, into: &buf)
+    public static func write(_ value: RecoverableRange, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.id, into: &buf)
+        FfiConverterTypeRole.write(value.direction, into: &buf)
+        FfiConverterUInt64.write(value.start, into: &buf)
+        FfiConverterUInt64.write(value.end, into: &buf)
+        FfiConverterOptionUInt64.write(value.dueAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeRecoverableRange_lift(_ buf: RustBuffer) throws -> RecoverableRange {
+    return try FfiConverterTypeRecoverableRange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeRecoverableRange_lower(_ value: RecoverableRange) -> RustBuffer {
+    return FfiConverterTypeRecoverableRange.lower(value)
+}
+
+
+/**
+ * When recorded key material is wiped
+ */
+public struct RetentionPolicy {
+    /**
+     * Wipe as soon as the message is marked delivered
+     */
+    public let wipeOnDelivery: Bool
+    /**
+     * Wipe this many seconds after the message is marked viewed
+     */
+    public let disappearAfter: UInt64?
+    /**
+     * Wipe this many seconds after the message was recorded
+     */
+    public let expireAfter: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Wipe as soon as the message is marked delivered
+         */wipeOnDelivery: Bool, 
+        /**
+         * Wipe this many seconds after the message is marked viewed
+         */disappearAfter: UInt64?, 
+        /**
+         * Wipe this many seconds after the message was recorded
+         */expireAfter: UInt64?) {
+        self.wipeOnDelivery = wipeOnDelivery
+        self.disappearAfter = disappearAfter
+        self.expireAfter = expireAfter
+    }
+}
+
+
+
+extension RetentionPolicy: Equatable, Hashable {
+    public static func ==(lhs: RetentionPolicy, rhs: RetentionPolicy) -> Bool {
+        if lhs.wipeOnDelivery != rhs.wipeOnDelivery {
+            return false
+        }
+        if lhs.disappearAfter != rhs.disappearAfter {
+            return false
+        }
+        if lhs.expireAfter != rhs.expireAfter {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(wipeOnDelivery)
+        hasher.combine(disappearAfter)
+        hasher.combine(expireAfter)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetentionPolicy: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetentionPolicy {
+        return
+            try RetentionPolicy(
+                wipeOnDelivery: FfiConverterBool.read(from: &buf), 
+                disappearAfter: FfiConverterOptionUInt64.read(from: &buf), 
+                expireAfter: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RetentionPolicy, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.wipeOnDelivery, into: &buf)
+        FfiConverterOptionUInt64.write(value.disappearAfter, into: &buf)
+        FfiConverterOptionUInt64.write(value.expireAfter, into: &buf)
     }
 }
 
@@ -1505,144 +4997,85 @@ public struct FfiConverterTypeAuthTokens: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeAuthTokens_lift(_ buf: RustBuffer) throws -> AuthTokens {
-    return try FfiConverterTypeAuthTokens.lift(buf)
+public nonisolated func FfiConverterTypeRetentionPolicy_lift(_ buf: RustBuffer) throws -> RetentionPolicy {
+    return try FfiConverterTypeRetentionPolicy.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeAuthTokens_lower(_ value: AuthTokens) -> RustBuffer {
-    return FfiConverterTypeAuthTokens.lower(value)
+public nonisolated func FfiConverterTypeRetentionPolicy_lower(_ value: RetentionPolicy) -> RustBuffer {
+    return FfiConverterTypeRetentionPolicy.lower(value)
 }
 
 
 /**
- * Ceremony metadata transferred via QR frame 0
- * Contains settings agreed upon during ceremony
+ * Which consumed key material is still recoverable from the pad
  */
-public struct CeremonyMetadata {
-    /**
-     * Protocol version (always 1)
-     */
-    public let version: UInt8
-    /**
-     * Message TTL in seconds (default 300 = 5 minutes)
-     */
-    public let ttlSeconds: UInt64
+public struct RetentionSummary {
     /**
-     * Disappearing messages timeout in seconds (0 = off)
+     * Messages whose keys are still in the pad
      */
-    public let disappearingMessagesSeconds: UInt32
+    public let recoverable: [RecoverableRange]
     /**
-     * Notification flags (16-bit bitfield) encoding various settings:
-     *
-     * Notification flags (bits 0-7):
-     * - Bit 0: NOTIFY_NEW_MESSAGE (0x0001) - notify on new message
-     * - Bit 1: NOTIFY_MESSAGE_EXPIRING (0x0002) - notify before message expires
-     * - Bit 2: NOTIFY_MESSAGE_EXPIRED (0x0004) - notify when message expires
-     * - Bit 3: NOTIFY_DELIVERY_FAILED (0x0008) - notify sender if TTL expires unread
-     * - Bit 4: NOTIFY_MESSAGE_READ (0x0010) - reserved for read receipts
-     * - Bits 5-7: Reserved for future notification types
-     *
-     * Security flags (bits 8-11):
-     * - Bit 8: Persistence consent (local message storage)
-     * - Bits 9-11: Message padding settings (enabled + size)
-     *
-     * UI flags (bits 12-15):
-     * - Bits 12-15: Conversation accent color (16 colors)
-     *
-     * Default: 0x000B (new message + expiring + delivery failed)
+     * Total bytes still recoverable
      */
-    public let notificationFlags: UInt16
+    public let recoverableBytes: UInt64
     /**
-     * Transfer method used for QR ceremony (Raptor, LT, or Sequential)
+     * Total bytes wiped so far
      */
-    public let transferMethod: TransferMethod
+    public let wipedBytes: UInt64
     /**
-     * Relay server URL
+     * Whether every wiped range reads back as zeros in the pad
      */
-    public let relayUrl: String
+    public let verified: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Protocol version (always 1)
-         */version: UInt8, 
-        /**
-         * Message TTL in seconds (default 300 = 5 minutes)
-         */ttlSeconds: UInt64, 
-        /**
-         * Disappearing messages timeout in seconds (0 = off)
-         */disappearingMessagesSeconds: UInt32, 
+         * Messages whose keys are still in the pad
+         */recoverable: [RecoverableRange], 
         /**
-         * Notification flags (16-bit bitfield) encoding various settings:
-         *
-         * Notification flags (bits 0-7):
-         * - Bit 0: NOTIFY_NEW_MESSAGE (0x0001) - notify on new message
-         * - Bit 1: NOTIFY_MESSAGE_EXPIRING (0x0002) - notify before message expires
-         * - Bit 2: NOTIFY_MESSAGE_EXPIRED (0x0004) - notify when message expires
-         * - Bit 3: NOTIFY_DELIVERY_FAILED (0x0008) - notify sender if TTL expires unread
-         * - Bit 4: NOTIFY_MESSAGE_READ (0x0010) - reserved for read receipts
-         * - Bits 5-7: Reserved for future notification types
-         *
-         * Security flags (bits 8-11):
-         * - Bit 8: Persistence consent (local message storage)
-         * - Bits 9-11: Message padding settings (enabled + size)
-         *
-         * UI flags (bits 12-15):
-         * - Bits 12-15: Conversation accent color (16 colors)
-         *
-         * Default: 0x000B (new message + expiring + delivery failed)
-         */notificationFlags: UInt16, 
+         * Total bytes still recoverable
+         */recoverableBytes: UInt64, 
         /**
-         * Transfer method used for QR ceremony (Raptor, LT, or Sequential)
-         */transferMethod: TransferMethod, 
+         * Total bytes wiped so far
+         */wipedBytes: UInt64, 
         /**
-         * Relay server URL
-         */relayUrl: String) {
-        self.version = version
-        self.ttlSeconds = ttlSeconds
-        self.disappearingMessagesSeconds = disappearingMessagesSeconds
-        self.notificationFlags = notificationFlags
-        self.transferMethod = transferMethod
-        self.relayUrl = relayUrl
+         * Whether every wiped range reads back as zeros in the pad
+         */verified: Bool) {
+        self.recoverable = recoverable
+        self.recoverableBytes = recoverableBytes
+        self.wipedBytes = wipedBytes
+        self.verified = verified
     }
 }
 
 
 
-extension CeremonyMetadata: Equatable, Hashable {
-    public static func ==(lhs: CeremonyMetadata, rhs: CeremonyMetadata) -> Bool {
-        if lhs.version != rhs.version {
-            return false
-        }
-        if lhs.ttlSeconds != rhs.ttlSeconds {
-            return false
-        }
-        if lhs.disappearingMessagesSeconds != rhs.disappearingMessagesSeconds {
+extension RetentionSummary: Equatable, Hashable {
+    public static func ==(lhs: RetentionSummary, rhs: RetentionSummary) -> Bool {
+        if lhs.recoverable != rhs.recoverable {
             return false
         }
-        if lhs.notificationFlags != rhs.notificationFlags {
+        if lhs.recoverableBytes != rhs.recoverableBytes {
             return false
         }
-        if lhs.transferMethod != rhs.transferMethod {
+        if lhs.wipedBytes != rhs.wipedBytes {
             return false
         }
-        if lhs.relayUrl != rhs.relayUrl {
+        if lhs.verified != rhs.verified {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(version)
-        hasher.combine(ttlSeconds)
-        hasher.combine(disappearingMessagesSeconds)
-        hasher.combine(notificationFlags)
-        hasher.combine(transferMethod)
-        hasher.combine(relayUrl)
+        hasher.combine(recoverable)
+        hasher.combine(recoverableBytes)
+        hasher.combine(wipedBytes)
+        hasher.combine(verified)
     }
 }
 
@@ -1650,26 +5083,22 @@ extension CeremonyMetadata: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCeremonyMetadata: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CeremonyMetadata {
+public struct FfiConverterTypeRetentionSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetentionSummary {
         return
-            try CeremonyMetadata(
-                version: FfiConverterUInt8.read(from: &buf), 
-                ttlSeconds: FfiConverterUInt64.read(from: &buf), 
-                disappearingMessagesSeconds: FfiConverterUInt32.read(from: &buf), 
-                notificationFlags: FfiConverterUInt16.read(from: &buf), 
-                transferMethod: FfiConverterTypeTransferMethod.read(from: &buf), 
-                relayUrl: FfiConverterString.read(from: &buf)
+            try RetentionSummary(
+                recoverable: FfiConverterSequenceTypeRecoverableRange.read(from: &buf), 
+                recoverableBytes: FfiConverterUInt64.read(from: &buf), 
+                wipedBytes: FfiConverterUInt64.read(from: &buf), 
+                verified: FfiConverterBool.read(from: &buf)
         )
     }
 
-    public static func write(_ value: CeremonyMetadata, into buf: inout [UInt8]) {
-        FfiConverterUInt8.write(value.version, into: &buf)
-        FfiConverterUInt64.write(value.ttlSeconds, into: &buf)
-        FfiConverterUInt32.write(value.disappearingMessagesSeconds, into: &buf)
-        FfiConverterUInt16.write(value.notificationFlags, into: &buf)
-        FfiConverterTypeTransferMethod.write(value.transferMethod, into: &buf)
-        FfiConverterString.write(value.relayUrl, into: &buf)
+    public static func write(_ value: RetentionSummary, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeRecoverableRange.write(value.recoverable, into: &buf)
+        FfiConverterUInt64.write(value.recoverableBytes, into: &buf)
+        FfiConverterUInt64.write(value.wipedBytes, into: &buf)
+        FfiConverterBool.write(value.verified, into: &buf)
     }
 }
 
@@ -1677,73 +5106,73 @@ public struct FfiConverterTypeCeremonyMetadata: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeCeremonyMetadata_lift(_ buf: RustBuffer) throws -> CeremonyMetadata {
-    return try FfiConverterTypeCeremonyMetadata.lift(buf)
+public nonisolated func FfiConverterTypeRetentionSummary_lift(_ buf: RustBuffer) throws -> RetentionSummary {
+    return try FfiConverterTypeRetentionSummary.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeCeremonyMetadata_lower(_ value: CeremonyMetadata) -> RustBuffer {
-    return FfiConverterTypeCeremonyMetadata.lower(value)
+public nonisolated func FfiConverterTypeRetentionSummary_lower(_ value: RetentionSummary) -> RustBuffer {
+    return FfiConverterTypeRetentionSummary.lower(value)
 }
 
 
 /**
- * Result of authenticated decryption
+ * Outcome of a completed pad top-up
  */
-public struct DecryptedMessage {
+public struct TopUpSummary {
     /**
-     * Decrypted plaintext
+     * Offset where the new segment was spliced into the pad
      */
-    public let plaintext: [UInt8]
+    public let insertOffset: UInt64
     /**
-     * Message type (0x01 = text, 0x02 = location)
+     * Number of bytes added
      */
-    public let msgType: UInt8
+    public let segmentLen: UInt64
     /**
-     * Authentication tag (32 bytes) - can be shown in UI
+     * Pad size after the top-up
      */
-    public let tag: [UInt8]
+    public let newTotalSize: UInt64
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Decrypted plaintext
-         */plaintext: [UInt8], 
+         * Offset where the new segment was spliced into the pad
+         */insertOffset: UInt64, 
         /**
-         * Message type (0x01 = text, 0x02 = location)
-         */msgType: UInt8, 
+         * Number of bytes added
+         */segmentLen: UInt64, 
         /**
-         * Authentication tag (32 bytes) - can be shown in UI
-         */tag: [UInt8]) {
-        self.plaintext = plaintext
-        self.msgType = msgType
-        self.tag = tag
+         * Pad size after the top-up
+         */newTotalSize: UInt64) {
+        self.insertOffset = insertOffset
+        self.segmentLen = segmentLen
+        self.newTotalSize = newTotalSize
     }
 }
 
 
 
-extension DecryptedMessage: Equatable, Hashable {
-    public static func ==(lhs: DecryptedMessage, rhs: DecryptedMessage) -> Bool {
-        if lhs.plaintext != rhs.plaintext {
+extension TopUpSummary: Equatable, Hashable {
+    public static func ==(lhs: TopUpSummary, rhs: TopUpSummary) -> Bool {
+        if lhs.insertOffset != rhs.insertOffset {
             return false
         }
-        if lhs.msgType != rhs.msgType {
+        if lhs.segmentLen != rhs.segmentLen {
             return false
         }
-        if lhs.tag != rhs.tag {
+        if lhs.newTotalSize != rhs.newTotalSize {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(plaintext)
-        hasher.combine(msgType)
-        hasher.combine(tag)
+        hasher.combine(insertOffset)
+        hasher.combine(segmentLen)
+        hasher.combine(newTotalSize)
     }
 }
 
@@ -1751,20 +5180,20 @@ extension DecryptedMessage: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDecryptedMessage: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecryptedMessage {
+public struct FfiConverterTypeTopUpSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TopUpSummary {
         return
-            try DecryptedMessage(
-                plaintext: FfiConverterSequenceUInt8.read(from: &buf), 
-                msgType: FfiConverterUInt8.read(from: &buf), 
-                tag: FfiConverterSequenceUInt8.read(from: &buf)
+            try TopUpSummary(
+                insertOffset: FfiConverterUInt64.read(from: &buf), 
+                segmentLen: FfiConverterUInt64.read(from: &buf), 
+                newTotalSize: FfiConverterUInt64.read(from: &buf)
         )
     }
 
-    public static func write(_ value: DecryptedMessage, into buf: inout [UInt8]) {
-        FfiConverterSequenceUInt8.write(value.plaintext, into: &buf)
-        FfiConverterUInt8.write(value.msgType, into: &buf)
-        FfiConverterSequenceUInt8.write(value.tag, into: &buf)
+    public static func write(_ value: TopUpSummary, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.insertOffset, into: &buf)
+        FfiConverterUInt64.write(value.segmentLen, into: &buf)
+        FfiConverterUInt64.write(value.newTotalSize, into: &buf)
     }
 }
 
@@ -1772,73 +5201,61 @@ public struct FfiConverterTypeDecryptedMessage: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeDecryptedMessage_lift(_ buf: RustBuffer) throws -> DecryptedMessage {
-    return try FfiConverterTypeDecryptedMessage.lift(buf)
+public nonisolated func FfiConverterTypeTopUpSummary_lift(_ buf: RustBuffer) throws -> TopUpSummary {
+    return try FfiConverterTypeTopUpSummary.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeDecryptedMessage_lower(_ value: DecryptedMessage) -> RustBuffer {
-    return FfiConverterTypeDecryptedMessage.lower(value)
+public nonisolated func FfiConverterTypeTopUpSummary_lower(_ value: TopUpSummary) -> RustBuffer {
+    return FfiConverterTypeTopUpSummary.lower(value)
 }
 
 
 /**
- * Result of fountain ceremony decoding
+ * A message whose key material was wiped by a sweep
  */
-public struct FountainCeremonyResult {
-    /**
-     * Ceremony metadata (settings)
-     */
-    public let metadata: CeremonyMetadata
+public struct WipedMessage {
     /**
-     * Reconstructed pad bytes
+     * Caller-assigned message ID
      */
-    public let pad: [UInt8]
+    public let id: UInt64
     /**
-     * Number of blocks used for decoding
+     * Trigger that fired first
      */
-    public let blocksUsed: UInt32
+    public let reason: WipeReason
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Ceremony metadata (settings)
-         */metadata: CeremonyMetadata, 
-        /**
-         * Reconstructed pad bytes
-         */pad: [UInt8], 
+         * Caller-assigned message ID
+         */id: UInt64, 
         /**
-         * Number of blocks used for decoding
-         */blocksUsed: UInt32) {
-        self.metadata = metadata
-        self.pad = pad
-        self.blocksUsed = blocksUsed
+         * Trigger that fired first
+         */reason: WipeReason) {
+        self.id = id
+        self.reason = reason
     }
 }
 
 
 
-extension FountainCeremonyResult: Equatable, Hashable {
-    public static func ==(lhs: FountainCeremonyResult, rhs: FountainCeremonyResult) -> Bool {
-        if lhs.metadata != rhs.metadata {
+extension WipedMessage: Equatable, Hashable {
+    public static func ==(lhs: WipedMessage, rhs: WipedMessage) -> Bool {
+        if lhs.id != rhs.id {
             return false
         }
-        if lhs.pad != rhs.pad {
-            return false
-        }
-        if lhs.blocksUsed != rhs.blocksUsed {
+        if lhs.reason != rhs.reason {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(metadata)
-        hasher.combine(pad)
-        hasher.combine(blocksUsed)
+        hasher.combine(id)
+        hasher.combine(reason)
     }
 }
 
@@ -1846,20 +5263,18 @@ extension FountainCeremonyResult: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFountainCeremonyResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FountainCeremonyResult {
+public struct FfiConverterTypeWipedMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WipedMessage {
         return
-            try FountainCeremonyResult(
-                metadata: FfiConverterTypeCeremonyMetadata.read(from: &buf), 
-                pad: FfiConverterSequenceUInt8.read(from: &buf), 
-                blocksUsed: FfiConverterUInt32.read(from: &buf)
+            try WipedMessage(
+                id: FfiConverterUInt64.read(from: &buf), 
+                reason: FfiConverterTypeWipeReason.read(from: &buf)
         )
     }
 
-    public static func write(_ value: FountainCeremonyResult, into buf: inout [UInt8]) {
-        FfiConverterTypeCeremonyMetadata.write(value.metadata, into: &buf)
-        FfiConverterSequenceUInt8.write(value.pad, into: &buf)
-        FfiConverterUInt32.write(value.blocksUsed, into: &buf)
+    public static func write(_ value: WipedMessage, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.id, into: &buf)
+        FfiConverterTypeWipeReason.write(value.reason, into: &buf)
     }
 }
 
@@ -1867,15 +5282,15 @@ public struct FfiConverterTypeFountainCeremonyResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeFountainCeremonyResult_lift(_ buf: RustBuffer) throws -> FountainCeremonyResult {
-    return try FfiConverterTypeFountainCeremonyResult.lift(buf)
+public nonisolated func FfiConverterTypeWipedMessage_lift(_ buf: RustBuffer) throws -> WipedMessage {
+    return try FfiConverterTypeWipedMessage.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypeFountainCeremonyResult_lower(_ value: FountainCeremonyResult) -> RustBuffer {
-    return FfiConverterTypeFountainCeremonyResult.lower(value)
+public nonisolated func FfiConverterTypeWipedMessage_lower(_ value: WipedMessage) -> RustBuffer {
+    return FfiConverterTypeWipedMessage.lower(value)
 }
 
 
@@ -1887,69 +5302,134 @@ public enum AshError {
     
     
     /**
-     * Not enough bytes remaining in pad
+     * Not enough bytes remaining in pad
+     */
+    case InsufficientPadBytes(message: String)
+    
+    /**
+     * Entropy size doesn't match expected pad size
+     */
+    case InvalidEntropySize(message: String)
+    
+    /**
+     * Key and data lengths don't match
+     */
+    case LengthMismatch(message: String)
+    
+    /**
+     * CRC checksum failed
+     */
+    case CrcMismatch(message: String)
+    
+    /**
+     * Payload cannot be empty
+     */
+    case EmptyPayload(message: String)
+    
+    /**
+     * Fountain block is too short
+     */
+    case FountainBlockTooShort(message: String)
+    
+    /**
+     * Metadata frame is too short
+     */
+    case MetadataTooShort(message: String)
+    
+    /**
+     * Unsupported metadata version
+     */
+    case UnsupportedMetadataVersion(message: String)
+    
+    /**
+     * Metadata URL is too long
+     */
+    case MetadataUrlTooLong(message: String)
+    
+    /**
+     * Invalid UTF-8 in metadata URL
+     */
+    case InvalidMetadataUrl(message: String)
+    
+    /**
+     * Pad is too small to derive authorization tokens
+     */
+    case PadTooSmallForTokens(message: String)
+    
+    /**
+     * Message authentication failed
+     */
+    case AuthenticationFailed(message: String)
+    
+    /**
+     * Invalid message padding
      */
-    case InsufficientPadBytes(message: String)
+    case InvalidPadding(message: String)
     
     /**
-     * Entropy size doesn't match expected pad size
+     * Ceremony step called in the wrong state
      */
-    case InvalidEntropySize(message: String)
+    case InvalidCeremonyState(message: String)
     
     /**
-     * Key and data lengths don't match
+     * Tokens requested before the mnemonic was confirmed
      */
-    case LengthMismatch(message: String)
+    case MnemonicNotConfirmed(message: String)
     
     /**
-     * CRC checksum failed
+     * Decoded ceremony payload is malformed
      */
-    case CrcMismatch(message: String)
+    case InvalidCeremonyPayload(message: String)
     
     /**
-     * Payload cannot be empty
+     * Top-up key range or insertion point is already consumed
      */
-    case EmptyPayload(message: String)
+    case InvalidTopUpOffset(message: String)
     
     /**
-     * Fountain block is too short
+     * Top-up was created for a pad of a different size
      */
-    case FountainBlockTooShort(message: String)
+    case TopUpPadMismatch(message: String)
     
     /**
-     * Metadata frame is too short
+     * Sealed pad state is malformed or has an unknown version
      */
-    case MetadataTooShort(message: String)
+    case InvalidPadState(message: String)
     
     /**
-     * Unsupported metadata version
+     * Sealed pad state is older than the last recorded generation
      */
-    case UnsupportedMetadataVersion(message: String)
+    case PadStateRollback(message: String)
     
     /**
-     * Metadata URL is too long
+     * Pad consumption counters are behind the consumption journal
      */
-    case MetadataUrlTooLong(message: String)
+    case PadRollback(message: String)
     
     /**
-     * Invalid UTF-8 in metadata URL
+     * Pad range was already consumed (pad used twice; burn the conversation)
      */
-    case InvalidMetadataUrl(message: String)
+    case PadRangeReuse(message: String)
     
     /**
-     * Pad is too small to derive authorization tokens
+     * Stored consumption journal is malformed or its hash chain is broken
      */
-    case PadTooSmallForTokens(message: String)
+    case InvalidJournal(message: String)
     
     /**
-     * Message authentication failed
+     * Message was already received (replayed by the relay)
      */
-    case AuthenticationFailed(message: String)
+    case ReplayedMessage(message: String)
     
     /**
-     * Invalid message padding
+     * Key material for the message was wiped (already received or expired)
      */
-    case InvalidPadding(message: String)
+    case KeyWiped(message: String)
+    
+    /**
+     * Stored receive window is malformed
+     */
+    case InvalidReceiveWindow(message: String)
     
 }
 
@@ -2019,6 +5499,58 @@ public struct FfiConverterTypeAshError: FfiConverterRustBuffer {
             message: try FfiConverterString.read(from: &buf)
         )
         
+        case 14: return .InvalidCeremonyState(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 15: return .MnemonicNotConfirmed(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 16: return .InvalidCeremonyPayload(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 17: return .InvalidTopUpOffset(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 18: return .TopUpPadMismatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 19: return .InvalidPadState(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 20: return .PadStateRollback(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 21: return .PadRollback(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 22: return .PadRangeReuse(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 23: return .InvalidJournal(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 24: return .ReplayedMessage(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 25: return .KeyWiped(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 26: return .InvalidReceiveWindow(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
 
         default: throw UniffiInternalError.unexpectedEnumCase
         }
@@ -2056,6 +5588,32 @@ public struct FfiConverterTypeAshError: FfiConverterRustBuffer {
             writeInt(&buf, Int32(12))
         case .InvalidPadding(_ /* message is ignored*/):
             writeInt(&buf, Int32(13))
+        case .InvalidCeremonyState(_ /* message is ignored*/):
+            writeInt(&buf, Int32(14))
+        case .MnemonicNotConfirmed(_ /* message is ignored*/):
+            writeInt(&buf, Int32(15))
+        case .InvalidCeremonyPayload(_ /* message is ignored*/):
+            writeInt(&buf, Int32(16))
+        case .InvalidTopUpOffset(_ /* message is ignored*/):
+            writeInt(&buf, Int32(17))
+        case .TopUpPadMismatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(18))
+        case .InvalidPadState(_ /* message is ignored*/):
+            writeInt(&buf, Int32(19))
+        case .PadStateRollback(_ /* message is ignored*/):
+            writeInt(&buf, Int32(20))
+        case .PadRollback(_ /* message is ignored*/):
+            writeInt(&buf, Int32(21))
+        case .PadRangeReuse(_ /* message is ignored*/):
+            writeInt(&buf, Int32(22))
+        case .InvalidJournal(_ /* message is ignored*/):
+            writeInt(&buf, Int32(23))
+        case .ReplayedMessage(_ /* message is ignored*/):
+            writeInt(&buf, Int32(24))
+        case .KeyWiped(_ /* message is ignored*/):
+            writeInt(&buf, Int32(25))
+        case .InvalidReceiveWindow(_ /* message is ignored*/):
+            writeInt(&buf, Int32(26))
 
         
         }
@@ -2074,81 +5632,271 @@ extension AshError: Foundation.LocalizedError {
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Available pad sizes
+ * State of an initiator or responder ceremony.
+ *
+ * Steps run in order: Configuring -> Transferring -> VerifyingMnemonic
+ * -> Confirmed -> Finished. Aborted is reachable from any earlier state.
+ */
+
+public enum CeremonyState {
+    
+    /**
+     * Settings can still be changed
+     */
+    case configuring
+    /**
+     * QR frames are being displayed or scanned
+     */
+    case transferring
+    /**
+     * Pad transferred, both parties compare mnemonics
+     */
+    case verifyingMnemonic
+    /**
+     * Mnemonics matched, finish() can produce the bundle
+     */
+    case confirmed
+    /**
+     * Bundle was handed out (terminal)
+     */
+    case finished
+    /**
+     * Ceremony cancelled or mnemonic rejected (terminal)
+     */
+    case aborted
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCeremonyState: FfiConverterRustBuffer {
+    typealias SwiftType = CeremonyState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CeremonyState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .configuring
+        
+        case 2: return .transferring
+        
+        case 3: return .verifyingMnemonic
+        
+        case 4: return .confirmed
+        
+        case 5: return .finished
+        
+        case 6: return .aborted
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CeremonyState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .configuring:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .transferring:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .verifyingMnemonic:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .confirmed:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .finished:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .aborted:
+            writeInt(&buf, Int32(6))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCeremonyState_lift(_ buf: RustBuffer) throws -> CeremonyState {
+    return try FfiConverterTypeCeremonyState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeCeremonyState_lower(_ value: CeremonyState) -> RustBuffer {
+    return FfiConverterTypeCeremonyState.lower(value)
+}
+
+
+
+extension CeremonyState: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Available pad sizes
+ */
+
+public enum PadSize {
+    
+    /**
+     * 32 KB - approximately 25 short messages
+     */
+    case tiny
+    /**
+     * 64 KB - approximately 50 short messages
+     */
+    case small
+    /**
+     * 256 KB - approximately 200 short messages
+     */
+    case medium
+    /**
+     * 512 KB - approximately 400 short messages
+     */
+    case large
+    /**
+     * 1 MB - approximately 800 short messages
+     */
+    case huge
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePadSize: FfiConverterRustBuffer {
+    typealias SwiftType = PadSize
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PadSize {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .tiny
+        
+        case 2: return .small
+        
+        case 3: return .medium
+        
+        case 4: return .large
+        
+        case 5: return .huge
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PadSize, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .tiny:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .small:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .medium:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .large:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .huge:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePadSize_lift(_ buf: RustBuffer) throws -> PadSize {
+    return try FfiConverterTypePadSize.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypePadSize_lower(_ value: PadSize) -> RustBuffer {
+    return FfiConverterTypePadSize.lower(value)
+}
+
+
+
+extension PadSize: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Padding applied to plaintext before encryption.
+ * Determines the real pad cost of a message.
  */
 
-public enum PadSize {
+public enum PaddingPolicy {
     
     /**
-     * 32 KB - approximately 25 short messages
-     */
-    case tiny
-    /**
-     * 64 KB - approximately 50 short messages
-     */
-    case small
-    /**
-     * 256 KB - approximately 200 short messages
-     */
-    case medium
-    /**
-     * 512 KB - approximately 400 short messages
+     * Plaintext is encrypted as-is
      */
-    case large
+    case unpadded
     /**
-     * 1 MB - approximately 800 short messages
+     * Plaintext is padded to at least 32 bytes (default)
      */
-    case huge
+    case padded
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePadSize: FfiConverterRustBuffer {
-    typealias SwiftType = PadSize
+public struct FfiConverterTypePaddingPolicy: FfiConverterRustBuffer {
+    typealias SwiftType = PaddingPolicy
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PadSize {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PaddingPolicy {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .tiny
-        
-        case 2: return .small
-        
-        case 3: return .medium
-        
-        case 4: return .large
+        case 1: return .unpadded
         
-        case 5: return .huge
+        case 2: return .padded
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: PadSize, into buf: inout [UInt8]) {
+    public static func write(_ value: PaddingPolicy, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .tiny:
+        case .unpadded:
             writeInt(&buf, Int32(1))
         
         
-        case .small:
+        case .padded:
             writeInt(&buf, Int32(2))
         
-        
-        case .medium:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .large:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .huge:
-            writeInt(&buf, Int32(5))
-        
         }
     }
 }
@@ -2157,20 +5905,20 @@ public struct FfiConverterTypePadSize: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypePadSize_lift(_ buf: RustBuffer) throws -> PadSize {
-    return try FfiConverterTypePadSize.lift(buf)
+public nonisolated func FfiConverterTypePaddingPolicy_lift(_ buf: RustBuffer) throws -> PaddingPolicy {
+    return try FfiConverterTypePaddingPolicy.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public nonisolated func FfiConverterTypePadSize_lower(_ value: PadSize) -> RustBuffer {
-    return FfiConverterTypePadSize.lower(value)
+public nonisolated func FfiConverterTypePaddingPolicy_lower(_ value: PaddingPolicy) -> RustBuffer {
+    return FfiConverterTypePaddingPolicy.lower(value)
 }
 
 
 
-extension PadSize: Equatable, Hashable {}
+extension PaddingPolicy: Equatable, Hashable {}
 
 
 
@@ -2337,6 +6085,147 @@ extension TransferMethod: Equatable, Hashable {}
 
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Why a message's key material was wiped
+ */
+
+public enum WipeReason {
+    
+    /**
+     * The message was delivered
+     */
+    case delivered
+    /**
+     * The disappearing-messages timer ran out
+     */
+    case disappeared
+    /**
+     * The message TTL passed
+     */
+    case expired
+    /**
+     * The app asked for the key to be wiped
+     */
+    case manual
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWipeReason: FfiConverterRustBuffer {
+    typealias SwiftType = WipeReason
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WipeReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .delivered
+        
+        case 2: return .disappeared
+        
+        case 3: return .expired
+        
+        case 4: return .manual
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: WipeReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .delivered:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .disappeared:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .expired:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .manual:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeWipeReason_lift(_ buf: RustBuffer) throws -> WipeReason {
+    return try FfiConverterTypeWipeReason.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public nonisolated func FfiConverterTypeWipeReason_lower(_ value: WipeReason) -> RustBuffer {
+    return FfiConverterTypeWipeReason.lower(value)
+}
+
+
+
+extension WipeReason: Equatable, Hashable {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCeremonyMetadata: FfiConverterRustBuffer {
+    typealias SwiftType = CeremonyMetadata?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCeremonyMetadata.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCeremonyMetadata.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2410,6 +6299,31 @@ fileprivate struct FfiConverterSequenceUInt8: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2434,6 +6348,81 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
         return seq
     }
 }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeReceiveGap: FfiConverterRustBuffer {
+    typealias SwiftType = [ReceiveGap]
+
+    public static func write(_ value: [ReceiveGap], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeReceiveGap.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ReceiveGap] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ReceiveGap]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeReceiveGap.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRecoverableRange: FfiConverterRustBuffer {
+    typealias SwiftType = [RecoverableRange]
+
+    public static func write(_ value: [RecoverableRange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRecoverableRange.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RecoverableRange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RecoverableRange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRecoverableRange.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeWipedMessage: FfiConverterRustBuffer {
+    typealias SwiftType = [WipedMessage]
+
+    public static func write(_ value: [WipedMessage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeWipedMessage.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [WipedMessage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [WipedMessage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeWipedMessage.read(from: &buf))
+        }
+        return seq
+    }
+}
 /**
  * Calculate expected frames needed for successful transfer.
  * This is the number displayed in UI as "~X QR frames".
@@ -2548,6 +6537,21 @@ public nonisolated func decryptAuthenticated(authKey: [UInt8], encryptionKey: [U
     )
 })
 }
+/**
+ * Decrypt and verify an authenticated message, taking the keys as
+ * SecretBytes so they stay in Rust memory.
+ *
+ * Arguments and result are as for decrypt_authenticated.
+ */
+public nonisolated func decryptAuthenticatedSecret(authKey: SecretBytes, encryptionKey: SecretBytes, encodedFrame: [UInt8])throws  -> DecryptedMessage {
+    return try  FfiConverterTypeDecryptedMessage.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_decrypt_authenticated_secret(
+        FfiConverterTypeSecretBytes.lower(authKey),
+        FfiConverterTypeSecretBytes.lower(encryptionKey),
+        FfiConverterSequenceUInt8.lower(encodedFrame),$0
+    )
+})
+}
 /**
  * Derive all tokens at once (conversation_id, auth_token, burn_token)
  */
@@ -2564,28 +6568,50 @@ public nonisolated func deriveAllTokens(padBytes: [UInt8])throws  -> AuthTokens
  */
 public nonisolated func deriveAuthToken(padBytes: [UInt8])throws  -> String {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
-    uniffi_ash_bindings_fn_func_derive_auth_token(
+    uniffi_ash_bindings_fn_func_derive_auth_token(
+        FfiConverterSequenceUInt8.lower(padBytes),$0
+    )
+})
+}
+/**
+ * Derive burn token from pad bytes (hex-encoded, 64 chars)
+ * Used specifically for burn operations (defense in depth)
+ */
+public nonisolated func deriveBurnToken(padBytes: [UInt8])throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_derive_burn_token(
+        FfiConverterSequenceUInt8.lower(padBytes),$0
+    )
+})
+}
+/**
+ * Derive conversation ID from pad bytes (hex-encoded, 64 chars)
+ */
+public nonisolated func deriveConversationId(padBytes: [UInt8])throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_derive_conversation_id(
         FfiConverterSequenceUInt8.lower(padBytes),$0
     )
 })
 }
 /**
- * Derive burn token from pad bytes (hex-encoded, 64 chars)
- * Used specifically for burn operations (defense in depth)
+ * Derive the conversation ID for a registration that carries a proof
+ * (hex-encoded, 64 chars). Differs from derive_conversation_id.
  */
-public nonisolated func deriveBurnToken(padBytes: [UInt8])throws  -> String {
+public nonisolated func deriveRegisteredConversationId(padBytes: [UInt8])throws  -> String {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
-    uniffi_ash_bindings_fn_func_derive_burn_token(
+    uniffi_ash_bindings_fn_func_derive_registered_conversation_id(
         FfiConverterSequenceUInt8.lower(padBytes),$0
     )
 })
 }
 /**
- * Derive conversation ID from pad bytes (hex-encoded, 64 chars)
+ * Derive the registration proof (hex-encoded, 64 chars)
+ * Sent with the token hashes when registering a conversation
  */
-public nonisolated func deriveConversationId(padBytes: [UInt8])throws  -> String {
+public nonisolated func deriveRegistrationProof(padBytes: [UInt8])throws  -> String {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
-    uniffi_ash_bindings_fn_func_derive_conversation_id(
+    uniffi_ash_bindings_fn_func_derive_registration_proof(
         FfiConverterSequenceUInt8.lower(padBytes),$0
     )
 })
@@ -2629,6 +6655,42 @@ public nonisolated func encryptAuthenticated(authKey: [UInt8], encryptionKey: [U
     )
 })
 }
+/**
+ * Encrypt like encrypt_authenticated_secret into a version 2 frame that
+ * also carries the pad offset in its authenticated header.
+ *
+ * `offset` is the sender's Pad.send_position before consuming the key.
+ * Receivers pass the frame to ReceiveWindow.receive, which reads the
+ * offset from the frame instead of the relay's sequence number.
+ */
+public nonisolated func encryptAuthenticatedAt(offset: UInt64, authKey: SecretBytes, encryptionKey: SecretBytes, plaintext: [UInt8], msgType: UInt8)throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_encrypt_authenticated_at(
+        FfiConverterUInt64.lower(offset),
+        FfiConverterTypeSecretBytes.lower(authKey),
+        FfiConverterTypeSecretBytes.lower(encryptionKey),
+        FfiConverterSequenceUInt8.lower(plaintext),
+        FfiConverterUInt8.lower(msgType),$0
+    )
+})
+}
+/**
+ * Encrypt plaintext with Wegman-Carter authentication, taking the keys
+ * as SecretBytes so they stay in Rust memory.
+ *
+ * Split the key returned by Pad.consume_secret with SecretBytes.slice.
+ * Arguments and result are as for encrypt_authenticated.
+ */
+public nonisolated func encryptAuthenticatedSecret(authKey: SecretBytes, encryptionKey: SecretBytes, plaintext: [UInt8], msgType: UInt8)throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_encrypt_authenticated_secret(
+        FfiConverterTypeSecretBytes.lower(authKey),
+        FfiConverterTypeSecretBytes.lower(encryptionKey),
+        FfiConverterSequenceUInt8.lower(plaintext),
+        FfiConverterUInt8.lower(msgType),$0
+    )
+})
+}
 /**
  * Generate 6-word mnemonic checksum from pad bytes
  */
@@ -2705,10 +6767,67 @@ public nonisolated func getPadSizeBytes(size: PadSize) -> UInt64 {
     )
 })
 }
+/**
+ * Hash a token as sent to the relay at registration (hex-encoded SHA-256)
+ */
+public nonisolated func hashToken(token: String) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_func_hash_token(
+        FfiConverterString.lower(token),$0
+    )
+})
+}
+/**
+ * Open a sealed pad state.
+ *
+ * Fails with AuthenticationFailed if the key is wrong or the container
+ * was modified, and with PadStateRollback if its generation is older
+ * than `min_generation`.
+ */
+public nonisolated func openPadState(sealed: [UInt8], wrappingKey: [UInt8], minGeneration: UInt64)throws  -> PadState {
+    return try  FfiConverterTypePadState.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_open_pad_state(
+        FfiConverterSequenceUInt8.lower(sealed),
+        FfiConverterSequenceUInt8.lower(wrappingKey),
+        FfiConverterUInt64.lower(minGeneration),$0
+    )
+})
+}
+/**
+ * Retention policy matching the conversation settings from the ceremony:
+ * wipe on delivery, after the disappearing timer, and at the relay TTL.
+ */
+public nonisolated func retentionPolicyFromMetadata(metadata: CeremonyMetadata) -> RetentionPolicy {
+    return try!  FfiConverterTypeRetentionPolicy.lift(try! rustCall() {
+    uniffi_ash_bindings_fn_func_retention_policy_from_metadata(
+        FfiConverterTypeCeremonyMetadata.lower(metadata),$0
+    )
+})
+}
+/**
+ * Seal pad state for storage at rest.
+ *
+ * The container is encrypted and authenticated under `wrapping_key`
+ * (32 bytes, keep it in the platform keychain). `nonce` must be 12 fresh
+ * random bytes for every seal. Increment `generation` on every seal and
+ * store it next to the wrapping key.
+ */
+public nonisolated func sealPadState(pad: Pad, role: Role, generation: UInt64, wrappingKey: [UInt8], nonce: [UInt8])throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeAshError.lift) {
+    uniffi_ash_bindings_fn_func_seal_pad_state(
+        FfiConverterTypePad.lower(pad),
+        FfiConverterTypeRole.lower(role),
+        FfiConverterUInt64.lower(generation),
+        FfiConverterSequenceUInt8.lower(wrappingKey),
+        FfiConverterSequenceUInt8.lower(nonce),$0
+    )
+})
+}
 /**
  * Securely zero a byte array using volatile writes.
  * This prevents the compiler from optimizing away the zeroing.
- * Use this for wiping sensitive data from memory.
+ * Only the copy passed across the FFI boundary is zeroed; the
+ * caller's own array is not wiped.
  */
 public nonisolated func secureZeroBytes(data: [UInt8]) {try! rustCall() {
     uniffi_ash_bindings_fn_func_secure_zero_bytes(
@@ -2766,6 +6885,9 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_func_decrypt_authenticated() != 13357) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_func_decrypt_authenticated_secret() != 45709) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_func_derive_all_tokens() != 49844) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2778,12 +6900,24 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_func_derive_conversation_id() != 20229) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_func_derive_registered_conversation_id() != 57516) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_func_derive_registration_proof() != 14389) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_func_encrypt() != 28557) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ash_bindings_checksum_func_encrypt_authenticated() != 2230) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_func_encrypt_authenticated_at() != 52738) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_func_encrypt_authenticated_secret() != 1881) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_func_generate_mnemonic() != 8039) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2808,12 +6942,51 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_func_get_pad_size_bytes() != 14934) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_func_hash_token() != 8961) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_func_open_pad_state() != 47731) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_func_retention_policy_from_metadata() != 43384) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_func_seal_pad_state() != 53813) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_func_secure_zero_bytes() != 4018) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ash_bindings_checksum_func_validate_passphrase() != 11471) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_check() != 18445) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_check_send() != 39174) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_consume() != 26417) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_encode() != 41268) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_head() != 57932) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_own_consumed() != 14478) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_peer_consumed() != 45730) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_record_received() != 55456) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_consumptionjournal_role() != 43142) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_fountainframegenerator_block_size() != 4068) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2853,18 +7026,66 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_method_fountainframereceiver_unique_blocks_received() != 24723) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_abort() != 10651) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_confirm_mnemonic() != 31291) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_finish() != 8805) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_finish_transfer() != 2566) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_mnemonic() != 16609) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_next_frame() != 40763) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_reject_mnemonic() != 4886) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_set_block_size() != 60400) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_set_metadata() != 58033) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_set_passphrase() != 45416) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_source_count() != 5885) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_start_transfer() != 63807) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_initiatorceremony_state() != 33454) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_as_bytes() != 57196) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_as_secret_bytes() != 23069) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_available_for_sending() != 19640) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ash_bindings_checksum_method_pad_can_send() != 58381) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_can_send_text() != 7654) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_consume() != 64469) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_consume_secret() != 7795) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_consumed() != 16684) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2874,27 +7095,210 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_method_pad_consumed_front() != 32220) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_forecast() != 13326) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_is_exhausted() != 34846) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_key_at() != 58730) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_next_send_offset() != 20689) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ash_bindings_checksum_method_pad_remaining() != 32900) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_pad_send_position() != 36813) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_method_pad_total_size() != 20873) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_ash_bindings_checksum_method_pad_update_peer_consumption() != 28070) {
+    if (uniffi_ash_bindings_checksum_method_pad_update_peer_consumption() != 24901) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ash_bindings_checksum_method_pad_zero_bytes_at() != 25947) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_accept() != 16628) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_check() != 20359) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_encode() != 3614) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_gaps() != 2316) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_highest() != 36163) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_receive() != 2354) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_receivewindow_role() != 52207) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_abort() != 29203) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_add_frame() != 65226) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_confirm_mnemonic() != 51151) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_finish() != 64264) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_metadata() != 21868) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_mnemonic() != 28954) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_progress() != 7937) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_reject_mnemonic() != 14881) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_set_passphrase() != 61384) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_start_transfer() != 19230) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_responderceremony_state() != 48232) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_mark_delivered() != 4454) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_mark_viewed() != 57095) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_next_due() != 4373) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_policy() != 58412) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_record_received() != 19093) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_record_sent() != 22486) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_set_policy() != 7941) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_summary() != 7640) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_sweep() != 36484) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_retentionmanager_wipe() != 1767) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_secretbytes_copy_bytes() != 22521) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_secretbytes_is_empty() != 57430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_secretbytes_len() != 49212) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_secretbytes_slice() != 32247) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_secretbytes_wipe() != 42964) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_abort() != 49443) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_add_frame() != 38262) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_confirm_mnemonic() != 39490) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_finish() != 63873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_mnemonic() != 7498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_progress() != 59036) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_reject_mnemonic() != 36551) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_set_passphrase() != 27526) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_start_transfer() != 21893) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupreceiver_state() != 15036) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_abort() != 57529) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_confirm_mnemonic() != 6029) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_finish() != 39172) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_finish_transfer() != 33680) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_mnemonic() != 44938) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_next_frame() != 24465) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_reject_mnemonic() != 34123) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_set_block_size() != 36725) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_set_passphrase() != 54034) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_set_transfer_method() != 58771) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_start_transfer() != 257) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_method_topupsender_state() != 39822) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_consumptionjournal_decode() != 45400) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_consumptionjournal_new() != 34531) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_constructor_fountainframereceiver_new() != 53774) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_constructor_initiatorceremony_new() != 56309) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ash_bindings_checksum_constructor_pad_from_bytes() != 21075) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -2904,6 +7308,27 @@ nonisolated(unsafe) private var initializationResult: InitializationResult = {
     if (uniffi_ash_bindings_checksum_constructor_pad_from_entropy() != 28891) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ash_bindings_checksum_constructor_receivewindow_decode() != 63430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_receivewindow_new() != 16109) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_responderceremony_new() != 51398) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_retentionmanager_new() != 19918) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_secretbytes_from_bytes() != 10938) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_topupreceiver_new() != 410) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ash_bindings_checksum_constructor_topupsender_new() != 40088) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
     return InitializationResult.ok
 }()
@@ -0,0 +1,306 @@
+//! Operator API for a running relay.
+//!
+//! Served by its own router on `ADMIN_BIND_ADDR`, never on the public
+//! listener, and authenticated with `Authorization: Bearer <ADMIN_KEY>`.
+//! Bind it to loopback or a private network: it speaks plain HTTP.
+//!
+//! Everything exposed is aggregate. There is no endpoint that returns
+//! ciphertext, conversation IDs, device tokens or any per-conversation
+//! listing, consistent with the no-identity contract.
+//!
+//! | Endpoint | Method | Description |
+//! |----------|--------|-------------|
+//! | `/admin/metrics` | GET | Store totals and current state sizes |
+//! | `/admin/capacity` | GET | Registered conversations vs. `MAX_CONVERSATIONS` |
+//! | `/admin/config` | GET | Effective configuration without secrets |
+//! | `/admin/cleanup` | POST | Run TTL cleanup and inactive eviction now |
+//! | `/admin/apns` | GET | APNS client status |
+//! | `/admin/log-level` | GET / PUT | Read or replace the log filter |
+
+use crate::auth::hash_token;
+use crate::config::Config;
+use crate::handlers::{extract_auth_token, ApiError, AppState};
+use crate::store::{StoreGauges, StoreMetrics};
+use axum::{
+    extract::{Request, State},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, warn};
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// Handle for replacing the global log filter at runtime.
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Minimum `ADMIN_KEY` length.
+pub const MIN_ADMIN_KEY_LEN: usize = 32;
+
+/// State shared by the admin handlers.
+#[derive(Clone)]
+pub struct AdminState {
+    app: AppState,
+    /// SHA-256 of the admin key (hex).
+    key_hash: Arc<str>,
+    /// Global log filter, if the subscriber was installed with one.
+    log_filter: Option<LogFilterHandle>,
+    started: Instant,
+}
+
+impl AdminState {
+    /// Create admin state for `app`, authenticated by `admin_key`.
+    pub fn new(app: AppState, admin_key: &str, log_filter: Option<LogFilterHandle>) -> Self {
+        Self {
+            app,
+            key_hash: hash_token(admin_key).into(),
+            log_filter,
+            started: Instant::now(),
+        }
+    }
+}
+
+/// Build the admin router.
+pub fn build_admin_router(state: AdminState) -> Router {
+    Router::new()
+        .route("/admin/metrics", get(metrics))
+        .route("/admin/capacity", get(capacity))
+        .route("/admin/config", get(config))
+        .route("/admin/cleanup", post(cleanup))
+        .route("/admin/apns", get(apns_status))
+        .route("/admin/log-level", get(log_level).put(set_log_level))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_admin_key,
+        ))
+        .with_state(state)
+}
+
+/// Reject requests without the admin key.
+async fn require_admin_key(
+    State(state): State<AdminState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let key = extract_auth_token(request.headers())?;
+    if hash_token(&key) != *state.key_hash {
+        warn!("Admin authentication failed");
+        return Err(crate::auth::AuthError::Unauthorized.into());
+    }
+    Ok(next.run(request).await)
+}
+
+// === Metrics ===
+
+/// Aggregate relay metrics.
+#[derive(Debug, Serialize)]
+pub struct MetricsResponse {
+    pub uptime_secs: u64,
+    /// Totals since start.
+    pub totals: StoreMetrics,
+    /// Current state sizes.
+    pub state: StoreGauges,
+    pub registered_conversations: usize,
+    pub sse_subscribers: usize,
+    pub parked_long_polls: usize,
+    pub pending_pushes: usize,
+}
+
+/// GET /admin/metrics
+async fn metrics(State(state): State<AdminState>) -> Json<MetricsResponse> {
+    let app = &state.app;
+    Json(MetricsResponse {
+        uptime_secs: state.started.elapsed().as_secs(),
+        totals: app.store.get_metrics().await,
+        state: app.store.gauges(),
+        registered_conversations: app.auth.len(),
+        sse_subscribers: app.broadcast_tx.receiver_count(),
+        parked_long_polls: app.waiters.iter().map(|w| *w.value()).sum(),
+        pending_pushes: app.pushes.len(),
+    })
+}
+
+// === Capacity ===
+
+/// Registration capacity.
+#[derive(Debug, Serialize)]
+pub struct CapacityResponse {
+    pub registered_conversations: usize,
+    pub max_conversations: usize,
+    /// Fraction of capacity in use (0.0 to 1.0).
+    pub utilization: f64,
+    pub at_capacity: bool,
+}
+
+/// GET /admin/capacity
+async fn capacity(State(state): State<AdminState>) -> Json<CapacityResponse> {
+    let registered = state.app.auth.len();
+    let max = state.app.auth.max_conversations();
+    #[allow(clippy::cast_precision_loss)]
+    let utilization = registered as f64 / max as f64;
+    Json(CapacityResponse {
+        registered_conversations: registered,
+        max_conversations: max,
+        utilization,
+        at_capacity: registered >= max,
+    })
+}
+
+// === Configuration ===
+
+/// Effective configuration. Secrets and file locations are reduced to
+/// whether they are set.
+#[derive(Debug, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ConfigDump {
+    pub bind_addr: String,
+    pub port: u16,
+    pub tls_enabled: bool,
+    pub tls_reload_interval_secs: u64,
+    pub shutdown_grace_secs: u64,
+    pub blob_ttl_secs: u64,
+    pub burn_ttl_secs: u64,
+    pub device_token_ttl_secs: u64,
+    pub max_ciphertext_size: usize,
+    pub max_blobs_per_conversation: usize,
+    pub max_conversations: usize,
+    pub inactive_ttl_secs: u64,
+    pub broadcast_capacity: usize,
+    pub cleanup_interval_secs: u64,
+    pub enforce_sequences: bool,
+    pub require_registration_proof: bool,
+    pub durable_tombstones: bool,
+    pub tombstone_retention_secs: u64,
+    pub log_mode: String,
+    pub log_pseudonym_rotation_secs: u64,
+    pub log_activity_interval_secs: u64,
+    pub apns_configured: bool,
+    pub apns_sandbox: bool,
+    pub admin_bind_addr: Option<String>,
+}
+
+impl From<&Config> for ConfigDump {
+    fn from(config: &Config) -> Self {
+        Self {
+            bind_addr: config.bind_addr.clone(),
+            port: config.port,
+            tls_enabled: config.tls_paths().is_some(),
+            tls_reload_interval_secs: config.tls_reload_interval.as_secs(),
+            shutdown_grace_secs: config.shutdown_grace.as_secs(),
+            blob_ttl_secs: config.blob_ttl.as_secs(),
+            burn_ttl_secs: config.burn_ttl.as_secs(),
+            device_token_ttl_secs: config.device_token_ttl.as_secs(),
+            max_ciphertext_size: config.max_ciphertext_size,
+            max_blobs_per_conversation: config.max_blobs_per_conversation,
+            max_conversations: config.max_conversations,
+            inactive_ttl_secs: config.inactive_ttl.as_secs(),
+            broadcast_capacity: config.broadcast_capacity,
+            cleanup_interval_secs: config.cleanup_interval.as_secs(),
+            enforce_sequences: config.enforce_sequences,
+            require_registration_proof: config.require_registration_proof,
+            durable_tombstones: config.tombstone_path.is_some(),
+            tombstone_retention_secs: config.tombstone_retention.as_secs(),
+            log_mode: format!("{:?}", config.log_mode).to_lowercase(),
+            log_pseudonym_rotation_secs: config.log_pseudonym_rotation.as_secs(),
+            log_activity_interval_secs: config.log_activity_interval.as_secs(),
+            apns_configured: config.apns_configured(),
+            apns_sandbox: config.apns_sandbox,
+            admin_bind_addr: config.admin_bind_addr.clone(),
+        }
+    }
+}
+
+/// GET /admin/config
+async fn config(State(state): State<AdminState>) -> Json<ConfigDump> {
+    Json(ConfigDump::from(state.app.store.config()))
+}
+
+// === Cleanup ===
+
+/// Result of a forced cleanup run.
+#[derive(Debug, Serialize)]
+pub struct CleanupResponse {
+    pub expired_blobs: usize,
+    pub evicted_conversations: usize,
+}
+
+/// POST /admin/cleanup - Run TTL cleanup and inactive eviction now
+async fn cleanup(State(state): State<AdminState>) -> Json<CleanupResponse> {
+    let expired_blobs = crate::expiry::cleanup_expired(&state.app).await;
+    let evicted_conversations = state.app.auth.evict_inactive();
+
+    info!(
+        expired_blobs,
+        evicted_conversations, "Forced cleanup run by operator"
+    );
+
+    Json(CleanupResponse {
+        expired_blobs,
+        evicted_conversations,
+    })
+}
+
+// === APNS ===
+
+/// APNS client status.
+#[derive(Debug, Serialize)]
+pub struct ApnsStatusResponse {
+    /// All APNS settings are present.
+    pub configured: bool,
+    /// A client was created and pushes are sent.
+    pub enabled: bool,
+    pub sandbox: bool,
+    pub pending_pushes: usize,
+}
+
+/// GET /admin/apns
+async fn apns_status(State(state): State<AdminState>) -> Json<ApnsStatusResponse> {
+    let config = state.app.store.config();
+    Json(ApnsStatusResponse {
+        configured: config.apns_configured(),
+        enabled: state.app.apns.is_enabled(),
+        sandbox: config.apns_sandbox,
+        pending_pushes: state.app.pushes.len(),
+    })
+}
+
+// === Log Level ===
+
+/// Log filter in `RUST_LOG` syntax, e.g. `ash_backend=info,tower_http=warn`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogLevel {
+    pub filter: String,
+}
+
+/// GET /admin/log-level
+async fn log_level(State(state): State<AdminState>) -> Result<Json<LogLevel>, ApiError> {
+    let handle = state.log_filter.as_ref().ok_or(ApiError::Unavailable(
+        "runtime log level control not available",
+    ))?;
+    let filter = handle
+        .with_current(ToString::to_string)
+        .map_err(|_| ApiError::Internal)?;
+    Ok(Json(LogLevel { filter }))
+}
+
+/// PUT /admin/log-level - Replace the log filter
+///
+/// Only changes verbosity. What a log line may contain is still governed by
+/// `LOG_MODE` (see [`crate::log_policy`]).
+async fn set_log_level(
+    State(state): State<AdminState>,
+    Json(req): Json<LogLevel>,
+) -> Result<Json<LogLevel>, ApiError> {
+    let handle = state.log_filter.as_ref().ok_or(ApiError::Unavailable(
+        "runtime log level control not available",
+    ))?;
+    let filter = EnvFilter::try_new(&req.filter)
+        .map_err(|_| ApiError::InvalidInput("invalid log filter"))?;
+    handle.reload(filter).map_err(|_| ApiError::Internal)?;
+
+    info!(filter = %req.filter, "Log filter changed by operator");
+    Ok(Json(req))
+}
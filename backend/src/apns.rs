@@ -9,15 +9,37 @@ use a2::{
     Client, ClientConfig, DefaultNotificationBuilder, Endpoint, NotificationBuilder,
     NotificationOptions, Priority, PushType,
 };
+use serde::Serialize;
 use std::fs::File;
 use std::io::Read;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tracing::{debug, error, warn};
+use uuid::Uuid;
+
+/// Why a push was sent, added to the payload as `event`.
+///
+/// Pushes without an event only mean "something changed, sync now".
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PushEvent {
+    /// Blobs submitted by the receiving device expired unacknowledged.
+    DeliveryFailed { blob_ids: Vec<Uuid> },
+}
+
+/// Push a recording client was asked to send (see [`ApnsClient::recording`]).
+#[derive(Debug, Clone)]
+pub struct RecordedPush {
+    pub device_token: String,
+    pub conversation_id: Option<String>,
+    pub event: Option<PushEvent>,
+}
 
 /// APNS client wrapper
 pub struct ApnsClient {
     client: Option<Client>,
     bundle_id: String,
+    /// Pushes requested from a recording client, which sends nothing.
+    recorded: Option<Mutex<Vec<RecordedPush>>>,
 }
 
 impl ApnsClient {
@@ -28,6 +50,7 @@ impl ApnsClient {
             return Self {
                 client: None,
                 bundle_id: String::new(),
+                recorded: None,
             };
         }
 
@@ -44,6 +67,7 @@ impl ApnsClient {
                 return Self {
                     client: None,
                     bundle_id,
+                    recorded: None,
                 };
             }
         };
@@ -54,6 +78,7 @@ impl ApnsClient {
             return Self {
                 client: None,
                 bundle_id,
+                recorded: None,
             };
         }
 
@@ -77,7 +102,31 @@ impl ApnsClient {
             debug!(sandbox = config.apns_sandbox, "APNS client initialized");
         }
 
-        Self { client, bundle_id }
+        Self {
+            client,
+            bundle_id,
+            recorded: None,
+        }
+    }
+
+    /// Create a client that sends nothing and records the pushes it is
+    /// asked to send, so tests can check which devices were targeted.
+    pub fn recording() -> Self {
+        Self {
+            client: None,
+            bundle_id: String::new(),
+            recorded: Some(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Take the pushes recorded so far, oldest first.
+    ///
+    /// Always empty for a client not created by [`ApnsClient::recording`].
+    pub fn take_recorded(&self) -> Vec<RecordedPush> {
+        self.recorded
+            .as_ref()
+            .map(|recorded| std::mem::take(&mut *recorded.lock().expect("push record poisoned")))
+            .unwrap_or_default()
     }
 
     /// Send silent push notification to a device (best-effort)
@@ -85,6 +134,16 @@ impl ApnsClient {
         &self,
         device_token: &str,
         conversation_id: Option<&str>,
+    ) -> bool {
+        self.send_push(device_token, conversation_id, None).await
+    }
+
+    /// Send silent push notification carrying an event (best-effort)
+    async fn send_push(
+        &self,
+        device_token: &str,
+        conversation_id: Option<&str>,
+        event: Option<&PushEvent>,
     ) -> bool {
         let client = match &self.client {
             Some(c) => c,
@@ -109,6 +168,11 @@ impl ApnsClient {
                 debug!(error = %e, "Failed to add conversation_id to payload");
             }
         }
+        if let Some(event) = event {
+            if let Err(e) = payload.add_custom_data("event", event) {
+                debug!(error = %e, "Failed to add event to payload");
+            }
+        }
 
         match client.send(payload).await {
             Ok(response) => {
@@ -132,6 +196,27 @@ impl ApnsClient {
         devices: &[DeviceRegistration],
         conversation_id: Option<&str>,
     ) {
+        self.send_to_devices_with_event(devices, conversation_id, None)
+            .await;
+    }
+
+    /// Send silent push carrying `event` to multiple devices (best-effort,
+    /// parallel)
+    pub async fn send_to_devices_with_event(
+        &self,
+        devices: &[DeviceRegistration],
+        conversation_id: Option<&str>,
+        event: Option<&PushEvent>,
+    ) {
+        if let Some(recorded) = &self.recorded {
+            let mut recorded = recorded.lock().expect("push record poisoned");
+            recorded.extend(devices.iter().map(|d| RecordedPush {
+                device_token: d.device_token.clone(),
+                conversation_id: conversation_id.map(str::to_string),
+                event: event.cloned(),
+            }));
+            return;
+        }
         if self.client.is_none() || devices.is_empty() {
             return;
         }
@@ -139,7 +224,7 @@ impl ApnsClient {
         // Send to all devices in parallel (best-effort)
         let send_futures: Vec<_> = devices
             .iter()
-            .map(|d| self.send_silent_push(&d.device_token, conversation_id))
+            .map(|d| self.send_push(&d.device_token, conversation_id, event))
             .collect();
 
         let results = futures::future::join_all(send_futures).await;
@@ -15,6 +15,16 @@
 //! - Without pad access, tokens cannot be computed
 //! - Separate tokens for different operations prevent escalation
 //!
+//! # Registration Proof
+//!
+//! Clients may send a pad-derived proof (a hash of a reserved pad region)
+//! with registration. Their conversation ID is then a SHA-256 commitment to
+//! the token hashes and the proof, so the relay recomputes it and rejects a
+//! registration whose proof or hashes do not match the ID. Registrations
+//! without a proof use the legacy pad-derived ID and are accepted unless
+//! `REQUIRE_REGISTRATION_PROOF` is set. A later registration with different
+//! hashes is rejected as a mismatch.
+//!
 //! # DoS Protection
 //!
 //! - Maximum conversation limit prevents memory exhaustion
@@ -27,7 +37,7 @@ use axum::{
     Json,
 };
 use dashmap::DashMap;
-use ring::digest::{digest, SHA256};
+use ring::digest::{digest, Context, SHA256};
 use serde::Serialize;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -41,11 +51,11 @@ pub enum TokenType {
     Burn,
 }
 
-/// Maximum number of registered conversations (DoS protection).
+/// Default maximum number of registered conversations (DoS protection).
 /// At ~200 bytes per entry, 100k entries = ~20MB memory.
 pub const MAX_CONVERSATIONS: usize = 100_000;
 
-/// Inactive conversation TTL (24 hours).
+/// Default inactive conversation TTL (24 hours).
 /// Conversations with no activity are eligible for eviction.
 pub const INACTIVE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
@@ -67,25 +77,49 @@ pub enum RegisterResult {
     Ok,
     /// Already registered (idempotent success)
     AlreadyExists,
+    /// Already registered with different token hashes
+    Mismatch,
     /// Server at capacity, try again later
     AtCapacity,
 }
 
 /// Thread-safe storage for conversation authentication
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct AuthStore {
     /// Token hashes per conversation
     conversations: Arc<DashMap<String, ConversationAuth>>,
+    /// Registration limit
+    max_conversations: usize,
+    /// Idle time before a conversation may be evicted
+    inactive_ttl: Duration,
+}
+
+impl Default for AuthStore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AuthStore {
-    /// Create a new empty auth store
+    /// Create a new empty auth store with the default limits
     pub fn new() -> Self {
+        Self::with_limits(MAX_CONVERSATIONS, INACTIVE_TTL)
+    }
+
+    /// Create a new empty auth store with the given limits
+    pub fn with_limits(max_conversations: usize, inactive_ttl: Duration) -> Self {
         Self {
             conversations: Arc::new(DashMap::new()),
+            max_conversations,
+            inactive_ttl,
         }
     }
 
+    /// Registration limit
+    pub fn max_conversations(&self) -> usize {
+        self.max_conversations
+    }
+
     /// Current number of registered conversations
     pub fn len(&self) -> usize {
         self.conversations.len()
@@ -103,9 +137,12 @@ impl AuthStore {
     ///
     /// # DoS Protection
     ///
-    /// - Returns `AtCapacity` if MAX_CONVERSATIONS reached
+    /// - Returns `AtCapacity` if the conversation limit is reached
     /// - Evicts stale conversations before rejecting
     /// - Idempotent: re-registering same conv_id just updates timestamp
+    ///
+    /// Re-registering with different hashes returns `Mismatch` and leaves
+    /// the stored registration untouched.
     pub fn register(
         &self,
         conversation_id: &str,
@@ -113,21 +150,24 @@ impl AuthStore {
         burn_token_hash: String,
     ) -> RegisterResult {
         // Check if already registered (idempotent update)
-        if self.conversations.contains_key(conversation_id) {
-            // Update timestamp on re-registration
-            if let Some(mut entry) = self.conversations.get_mut(conversation_id) {
-                entry.last_activity = Instant::now();
+        if let Some(mut entry) = self.conversations.get_mut(conversation_id) {
+            if entry.auth_token_hash != auth_token_hash || entry.burn_token_hash != burn_token_hash
+            {
+                return RegisterResult::Mismatch;
             }
+
+            // Update timestamp on re-registration
+            entry.last_activity = Instant::now();
             return RegisterResult::AlreadyExists;
         }
 
         // Check capacity
-        if self.conversations.len() >= MAX_CONVERSATIONS {
+        if self.conversations.len() >= self.max_conversations {
             // Try to evict stale entries first
             self.evict_inactive();
 
             // Still at capacity?
-            if self.conversations.len() >= MAX_CONVERSATIONS {
+            if self.conversations.len() >= self.max_conversations {
                 return RegisterResult::AtCapacity;
             }
         }
@@ -144,11 +184,17 @@ impl AuthStore {
         RegisterResult::Ok
     }
 
-    /// Evict conversations inactive for longer than INACTIVE_TTL.
-    fn evict_inactive(&self) {
-        let cutoff = Instant::now() - INACTIVE_TTL;
+    /// Evict conversations inactive for longer than the inactive TTL,
+    /// returning how many were evicted.
+    pub fn evict_inactive(&self) -> usize {
+        // Nothing can be older than the process clock allows
+        let Some(cutoff) = Instant::now().checked_sub(self.inactive_ttl) else {
+            return 0;
+        };
+        let before = self.conversations.len();
         self.conversations
             .retain(|_, auth| auth.last_activity > cutoff);
+        before.saturating_sub(self.conversations.len())
     }
 
     /// Update last activity timestamp (call on successful auth).
@@ -201,6 +247,30 @@ pub fn hash_token(token: &str) -> String {
     hex::encode(hash.as_ref())
 }
 
+/// Domain separation prefix of the conversation ID commitment
+/// (must match ash-core's `auth::conversation_id_for_registration`).
+const CONVERSATION_ID_CONTEXT: &[u8] = b"ash-conversation-id-v1";
+
+/// Check that a registration proof and token hashes match the
+/// conversation ID they are registered under.
+///
+/// The ID is SHA-256 over a domain prefix and the lowercase hex of both
+/// token hashes and the proof. A forged proof, or hashes other than the
+/// ones derived from the pad, commit to a different ID.
+pub fn verify_registration_proof(
+    conversation_id: &str,
+    auth_token_hash: &str,
+    burn_token_hash: &str,
+    registration_proof: &str,
+) -> bool {
+    let mut ctx = Context::new(&SHA256);
+    ctx.update(CONVERSATION_ID_CONTEXT);
+    for part in [auth_token_hash, burn_token_hash, registration_proof] {
+        ctx.update(part.to_ascii_lowercase().as_bytes());
+    }
+    hex::encode(ctx.finish().as_ref()).eq_ignore_ascii_case(conversation_id)
+}
+
 /// Extracted and verified auth token from request
 #[derive(Debug, Clone)]
 pub struct AuthToken {
@@ -315,21 +385,101 @@ mod tests {
     }
 
     #[test]
-    fn auth_store_capacity_limit() {
+    fn auth_store_rejects_mismatched_registration() {
         let store = AuthStore::new();
+        let auth_hash = hash_token("auth");
+        let burn_hash = hash_token("burn");
+
+        store.register("conv-1", auth_hash.clone(), burn_hash.clone());
+
+        let result = store.register("conv-1", hash_token("squatter"), burn_hash.clone());
+        assert_eq!(result, RegisterResult::Mismatch);
+        let result = store.register("conv-1", auth_hash.clone(), hash_token("squatter"));
+        assert_eq!(result, RegisterResult::Mismatch);
+
+        // The original registration is untouched
+        assert!(store.verify_auth_token("conv-1", "auth"));
+        assert!(store.verify_burn_token("conv-1", "burn"));
+    }
+
+    #[test]
+    fn registration_proof_matches_core_conversation_id() {
+        let pad: Vec<u8> = (0u8..=255).cycle().take(1024).collect();
+        let (legacy_id, auth_token, burn_token) = ash_core::auth::derive_all_tokens(&pad).unwrap();
+        let conversation_id = ash_core::auth::derive_registered_conversation_id(&pad).unwrap();
+        let (auth_hash, burn_hash) = (hash_token(&auth_token), hash_token(&burn_token));
+        let proof = ash_core::auth::derive_registration_proof(&pad).unwrap();
+
+        assert!(verify_registration_proof(
+            &conversation_id,
+            &auth_hash,
+            &burn_hash,
+            &proof
+        ));
+        assert!(verify_registration_proof(
+            &conversation_id.to_uppercase(),
+            &auth_hash.to_uppercase(),
+            &burn_hash,
+            &proof.to_uppercase()
+        ));
+
+        // A forged proof, another party's hashes or the legacy ID do not match
+        assert!(!verify_registration_proof(
+            &legacy_id, &auth_hash, &burn_hash, &proof
+        ));
+        assert!(!verify_registration_proof(
+            &conversation_id,
+            &auth_hash,
+            &burn_hash,
+            &"0".repeat(64)
+        ));
+        assert!(!verify_registration_proof(
+            &conversation_id,
+            &hash_token("squatter"),
+            &burn_hash,
+            &proof
+        ));
+    }
+
+    #[test]
+    fn auth_store_capacity_limit() {
+        let store = AuthStore::with_limits(100, INACTIVE_TTL);
 
-        // Register up to max (but use a smaller test limit)
-        // For real tests, we'd mock MAX_CONVERSATIONS
+        // Register up to max
         for i in 0..100 {
             let result = store.register(
-                &format!("conv-{:064x}", i),
-                format!("{:064x}", i),
+                &format!("conv-{i:064x}"),
+                format!("{i:064x}"),
                 format!("{:064x}", i + 1000),
             );
-            assert_eq!(result, RegisterResult::Ok, "Failed at {}", i);
+            assert_eq!(result, RegisterResult::Ok, "Failed at {i}");
         }
 
         assert_eq!(store.len(), 100);
+
+        // Nothing is inactive, so nothing can be evicted to make room
+        let result = store.register("conv-full", hash_token("auth"), hash_token("burn"));
+        assert_eq!(result, RegisterResult::AtCapacity);
+        assert!(!store.is_registered("conv-full"));
+
+        // Re-registering an existing conversation is still allowed
+        let result = store.register(
+            &format!("conv-{:064x}", 0),
+            format!("{:064x}", 0),
+            format!("{:064x}", 1000),
+        );
+        assert_eq!(result, RegisterResult::AlreadyExists);
+    }
+
+    #[test]
+    fn auth_store_evicts_inactive_at_capacity() {
+        let store = AuthStore::with_limits(1, Duration::ZERO);
+        store.register("conv-1", hash_token("auth"), hash_token("burn"));
+
+        let result = store.register("conv-2", hash_token("auth"), hash_token("burn"));
+        assert_eq!(result, RegisterResult::Ok);
+        assert!(!store.is_registered("conv-1"));
+        assert!(store.is_registered("conv-2"));
     }
 
     #[test]
@@ -1,40 +1,117 @@
-//! Server configuration loaded from environment variables.
+//! Server configuration loaded from an optional TOML file and environment
+//! variables.
 //!
 //! All configuration is loaded at startup. No secrets are logged.
 //!
+//! # Sources
+//!
+//! Settings are resolved in this order, later sources winning:
+//!
+//! 1. Built-in defaults ([`Config::default`])
+//! 2. A TOML file given with `--config <path>` or `CONFIG_FILE`, using the
+//!    lower-case variable names as keys (`max_ciphertext_size = 8192`)
+//! 3. Environment variables (and `.env`)
+//!
+//! Values that fail to parse, unknown keys in the file and inconsistent
+//! settings are errors; nothing falls back to a default silently. Empty
+//! environment variables are treated as unset. `ash-backend --check-config`
+//! validates the configuration and exits.
+//!
 //! # Environment Variables
 //!
 //! | Variable | Default | Description |
 //! |----------|---------|-------------|
 //! | `BIND_ADDR` | `0.0.0.0` | Server bind address |
 //! | `PORT` | `8080` | Server port |
+//! | `TLS_CERT_PATH` | - | PEM certificate chain (plain HTTP if unset) |
+//! | `TLS_KEY_PATH` | - | PEM private key |
+//! | `TLS_RELOAD_INTERVAL_SECS` | `60` | Certificate change check interval |
+//! | `ADMIN_BIND_ADDR` | - | Operator API address, e.g. `127.0.0.1:9090` (disabled if unset) |
+//! | `ADMIN_KEY` | - | Bearer key for the operator API (required with `ADMIN_BIND_ADDR`) |
+//! | `SHUTDOWN_GRACE_SECS` | `10` | Time allowed for connections, then pushes, to drain on shutdown |
 //! | `BURN_TTL_SECS` | `300` | Burn flag TTL |
 //! | `DEVICE_TOKEN_TTL_SECS` | `86400` | Device registration TTL |
 //! | `MAX_CIPHERTEXT_SIZE` | `8192` | Max ciphertext size (bytes) |
 //! | `MAX_BLOBS_PER_CONVERSATION` | `50` | Max queued messages |
-//! | `CLEANUP_INTERVAL_SECS` | `10` | TTL cleanup interval |
+//! | `MAX_CONVERSATIONS` | `100000` | Max registered conversations |
+//! | `INACTIVE_TTL_SECS` | `86400` | Idle time after which a conversation may be evicted |
+//! | `BROADCAST_CAPACITY` | `1024` | SSE event buffer shared by all streams |
+//! | `CLEANUP_INTERVAL_SECS` | `10` | Sweep interval for burn flags, tombstones and devices |
+//! | `ENFORCE_SEQUENCES` | `false` | Reject non-increasing sequences per sender |
+//! | `REQUIRE_REGISTRATION_PROOF` | `false` | Reject registrations without a pad-derived proof |
+//! | `TOMBSTONE_PATH` | - | Burn tombstone file (RAM-only if unset) |
+//! | `TOMBSTONE_KEY_PATH` | `<TOMBSTONE_PATH>.key` | Key for hashing tombstoned IDs |
+//! | `TOMBSTONE_RETENTION_SECS` | `2592000` | How long burns are remembered |
+//! | `LOG_MODE` | `pseudonymous` | `pseudonymous` or `strict` (see [`crate::log_policy`]) |
+//! | `LOG_PSEUDONYM_ROTATION_SECS` | `3600` | Lifetime of the conversation pseudonym key |
+//! | `LOG_ACTIVITY_INTERVAL_SECS` | `60` | Interval between aggregated activity reports |
 //! | `APNS_TEAM_ID` | - | Apple team ID |
 //! | `APNS_KEY_ID` | - | APNS key ID |
 //! | `APNS_KEY_PATH` | - | Path to .p8 key file |
 //! | `APNS_BUNDLE_ID` | - | App bundle identifier |
 //! | `APNS_SANDBOX` | `true` | Use sandbox environment |
 
+use crate::log_policy::LogMode;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::Duration;
 
 /// Fixed message TTL (5 minutes). Not configurable by design.
 pub const MESSAGE_TTL: Duration = Duration::from_secs(300);
 
+/// Request body bytes reserved for the JSON envelope around a submitted
+/// ciphertext (conversation ID, sequence, field names).
+const SUBMIT_ENVELOPE_SIZE: usize = 512;
+
 /// Default values as constants for clarity.
 mod defaults {
     use std::time::Duration;
 
+    pub use crate::auth::{INACTIVE_TTL, MAX_CONVERSATIONS};
+
     pub const BIND_ADDR: &str = "0.0.0.0";
     pub const PORT: u16 = 8080;
     pub const BURN_TTL: Duration = Duration::from_secs(300);
     pub const DEVICE_TOKEN_TTL: Duration = Duration::from_secs(24 * 3600);
     pub const MAX_CIPHERTEXT_SIZE: usize = 8 * 1024;
     pub const MAX_BLOBS_PER_CONVERSATION: usize = 50;
+    pub const BROADCAST_CAPACITY: usize = 1024;
     pub const CLEANUP_INTERVAL: Duration = Duration::from_secs(10);
+    pub const TLS_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+    pub const SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+    pub const TOMBSTONE_RETENTION: Duration = Duration::from_secs(30 * 24 * 3600);
+    pub const LOG_PSEUDONYM_ROTATION: Duration = Duration::from_secs(3600);
+    pub const LOG_ACTIVITY_INTERVAL: Duration = Duration::from_secs(60);
+}
+
+/// Configuration loading errors.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("failed to parse config file {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+
+    #[error("unknown settings in {path}: {}", keys.join(", "))]
+    UnknownKeys { path: PathBuf, keys: Vec<String> },
+
+    #[error("invalid {key} {value:?} (from {origin}): {reason}")]
+    InvalidValue {
+        key: &'static str,
+        value: String,
+        origin: String,
+        reason: String,
+    },
+
+    #[error("invalid configuration:\n  - {}", problems.join("\n  - "))]
+    Invalid { problems: Vec<String> },
 }
 
 /// Server configuration.
@@ -45,6 +122,19 @@ pub struct Config {
     pub bind_addr: String,
     /// Server port.
     pub port: u16,
+    /// PEM certificate chain for built-in TLS (plain HTTP if unset).
+    pub tls_cert_path: Option<PathBuf>,
+    /// PEM private key for built-in TLS.
+    pub tls_key_path: Option<PathBuf>,
+    /// Interval between checks for a renewed certificate or key.
+    pub tls_reload_interval: Duration,
+    /// Address of the operator API (disabled if unset).
+    pub admin_bind_addr: Option<String>,
+    /// Bearer key for the operator API. Never logged or dumped.
+    pub admin_key: Option<String>,
+    /// On shutdown, how long open connections and then pending push sends
+    /// are each given to finish.
+    pub shutdown_grace: Duration,
 
     // === TTL Configuration ===
     /// Fixed TTL for encrypted message blobs (5 minutes, not configurable).
@@ -59,9 +149,43 @@ pub struct Config {
     pub max_ciphertext_size: usize,
     /// Maximum queued blobs per conversation.
     pub max_blobs_per_conversation: usize,
+    /// Maximum registered conversations (DoS protection).
+    pub max_conversations: usize,
+    /// Idle time after which a conversation registration may be evicted.
+    pub inactive_ttl: Duration,
+    /// Capacity of the broadcast channel feeding SSE streams. Streams that
+    /// fall further behind skip events.
+    pub broadcast_capacity: usize,
     /// Interval between TTL cleanup runs.
     pub cleanup_interval: Duration,
 
+    // === Message Ordering ===
+    /// Reject submissions whose sequence is not greater than the highest
+    /// sequence already accepted from the same sender role.
+    pub enforce_sequences: bool,
+
+    // === Registration ===
+    /// Reject conversation registrations that carry no registration proof.
+    /// Off by default; enable once no clients without proof support remain.
+    pub require_registration_proof: bool,
+
+    // === Burn Tombstones ===
+    /// File persisting burn tombstones across restarts (RAM-only if unset).
+    pub tombstone_path: Option<PathBuf>,
+    /// Key file for hashing tombstoned conversation IDs.
+    /// Defaults to `tombstone_path` with a `.key` extension.
+    pub tombstone_key_path: Option<PathBuf>,
+    /// How long a burned conversation keeps reporting burned.
+    pub tombstone_retention: Duration,
+
+    // === Logging ===
+    /// What per-request log lines may contain.
+    pub log_mode: LogMode,
+    /// How long a conversation pseudonym key is used before it is replaced.
+    pub log_pseudonym_rotation: Duration,
+    /// Interval between aggregated activity reports.
+    pub log_activity_interval: Duration,
+
     // === APNS Configuration ===
     /// Apple team ID.
     pub apns_team_id: Option<String>,
@@ -76,31 +200,183 @@ pub struct Config {
 }
 
 impl Config {
-    /// Load configuration from environment variables.
-    pub fn from_env() -> Self {
-        Self {
-            bind_addr: env_or("BIND_ADDR", defaults::BIND_ADDR.to_string()),
-            port: env_parse("PORT", defaults::PORT),
+    /// Load and validate configuration from environment variables.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::load(None)
+    }
+
+    /// Load and validate configuration from an optional TOML file,
+    /// overridden by environment variables.
+    pub fn load(file: Option<&Path>) -> Result<Self, ConfigError> {
+        let file = file.map(FileValues::read).transpose()?;
+        let config = Self::from_sources(file, &|key| std::env::var(key).ok())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Resolve every setting from `env`, then `file`, then the default.
+    fn from_sources(
+        file: Option<FileValues>,
+        env: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Self, ConfigError> {
+        let src = Sources {
+            file: RefCell::new(file),
+            env,
+        };
+        let d = Self::default();
+
+        let config = Self {
+            bind_addr: src.string("BIND_ADDR").unwrap_or(d.bind_addr),
+            port: src.parse("PORT", d.port)?,
+            tls_cert_path: src.path("TLS_CERT_PATH"),
+            tls_key_path: src.path("TLS_KEY_PATH"),
+            tls_reload_interval: src.secs("TLS_RELOAD_INTERVAL_SECS", d.tls_reload_interval)?,
+            admin_bind_addr: src.string("ADMIN_BIND_ADDR"),
+            admin_key: src.string("ADMIN_KEY"),
+            shutdown_grace: src.secs("SHUTDOWN_GRACE_SECS", d.shutdown_grace)?,
             blob_ttl: MESSAGE_TTL,
-            burn_ttl: Duration::from_secs(env_parse("BURN_TTL_SECS", defaults::BURN_TTL.as_secs())),
-            device_token_ttl: Duration::from_secs(env_parse(
-                "DEVICE_TOKEN_TTL_SECS",
-                defaults::DEVICE_TOKEN_TTL.as_secs(),
-            )),
-            max_ciphertext_size: env_parse("MAX_CIPHERTEXT_SIZE", defaults::MAX_CIPHERTEXT_SIZE),
-            max_blobs_per_conversation: env_parse(
+            burn_ttl: src.secs("BURN_TTL_SECS", d.burn_ttl)?,
+            device_token_ttl: src.secs("DEVICE_TOKEN_TTL_SECS", d.device_token_ttl)?,
+            max_ciphertext_size: src.parse("MAX_CIPHERTEXT_SIZE", d.max_ciphertext_size)?,
+            max_blobs_per_conversation: src
+                .parse("MAX_BLOBS_PER_CONVERSATION", d.max_blobs_per_conversation)?,
+            max_conversations: src.parse("MAX_CONVERSATIONS", d.max_conversations)?,
+            inactive_ttl: src.secs("INACTIVE_TTL_SECS", d.inactive_ttl)?,
+            broadcast_capacity: src.parse("BROADCAST_CAPACITY", d.broadcast_capacity)?,
+            cleanup_interval: src.secs("CLEANUP_INTERVAL_SECS", d.cleanup_interval)?,
+            enforce_sequences: src.flag("ENFORCE_SEQUENCES", d.enforce_sequences)?,
+            require_registration_proof: src
+                .flag("REQUIRE_REGISTRATION_PROOF", d.require_registration_proof)?,
+            tombstone_path: src.path("TOMBSTONE_PATH"),
+            tombstone_key_path: src.path("TOMBSTONE_KEY_PATH"),
+            tombstone_retention: src.secs("TOMBSTONE_RETENTION_SECS", d.tombstone_retention)?,
+            log_mode: src.parse("LOG_MODE", d.log_mode)?,
+            log_pseudonym_rotation: src
+                .secs("LOG_PSEUDONYM_ROTATION_SECS", d.log_pseudonym_rotation)?,
+            log_activity_interval: src
+                .secs("LOG_ACTIVITY_INTERVAL_SECS", d.log_activity_interval)?,
+            apns_team_id: src.string("APNS_TEAM_ID"),
+            apns_key_id: src.string("APNS_KEY_ID"),
+            apns_key_path: src.string("APNS_KEY_PATH"),
+            apns_bundle_id: src.string("APNS_BUNDLE_ID"),
+            apns_sandbox: src.flag("APNS_SANDBOX", d.apns_sandbox)?,
+        };
+
+        src.finish()?;
+        Ok(config)
+    }
+
+    /// Check settings against each other and against fixed limits.
+    ///
+    /// Reports every problem found, not just the first.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        let max_ciphertext = max_ciphertext_for_body(crate::MAX_BODY_SIZE);
+        if self.max_ciphertext_size == 0 {
+            problems.push("MAX_CIPHERTEXT_SIZE must be greater than 0".to_string());
+        } else if self.max_ciphertext_size > max_ciphertext {
+            problems.push(format!(
+                "MAX_CIPHERTEXT_SIZE is {} but base64-encoded submissions must fit the \
+                 {}-byte request body limit; use at most {max_ciphertext}",
+                self.max_ciphertext_size,
+                crate::MAX_BODY_SIZE
+            ));
+        }
+
+        for (key, value) in [
+            (
                 "MAX_BLOBS_PER_CONVERSATION",
-                defaults::MAX_BLOBS_PER_CONVERSATION,
+                self.max_blobs_per_conversation,
             ),
-            cleanup_interval: Duration::from_secs(env_parse(
-                "CLEANUP_INTERVAL_SECS",
-                defaults::CLEANUP_INTERVAL.as_secs(),
-            )),
-            apns_team_id: std::env::var("APNS_TEAM_ID").ok(),
-            apns_key_id: std::env::var("APNS_KEY_ID").ok(),
-            apns_key_path: std::env::var("APNS_KEY_PATH").ok(),
-            apns_bundle_id: std::env::var("APNS_BUNDLE_ID").ok(),
-            apns_sandbox: env_bool("APNS_SANDBOX", true),
+            ("MAX_CONVERSATIONS", self.max_conversations),
+            ("BROADCAST_CAPACITY", self.broadcast_capacity),
+        ] {
+            if value == 0 {
+                problems.push(format!("{key} must be greater than 0"));
+            }
+        }
+
+        for (key, value) in [
+            ("INACTIVE_TTL_SECS", self.inactive_ttl),
+            ("CLEANUP_INTERVAL_SECS", self.cleanup_interval),
+            ("TLS_RELOAD_INTERVAL_SECS", self.tls_reload_interval),
+            ("LOG_ACTIVITY_INTERVAL_SECS", self.log_activity_interval),
+        ] {
+            if value.is_zero() {
+                problems.push(format!("{key} must be greater than 0"));
+            }
+        }
+
+        match (&self.tls_cert_path, &self.tls_key_path) {
+            (Some(_), None) => {
+                problems.push("TLS_CERT_PATH is set but TLS_KEY_PATH is not".to_string());
+            }
+            (None, Some(_)) => {
+                problems.push("TLS_KEY_PATH is set but TLS_CERT_PATH is not".to_string());
+            }
+            _ => {}
+        }
+        if let Some((cert, key)) = self.tls_paths() {
+            for (name, path) in [("TLS_CERT_PATH", cert), ("TLS_KEY_PATH", key)] {
+                if !path.is_file() {
+                    problems.push(format!("{name} {} does not exist", path.display()));
+                }
+            }
+        }
+
+        if self.admin_bind_addr.is_some() {
+            match &self.admin_key {
+                None => problems.push("ADMIN_BIND_ADDR is set but ADMIN_KEY is not".to_string()),
+                Some(key) if key.len() < crate::admin::MIN_ADMIN_KEY_LEN => {
+                    problems.push(format!(
+                        "ADMIN_KEY must be at least {} characters",
+                        crate::admin::MIN_ADMIN_KEY_LEN
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        if self.tombstone_key_path.is_some() && self.tombstone_path.is_none() {
+            problems.push("TOMBSTONE_KEY_PATH is set but TOMBSTONE_PATH is not".to_string());
+        }
+
+        let apns = [
+            ("APNS_TEAM_ID", self.apns_team_id.is_some()),
+            ("APNS_KEY_ID", self.apns_key_id.is_some()),
+            ("APNS_KEY_PATH", self.apns_key_path.is_some()),
+            ("APNS_BUNDLE_ID", self.apns_bundle_id.is_some()),
+        ];
+        if apns.iter().any(|(_, set)| *set) && !self.apns_configured() {
+            let missing: Vec<_> = apns
+                .iter()
+                .filter(|(_, set)| !set)
+                .map(|(name, _)| *name)
+                .collect();
+            problems.push(format!(
+                "APNS is partially configured; also set {} or unset the others",
+                missing.join(", ")
+            ));
+        }
+        if let Some(path) = &self.apns_key_path {
+            if !Path::new(path).is_file() {
+                problems.push(format!("APNS_KEY_PATH {path} does not exist"));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid { problems })
+        }
+    }
+
+    /// Certificate and key paths, if built-in TLS is configured.
+    pub fn tls_paths(&self) -> Option<(&Path, &Path)> {
+        match (&self.tls_cert_path, &self.tls_key_path) {
+            (Some(cert), Some(key)) => Some((cert, key)),
+            _ => None,
         }
     }
 
@@ -113,55 +389,369 @@ impl Config {
     }
 }
 
+/// Built-in defaults, without reading the environment.
 impl Default for Config {
     fn default() -> Self {
-        Self::from_env()
+        Self {
+            bind_addr: defaults::BIND_ADDR.to_string(),
+            port: defaults::PORT,
+            tls_cert_path: None,
+            tls_key_path: None,
+            tls_reload_interval: defaults::TLS_RELOAD_INTERVAL,
+            admin_bind_addr: None,
+            admin_key: None,
+            shutdown_grace: defaults::SHUTDOWN_GRACE,
+            blob_ttl: MESSAGE_TTL,
+            burn_ttl: defaults::BURN_TTL,
+            device_token_ttl: defaults::DEVICE_TOKEN_TTL,
+            max_ciphertext_size: defaults::MAX_CIPHERTEXT_SIZE,
+            max_blobs_per_conversation: defaults::MAX_BLOBS_PER_CONVERSATION,
+            max_conversations: defaults::MAX_CONVERSATIONS,
+            inactive_ttl: defaults::INACTIVE_TTL,
+            broadcast_capacity: defaults::BROADCAST_CAPACITY,
+            cleanup_interval: defaults::CLEANUP_INTERVAL,
+            enforce_sequences: false,
+            require_registration_proof: false,
+            tombstone_path: None,
+            tombstone_key_path: None,
+            tombstone_retention: defaults::TOMBSTONE_RETENTION,
+            log_mode: LogMode::default(),
+            log_pseudonym_rotation: defaults::LOG_PSEUDONYM_ROTATION,
+            log_activity_interval: defaults::LOG_ACTIVITY_INTERVAL,
+            apns_team_id: None,
+            apns_key_id: None,
+            apns_key_path: None,
+            apns_bundle_id: None,
+            apns_sandbox: true,
+        }
     }
 }
 
-/// Get environment variable or default.
-fn env_or(key: &str, default: String) -> String {
-    std::env::var(key).unwrap_or(default)
+/// Largest ciphertext whose base64 encoding fits a request body of
+/// `body_limit` bytes alongside the submission envelope.
+fn max_ciphertext_for_body(body_limit: usize) -> usize {
+    body_limit.saturating_sub(SUBMIT_ENVELOPE_SIZE) / 4 * 3
 }
 
-/// Parse environment variable or use default.
-fn env_parse<T: std::str::FromStr>(key: &str, default: T) -> T {
-    std::env::var(key)
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(default)
+/// Scalar settings read from a TOML config file, keyed in lower case.
+#[derive(Debug)]
+struct FileValues {
+    path: PathBuf,
+    values: BTreeMap<String, String>,
 }
 
-/// Parse boolean environment variable (accepts "true", "1", "yes").
-fn env_bool(key: &str, default: bool) -> bool {
-    std::env::var(key)
-        .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
-        .unwrap_or(default)
+impl FileValues {
+    fn read(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> Result<Self, ConfigError> {
+        let table: toml::value::Table = toml::from_str(text).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+        let mut values = BTreeMap::new();
+        for (key, value) in table {
+            let value = match value {
+                toml::Value::String(s) => s,
+                toml::Value::Integer(i) => i.to_string(),
+                toml::Value::Boolean(b) => b.to_string(),
+                other => {
+                    return Err(ConfigError::Parse {
+                        path: path.to_path_buf(),
+                        message: format!(
+                            "{key}: expected a string, integer or boolean, found {}",
+                            other.type_str()
+                        ),
+                    })
+                }
+            };
+            values.insert(key, value);
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            values,
+        })
+    }
+}
+
+/// Raw setting lookup across the environment and the config file.
+///
+/// File values are consumed as they are looked up, so whatever remains at
+/// the end is an unknown key.
+struct Sources<'a> {
+    file: RefCell<Option<FileValues>>,
+    env: &'a dyn Fn(&str) -> Option<String>,
+}
+
+impl Sources<'_> {
+    /// Raw value and where it came from.
+    fn raw(&self, key: &str) -> Option<(String, String)> {
+        let from_file = self.file.borrow_mut().as_mut().and_then(|file| {
+            let value = file.values.remove(&key.to_lowercase())?;
+            Some((value, file.path.display().to_string()))
+        });
+
+        match (self.env)(key).filter(|v| !v.is_empty()) {
+            Some(value) => Some((value, "environment".to_string())),
+            None => from_file,
+        }
+    }
+
+    fn string(&self, key: &str) -> Option<String> {
+        self.raw(key).map(|(value, _)| value)
+    }
+
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        self.string(key).map(PathBuf::from)
+    }
+
+    fn parse<T>(&self, key: &'static str, default: T) -> Result<T, ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.raw(key) {
+            None => Ok(default),
+            Some((value, origin)) => {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|e: T::Err| ConfigError::InvalidValue {
+                        key,
+                        reason: e.to_string(),
+                        value,
+                        origin,
+                    })
+            }
+        }
+    }
+
+    fn secs(&self, key: &'static str, default: Duration) -> Result<Duration, ConfigError> {
+        self.parse(key, default.as_secs()).map(Duration::from_secs)
+    }
+
+    /// Boolean setting (accepts "true"/"false", "1"/"0", "yes"/"no").
+    fn flag(&self, key: &'static str, default: bool) -> Result<bool, ConfigError> {
+        match self.raw(key) {
+            None => Ok(default),
+            Some((value, origin)) => match value.trim().to_lowercase().as_str() {
+                "true" | "1" | "yes" => Ok(true),
+                "false" | "0" | "no" => Ok(false),
+                _ => Err(ConfigError::InvalidValue {
+                    key,
+                    value,
+                    origin,
+                    reason: "expected true or false".to_string(),
+                }),
+            },
+        }
+    }
+
+    /// Fail on file keys that no setting consumed.
+    fn finish(self) -> Result<(), ConfigError> {
+        match self.file.into_inner() {
+            Some(file) if !file.values.is_empty() => Err(ConfigError::UnknownKeys {
+                path: file.path,
+                keys: file.values.into_keys().collect(),
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
+
+    fn load(file: Option<&str>, env: &[(&str, &str)]) -> Result<Config, ConfigError> {
+        let file = file
+            .map(|text| FileValues::parse(Path::new("ash.toml"), text))
+            .transpose()?;
+        let env: HashMap<String, String> = env
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        let config = Config::from_sources(file, &|key| env.get(key).cloned())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn problems(result: Result<Config, ConfigError>) -> Vec<String> {
+        match result {
+            Err(ConfigError::Invalid { problems }) => problems,
+            other => panic!("expected validation problems, got {other:?}"),
+        }
+    }
 
     #[test]
     fn default_config_is_valid() {
-        // Clear any env vars that might interfere
-        std::env::remove_var("BIND_ADDR");
-        std::env::remove_var("PORT");
-
-        let config = Config::from_env();
+        let config = load(None, &[]).unwrap();
 
         assert_eq!(config.bind_addr, "0.0.0.0");
         assert_eq!(config.port, 8080);
         assert_eq!(config.blob_ttl, MESSAGE_TTL);
         assert_eq!(config.max_ciphertext_size, 8 * 1024);
+        assert_eq!(config.max_conversations, crate::auth::MAX_CONVERSATIONS);
         assert!(!config.apns_configured());
+        Config::default().validate().unwrap();
     }
 
     #[test]
     fn env_parsing_works() {
-        assert_eq!(env_parse::<u16>("NONEXISTENT_VAR", 42), 42);
-        assert!(env_bool("NONEXISTENT_VAR", true));
-        assert!(!env_bool("NONEXISTENT_VAR", false));
+        let config = load(
+            None,
+            &[
+                ("PORT", "9000"),
+                ("ENFORCE_SEQUENCES", "yes"),
+                ("APNS_SANDBOX", "0"),
+                ("BURN_TTL_SECS", " 60 "),
+                ("BIND_ADDR", ""),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(config.port, 9000);
+        assert!(config.enforce_sequences);
+        assert!(!config.apns_sandbox);
+        assert_eq!(config.burn_ttl, Duration::from_secs(60));
+        assert_eq!(config.bind_addr, "0.0.0.0");
+    }
+
+    #[test]
+    fn unparsable_values_are_errors() {
+        let err = load(None, &[("MAX_CIPHERTEXT_SIZE", "8k")]).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidValue { key: "MAX_CIPHERTEXT_SIZE", ref origin, .. }
+                if origin == "environment"
+        ));
+
+        let err = load(None, &[("ENFORCE_SEQUENCES", "ture")]).unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidValue {
+                key: "ENFORCE_SEQUENCES",
+                ..
+            }
+        ));
+
+        let err = load(None, &[("LOG_MODE", "verbose")]).unwrap_err();
+        assert!(err.to_string().contains("unknown log mode"));
+    }
+
+    #[test]
+    fn file_is_layered_under_env() {
+        let file = r#"
+            port = 9000
+            bind_addr = "127.0.0.1"
+            max_conversations = 500
+            enforce_sequences = true
+            log_mode = "strict"
+        "#;
+
+        let config = load(Some(file), &[("PORT", "9100")]).unwrap();
+
+        assert_eq!(config.port, 9100);
+        assert_eq!(config.bind_addr, "127.0.0.1");
+        assert_eq!(config.max_conversations, 500);
+        assert!(config.enforce_sequences);
+        assert_eq!(config.log_mode, LogMode::Strict);
+    }
+
+    #[test]
+    fn file_errors_name_the_file() {
+        let err = load(Some("max_ciphertext_size = \"big\""), &[]).unwrap_err();
+        assert!(err.to_string().contains("(from ash.toml)"), "{err}");
+
+        let err = load(Some("port = [8080]"), &[]).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { .. }), "{err}");
+
+        let err = load(Some("port = "), &[]).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { .. }), "{err}");
+    }
+
+    #[test]
+    fn unknown_file_keys_are_rejected() {
+        let err = load(Some("prot = 8080\nblob_ttl_secs = 60"), &[]).unwrap_err();
+        match err {
+            ConfigError::UnknownKeys { keys, .. } => {
+                assert_eq!(keys, ["blob_ttl_secs", "prot"]);
+            }
+            other => panic!("expected unknown keys, got {other:?}"),
+        }
+
+        // A key overridden by the environment is still known
+        load(Some("port = 9000"), &[("PORT", "9100")]).unwrap();
+    }
+
+    #[test]
+    fn ciphertext_limit_must_fit_body_limit() {
+        let max = max_ciphertext_for_body(crate::MAX_BODY_SIZE);
+        load(None, &[("MAX_CIPHERTEXT_SIZE", &max.to_string())]).unwrap();
+
+        let problems = problems(load(
+            None,
+            &[("MAX_CIPHERTEXT_SIZE", &(max + 1).to_string())],
+        ));
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains(&format!("use at most {max}")));
+    }
+
+    #[test]
+    fn validation_reports_every_problem() {
+        let problems = problems(load(
+            None,
+            &[
+                ("APNS_TEAM_ID", "TEAM"),
+                ("APNS_BUNDLE_ID", "app.ash"),
+                ("TLS_KEY_PATH", "/nonexistent/key.pem"),
+                ("ADMIN_BIND_ADDR", "127.0.0.1:9090"),
+                ("ADMIN_KEY", "short"),
+                ("BROADCAST_CAPACITY", "0"),
+            ],
+        ));
+
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("APNS_KEY_ID, APNS_KEY_PATH")));
+        assert!(problems.iter().any(|p| p.contains("TLS_CERT_PATH")));
+        assert!(problems.iter().any(|p| p.contains("ADMIN_KEY")));
+        assert!(problems.iter().any(|p| p.contains("BROADCAST_CAPACITY")));
+    }
+
+    #[test]
+    fn admin_requires_key() {
+        let problems = problems(load(None, &[("ADMIN_BIND_ADDR", "127.0.0.1:9090")]));
+        assert_eq!(problems, ["ADMIN_BIND_ADDR is set but ADMIN_KEY is not"]);
+
+        load(
+            None,
+            &[
+                ("ADMIN_BIND_ADDR", "127.0.0.1:9090"),
+                ("ADMIN_KEY", &"k".repeat(crate::admin::MIN_ADMIN_KEY_LEN)),
+            ],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn tls_paths_must_be_set_together() {
+        assert_eq!(
+            problems(load(None, &[("TLS_CERT_PATH", "/nonexistent/cert.pem")])),
+            ["TLS_CERT_PATH is set but TLS_KEY_PATH is not"]
+        );
+        assert_eq!(
+            problems(load(None, &[("TLS_KEY_PATH", "/nonexistent/key.pem")])),
+            ["TLS_KEY_PATH is set but TLS_CERT_PATH is not"]
+        );
     }
 }
@@ -1,203 +1,221 @@
-//! Expiry notification worker.
+//! Expiry worker and TTL cleanup.
 //!
-//! Background task that monitors message TTL and sends notifications
-//! for messages about to expire, based on notification flags.
-
-use crate::apns::ApnsClient;
+//! A single background task drives the store's expiry schedule (see
+//! [`crate::schedule`]): it sleeps until the next deadline, or until an
+//! earlier one is scheduled, then sends the expiry warnings that came due
+//! and purges expired blobs. Blobs purged without an ACK are reported to
+//! the device that submitted them (`NOTIFY_DELIVERY_FAILED`). Burn flags,
+//! tombstones and device registrations are swept every
+//! `CLEANUP_INTERVAL_SECS`.
+
+use crate::apns::PushEvent;
 use crate::handlers::AppState;
-use crate::models::notification_flags;
-use chrono::{Duration, Utc};
-use std::collections::HashSet;
+use crate::models::{
+    BroadcastEvent, ConversationId, DeviceHandle, DeviceRegistration, StreamEvent,
+};
+use crate::store::ExpiredBlob;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{debug, info, warn};
+use tokio::time::Instant;
+use tracing::{debug, info};
 use uuid::Uuid;
 
-/// Tracks which expiry notifications have been sent to avoid duplicates
-#[derive(Default)]
-struct ExpiryTracker {
-    /// Blob IDs for which 5-min warning was sent
-    warned_5min: HashSet<Uuid>,
-    /// Blob IDs for which 1-min warning was sent
-    warned_1min: HashSet<Uuid>,
-    /// Blob IDs for which expiry notification was sent
-    expired: HashSet<Uuid>,
-}
-
-impl ExpiryTracker {
-    fn cleanup_old(&mut self, valid_ids: &HashSet<Uuid>) {
-        self.warned_5min.retain(|id| valid_ids.contains(id));
-        self.warned_1min.retain(|id| valid_ids.contains(id));
-        self.expired.retain(|id| valid_ids.contains(id));
-    }
-}
-
-/// Expiry notification worker
+/// Expiry worker
 pub struct ExpiryWorker {
     state: AppState,
-    apns: Arc<ApnsClient>,
-    tracker: Arc<RwLock<ExpiryTracker>>,
-    /// Check interval (default 30 seconds)
-    check_interval: std::time::Duration,
 }
 
 impl ExpiryWorker {
-    pub fn new(state: AppState, apns: Arc<ApnsClient>) -> Self {
-        Self {
-            state,
-            apns,
-            tracker: Arc::new(RwLock::new(ExpiryTracker::default())),
-            check_interval: std::time::Duration::from_secs(30),
-        }
+    pub fn new(state: AppState) -> Self {
+        Self { state }
     }
 
     /// Start the background worker
     pub fn start(self: Arc<Self>) {
-        let worker = self.clone();
-        tokio::spawn(async move {
-            info!(
-                interval_secs = worker.check_interval.as_secs(),
-                "Started expiry notification worker"
-            );
+        let interval = self.state.store.config().cleanup_interval;
+        info!(
+            cleanup_interval_secs = interval.as_secs(),
+            "Started expiry worker"
+        );
 
-            let mut ticker = tokio::time::interval(worker.check_interval);
+        tokio::spawn(async move {
+            let schedule = self.state.store.schedule();
+            let mut next_sweep = Instant::now();
             loop {
-                ticker.tick().await;
-                if let Err(e) = worker.check_expiring_messages().await {
-                    warn!(error = ?e, "Error checking expiring messages");
+                self.send_due_warnings();
+                if Instant::now() >= next_sweep {
+                    cleanup_expired(&self.state).await;
+                    next_sweep = Instant::now() + interval;
+                } else {
+                    let expired = self.state.store.expire_due().await;
+                    report_expired(&self.state, expired);
+                }
+
+                let wake = schedule.next_deadline().map_or(next_sweep, |at| {
+                    let until = (at - Utc::now()).to_std().unwrap_or_default();
+                    next_sweep.min(Instant::now() + until)
+                });
+                tokio::select! {
+                    () = tokio::time::sleep_until(wake) => {}
+                    () = schedule.changed() => {}
                 }
             }
         });
     }
 
-    /// Check for messages near expiry and send notifications
-    async fn check_expiring_messages(
-        &self,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let now = Utc::now();
-        let five_min = Duration::minutes(5);
-        let one_min = Duration::minutes(1);
-
-        // Get all conversations with expiry notifications enabled
-        let conversations = self
-            .state
-            .store
-            .get_conversations_with_expiry_notifications();
-
-        let mut active_blob_ids = HashSet::new();
-
-        for conversation_id in conversations {
-            // Get notification preferences
-            let prefs = match self.state.store.get_prefs(&conversation_id) {
-                Some(p) => p,
-                None => continue,
-            };
-
-            let notify_expiring =
-                (prefs.notification_flags & notification_flags::NOTIFY_MESSAGE_EXPIRING) != 0;
-            let notify_expired =
-                (prefs.notification_flags & notification_flags::NOTIFY_MESSAGE_EXPIRED) != 0;
-
-            if !notify_expiring && !notify_expired {
-                continue;
+    /// Send the expiry warnings that came due to the recipients' devices.
+    ///
+    /// One push per conversation, warning and sender, however many blobs
+    /// came due together: the push carries no blob IDs.
+    fn send_due_warnings(&self) {
+        let store = &self.state.store;
+        let due: HashSet<_> = store
+            .schedule()
+            .take_due_warnings(Utc::now())
+            .into_iter()
+            .filter_map(|due| {
+                let (sender, sender_device) =
+                    store.blob_sender(&due.conversation_id, &due.blob_id)?;
+                Some((due.conversation_id, due.warning, sender, sender_device))
+            })
+            .collect();
+
+        for (conversation_id, warning, sender, sender_device) in due {
+            let enabled = store
+                .get_prefs(&conversation_id)
+                .is_some_and(|p| p.notify_message_expiring());
+            if enabled {
+                let devices =
+                    store.get_recipient_devices(&conversation_id, sender, sender_device.as_deref());
+                send_expiry_notification(
+                    &self.state,
+                    &conversation_id,
+                    devices,
+                    warning.notification_type(),
+                );
             }
+        }
+    }
+}
 
-            // Get blobs for this conversation
-            let (blobs, _) = self.state.store.get_blobs(&conversation_id, None);
-
-            for blob in blobs {
-                active_blob_ids.insert(blob.id);
-
-                let time_until_expiry = blob.expires_at - now;
+/// Purge expired data and notify about blobs that expired
+/// unacknowledged, returning the number of expired blobs.
+pub async fn cleanup_expired(state: &AppState) -> usize {
+    let expired = state.store.cleanup_expired().await;
+    report_expired(state, expired)
+}
 
-                // Check 5-minute warning
-                if notify_expiring && time_until_expiry <= five_min && time_until_expiry > one_min {
-                    let mut tracker = self.tracker.write().await;
-                    if !tracker.warned_5min.contains(&blob.id) {
-                        tracker.warned_5min.insert(blob.id);
-                        drop(tracker);
+/// Notify the conversation and the senders of expired blobs, returning
+/// the number of blobs.
+fn report_expired(state: &AppState, expired: Vec<ExpiredBlob>) -> usize {
+    let count = expired.len();
+
+    let senders: HashSet<_> = expired
+        .iter()
+        .map(|b| (&b.conversation_id, b.sender, b.sender_device.as_deref()))
+        .collect();
+    for (conversation_id, sender, sender_device) in senders {
+        let enabled = state
+            .store
+            .get_prefs(conversation_id)
+            .is_some_and(|p| p.notify_message_expired());
+        if enabled {
+            let devices = state
+                .store
+                .get_recipient_devices(conversation_id, sender, sender_device);
+            send_expiry_notification(state, conversation_id, devices, "expired");
+        }
+    }
 
-                        debug!(
-                            blob_id = %blob.id,
-                            minutes_left = time_until_expiry.num_minutes(),
-                            "Sending 5-minute expiry warning"
-                        );
+    notify_delivery_failed(state, expired);
+    count
+}
 
-                        self.send_expiry_notification(&conversation_id, "expiring_5min")
-                            .await;
-                    }
-                }
+/// Send an expiry notification to the given devices of a conversation
+fn send_expiry_notification(
+    state: &AppState,
+    conversation_id: &ConversationId,
+    devices: Vec<DeviceRegistration>,
+    notification_type: &str,
+) {
+    if devices.is_empty() {
+        return;
+    }
 
-                // Check 1-minute warning
-                if notify_expiring
-                    && time_until_expiry <= one_min
-                    && time_until_expiry > Duration::zero()
-                {
-                    let mut tracker = self.tracker.write().await;
-                    if !tracker.warned_1min.contains(&blob.id) {
-                        tracker.warned_1min.insert(blob.id);
-                        drop(tracker);
-
-                        debug!(
-                            blob_id = %blob.id,
-                            seconds_left = time_until_expiry.num_seconds(),
-                            "Sending 1-minute expiry warning"
-                        );
-
-                        self.send_expiry_notification(&conversation_id, "expiring_1min")
-                            .await;
-                    }
-                }
+    debug!(
+        notification_type = notification_type,
+        device_count = devices.len(),
+        "Sending expiry notification"
+    );
+
+    // Send push notification (best-effort)
+    let apns = state.apns.clone();
+    let conv_id = conversation_id.clone();
+    state.pushes.spawn(async move {
+        apns.send_to_devices(&devices, Some(&conv_id)).await;
+    });
+}
 
-                // Check if expired (for "expired" notification)
-                if notify_expired && time_until_expiry <= Duration::zero() {
-                    let mut tracker = self.tracker.write().await;
-                    if !tracker.expired.contains(&blob.id) {
-                        tracker.expired.insert(blob.id);
-                        drop(tracker);
-
-                        debug!(
-                            blob_id = %blob.id,
-                            "Sending message expired notification"
-                        );
-
-                        self.send_expiry_notification(&conversation_id, "expired")
-                            .await;
-                    }
-                }
-            }
+/// Tell each submitting device which of its blobs expired unacknowledged,
+/// by push and on streams opened with its device handle.
+///
+/// Blobs submitted without a device handle, and conversations without
+/// prefs or without `NOTIFY_DELIVERY_FAILED`, are skipped.
+fn notify_delivery_failed(state: &AppState, expired: Vec<ExpiredBlob>) {
+    let mut failed: HashMap<(ConversationId, DeviceHandle), Vec<Uuid>> = HashMap::new();
+    for blob in expired {
+        if let Some(device_handle) = blob.sender_device {
+            failed
+                .entry((blob.conversation_id, device_handle))
+                .or_default()
+                .push(blob.blob_id);
         }
-
-        // Cleanup tracker (remove entries for blobs that no longer exist)
-        let mut tracker = self.tracker.write().await;
-        tracker.cleanup_old(&active_blob_ids);
-
-        Ok(())
     }
 
-    /// Send an expiry notification to devices registered for a conversation
-    async fn send_expiry_notification(&self, conversation_id: &str, notification_type: &str) {
-        let devices = self
-            .state
+    let failed_at = Utc::now();
+    for ((conversation_id, device_handle), blob_ids) in failed {
+        let enabled = state
             .store
-            .get_device_tokens(&conversation_id.to_string());
+            .get_prefs(&conversation_id)
+            .is_some_and(|p| p.notify_delivery_failed());
+        if !enabled {
+            continue;
+        }
 
-        if devices.is_empty() {
-            return;
+        if let Some(conv) = state.log.conv(&conversation_id) {
+            debug!(
+                conv = %conv,
+                count = blob_ids.len(),
+                "Sending delivery failed notification"
+            );
         }
 
-        debug!(
-            notification_type = notification_type,
-            device_count = devices.len(),
-            "Sending expiry notification"
-        );
+        let devices: Vec<_> = state
+            .store
+            .get_device_tokens(&conversation_id)
+            .into_iter()
+            .filter(|d| d.device_handle.as_ref() == Some(&device_handle))
+            .collect();
+        if !devices.is_empty() {
+            let apns = state.apns.clone();
+            let conv_id = conversation_id.clone();
+            let event = PushEvent::DeliveryFailed {
+                blob_ids: blob_ids.clone(),
+            };
+            state.pushes.spawn(async move {
+                apns.send_to_devices_with_event(&devices, Some(&conv_id), Some(&event))
+                    .await;
+            });
+        }
 
-        // Send push notification (best-effort)
-        let apns = self.apns.clone();
-        let devices = devices.clone();
-        let conv_id = conversation_id.to_string();
-        tokio::spawn(async move {
-            apns.send_to_devices(&devices, Some(&conv_id)).await;
+        let _ = state.broadcast_tx.send(BroadcastEvent {
+            conversation_id,
+            event: StreamEvent::DeliveryFailed {
+                blob_ids,
+                failed_at,
+            },
+            device_handle: Some(device_handle),
         });
     }
 }
@@ -4,12 +4,14 @@
 //! - No plaintext content
 //! - No user identity
 //! - Best-effort delivery
-//! - Minimal logging (no PII)
+//! - Minimal logging (no PII, conversation IDs only as pseudonyms; see
+//!   [`crate::log_policy`])
 
 use crate::apns::ApnsClient;
-use crate::auth::{extract_bearer_token, AuthError, AuthStore, RegisterResult};
+use crate::auth::{self, extract_bearer_token, AuthError, AuthStore, RegisterResult};
+use crate::log_policy::{Activity, LogPolicy};
 use crate::models::*;
-use crate::store::{Store, StoreError};
+use crate::store::{RegisterOptions, SequenceFilter, Store, StoreError, SubmitOptions};
 use axum::{
     extract::{Query, State},
     http::{header::AUTHORIZATION, StatusCode},
@@ -20,6 +22,7 @@ use axum::{
     Json,
 };
 use base64::Engine;
+use dashmap::DashMap;
 use futures::stream::Stream;
 use std::convert::Infallible;
 use std::sync::Arc;
@@ -27,10 +30,18 @@ use std::time::Duration;
 use tokio::sync::broadcast;
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::StreamExt;
-use tracing::{debug, info, warn};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use tracing::{debug, warn};
 
-/// Broadcast channel capacity for SSE events
-const BROADCAST_CAPACITY: usize = 1024;
+/// Maximum time a long-poll request is parked
+pub const MAX_POLL_WAIT: Duration = Duration::from_secs(30);
+
+/// Maximum concurrent long-poll requests per conversation
+pub const MAX_WAITERS_PER_CONVERSATION: usize = 4;
+
+/// Accepted length range of a device handle
+const DEVICE_HANDLE_LEN: std::ops::RangeInclusive<usize> = 16..=64;
 
 /// Application state shared across handlers
 #[derive(Clone)]
@@ -41,18 +52,48 @@ pub struct AppState {
     pub auth: AuthStore,
     /// Broadcast channel for SSE events
     pub broadcast_tx: broadcast::Sender<BroadcastEvent>,
+    /// Parked long-poll requests per conversation
+    pub waiters: Arc<DashMap<ConversationId, usize>>,
+    /// What may be logged about requests
+    pub log: Arc<LogPolicy>,
+    /// Cancelled when the server starts shutting down
+    pub shutdown: CancellationToken,
+    /// In-flight push notification sends, drained on shutdown
+    pub pushes: TaskTracker,
 }
 
 impl AppState {
     pub fn new(store: Arc<Store>, apns: Arc<ApnsClient>) -> Self {
-        let (broadcast_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
+        let config = store.config();
+        let (broadcast_tx, _) = broadcast::channel(config.broadcast_capacity);
+        let auth = AuthStore::with_limits(config.max_conversations, config.inactive_ttl);
         Self {
+            log: store.log_policy().clone(),
             store,
             apns,
-            auth: AuthStore::new(),
+            auth,
             broadcast_tx,
+            waiters: Arc::new(DashMap::new()),
+            shutdown: CancellationToken::new(),
+            pushes: TaskTracker::new(),
         }
     }
+
+    /// Start shutting down: SSE streams send [`StreamEvent::Reconnect`]
+    /// and end, parked long polls return.
+    pub fn begin_shutdown(&self) {
+        self.shutdown.cancel();
+    }
+
+    /// Wait up to `deadline` for in-flight push sends.
+    ///
+    /// Returns `false` if sends were still pending at the deadline.
+    pub async fn drain_pushes(&self, deadline: Duration) -> bool {
+        self.pushes.close();
+        tokio::time::timeout(deadline, self.pushes.wait())
+            .await
+            .is_ok()
+    }
 }
 
 // === Health Check ===
@@ -101,6 +142,42 @@ pub async fn register_conversation(
         return Err(ApiError::InvalidInput("invalid burn_token_hash format"));
     }
 
+    // Validate registration proof format (should be 64 hex chars = 32-byte hash)
+    // and check that the conversation ID commits to it and the token hashes
+    match &req.registration_proof {
+        Some(proof) if proof.len() != 64 || !proof.chars().all(|c| c.is_ascii_hexdigit()) => {
+            return Err(ApiError::InvalidInput("invalid registration_proof format"));
+        }
+        Some(proof)
+            if !auth::verify_registration_proof(
+                &req.conversation_id,
+                &req.auth_token_hash,
+                &req.burn_token_hash,
+                proof,
+            ) =>
+        {
+            warn!(
+                conv = state.log.conv(&req.conversation_id),
+                "Registration rejected: proof does not match conversation ID"
+            );
+            return Err(ApiError::InvalidRegistrationProof);
+        }
+        None if state.store.config().require_registration_proof => {
+            return Err(ApiError::InvalidInput("registration_proof required"));
+        }
+        _ => {}
+    }
+
+    // A burned conversation stays burned, even after its flag expired or
+    // the relay restarted
+    if state.store.is_burned(&req.conversation_id) {
+        debug!(
+            conv = state.log.conv(&req.conversation_id),
+            "Registration rejected: conversation burned"
+        );
+        return Err(ApiError::ConversationBurned);
+    }
+
     // Register the conversation (idempotent - both parties may register)
     let result = state.auth.register(
         &req.conversation_id,
@@ -116,12 +193,15 @@ pub async fn register_conversation(
                 req.notification_flags,
                 req.ttl_seconds,
             );
-            info!(
-                conv_id_prefix = &req.conversation_id[..8],
-                notification_flags = req.notification_flags,
-                ttl_seconds = req.ttl_seconds,
-                "Conversation registered"
-            );
+            state.log.record(Activity::Registration);
+            if let Some(conv) = state.log.conv(&req.conversation_id) {
+                debug!(
+                    conv = %conv,
+                    notification_flags = req.notification_flags,
+                    ttl_seconds = req.ttl_seconds,
+                    "Conversation registered"
+                );
+            }
         }
         RegisterResult::AlreadyExists => {
             // Update preferences on re-registration (e.g., second party registering)
@@ -131,10 +211,17 @@ pub async fn register_conversation(
                 req.ttl_seconds,
             );
             debug!(
-                conv_id_prefix = &req.conversation_id[..8],
+                conv = state.log.conv(&req.conversation_id),
                 "Conversation re-registered"
             );
         }
+        RegisterResult::Mismatch => {
+            warn!(
+                conv = state.log.conv(&req.conversation_id),
+                "Registration rejected: credentials differ from existing registration"
+            );
+            return Err(ApiError::RegistrationMismatch);
+        }
         RegisterResult::AtCapacity => {
             warn!("Registration rejected: server at capacity");
             return Err(ApiError::ServerAtCapacity);
@@ -147,7 +234,7 @@ pub async fn register_conversation(
 // === Token Verification Helpers ===
 
 /// Extract auth token from request headers
-fn extract_auth_token(headers: &axum::http::HeaderMap) -> Result<String, AuthError> {
+pub(crate) fn extract_auth_token(headers: &axum::http::HeaderMap) -> Result<String, AuthError> {
     let header_value = headers
         .get(AUTHORIZATION)
         .ok_or(AuthError::MissingHeader)?
@@ -159,44 +246,65 @@ fn extract_auth_token(headers: &axum::http::HeaderMap) -> Result<String, AuthErr
         .ok_or(AuthError::InvalidHeader)
 }
 
-/// Verify auth token for a conversation
-fn verify_auth_token(
-    state: &AppState,
-    conversation_id: &str,
-    token: &str,
-) -> Result<(), AuthError> {
-    if !state.auth.is_registered(conversation_id) {
-        return Err(AuthError::ConversationNotFound);
+/// Fail unless the conversation is registered.
+///
+/// An unregistered conversation with a live burn flag or tombstone is
+/// reported as burned, so a peer that was offline (or a relay that
+/// restarted) does not see it as merely missing.
+fn ensure_registered(state: &AppState, conversation_id: &str) -> Result<(), ApiError> {
+    if state.auth.is_registered(conversation_id) {
+        return Ok(());
     }
+    if state.store.is_burned(conversation_id) {
+        return Err(ApiError::ConversationBurned);
+    }
+    Err(AuthError::ConversationNotFound.into())
+}
+
+/// Verify auth token for a conversation
+fn verify_auth_token(state: &AppState, conversation_id: &str, token: &str) -> Result<(), ApiError> {
+    ensure_registered(state, conversation_id)?;
     if !state.auth.verify_auth_token(conversation_id, token) {
+        state.log.record(Activity::AuthFailure);
         warn!(
-            conv_id_prefix = &conversation_id[..8.min(conversation_id.len())],
+            conv = state.log.conv(conversation_id),
             "Auth token verification failed"
         );
-        return Err(AuthError::Unauthorized);
+        return Err(AuthError::Unauthorized.into());
     }
     Ok(())
 }
 
 /// Verify burn token for a conversation
-fn verify_burn_token(
-    state: &AppState,
-    conversation_id: &str,
-    token: &str,
-) -> Result<(), AuthError> {
-    if !state.auth.is_registered(conversation_id) {
-        return Err(AuthError::ConversationNotFound);
-    }
+fn verify_burn_token(state: &AppState, conversation_id: &str, token: &str) -> Result<(), ApiError> {
+    ensure_registered(state, conversation_id)?;
     if !state.auth.verify_burn_token(conversation_id, token) {
+        state.log.record(Activity::AuthFailure);
         warn!(
-            conv_id_prefix = &conversation_id[..8.min(conversation_id.len())],
+            conv = state.log.conv(conversation_id),
             "Burn token verification failed"
         );
-        return Err(AuthError::Unauthorized);
+        return Err(AuthError::Unauthorized.into());
     }
     Ok(())
 }
 
+/// Fail unless `device_handle` is absent or well-formed (16-64 URL-safe
+/// characters)
+fn validate_device_handle(device_handle: Option<&str>) -> Result<(), ApiError> {
+    match device_handle {
+        Some(handle)
+            if !DEVICE_HANDLE_LEN.contains(&handle.len())
+                || !handle
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+        {
+            Err(ApiError::InvalidInput("invalid device_handle"))
+        }
+        _ => Ok(()),
+    }
+}
+
 // === Device Registration ===
 
 /// POST /v1/register - Register device for push notifications
@@ -213,10 +321,19 @@ pub async fn register_device(
     if req.device_token.is_empty() || req.device_token.len() > 200 {
         return Err(ApiError::InvalidInput("invalid device token"));
     }
+    validate_device_handle(req.device_handle.as_deref())?;
 
     state
         .store
-        .register_device(req.conversation_id, req.device_token, req.platform)
+        .register_device(
+            req.conversation_id,
+            req.device_token,
+            req.platform,
+            RegisterOptions {
+                role: req.role,
+                device_handle: req.device_handle,
+            },
+        )
         .await
         .map_err(|e| match e {
             StoreError::ConversationBurned => ApiError::ConversationBurned,
@@ -233,6 +350,13 @@ pub async fn register_device(
 /// POST /v1/messages - Submit encrypted message blob
 ///
 /// Messages are stored with fixed 5-minute TTL. Deleted on ACK or expiry.
+///
+/// A retry with the same `sender` and `sequence` returns the existing blob
+/// without broadcasting or notifying again.
+///
+/// With `device_handle`, the submitting device is told (push and SSE) if the
+/// blob expires unacknowledged. Push notifications skip devices registered
+/// with the sender's role or device handle.
 pub async fn submit_message(
     State(state): State<AppState>,
     headers: axum::http::HeaderMap,
@@ -242,53 +366,81 @@ pub async fn submit_message(
     let token = extract_auth_token(&headers)?;
     verify_auth_token(&state, &req.conversation_id, &token)?;
 
-    tracing::info!(
-        conv_id = %req.conversation_id,
-        sequence = ?req.sequence,
-        ciphertext_len = req.ciphertext.len(),
-        "Received message submission"
-    );
+    if let Some(conv) = state.log.conv(&req.conversation_id) {
+        debug!(
+            conv = %conv,
+            sequence = ?req.sequence,
+            ciphertext_len = req.ciphertext.len(),
+            "Received message submission"
+        );
+    }
 
     // Decode base64 ciphertext
     let ciphertext = base64::engine::general_purpose::STANDARD
         .decode(&req.ciphertext)
         .map_err(|_| ApiError::InvalidInput("invalid base64 ciphertext"))?;
+    validate_device_handle(req.device_handle.as_deref())?;
 
     // Store the blob (fixed 5-minute TTL)
     let conversation_id = req.conversation_id;
     let received_at = chrono::Utc::now();
-    // Calculate expiry time using same logic as store (for client synchronization)
-    let expires_at = received_at
-        + chrono::Duration::from_std(crate::config::MESSAGE_TTL).expect("valid duration");
 
-    let blob_id = state
+    let submission = state
         .store
-        .store_blob(conversation_id.clone(), ciphertext.clone(), req.sequence)
+        .submit_blob(
+            conversation_id.clone(),
+            ciphertext.clone(),
+            SubmitOptions {
+                sequence: req.sequence,
+                sender: req.sender,
+                sender_device: req.device_handle.clone(),
+            },
+        )
         .await
         .map_err(|e| match e {
             StoreError::ConversationBurned => ApiError::ConversationBurned,
             StoreError::PayloadTooLarge => ApiError::PayloadTooLarge,
             StoreError::QueueFull => ApiError::QueueFull,
+            StoreError::SequenceNotIncreasing { .. } => ApiError::SequenceNotIncreasing,
             StoreError::DatabaseError(_) => ApiError::Internal,
         })?;
+    let blob_id = submission.blob_id;
 
-    tracing::info!(
-        blob_id = %blob_id,
-        conv_id = %conversation_id,
-        size = ciphertext.len(),
-        "Message stored successfully"
-    );
+    if submission.duplicate {
+        state.log.record(Activity::DuplicateSubmission);
+        if state.log.detailed() {
+            debug!(blob_id = %blob_id, "Duplicate submission, returning existing blob");
+        }
+        return Ok(Json(SubmitMessageResponse {
+            accepted: true,
+            blob_id,
+            expires_at: submission.expires_at,
+            duplicate: true,
+        }));
+    }
+
+    state.log.record(Activity::Submission);
+    if let Some(conv) = state.log.conv(&conversation_id) {
+        debug!(
+            blob_id = %blob_id,
+            conv = %conv,
+            size = ciphertext.len(),
+            "Message stored successfully"
+        );
+    }
 
     // Broadcast to SSE subscribers
     let message_blob = MessageBlob {
         id: blob_id,
         sequence: req.sequence,
+        sender: req.sender,
         ciphertext: base64::engine::general_purpose::STANDARD.encode(&ciphertext),
         received_at,
     };
     let _ = state.broadcast_tx.send(BroadcastEvent {
         conversation_id: conversation_id.clone(),
         event: StreamEvent::Message(message_blob),
+        device_handle: None,
     });
 
     // Send push notifications to registered devices (best-effort, async)
@@ -299,12 +451,17 @@ pub async fn submit_message(
         .map(|p| p.notify_new_message())
         .unwrap_or(true);
 
+    // Only the other party's devices, not the sender's own
     if should_notify {
-        let devices = state.store.get_device_tokens(&conversation_id);
+        let devices = state.store.get_recipient_devices(
+            &conversation_id,
+            req.sender,
+            req.device_handle.as_deref(),
+        );
         if !devices.is_empty() {
             let apns = state.apns.clone();
             let conv_id = conversation_id.clone();
-            tokio::spawn(async move {
+            state.pushes.spawn(async move {
                 apns.send_to_devices(&devices, Some(&conv_id)).await;
             });
         }
@@ -313,13 +470,25 @@ pub async fn submit_message(
     Ok(Json(SubmitMessageResponse {
         accepted: true,
         blob_id,
-        expires_at,
+        expires_at: submission.expires_at,
+        duplicate: false,
     }))
 }
 
 // === Message Polling ===
 
 /// GET /v1/messages - Poll for messages
+///
+/// Returns blobs queued after `cursor`, at most `limit` at a time. A cursor
+/// that fails verification or is stale is ignored and `cursor_reset` is set,
+/// so the client knows it received the queue from the beginning.
+///
+/// `sender` and `after_sequence` narrow the result to one sender direction.
+/// A sender-filtered poll also reports `missing_sequences` so the client can
+/// detect gaps.
+///
+/// With `wait`, an empty poll is parked until a blob arrives, the
+/// conversation is burned or the wait (capped at `MAX_POLL_WAIT`) elapses.
 pub async fn poll_messages(
     State(state): State<AppState>,
     headers: axum::http::HeaderMap,
@@ -329,43 +498,93 @@ pub async fn poll_messages(
     let token = extract_auth_token(&headers)?;
     verify_auth_token(&state, &query.conversation_id, &token)?;
 
-    tracing::info!(
-        conv_id = %query.conversation_id,
-        has_cursor = query.cursor.is_some(),
-        "Polling messages"
-    );
+    state.log.record(Activity::Poll);
+    if let Some(conv) = state.log.conv(&query.conversation_id) {
+        debug!(
+            conv = %conv,
+            has_cursor = query.cursor.is_some(),
+            wait_secs = ?query.wait,
+            "Polling messages"
+        );
+    }
 
-    // Parse cursor if provided
-    let cursor = query.cursor.as_ref().and_then(|c| Cursor::decode(c));
+    if query.limit == Some(0) {
+        return Err(ApiError::InvalidInput("limit must be positive"));
+    }
+    let limit = query.limit.map(|limit| limit.min(MAX_POLL_LIMIT));
 
-    // Check burn status
-    let burned = state.store.is_burned(&query.conversation_id);
+    // Parse and verify cursor if provided
+    let cursor = query
+        .cursor
+        .as_ref()
+        .and_then(|c| state.store.decode_cursor(&query.conversation_id, c));
+    let invalid_cursor = query.cursor.is_some() && cursor.is_none();
+
+    // Subscribe before reading the store, so a blob stored in between
+    // still wakes the waiter
+    let wait = query
+        .wait
+        .map(|secs| Duration::from_secs(secs).min(MAX_POLL_WAIT))
+        .filter(|wait| !wait.is_zero());
+    let mut waiter = match wait {
+        Some(wait) => Some((
+            WaiterGuard::acquire(&state.waiters, &query.conversation_id)?,
+            state.broadcast_tx.subscribe(),
+            tokio::time::Instant::now() + wait,
+        )),
+        None => None,
+    };
 
     // Get messages
-    let (blobs, next_cursor) = state
+    let filter = SequenceFilter {
+        sender: query.sender,
+        after: query.after_sequence,
+    };
+    let mut page = state
         .store
-        .get_blobs(&query.conversation_id, cursor.as_ref());
+        .poll_blobs(&query.conversation_id, cursor.as_ref(), filter, limit);
+
+    if let Some((_guard, rx, deadline)) = waiter.as_mut() {
+        while page.blobs.is_empty()
+            && !state.store.is_burned(&query.conversation_id)
+            && wait_for_activity(rx, &query.conversation_id, *deadline, &state.shutdown).await
+        {
+            page = state
+                .store
+                .poll_blobs(&query.conversation_id, cursor.as_ref(), filter, limit);
+        }
+    }
+    drop(waiter);
 
-    tracing::info!(
-        conv_id = %query.conversation_id,
-        blob_count = blobs.len(),
-        burned = burned,
-        "Retrieved blobs from store"
-    );
+    // Check burn status
+    let burned = state.store.is_burned(&query.conversation_id);
+    let blobs = page.blobs;
+
+    if let Some(conv) = state.log.conv(&query.conversation_id) {
+        debug!(
+            conv = %conv,
+            blob_count = blobs.len(),
+            burned = burned,
+            "Retrieved blobs from store"
+        );
+    }
 
     // Convert to response format
     let messages: Vec<MessageBlob> = blobs
         .into_iter()
         .map(|b| {
-            tracing::debug!(
-                blob_id = %b.id,
-                sequence = ?b.sequence,
-                size = b.ciphertext.len(),
-                "Returning blob"
-            );
+            if state.log.detailed() {
+                debug!(
+                    blob_id = %b.id,
+                    sequence = ?b.sequence,
+                    size = b.ciphertext.len(),
+                    "Returning blob"
+                );
+            }
             MessageBlob {
                 id: b.id,
                 sequence: b.sequence,
+                sender: b.sender,
                 ciphertext: base64::engine::general_purpose::STANDARD.encode(&b.ciphertext),
                 received_at: b.received_at,
             }
@@ -374,11 +593,82 @@ pub async fn poll_messages(
 
     Ok(Json(PollMessagesResponse {
         messages,
-        next_cursor: next_cursor.map(|c| c.encode()),
+        next_cursor: page
+            .next_cursor
+            .map(|c| state.store.encode_cursor(&query.conversation_id, &c)),
         burned,
+        has_more: page.has_more,
+        cursor_reset: invalid_cursor || page.cursor_reset,
+        missing_sequences: page.missing_sequences,
     }))
 }
 
+/// Wait for a message or burn event for `conversation_id`.
+///
+/// Returns `false` when `deadline` passes or the server shuts down. A lagged
+/// receiver returns `true` since the missed events may include one for this
+/// conversation.
+async fn wait_for_activity(
+    rx: &mut broadcast::Receiver<BroadcastEvent>,
+    conversation_id: &str,
+    deadline: tokio::time::Instant,
+    shutdown: &CancellationToken,
+) -> bool {
+    loop {
+        let received = tokio::select! {
+            () = shutdown.cancelled() => return false,
+            received = tokio::time::timeout_at(deadline, rx.recv()) => received,
+        };
+        match received {
+            Err(_) | Ok(Err(broadcast::error::RecvError::Closed)) => return false,
+            Ok(Err(broadcast::error::RecvError::Lagged(_))) => return true,
+            Ok(Ok(event)) if event.conversation_id == conversation_id => {
+                if matches!(
+                    event.event,
+                    StreamEvent::Message(_) | StreamEvent::Burned { .. }
+                ) {
+                    return true;
+                }
+            }
+            Ok(Ok(_)) => {}
+        }
+    }
+}
+
+/// Slot of a parked long-poll request, released on drop
+struct WaiterGuard {
+    waiters: Arc<DashMap<ConversationId, usize>>,
+    conversation_id: ConversationId,
+}
+
+impl WaiterGuard {
+    /// Take a waiter slot, or fail if the conversation has no free slot
+    fn acquire(
+        waiters: &Arc<DashMap<ConversationId, usize>>,
+        conversation_id: &str,
+    ) -> Result<Self, ApiError> {
+        let mut count = waiters.entry(conversation_id.to_string()).or_insert(0);
+        if *count >= MAX_WAITERS_PER_CONVERSATION {
+            return Err(ApiError::TooManyWaiters);
+        }
+        *count += 1;
+        Ok(Self {
+            waiters: waiters.clone(),
+            conversation_id: conversation_id.to_string(),
+        })
+    }
+}
+
+impl Drop for WaiterGuard {
+    fn drop(&mut self) {
+        self.waiters
+            .remove_if_mut(&self.conversation_id, |_, count| {
+                *count -= 1;
+                *count == 0
+            });
+    }
+}
+
 // === Burn Conversation ===
 
 /// POST /v1/burn - Burn a conversation
@@ -411,18 +701,23 @@ pub async fn burn_conversation(
         event: StreamEvent::Burned {
             burned_at: chrono::Utc::now(),
         },
+        device_handle: None,
     });
 
     // Send burn notification to registered devices (best-effort)
     if !devices.is_empty() {
         let apns = state.apns.clone();
         let conv_id = conversation_id.clone();
-        tokio::spawn(async move {
+        state.pushes.spawn(async move {
             apns.send_to_devices(&devices, Some(&conv_id)).await;
         });
     }
 
-    debug!("Conversation burned");
+    state.log.record(Activity::Burn);
+    debug!(
+        conv = state.log.conv(&conversation_id),
+        "Conversation burned"
+    );
 
     Ok(Json(BurnConversationResponse { accepted: true }))
 }
@@ -457,11 +752,10 @@ pub async fn ack_messages(
     }
 
     if acknowledged > 0 {
-        debug!(
-            conv_id_prefix = &conversation_id[..8],
-            count = acknowledged,
-            "Messages acknowledged"
-        );
+        state.log.record(Activity::Ack);
+        if let Some(conv) = state.log.conv(&conversation_id) {
+            debug!(conv = %conv, count = acknowledged, "Messages acknowledged");
+        }
 
         // Broadcast delivery event to SSE subscribers (notifies sender)
         let _ = state.broadcast_tx.send(BroadcastEvent {
@@ -470,6 +764,7 @@ pub async fn ack_messages(
                 blob_ids: req.blob_ids,
                 delivered_at: chrono::Utc::now(),
             },
+            device_handle: None,
         });
     }
 
@@ -499,6 +794,10 @@ pub async fn burn_status(
 // === SSE Message Stream ===
 
 /// GET /v1/messages/stream - Server-Sent Events stream for real-time messages
+///
+/// A stream opened with `device_handle` also receives `delivery_failed`
+/// events for blobs that device submitted. On shutdown the stream sends a
+/// `reconnect` event and ends.
 pub async fn message_stream(
     State(state): State<AppState>,
     headers: axum::http::HeaderMap,
@@ -507,18 +806,24 @@ pub async fn message_stream(
     // Verify auth token before establishing stream
     let token = extract_auth_token(&headers)?;
     verify_auth_token(&state, &query.conversation_id, &token)?;
+    validate_device_handle(query.device_handle.as_deref())?;
 
     let conversation_id = query.conversation_id;
+    let device_handle = query.device_handle;
 
-    info!(conv_id = %conversation_id, "SSE client connected");
+    state.log.record(Activity::StreamConnect);
+    debug!(
+        conv = state.log.conv(&conversation_id),
+        "SSE client connected"
+    );
 
     // Subscribe to the broadcast channel
     let rx = state.broadcast_tx.subscribe();
 
     // Create a stream that filters events for this conversation
-    let stream = BroadcastStream::new(rx).filter_map(move |result| {
+    let events = BroadcastStream::new(rx).filter_map(move |result| {
         match result {
-            Ok(event) if event.conversation_id == conversation_id => {
+            Ok(event) if event.is_for(&conversation_id, device_handle.as_deref()) => {
                 // Serialize the event to JSON
                 match serde_json::to_string(&event.event) {
                     Ok(json) => Some(Ok(Event::default().data(json))),
@@ -529,6 +834,12 @@ pub async fn message_stream(
         }
     });
 
+    // End with a reconnect event once shutdown begins
+    let reconnect =
+        serde_json::to_string(&StreamEvent::Reconnect).map(|json| Ok(Event::default().data(json)));
+    let stream = futures::StreamExt::take_until(events, state.shutdown.cancelled_owned())
+        .chain(tokio_stream::iter(reconnect.ok()));
+
     Ok(Sse::new(stream).keep_alive(
         KeepAlive::new()
             .interval(Duration::from_secs(15))
@@ -545,8 +856,18 @@ pub enum ApiError {
     ConversationBurned,
     PayloadTooLarge,
     QueueFull,
+    /// Sequence not greater than the last one accepted from the sender
+    SequenceNotIncreasing,
+    /// Too many parked long-poll requests for the conversation
+    TooManyWaiters,
+    /// Conversation already registered with different hashes
+    RegistrationMismatch,
+    /// Registration proof does not match the conversation ID
+    InvalidRegistrationProof,
     /// Server at capacity, cannot register new conversations
     ServerAtCapacity,
+    /// Feature not available on this instance
+    Unavailable(&'static str),
     Internal,
     /// Authorization error (wraps AuthError)
     Auth(AuthError),
@@ -581,11 +902,34 @@ impl IntoResponse for ApiError {
                         "QUEUE_FULL",
                         "message queue is full",
                     ),
+                    ApiError::SequenceNotIncreasing => (
+                        StatusCode::CONFLICT,
+                        "SEQUENCE_NOT_INCREASING",
+                        "sequence must be greater than the last accepted one",
+                    ),
+                    ApiError::TooManyWaiters => (
+                        StatusCode::TOO_MANY_REQUESTS,
+                        "TOO_MANY_WAITERS",
+                        "too many long-poll requests for this conversation",
+                    ),
+                    ApiError::RegistrationMismatch => (
+                        StatusCode::CONFLICT,
+                        "REGISTRATION_MISMATCH",
+                        "conversation registered with different credentials",
+                    ),
+                    ApiError::InvalidRegistrationProof => (
+                        StatusCode::FORBIDDEN,
+                        "INVALID_REGISTRATION_PROOF",
+                        "registration proof does not match conversation ID",
+                    ),
                     ApiError::ServerAtCapacity => (
                         StatusCode::SERVICE_UNAVAILABLE,
                         "SERVER_AT_CAPACITY",
                         "server at capacity, try again later",
                     ),
+                    ApiError::Unavailable(msg) => {
+                        (StatusCode::SERVICE_UNAVAILABLE, "UNAVAILABLE", msg)
+                    }
                     ApiError::Internal => (
                         StatusCode::INTERNAL_SERVER_ERROR,
                         "INTERNAL_ERROR",
@@ -8,7 +8,7 @@
 //! - **No user identity**: Backend has no concept of users, only conversation IDs
 //! - **No long-term storage**: RAM-only with fixed 5-minute TTL
 //! - **Best-effort delivery**: Messages may expire before delivery
-//! - **Minimal logging**: No PII ever logged
+//! - **Minimal logging**: No PII ever logged; conversation IDs only as rotating pseudonyms
 //!
 //! ## Architecture
 //!
@@ -37,19 +37,25 @@
 //! | `/v1/burn` | POST | Burn conversation |
 //! | `/v1/burn` | GET | Check burn status |
 
+pub mod admin;
 pub mod apns;
 pub mod auth;
 pub mod config;
 pub mod expiry;
 pub mod handlers;
+pub mod log_policy;
 pub mod models;
+pub mod schedule;
 pub mod store;
+pub mod tls;
+pub mod tombstone;
 
 pub use config::Config;
 pub use handlers::AppState;
 pub use store::Store;
 
 use axum::{
+    extract::Request,
     http::{header, Method},
     routing::{get, post},
     Router,
@@ -85,6 +91,17 @@ pub fn build_router(state: AppState) -> Router {
                 .allow_methods([Method::GET, Method::POST])
                 .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION]),
         )
-        .layer(TraceLayer::new_for_http())
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
         .with_state(state)
 }
+
+/// Request span without the query string, which carries conversation IDs
+/// and cursors.
+fn request_span(request: &Request) -> tracing::Span {
+    tracing::debug_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        version = ?request.version(),
+    )
+}
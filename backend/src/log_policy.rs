@@ -0,0 +1,294 @@
+//! Privacy policy for relay logs.
+//!
+//! The backend contract forbids logs that let conversations be correlated.
+//! Handlers therefore never log raw conversation IDs, tokens or ciphertext.
+//! What they may log is decided here:
+//!
+//! - [`LogMode::Pseudonymous`] (default): per-request debug lines carry a
+//!   short keyed pseudonym instead of the conversation ID. The key lives
+//!   only in memory and is replaced every `LOG_PSEUDONYM_ROTATION_SECS`, so
+//!   pseudonyms link requests within one rotation period and nothing else.
+//! - [`LogMode::Strict`]: per-request fields are stripped entirely.
+//!
+//! In both modes request handling is reported as aggregated counters,
+//! logged once per `LOG_ACTIVITY_INTERVAL_SECS` instead of one info line
+//! per request.
+
+use ring::hmac;
+use ring::rand::SystemRandom;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Bytes of the keyed hash shown as a pseudonym (hex-encoded).
+const PSEUDONYM_LEN: usize = 6;
+
+/// What per-request log lines may contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogMode {
+    /// Per-request lines carry rotating conversation pseudonyms.
+    #[default]
+    Pseudonymous,
+    /// Per-request fields are stripped; only aggregated counters remain.
+    Strict,
+}
+
+impl FromStr for LogMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pseudonymous" => Ok(Self::Pseudonymous),
+            "strict" => Ok(Self::Strict),
+            other => Err(format!("unknown log mode: {other}")),
+        }
+    }
+}
+
+/// Request outcomes counted between activity reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activity {
+    Registration,
+    Submission,
+    DuplicateSubmission,
+    Poll,
+    StreamConnect,
+    Ack,
+    Burn,
+    AuthFailure,
+}
+
+impl Activity {
+    const ALL: [Self; 8] = [
+        Self::Registration,
+        Self::Submission,
+        Self::DuplicateSubmission,
+        Self::Poll,
+        Self::StreamConnect,
+        Self::Ack,
+        Self::Burn,
+        Self::AuthFailure,
+    ];
+}
+
+/// Counter values for one report period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActivityCounts {
+    pub registrations: u64,
+    pub submissions: u64,
+    pub duplicate_submissions: u64,
+    pub polls: u64,
+    pub stream_connects: u64,
+    pub acks: u64,
+    pub burns: u64,
+    pub auth_failures: u64,
+}
+
+impl ActivityCounts {
+    /// True if nothing was counted.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Pseudonym key of the current rotation period.
+struct Epoch {
+    key: hmac::Key,
+    started: Instant,
+}
+
+impl Epoch {
+    fn generate() -> Self {
+        Self {
+            key: hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+                .expect("system randomness available"),
+            started: Instant::now(),
+        }
+    }
+}
+
+/// Log policy shared by the store and the handlers.
+pub struct LogPolicy {
+    mode: LogMode,
+    /// How long one pseudonym key is used.
+    rotation: Duration,
+    /// Interval between activity reports.
+    report_interval: Duration,
+    epoch: RwLock<Epoch>,
+    counters: [AtomicU64; Activity::ALL.len()],
+}
+
+impl std::fmt::Debug for LogPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogPolicy")
+            .field("mode", &self.mode)
+            .field("rotation", &self.rotation)
+            .field("report_interval", &self.report_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LogPolicy {
+    /// Create a policy with a fresh pseudonym key.
+    pub fn new(mode: LogMode, rotation: Duration, report_interval: Duration) -> Self {
+        Self {
+            mode,
+            rotation,
+            report_interval,
+            epoch: RwLock::new(Epoch::generate()),
+            counters: Default::default(),
+        }
+    }
+
+    /// Configured mode.
+    pub fn mode(&self) -> LogMode {
+        self.mode
+    }
+
+    /// Whether per-request fields (pseudonyms, blob IDs, sizes) may be logged.
+    pub fn detailed(&self) -> bool {
+        self.mode == LogMode::Pseudonymous
+    }
+
+    /// Pseudonym for a conversation ID, or `None` in strict mode.
+    ///
+    /// Stable within a rotation period; unlinkable across periods because
+    /// the previous key is discarded.
+    pub fn conv(&self, conversation_id: &str) -> Option<String> {
+        if !self.detailed() {
+            return None;
+        }
+
+        let stale = self
+            .epoch
+            .read()
+            .expect("log epoch lock poisoned")
+            .started
+            .elapsed()
+            >= self.rotation;
+        if stale {
+            let mut epoch = self.epoch.write().expect("log epoch lock poisoned");
+            if epoch.started.elapsed() >= self.rotation {
+                *epoch = Epoch::generate();
+            }
+        }
+
+        let epoch = self.epoch.read().expect("log epoch lock poisoned");
+        let tag = hmac::sign(&epoch.key, conversation_id.as_bytes());
+        Some(hex::encode(&tag.as_ref()[..PSEUDONYM_LEN]))
+    }
+
+    /// Count one request outcome.
+    pub fn record(&self, activity: Activity) {
+        self.counters[activity as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Read and reset all counters.
+    pub fn take_counts(&self) -> ActivityCounts {
+        let take = |activity: Activity| self.counters[activity as usize].swap(0, Ordering::Relaxed);
+        ActivityCounts {
+            registrations: take(Activity::Registration),
+            submissions: take(Activity::Submission),
+            duplicate_submissions: take(Activity::DuplicateSubmission),
+            polls: take(Activity::Poll),
+            stream_connects: take(Activity::StreamConnect),
+            acks: take(Activity::Ack),
+            burns: take(Activity::Burn),
+            auth_failures: take(Activity::AuthFailure),
+        }
+    }
+
+    /// Start a background task logging the counters every report interval.
+    ///
+    /// Periods without activity are not logged.
+    pub fn start_reporter(self: Arc<Self>) {
+        let interval = self.report_interval;
+        let mode = self.mode;
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let counts = self.take_counts();
+                if counts.is_empty() {
+                    continue;
+                }
+                info!(
+                    interval_secs = interval.as_secs(),
+                    registrations = counts.registrations,
+                    submissions = counts.submissions,
+                    duplicate_submissions = counts.duplicate_submissions,
+                    polls = counts.polls,
+                    stream_connects = counts.stream_connects,
+                    acks = counts.acks,
+                    burns = counts.burns,
+                    auth_failures = counts.auth_failures,
+                    "Relay activity"
+                );
+            }
+        });
+
+        info!(
+            mode = ?mode,
+            interval_secs = interval.as_secs(),
+            "Started activity reporter"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn pseudonyms_are_stable_within_period() {
+        let policy = LogPolicy::new(LogMode::Pseudonymous, HOUR, HOUR);
+        let id = "ab".repeat(32);
+
+        let first = policy.conv(&id).unwrap();
+        assert_eq!(policy.conv(&id).unwrap(), first);
+        assert_eq!(first.len(), PSEUDONYM_LEN * 2);
+        assert!(!id.contains(&first));
+        assert_ne!(policy.conv(&"cd".repeat(32)).unwrap(), first);
+    }
+
+    #[test]
+    fn pseudonyms_change_on_rotation() {
+        let policy = LogPolicy::new(LogMode::Pseudonymous, Duration::ZERO, HOUR);
+        let id = "ab".repeat(32);
+
+        assert_ne!(policy.conv(&id), policy.conv(&id));
+    }
+
+    #[test]
+    fn strict_mode_has_no_pseudonyms() {
+        let policy = LogPolicy::new(LogMode::Strict, HOUR, HOUR);
+        assert!(!policy.detailed());
+        assert_eq!(policy.conv(&"ab".repeat(32)), None);
+    }
+
+    #[test]
+    fn counts_reset_when_taken() {
+        let policy = LogPolicy::new(LogMode::Strict, HOUR, HOUR);
+        policy.record(Activity::Submission);
+        policy.record(Activity::Submission);
+        policy.record(Activity::Burn);
+
+        let counts = policy.take_counts();
+        assert_eq!(counts.submissions, 2);
+        assert_eq!(counts.burns, 1);
+        assert_eq!(counts.polls, 0);
+        assert!(policy.take_counts().is_empty());
+    }
+
+    #[test]
+    fn mode_parses_case_insensitively() {
+        assert_eq!("Strict".parse::<LogMode>(), Ok(LogMode::Strict));
+        assert_eq!("pseudonymous".parse::<LogMode>(), Ok(LogMode::Pseudonymous));
+        assert!("verbose".parse::<LogMode>().is_err());
+    }
+}
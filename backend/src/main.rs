@@ -11,50 +11,219 @@
 //! - No user identity - only opaque conversation IDs
 //! - No long-term storage - data deleted on ACK or expiry
 //! - Best-effort delivery - no guaranteed message persistence
+//!
+//! # Shutdown
+//!
+//! On SIGTERM or SIGINT the server stops accepting connections, SSE
+//! streams receive a `reconnect` event and end, and open connections get
+//! `SHUTDOWN_GRACE_SECS` to finish. Pending push sends then get the same
+//! grace period, and burn tombstones are flushed before exit.
+//!
+//! # Usage
+//!
+//! ```text
+//! ash-backend [--config <path>] [--check-config]
+//! ```
+//!
+//! `--config` names a TOML file layered under environment variables
+//! (defaults to `CONFIG_FILE`). `--check-config` validates the
+//! configuration, prints the effective settings without secrets and exits.
 
-use ash_backend::{apns, build_router, config::Config, expiry, handlers::AppState, store::Store};
+use ash_backend::{
+    admin::{self, AdminState, ConfigDump, LogFilterHandle},
+    apns, build_router,
+    config::Config,
+    expiry,
+    handlers::AppState,
+    store::Store,
+    tls,
+};
+use std::path::PathBuf;
+use std::process::ExitCode;
 use std::sync::Arc;
-use tracing::info;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+/// Command line options.
+#[derive(Debug, Default)]
+struct Args {
+    /// TOML config file.
+    config: Option<PathBuf>,
+    /// Validate configuration and exit.
+    check_config: bool,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let mut args = Self::default();
+        let mut raw = std::env::args_os().skip(1);
+        while let Some(arg) = raw.next() {
+            match arg.to_str() {
+                Some("--config") => {
+                    let path = raw.next().ok_or("--config requires a path")?;
+                    args.config = Some(PathBuf::from(path));
+                }
+                Some("--check-config") => args.check_config = true,
+                _ => return Err(format!("unexpected argument: {}", arg.to_string_lossy())),
+            }
+        }
+        Ok(args)
+    }
+}
+
+const USAGE: &str = "usage: ash-backend [--config <path>] [--check-config]";
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
+    let args = match Args::parse() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
     // Load environment variables from .env file if present
     let _ = dotenvy::dotenv();
 
-    // Initialize structured logging
-    init_tracing();
-
     // Load and validate configuration
-    let config = Config::from_env();
+    let config_file = args
+        .config
+        .or_else(|| std::env::var_os("CONFIG_FILE").map(PathBuf::from));
+    let config = match Config::load(config_file.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.check_config {
+        let dump = serde_json::to_string_pretty(&ConfigDump::from(&config))
+            .expect("config dump serializes");
+        println!("{dump}");
+        println!("Configuration OK");
+        return ExitCode::SUCCESS;
+    }
+
+    // Initialize structured logging
+    let log_filter = init_tracing();
     log_startup_info(&config);
 
     // Initialize core components
     let store = Arc::new(Store::new(config.clone()));
-    store.clone().start_cleanup_task();
 
     let apns = apns::create_client(&config).await;
-    let state = AppState::new(store, apns.clone());
+    let state = AppState::new(store, apns);
+    state.log.clone().start_reporter();
 
     // Start background workers
-    let expiry_worker = Arc::new(expiry::ExpiryWorker::new(state.clone(), apns));
+    let expiry_worker = Arc::new(expiry::ExpiryWorker::new(state.clone()));
     expiry_worker.start();
 
+    // Begin shutdown on SIGTERM/SIGINT
+    tokio::spawn({
+        let state = state.clone();
+        async move {
+            shutdown_signal().await;
+            info!("Shutdown signal received, draining");
+            state.begin_shutdown();
+        }
+    });
+
+    // Operator API on its own listener
+    if let Some(admin_addr) = &config.admin_bind_addr {
+        serve_admin(admin_addr, &config, &state, log_filter).await;
+    }
+
     // Build and serve the application
-    let app = build_router(state);
-    serve(app, &config).await;
+    let app = build_router(state.clone());
+    serve(app, &config, state.shutdown.clone()).await;
+
+    finish_shutdown(&state, &config).await;
+    ExitCode::SUCCESS
+}
+
+/// Wait for SIGTERM or SIGINT.
+async fn shutdown_signal() {
+    let interrupt = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install SIGINT handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = interrupt => {}
+        () = terminate => {}
+    }
+}
+
+/// Drain pending push sends and flush persistent state.
+async fn finish_shutdown(state: &AppState, config: &Config) {
+    if !state.drain_pushes(config.shutdown_grace).await {
+        warn!("Push notifications still pending after grace period, dropping");
+    }
+    if let Err(e) = state.store.flush() {
+        error!(error = %e, "Failed to flush burn tombstones");
+    }
+    info!("Shutdown complete");
 }
 
 /// Initialize tracing with environment-based log levels.
-fn init_tracing() {
-    use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+///
+/// Returns a handle for changing the filter at runtime (operator API).
+fn init_tracing() -> LogFilterHandle {
+    use tracing_subscriber::{fmt, prelude::*, reload, EnvFilter};
+
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new("ash_backend=debug,tower_http=info"));
+    let (filter, handle) = reload::Layer::new(filter);
 
     tracing_subscriber::registry()
+        .with(filter)
         .with(fmt::layer())
-        .with(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new("ash_backend=debug,tower_http=info")),
-        )
         .init();
+    handle
+}
+
+/// Bind the operator API and serve it in the background until shutdown.
+async fn serve_admin(
+    admin_addr: &str,
+    config: &Config,
+    state: &AppState,
+    log_filter: LogFilterHandle,
+) {
+    let admin_key = config
+        .admin_key
+        .as_deref()
+        .expect("ADMIN_KEY validated with ADMIN_BIND_ADDR");
+
+    let listener = tokio::net::TcpListener::bind(admin_addr)
+        .await
+        .expect("Failed to bind admin address");
+    let app =
+        admin::build_admin_router(AdminState::new(state.clone(), admin_key, Some(log_filter)));
+    let shutdown = state.shutdown.clone();
+
+    info!(addr = %admin_addr, "Admin API listening");
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown.cancelled_owned())
+            .await
+        {
+            error!(error = %e, "Admin server error");
+        }
+    });
 }
 
 /// Log startup configuration (no secrets).
@@ -66,19 +235,50 @@ fn log_startup_info(config: &Config) {
         blob_ttl_secs = config.blob_ttl.as_secs(),
         max_ciphertext_size = config.max_ciphertext_size,
         apns_enabled = config.apns_configured(),
+        tls_enabled = config.tls_paths().is_some(),
+        durable_tombstones = config.tombstone_path.is_some(),
+        log_mode = ?config.log_mode,
+        admin_enabled = config.admin_bind_addr.is_some(),
         "Starting ASH backend"
     );
 }
 
-/// Bind to address and serve the application.
-async fn serve(app: axum::Router, config: &Config) {
+/// Bind to address and serve the application until `shutdown` is
+/// cancelled and open connections have drained (or the grace period ends).
+async fn serve(app: axum::Router, config: &Config, shutdown: CancellationToken) {
     let bind_addr = format!("{}:{}", config.bind_addr, config.port);
 
     let listener = tokio::net::TcpListener::bind(&bind_addr)
         .await
         .expect("Failed to bind to address");
 
-    info!(addr = %bind_addr, "Server listening");
+    let server = async {
+        if let Some((cert_path, key_path)) = config.tls_paths() {
+            let reloader = Arc::new(
+                tls::CertReloader::new(cert_path, key_path)
+                    .expect("Failed to load TLS certificate"),
+            );
+            reloader.clone().start_watcher(config.tls_reload_interval);
+            let acceptor = tokio_rustls::TlsAcceptor::from(tls::server_config(reloader));
+
+            info!(addr = %bind_addr, "Server listening (TLS)");
+            tls::serve(listener, app, acceptor, shutdown.clone()).await;
+        } else {
+            info!(addr = %bind_addr, "Server listening");
+            axum::serve(listener, app)
+                .with_graceful_shutdown(shutdown.clone().cancelled_owned())
+                .await
+                .expect("Server error");
+        }
+    };
+
+    let grace_elapsed = async {
+        shutdown.cancelled().await;
+        tokio::time::sleep(config.shutdown_grace).await;
+    };
 
-    axum::serve(listener, app).await.expect("Server error");
+    tokio::select! {
+        () = server => {}
+        () = grace_elapsed => warn!("Connections still open after grace period, closing"),
+    }
 }
@@ -5,6 +5,7 @@
 
 use base64::Engine;
 use chrono::{DateTime, Utc};
+use ring::hmac;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -18,6 +19,17 @@ pub type ConversationId = String;
 /// Message sequence number for ordering.
 pub type SequenceNumber = u64;
 
+/// Opaque per-device handle chosen by the client (16-64 URL-safe
+/// characters). Links a submitted blob to the sender's device so only that
+/// device hears about its delivery failure.
+pub type DeviceHandle = String;
+
+/// Maximum number of entries in a `missing_sequences` hint.
+pub const MAX_MISSING_SEQUENCES: usize = 64;
+
+/// Maximum number of blobs returned by one poll.
+pub const MAX_POLL_LIMIT: usize = 100;
+
 // =============================================================================
 // Notification Flags
 // =============================================================================
@@ -107,8 +119,14 @@ impl ConversationPrefs {
 pub struct StoredBlob {
     /// Unique blob ID for deduplication and acknowledgment.
     pub id: Uuid,
+    /// Per-conversation insertion index (strictly increasing).
+    pub index: u64,
     /// Client-provided sequence number (optional).
     pub sequence: Option<SequenceNumber>,
+    /// Ceremony role of the sender, if the client declared it.
+    pub sender: Option<PartyRole>,
+    /// Handle of the submitting device, if the client declared it.
+    pub sender_device: Option<DeviceHandle>,
     /// Encrypted ciphertext (opaque to backend).
     pub ciphertext: Vec<u8>,
     /// When the blob was received.
@@ -117,6 +135,53 @@ pub struct StoredBlob {
     pub expires_at: DateTime<Utc>,
 }
 
+/// Ceremony role of a conversation party.
+///
+/// Sequences are tracked per role, so each sender direction has its own
+/// strictly increasing counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PartyRole {
+    Initiator,
+    Responder,
+}
+
+/// Highest accepted sequence per sender direction.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SequenceLanes {
+    /// Highest sequence accepted from the initiator.
+    pub initiator: Option<SequenceNumber>,
+    /// Highest sequence accepted from the responder.
+    pub responder: Option<SequenceNumber>,
+}
+
+impl SequenceLanes {
+    /// Create empty lanes.
+    pub const fn new() -> Self {
+        Self {
+            initiator: None,
+            responder: None,
+        }
+    }
+
+    /// Highest accepted sequence for a sender.
+    pub const fn last(&self, sender: PartyRole) -> Option<SequenceNumber> {
+        match sender {
+            PartyRole::Initiator => self.initiator,
+            PartyRole::Responder => self.responder,
+        }
+    }
+
+    /// Record an accepted sequence, keeping the highest per sender.
+    pub fn record(&mut self, sender: PartyRole, sequence: SequenceNumber) {
+        let lane = match sender {
+            PartyRole::Initiator => &mut self.initiator,
+            PartyRole::Responder => &mut self.responder,
+        };
+        *lane = Some(lane.map_or(sequence, |last| last.max(sequence)));
+    }
+}
+
 /// Registered device for push notifications.
 #[derive(Debug, Clone)]
 pub struct DeviceRegistration {
@@ -124,12 +189,27 @@ pub struct DeviceRegistration {
     pub device_token: String,
     /// Platform (ios, macos).
     pub platform: Platform,
+    /// Opaque handle the device submits blobs under.
+    pub device_handle: Option<DeviceHandle>,
+    /// Party the device belongs to.
+    pub role: Option<PartyRole>,
     /// When the registration was created.
     pub registered_at: DateTime<Utc>,
     /// When the registration expires.
     pub expires_at: DateTime<Utc>,
 }
 
+impl DeviceRegistration {
+    /// Check if the device belongs to the sender of a blob: it was
+    /// registered with the sender's role or under the sender's handle.
+    pub fn is_sender(&self, sender: Option<PartyRole>, sender_device: Option<&str>) -> bool {
+        let same_role = self.role.is_some() && self.role == sender;
+        let same_device =
+            self.device_handle.is_some() && self.device_handle.as_deref() == sender_device;
+        same_role || same_device
+    }
+}
+
 /// Supported platforms for push notifications.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -149,38 +229,63 @@ pub struct BurnFlag {
 }
 
 /// Cursor for message pagination.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// A cursor is a position in the conversation's insertion order, tagged
+/// with the epoch of the queue it was issued for. On the wire it is
+/// authenticated with an HMAC bound to the conversation ID, so clients
+/// cannot forge positions or reuse a cursor across conversations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Cursor {
-    /// Last seen blob ID.
-    pub last_id: Option<Uuid>,
-    /// Last seen sequence number.
+    /// Random nonce of the blob queue the position belongs to.
+    pub epoch: u64,
+    /// Insertion index after the last returned blob.
+    pub position: u64,
+    /// Highest sequence returned by a sender-filtered poll.
     pub last_sequence: Option<SequenceNumber>,
-    /// Timestamp for time-based cursors.
-    pub since: Option<DateTime<Utc>>,
 }
 
+/// Length of the cursor authentication tag (HMAC-SHA256).
+const CURSOR_TAG_LEN: usize = 32;
+
 impl Cursor {
     /// Create an empty cursor (start from beginning).
     pub const fn empty() -> Self {
         Self {
-            last_id: None,
+            epoch: 0,
+            position: 0,
             last_sequence: None,
-            since: None,
         }
     }
 
-    /// Encode cursor to URL-safe base64 string.
-    pub fn encode(&self) -> String {
-        let json = serde_json::to_string(self).unwrap_or_default();
-        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    /// Encode cursor to an authenticated URL-safe base64 string.
+    pub fn encode(&self, key: &hmac::Key, conversation_id: &str) -> String {
+        let mut bytes = serde_json::to_vec(self).unwrap_or_default();
+        let tag = hmac::sign(key, &Self::signed_input(conversation_id, &bytes));
+        bytes.extend_from_slice(tag.as_ref());
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
     }
 
-    /// Decode cursor from URL-safe base64 string.
-    pub fn decode(s: &str) -> Option<Self> {
+    /// Decode and verify a cursor from an authenticated URL-safe base64 string.
+    ///
+    /// Returns `None` if the string is malformed, was issued for another
+    /// conversation or under another key, or was modified.
+    pub fn decode(s: &str, key: &hmac::Key, conversation_id: &str) -> Option<Self> {
         let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
             .decode(s)
             .ok()?;
-        serde_json::from_slice(&bytes).ok()
+        let split = bytes.len().checked_sub(CURSOR_TAG_LEN)?;
+        let (payload, tag) = bytes.split_at(split);
+        hmac::verify(key, &Self::signed_input(conversation_id, payload), tag).ok()?;
+        serde_json::from_slice(payload).ok()
+    }
+
+    /// HMAC input: length-prefixed conversation ID followed by the payload.
+    fn signed_input(conversation_id: &str, payload: &[u8]) -> Vec<u8> {
+        let mut input = Vec::with_capacity(8 + conversation_id.len() + payload.len());
+        input.extend_from_slice(&(conversation_id.len() as u64).to_be_bytes());
+        input.extend_from_slice(conversation_id.as_bytes());
+        input.extend_from_slice(payload);
+        input
     }
 }
 
@@ -199,6 +304,9 @@ pub struct RegisterConversationRequest {
     pub auth_token_hash: String,
     /// SHA-256 hash of burn token (64-char hex string).
     pub burn_token_hash: String,
+    /// Pad-derived proof over the token hashes (64-char hex string).
+    #[serde(default)]
+    pub registration_proof: Option<String>,
     /// Notification preferences (16-bit flags).
     #[serde(default = "default_notification_flags")]
     pub notification_flags: u16,
@@ -225,6 +333,14 @@ pub struct RegisterDeviceRequest {
     /// Platform (defaults to iOS).
     #[serde(default)]
     pub platform: Platform,
+    /// Opaque handle this device submits blobs under, for delivery-failed
+    /// notifications.
+    #[serde(default)]
+    pub device_handle: Option<DeviceHandle>,
+    /// Party the device belongs to. Message pushes skip the sender's own
+    /// devices.
+    #[serde(default)]
+    pub role: Option<PartyRole>,
 }
 
 /// Submit encrypted message.
@@ -236,6 +352,14 @@ pub struct SubmitMessageRequest {
     pub ciphertext: String,
     /// Optional client sequence number.
     pub sequence: Option<SequenceNumber>,
+    /// Optional sender role. Required for sequence enforcement and
+    /// duplicate detection.
+    #[serde(default)]
+    pub sender: Option<PartyRole>,
+    /// Optional handle of the submitting device. The device is told if the
+    /// blob expires unacknowledged.
+    #[serde(default)]
+    pub device_handle: Option<DeviceHandle>,
 }
 
 /// Poll messages query parameters.
@@ -245,6 +369,18 @@ pub struct PollMessagesQuery {
     pub conversation_id: ConversationId,
     /// Pagination cursor (base64-encoded).
     pub cursor: Option<String>,
+    /// Maximum number of blobs to return (capped at `MAX_POLL_LIMIT`).
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Only return blobs from this sender.
+    #[serde(default)]
+    pub sender: Option<PartyRole>,
+    /// Only return blobs with a sequence greater than this.
+    #[serde(default)]
+    pub after_sequence: Option<SequenceNumber>,
+    /// Long-poll: seconds to wait for a blob or burn if nothing is queued.
+    #[serde(default)]
+    pub wait: Option<u64>,
 }
 
 /// Burn conversation request.
@@ -275,6 +411,9 @@ pub struct AckMessageRequest {
 pub struct StreamQuery {
     /// Conversation ID.
     pub conversation_id: ConversationId,
+    /// Handle of the connecting device, to receive its delivery failures.
+    #[serde(default)]
+    pub device_handle: Option<DeviceHandle>,
 }
 
 // =============================================================================
@@ -300,6 +439,8 @@ pub struct SubmitMessageResponse {
     pub blob_id: Uuid,
     /// Server-calculated expiry time (for client timer synchronization).
     pub expires_at: DateTime<Utc>,
+    /// True if the sequence was already stored and `blob_id` is the existing blob.
+    pub duplicate: bool,
 }
 
 /// Poll messages response.
@@ -308,6 +449,15 @@ pub struct PollMessagesResponse {
     pub messages: Vec<MessageBlob>,
     pub next_cursor: Option<String>,
     pub burned: bool,
+    /// More blobs are queued after this page (only with `limit`).
+    pub has_more: bool,
+    /// The supplied cursor was invalid or stale and the poll started from
+    /// the beginning of the queue.
+    pub cursor_reset: bool,
+    /// Sequences from the polled sender that the relay cannot return
+    /// (never submitted, expired or acknowledged). Only filled when the
+    /// poll names a `sender`; capped at `MAX_MISSING_SEQUENCES`.
+    pub missing_sequences: Vec<SequenceNumber>,
 }
 
 /// Message blob in API responses.
@@ -315,6 +465,8 @@ pub struct PollMessagesResponse {
 pub struct MessageBlob {
     pub id: Uuid,
     pub sequence: Option<SequenceNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<PartyRole>,
     /// Base64-encoded ciphertext.
     pub ciphertext: String,
     pub received_at: DateTime<Utc>,
@@ -368,10 +520,19 @@ pub enum StreamEvent {
         blob_ids: Vec<Uuid>,
         delivered_at: DateTime<Utc>,
     },
+    /// Blobs submitted by this device expired without being acknowledged.
+    /// Only sent to the stream of the submitting device.
+    DeliveryFailed {
+        blob_ids: Vec<Uuid>,
+        failed_at: DateTime<Utc>,
+    },
     /// Conversation has been burned.
     Burned { burned_at: DateTime<Utc> },
     /// Keep-alive ping.
     Ping,
+    /// Server is shutting down; the stream ends after this event and the
+    /// client should reconnect.
+    Reconnect,
 }
 
 /// Internal broadcast event (conversation_id + event).
@@ -379,6 +540,20 @@ pub enum StreamEvent {
 pub struct BroadcastEvent {
     pub conversation_id: ConversationId,
     pub event: StreamEvent,
+    /// Only streams opened with this device handle receive the event
+    /// (all streams of the conversation if `None`).
+    pub device_handle: Option<DeviceHandle>,
+}
+
+impl BroadcastEvent {
+    /// Whether a stream opened with `device_handle` receives this event.
+    pub fn is_for(&self, conversation_id: &str, device_handle: Option<&str>) -> bool {
+        self.conversation_id == conversation_id
+            && self
+                .device_handle
+                .as_deref()
+                .map_or(true, |target| Some(target) == device_handle)
+    }
 }
 
 // =============================================================================
@@ -389,25 +564,54 @@ pub struct BroadcastEvent {
 mod tests {
     use super::*;
 
+    fn cursor_key(byte: u8) -> hmac::Key {
+        hmac::Key::new(hmac::HMAC_SHA256, &[byte; 32])
+    }
+
     #[test]
     fn cursor_encode_decode_roundtrip() {
+        let key = cursor_key(1);
         let cursor = Cursor {
-            last_id: Some(Uuid::new_v4()),
+            epoch: 9,
+            position: 7,
             last_sequence: Some(42),
-            since: Some(Utc::now()),
         };
 
-        let encoded = cursor.encode();
-        let decoded = Cursor::decode(&encoded).expect("decode failed");
+        let encoded = cursor.encode(&key, "conv");
+        let decoded = Cursor::decode(&encoded, &key, "conv").expect("decode failed");
 
-        assert_eq!(cursor.last_id, decoded.last_id);
-        assert_eq!(cursor.last_sequence, decoded.last_sequence);
+        assert_eq!(cursor, decoded);
     }
 
     #[test]
     fn cursor_decode_invalid_returns_none() {
-        assert!(Cursor::decode("not-valid-base64!!!").is_none());
-        assert!(Cursor::decode("").is_none());
+        let key = cursor_key(1);
+        assert!(Cursor::decode("not-valid-base64!!!", &key, "conv").is_none());
+        assert!(Cursor::decode("", &key, "conv").is_none());
+    }
+
+    #[test]
+    fn cursor_rejects_tampering_and_foreign_use() {
+        let key = cursor_key(1);
+        let encoded = Cursor {
+            epoch: 5,
+            position: 3,
+            last_sequence: None,
+        }
+        .encode(&key, "conv");
+
+        // Other conversation or other key
+        assert!(Cursor::decode(&encoded, &key, "other").is_none());
+        assert!(Cursor::decode(&encoded, &cursor_key(2), "conv").is_none());
+
+        // Modified payload
+        let mut bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(&encoded)
+            .unwrap();
+        let digit = bytes.iter().position(|&b| b == b'3').unwrap();
+        bytes[digit] = b'9';
+        let forged = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+        assert!(Cursor::decode(&forged, &key, "conv").is_none());
     }
 
     #[test]
@@ -431,4 +635,32 @@ mod tests {
         let macos: Platform = serde_json::from_str(r#""macos""#).unwrap();
         assert_eq!(macos, Platform::Macos);
     }
+
+    #[test]
+    fn broadcast_event_targets_device() {
+        let event = |device_handle: Option<&str>| BroadcastEvent {
+            conversation_id: "conv".to_string(),
+            event: StreamEvent::Ping,
+            device_handle: device_handle.map(str::to_string),
+        };
+
+        assert!(event(None).is_for("conv", None));
+        assert!(event(None).is_for("conv", Some("device-a")));
+        assert!(!event(None).is_for("other", None));
+
+        assert!(event(Some("device-a")).is_for("conv", Some("device-a")));
+        assert!(!event(Some("device-a")).is_for("conv", Some("device-b")));
+        assert!(!event(Some("device-a")).is_for("conv", None));
+    }
+
+    #[test]
+    fn sequence_lanes_are_independent() {
+        let mut lanes = SequenceLanes::new();
+        lanes.record(PartyRole::Initiator, 5);
+        lanes.record(PartyRole::Initiator, 3);
+        lanes.record(PartyRole::Responder, 1);
+
+        assert_eq!(lanes.last(PartyRole::Initiator), Some(5));
+        assert_eq!(lanes.last(PartyRole::Responder), Some(1));
+    }
 }
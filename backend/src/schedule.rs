@@ -0,0 +1,351 @@
+//! Expiry schedule for queued blobs.
+//!
+//! Keeps every deadline of a queued blob — its expiry warnings and its
+//! expiry — in time order, plus the time each empty queue becomes idle.
+//! Cleanup and expiry notifications pop what is due instead of scanning
+//! every queue, so a tick costs the number of due deadlines, not the
+//! number of stored blobs.
+//!
+//! The store keeps the schedule in step with the queues: deadlines are
+//! added on submit and removed on ACK, burn and expiry.
+
+use crate::models::ConversationId;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+/// Warning sent before a queued blob expires (`NOTIFY_MESSAGE_EXPIRING`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExpiryWarning {
+    /// Five minutes before expiry.
+    FiveMinutes,
+    /// One minute before expiry.
+    OneMinute,
+}
+
+impl ExpiryWarning {
+    /// All warnings, earliest first.
+    pub const ALL: [Self; 2] = [Self::FiveMinutes, Self::OneMinute];
+
+    /// How long before expiry the warning is due.
+    pub fn lead(self) -> Duration {
+        match self {
+            Self::FiveMinutes => Duration::minutes(5),
+            Self::OneMinute => Duration::minutes(1),
+        }
+    }
+
+    /// Notification type sent for the warning.
+    pub fn notification_type(self) -> &'static str {
+        match self {
+            Self::FiveMinutes => "expiring_5min",
+            Self::OneMinute => "expiring_1min",
+        }
+    }
+
+    /// How long before expiry the warning is superseded by the next one
+    /// (or by the expiry itself) and no longer worth sending.
+    fn superseded_lead(self) -> Duration {
+        match self {
+            Self::FiveMinutes => Self::OneMinute.lead(),
+            Self::OneMinute => Duration::zero(),
+        }
+    }
+}
+
+/// Expiry warning that came due for a queued blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueWarning {
+    pub conversation_id: ConversationId,
+    pub blob_id: Uuid,
+    pub warning: ExpiryWarning,
+}
+
+/// Blob whose expiry came due.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueExpiry {
+    pub conversation_id: ConversationId,
+    pub blob_id: Uuid,
+}
+
+/// Deadlines in time order.
+#[derive(Debug, Default)]
+struct Deadlines {
+    warnings: BTreeSet<(DateTime<Utc>, ExpiryWarning, ConversationId, Uuid)>,
+    expiries: BTreeSet<(DateTime<Utc>, ConversationId, Uuid)>,
+    /// When an empty queue may be dropped.
+    idle: BTreeSet<(DateTime<Utc>, ConversationId)>,
+}
+
+impl Deadlines {
+    fn next(&self) -> Option<DateTime<Utc>> {
+        let warning = self.warnings.first().map(|d| d.0);
+        let expiry = self.expiries.first().map(|d| d.0);
+        let idle = self.idle.first().map(|d| d.0);
+        [warning, expiry, idle].into_iter().flatten().min()
+    }
+}
+
+/// Time-ordered deadlines of all queued blobs.
+#[derive(Debug, Default)]
+pub struct ExpirySchedule {
+    deadlines: Mutex<Deadlines>,
+    /// Signalled when a deadline earlier than all others is added.
+    changed: Notify,
+}
+
+impl ExpirySchedule {
+    /// Create an empty schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedule the warnings and expiry of a blob.
+    pub fn add_blob(&self, conversation_id: &str, blob_id: Uuid, expires_at: DateTime<Utc>) {
+        let mut deadlines = self.lock();
+        let earliest = deadlines.next();
+        for warning in ExpiryWarning::ALL {
+            deadlines.warnings.insert((
+                expires_at - warning.lead(),
+                warning,
+                conversation_id.to_string(),
+                blob_id,
+            ));
+        }
+        deadlines
+            .expiries
+            .insert((expires_at, conversation_id.to_string(), blob_id));
+        let wake = earliest.map_or(true, |earliest| deadlines.next() < Some(earliest));
+        drop(deadlines);
+
+        if wake {
+            self.changed.notify_one();
+        }
+    }
+
+    /// Unschedule a blob that was acknowledged, burned or purged.
+    pub fn remove_blob(&self, conversation_id: &str, blob_id: Uuid, expires_at: DateTime<Utc>) {
+        let conversation_id = conversation_id.to_string();
+        let mut deadlines = self.lock();
+        for warning in ExpiryWarning::ALL {
+            deadlines.warnings.remove(&(
+                expires_at - warning.lead(),
+                warning,
+                conversation_id.clone(),
+                blob_id,
+            ));
+        }
+        deadlines
+            .expiries
+            .remove(&(expires_at, conversation_id, blob_id));
+    }
+
+    /// Schedule a check for an empty queue that may be dropped at `at`.
+    pub fn add_idle(&self, conversation_id: &str, at: DateTime<Utc>) {
+        self.lock().idle.insert((at, conversation_id.to_string()));
+    }
+
+    /// Remove and return the warnings due at `now`.
+    ///
+    /// Warnings already superseded by the next one are dropped, so a
+    /// late tick never sends a five-minute warning with seconds left.
+    pub fn take_due_warnings(&self, now: DateTime<Utc>) -> Vec<DueWarning> {
+        let mut due = Vec::new();
+        let mut deadlines = self.lock();
+        while deadlines.warnings.first().is_some_and(|d| d.0 <= now) {
+            let (at, warning, conversation_id, blob_id) =
+                deadlines.warnings.pop_first().expect("checked above");
+            let expires_at = at + warning.lead();
+            if expires_at - now > warning.superseded_lead() {
+                due.push(DueWarning {
+                    conversation_id,
+                    blob_id,
+                    warning,
+                });
+            }
+        }
+        due
+    }
+
+    /// Remove and return the blob expiries due at `now`.
+    pub fn take_due_expiries(&self, now: DateTime<Utc>) -> Vec<DueExpiry> {
+        let mut due = Vec::new();
+        let mut deadlines = self.lock();
+        while deadlines.expiries.first().is_some_and(|d| d.0 <= now) {
+            let (_, conversation_id, blob_id) =
+                deadlines.expiries.pop_first().expect("checked above");
+            due.push(DueExpiry {
+                conversation_id,
+                blob_id,
+            });
+        }
+        due
+    }
+
+    /// Remove and return the conversations whose queue may be idle at `now`.
+    pub fn take_due_idle(&self, now: DateTime<Utc>) -> Vec<ConversationId> {
+        let mut due = Vec::new();
+        let mut deadlines = self.lock();
+        while deadlines.idle.first().is_some_and(|d| d.0 <= now) {
+            let (_, conversation_id) = deadlines.idle.pop_first().expect("checked above");
+            due.push(conversation_id);
+        }
+        due
+    }
+
+    /// Earliest scheduled deadline, if any.
+    pub fn next_deadline(&self) -> Option<DateTime<Utc>> {
+        self.lock().next()
+    }
+
+    /// Wait until a deadline earlier than all others is added.
+    pub async fn changed(&self) {
+        self.changed.notified().await;
+    }
+
+    /// Number of scheduled deadlines.
+    pub fn len(&self) -> usize {
+        let deadlines = self.lock();
+        deadlines.warnings.len() + deadlines.expiries.len() + deadlines.idle.len()
+    }
+
+    /// True if nothing is scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Deadlines> {
+        self.deadlines
+            .lock()
+            .expect("expiry schedule lock poisoned")
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conv(c: char) -> ConversationId {
+        c.to_string().repeat(64)
+    }
+
+    #[test]
+    fn expiries_are_returned_in_time_order_once() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        let (late, early) = (Uuid::new_v4(), Uuid::new_v4());
+        schedule.add_blob(&conv('a'), late, now + Duration::seconds(20));
+        schedule.add_blob(&conv('b'), early, now + Duration::seconds(10));
+
+        assert!(schedule.take_due_expiries(now).is_empty());
+
+        let due = schedule.take_due_expiries(now + Duration::seconds(30));
+        let ids: Vec<_> = due.iter().map(|d| d.blob_id).collect();
+        assert_eq!(ids, vec![early, late]);
+        assert_eq!(due[0].conversation_id, conv('b'));
+        assert!(schedule
+            .take_due_expiries(now + Duration::seconds(30))
+            .is_empty());
+    }
+
+    #[test]
+    fn removed_blobs_are_never_due() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        let expires_at = now + Duration::minutes(10);
+        let blob_id = Uuid::new_v4();
+        schedule.add_blob(&conv('a'), blob_id, expires_at);
+        assert_eq!(schedule.len(), 3);
+
+        schedule.remove_blob(&conv('a'), blob_id, expires_at);
+
+        assert!(schedule.is_empty());
+        assert_eq!(schedule.next_deadline(), None);
+        let later = expires_at + Duration::seconds(1);
+        assert!(schedule.take_due_warnings(later).is_empty());
+        assert!(schedule.take_due_expiries(later).is_empty());
+    }
+
+    #[test]
+    fn warnings_fire_at_their_lead() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        let expires_at = now + Duration::minutes(10);
+        let blob_id = Uuid::new_v4();
+        schedule.add_blob(&conv('a'), blob_id, expires_at);
+
+        assert_eq!(
+            schedule.next_deadline(),
+            Some(expires_at - Duration::minutes(5))
+        );
+        assert!(schedule.take_due_warnings(now).is_empty());
+
+        let due = schedule.take_due_warnings(expires_at - Duration::minutes(4));
+        assert_eq!(
+            due,
+            vec![DueWarning {
+                conversation_id: conv('a'),
+                blob_id,
+                warning: ExpiryWarning::FiveMinutes,
+            }]
+        );
+
+        let due = schedule.take_due_warnings(expires_at - Duration::seconds(30));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].warning, ExpiryWarning::OneMinute);
+    }
+
+    #[test]
+    fn superseded_warnings_are_dropped() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        let expires_at = now + Duration::seconds(30);
+        schedule.add_blob(&conv('a'), Uuid::new_v4(), expires_at);
+
+        // Both warnings are due, only the one-minute warning still applies
+        let due = schedule.take_due_warnings(now);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].warning, ExpiryWarning::OneMinute);
+
+        // Nothing is sent once the blob has expired
+        schedule.add_blob(&conv('b'), Uuid::new_v4(), now);
+        assert!(schedule.take_due_warnings(now).is_empty());
+    }
+
+    #[test]
+    fn idle_checks_are_due_after_their_time() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        schedule.add_idle(&conv('a'), now + Duration::hours(1));
+
+        assert!(schedule.take_due_idle(now).is_empty());
+        assert_eq!(
+            schedule.take_due_idle(now + Duration::hours(1)),
+            vec![conv('a')]
+        );
+        assert!(schedule.is_empty());
+    }
+
+    #[tokio::test]
+    async fn earlier_deadline_signals_change() {
+        let schedule = ExpirySchedule::new();
+        let now = Utc::now();
+        schedule.add_blob(&conv('a'), Uuid::new_v4(), now + Duration::minutes(10));
+        schedule.changed().await;
+
+        // A later blob does not move the earliest deadline
+        schedule.add_blob(&conv('b'), Uuid::new_v4(), now + Duration::minutes(20));
+        let changed =
+            tokio::time::timeout(std::time::Duration::from_millis(20), schedule.changed());
+        assert!(changed.await.is_err());
+
+        schedule.add_blob(&conv('c'), Uuid::new_v4(), now + Duration::minutes(1));
+        schedule.changed().await;
+    }
+}
@@ -1,18 +1,27 @@
 //! In-memory ephemeral store with TTL-based cleanup.
 //!
 //! All data is automatically deleted when TTL expires.
-//! No persistence - data is lost on restart (by design).
+//! No persistence - data is lost on restart (by design). The one exception
+//! is burn tombstones (see [`crate::tombstone`]), which only hold keyed
+//! hashes of burned conversation IDs.
 
 use crate::config::Config;
+use crate::log_policy::LogPolicy;
 use crate::models::{
-    BurnFlag, ConversationId, ConversationPrefs, DeviceRegistration, Platform, SequenceNumber,
-    StoredBlob,
+    BurnFlag, ConversationId, ConversationPrefs, Cursor, DeviceHandle, DeviceRegistration,
+    PartyRole, Platform, SequenceLanes, SequenceNumber, StoredBlob, MAX_MISSING_SEQUENCES,
 };
-use chrono::Utc;
+use crate::schedule::ExpirySchedule;
+use crate::tombstone::Tombstones;
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::Serialize;
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::debug;
 use uuid::Uuid;
 
 // =============================================================================
@@ -22,22 +31,66 @@ use uuid::Uuid;
 /// Thread-safe in-memory store for ephemeral message relay.
 #[derive(Clone)]
 pub struct Store {
-    /// Encrypted blobs per conversation.
-    blobs: Arc<DashMap<ConversationId, Vec<StoredBlob>>>,
+    /// Encrypted blob queue per conversation.
+    blobs: Arc<DashMap<ConversationId, BlobQueue>>,
+    /// Warning, expiry and idle deadlines of the blob queues.
+    schedule: Arc<ExpirySchedule>,
     /// Burn flags per conversation.
     burns: Arc<DashMap<ConversationId, BurnFlag>>,
+    /// Long-lived burn tombstones (keyed hashes, optionally durable).
+    tombstones: Arc<Tombstones>,
     /// Device registrations per conversation.
     devices: Arc<DashMap<ConversationId, Vec<DeviceRegistration>>>,
     /// Conversation notification preferences.
     prefs: Arc<DashMap<ConversationId, ConversationPrefs>>,
     /// Configuration.
     config: Arc<Config>,
+    /// What may be logged about requests.
+    log: Arc<LogPolicy>,
     /// Aggregate metrics (no PII).
     metrics: Arc<RwLock<StoreMetrics>>,
+    /// Per-process key authenticating pagination cursors.
+    cursor_key: hmac::Key,
+}
+
+/// Blob queue of one conversation.
+///
+/// Outlives its blobs for `INACTIVE_TTL_SECS`, so acked or expired sequences
+/// are still known. A queue dropped when idle and created again starts its
+/// insertion indices over under a new epoch, so cursors issued for the old
+/// queue are reset instead of skipping new blobs.
+#[derive(Debug)]
+struct BlobQueue {
+    /// Random nonce identifying this queue in cursors.
+    epoch: u64,
+    /// Queued blobs in insertion order.
+    blobs: Vec<StoredBlob>,
+    /// Insertion index of the next blob.
+    next_index: u64,
+    /// Highest accepted sequence per sender role.
+    lanes: SequenceLanes,
+    /// When a blob was last inserted.
+    updated_at: DateTime<Utc>,
+}
+
+impl Default for BlobQueue {
+    fn default() -> Self {
+        let mut epoch = [0u8; 8];
+        SystemRandom::new()
+            .fill(&mut epoch)
+            .expect("system randomness available");
+        Self {
+            epoch: u64::from_be_bytes(epoch),
+            blobs: Vec::new(),
+            next_index: 0,
+            lanes: SequenceLanes::new(),
+            updated_at: Utc::now(),
+        }
+    }
 }
 
 /// Aggregate metrics (no PII, no per-conversation data).
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct StoreMetrics {
     pub total_blobs_stored: u64,
     pub total_blobs_expired: u64,
@@ -45,36 +98,136 @@ pub struct StoreMetrics {
     pub total_registrations: u64,
 }
 
+/// Current sizes of the in-memory state (no per-conversation data).
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct StoreGauges {
+    /// Conversations with a blob queue.
+    pub blob_queues: usize,
+    /// Blobs currently queued across all conversations.
+    pub queued_blobs: usize,
+    /// Unexpired burn flags.
+    pub burn_flags: usize,
+    /// Burn tombstones held, including expired ones not yet pruned.
+    pub tombstones: usize,
+    /// Registered push devices across all conversations.
+    pub device_registrations: usize,
+    /// Deadlines in the expiry schedule.
+    pub scheduled_deadlines: usize,
+}
+
+/// Outcome of submitting a blob.
+#[derive(Debug, Clone)]
+pub struct Submission {
+    /// ID of the stored blob (or of the existing one for a duplicate).
+    pub blob_id: Uuid,
+    /// When the blob expires.
+    pub expires_at: DateTime<Utc>,
+    /// True if the same sender already submitted this sequence.
+    pub duplicate: bool,
+}
+
+/// Optional sequence and sender details for [`Store::submit_blob`].
+#[derive(Debug, Clone, Default)]
+pub struct SubmitOptions {
+    /// Sender-assigned sequence number.
+    pub sequence: Option<SequenceNumber>,
+    /// Role of the sender, if it declared one.
+    pub sender: Option<PartyRole>,
+    /// Handle of the submitting device, if it declared one.
+    pub sender_device: Option<DeviceHandle>,
+}
+
+/// Optional party details for [`Store::register_device`].
+#[derive(Debug, Clone, Default)]
+pub struct RegisterOptions {
+    /// Role of the party that owns the device, if it declared one.
+    pub role: Option<PartyRole>,
+    /// Opaque handle the device also sends with its submissions.
+    pub device_handle: Option<DeviceHandle>,
+}
+
+/// Blob removed on expiry without having been acknowledged.
+#[derive(Debug, Clone)]
+pub struct ExpiredBlob {
+    pub conversation_id: ConversationId,
+    pub blob_id: Uuid,
+    /// Role of the sender, if it declared one.
+    pub sender: Option<PartyRole>,
+    /// Handle of the submitting device, if it declared one.
+    pub sender_device: Option<DeviceHandle>,
+}
+
+/// Sender and sequence filter for polling.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SequenceFilter {
+    /// Only return blobs from this sender.
+    pub sender: Option<PartyRole>,
+    /// Only return blobs with a sequence greater than this.
+    pub after: Option<SequenceNumber>,
+}
+
+/// One page of blobs returned by [`Store::poll_blobs`].
+#[derive(Debug, Clone, Default)]
+pub struct BlobPage {
+    /// Matching blobs in insertion order.
+    pub blobs: Vec<StoredBlob>,
+    /// Cursor for the next poll, if any blob was returned.
+    pub next_cursor: Option<Cursor>,
+    /// Sequences from the filtered sender the relay cannot return.
+    pub missing_sequences: Vec<SequenceNumber>,
+    /// More matching blobs are queued after this page.
+    pub has_more: bool,
+    /// The cursor points past the queue (stale) and was ignored.
+    pub cursor_reset: bool,
+}
+
 impl Store {
     /// Create a new empty store with the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configured tombstone file cannot be opened. Starting
+    /// without it would let burned conversations come back.
     pub fn new(config: Config) -> Self {
+        let tombstones =
+            Tombstones::from_config(&config).expect("Failed to open burn tombstone file");
+        Self::with_tombstones(config, tombstones)
+    }
+
+    /// Create a new empty store using the given tombstone set.
+    pub fn with_tombstones(config: Config, tombstones: Tombstones) -> Self {
         Self {
             blobs: Arc::new(DashMap::new()),
+            schedule: Arc::new(ExpirySchedule::new()),
             burns: Arc::new(DashMap::new()),
+            tombstones: Arc::new(tombstones),
             devices: Arc::new(DashMap::new()),
             prefs: Arc::new(DashMap::new()),
+            log: Arc::new(LogPolicy::new(
+                config.log_mode,
+                config.log_pseudonym_rotation,
+                config.log_activity_interval,
+            )),
             config: Arc::new(config),
             metrics: Arc::new(RwLock::new(StoreMetrics::default())),
+            cursor_key: hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+                .expect("system randomness available"),
         }
     }
 
-    /// Start background TTL cleanup task.
-    pub fn start_cleanup_task(self: Arc<Self>) {
-        let store = self.clone();
-        let interval = self.config.cleanup_interval;
+    /// Configuration the store was created with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
 
-        tokio::spawn(async move {
-            let mut ticker = tokio::time::interval(interval);
-            loop {
-                ticker.tick().await;
-                store.cleanup_expired().await;
-            }
-        });
+    /// Log policy shared with the handlers.
+    pub fn log_policy(&self) -> &Arc<LogPolicy> {
+        &self.log
+    }
 
-        info!(
-            interval_secs = interval.as_secs(),
-            "Started TTL cleanup task"
-        );
+    /// Deadlines of the queued blobs, driven by the expiry worker.
+    pub fn schedule(&self) -> &ExpirySchedule {
+        &self.schedule
     }
 
     // =========================================================================
@@ -84,12 +237,27 @@ impl Store {
     /// Store an encrypted blob for a conversation.
     ///
     /// Uses fixed 5-minute TTL. Messages are deleted on expiry or ACK.
-    pub async fn store_blob(
+    ///
+    /// When both `sender` and `sequence` are given:
+    /// - a queued blob with the same sender and sequence is returned instead
+    ///   of storing a second copy (idempotent retry)
+    /// - with `enforce_sequences`, a sequence not greater than the highest one
+    ///   accepted from that sender is rejected
+    ///
+    /// The sender's device handle is kept with the blob so the sender can be
+    /// told if it expires unacknowledged (see [`Store::cleanup_expired`]).
+    pub async fn submit_blob(
         &self,
         conversation_id: ConversationId,
         ciphertext: Vec<u8>,
-        sequence: Option<SequenceNumber>,
-    ) -> Result<Uuid, StoreError> {
+        options: SubmitOptions,
+    ) -> Result<Submission, StoreError> {
+        let SubmitOptions {
+            sequence,
+            sender,
+            sender_device,
+        } = options;
+
         if self.is_burned(&conversation_id) {
             return Err(StoreError::ConversationBurned);
         }
@@ -102,91 +270,226 @@ impl Store {
         let ttl = self.config.blob_ttl;
         let expires_at = now + chrono::Duration::from_std(ttl).expect("valid duration");
 
+        // The queue entry lock serializes submissions for the conversation
+        let mut entry = self.blobs.entry(conversation_id.clone()).or_default();
+
+        if let (Some(sender), Some(sequence)) = (sender, sequence) {
+            if let Some(existing) = entry.value().blobs.iter().find(|b| {
+                b.sender == Some(sender) && b.sequence == Some(sequence) && b.expires_at > now
+            }) {
+                if self.log.detailed() {
+                    debug!(blob_id = %existing.id, "Duplicate sequence, returning existing blob");
+                }
+                return Ok(Submission {
+                    blob_id: existing.id,
+                    expires_at: existing.expires_at,
+                    duplicate: true,
+                });
+            }
+
+            if self.config.enforce_sequences {
+                let last = entry.value().lanes.last(sender);
+                if let Some(last) = last.filter(|&last| sequence <= last) {
+                    return Err(StoreError::SequenceNotIncreasing { last });
+                }
+            }
+        }
+
+        // Queue limit enforcement
+        if entry.value().blobs.len() >= self.config.max_blobs_per_conversation {
+            return Err(StoreError::QueueFull);
+        }
+
+        let queue = entry.value_mut();
         let blob = StoredBlob {
             id: Uuid::new_v4(),
+            index: queue.next_index,
             sequence,
+            sender,
+            sender_device,
             ciphertext,
             received_at: now,
             expires_at,
         };
 
         let blob_id = blob.id;
-
-        // Store blob with queue limit enforcement
-        let mut entry = self.blobs.entry(conversation_id).or_default();
-        if entry.value().len() >= self.config.max_blobs_per_conversation {
-            return Err(StoreError::QueueFull);
+        queue.blobs.push(blob);
+        queue.next_index += 1;
+        queue.updated_at = now;
+        if let (Some(sender), Some(sequence)) = (sender, sequence) {
+            queue.lanes.record(sender, sequence);
         }
-        entry.value_mut().push(blob);
+        // Scheduled under the entry lock so a concurrent ACK cannot
+        // unschedule the blob before it is scheduled
+        self.schedule
+            .add_blob(&conversation_id, blob_id, expires_at);
+        drop(entry);
 
         // Update metrics
         self.metrics.write().await.total_blobs_stored += 1;
 
-        debug!(blob_id = %blob_id, ttl_secs = ttl.as_secs(), "Stored blob");
-        Ok(blob_id)
+        if self.log.detailed() {
+            debug!(blob_id = %blob_id, ttl_secs = ttl.as_secs(), "Stored blob");
+        }
+        Ok(Submission {
+            blob_id,
+            expires_at,
+            duplicate: false,
+        })
     }
 
-    /// Get blobs for a conversation, optionally filtered by cursor.
+    /// Get blobs for a conversation, optionally after a cursor.
     pub fn get_blobs(
         &self,
         conversation_id: &ConversationId,
-        cursor: Option<&crate::models::Cursor>,
-    ) -> (Vec<StoredBlob>, Option<crate::models::Cursor>) {
-        use crate::models::Cursor;
+        cursor: Option<&Cursor>,
+    ) -> (Vec<StoredBlob>, Option<Cursor>) {
+        let page = self.poll_blobs(conversation_id, cursor, SequenceFilter::default(), None);
+        (page.blobs, page.next_cursor)
+    }
 
+    /// Get up to `limit` blobs after `cursor`, filtered by sender and sequence.
+    ///
+    /// The cursor is a position in insertion order, so each poll only returns
+    /// blobs queued after the previous page. A cursor past the end of the
+    /// queue, or issued for an earlier queue of the conversation, is stale
+    /// and the poll starts from the beginning.
+    ///
+    /// For a sender-filtered poll the cursor's `last_sequence` is used when
+    /// `filter.after` is not given, and the page reports the sequences between
+    /// `after` and the highest one accepted from that sender that it could
+    /// not return.
+    pub fn poll_blobs(
+        &self,
+        conversation_id: &ConversationId,
+        cursor: Option<&Cursor>,
+        filter: SequenceFilter,
+        limit: Option<usize>,
+    ) -> BlobPage {
         if self.is_burned(conversation_id) {
-            return (vec![], None);
+            return BlobPage::default();
         }
 
-        let now = Utc::now();
-
         let Some(entry) = self.blobs.get(conversation_id) else {
-            return (vec![], None);
+            return BlobPage {
+                cursor_reset: cursor.is_some_and(|c| c.position > 0),
+                ..BlobPage::default()
+            };
         };
+        let queue = entry.value();
+
+        let now = Utc::now();
+        let cursor_reset =
+            cursor.is_some_and(|c| c.epoch != queue.epoch || c.position > queue.next_index);
+        let cursor = cursor.filter(|_| !cursor_reset);
+        let position = cursor.map_or(0, |c| c.position);
+        let after = filter
+            .after
+            .or_else(|| filter.sender.and(cursor.and_then(|c| c.last_sequence)));
 
         // Filter expired and apply cursor
-        let filtered: Vec<StoredBlob> = entry
-            .value()
+        let mut blobs: Vec<StoredBlob> = queue
+            .blobs
             .iter()
             .filter(|b| b.expires_at > now)
-            .filter(|b| {
-                cursor.map_or(true, |c| {
-                    if let Some(last_id) = c.last_id {
-                        return b.id != last_id;
-                    }
-                    if let Some(since) = c.since {
-                        return b.received_at > since;
-                    }
-                    true
-                })
-            })
+            .filter(|b| b.index >= position)
+            .filter(|b| filter.sender.map_or(true, |s| b.sender == Some(s)))
+            .filter(|b| after.map_or(true, |a| b.sequence.is_some_and(|seq| seq > a)))
             .cloned()
             .collect();
 
+        let has_more = limit.is_some_and(|limit| blobs.len() > limit);
+        if let Some(limit) = limit {
+            blobs.truncate(limit);
+        }
+
         // Generate next cursor from last blob
-        let next_cursor = filtered.last().map(|b| Cursor {
-            last_id: Some(b.id),
-            last_sequence: b.sequence,
-            since: Some(b.received_at),
+        let next_cursor = blobs.last().map(|b| Cursor {
+            epoch: queue.epoch,
+            position: b.index + 1,
+            last_sequence: filter
+                .sender
+                .and(blobs.iter().filter_map(|b| b.sequence).chain(after).max()),
         });
 
-        (filtered, next_cursor)
+        // Sequences beyond a truncated page are not missing
+        let last = if has_more {
+            None
+        } else {
+            filter.sender.and_then(|sender| queue.lanes.last(sender))
+        };
+        let missing_sequences = filter
+            .sender
+            .map(|_| missing_sequences(after, last, &blobs))
+            .unwrap_or_default();
+
+        BlobPage {
+            blobs,
+            next_cursor,
+            missing_sequences,
+            has_more,
+            cursor_reset,
+        }
+    }
+
+    /// Role and device handle a queued blob was submitted with.
+    pub fn blob_sender(
+        &self,
+        conversation_id: &ConversationId,
+        blob_id: &Uuid,
+    ) -> Option<(Option<PartyRole>, Option<DeviceHandle>)> {
+        let entry = self.blobs.get(conversation_id)?;
+        let blob = entry.value().blobs.iter().find(|b| b.id == *blob_id)?;
+        Some((blob.sender, blob.sender_device.clone()))
+    }
+
+    /// Encode a cursor for `conversation_id`, authenticated with this store's key.
+    pub fn encode_cursor(&self, conversation_id: &ConversationId, cursor: &Cursor) -> String {
+        cursor.encode(&self.cursor_key, conversation_id)
+    }
+
+    /// Decode a cursor issued by this store for `conversation_id`.
+    ///
+    /// Returns `None` for forged or modified cursors, cursors of another
+    /// conversation and cursors issued before a restart.
+    pub fn decode_cursor(&self, conversation_id: &ConversationId, cursor: &str) -> Option<Cursor> {
+        Cursor::decode(cursor, &self.cursor_key, conversation_id)
     }
 
     /// Delete a specific blob by ID (used for ACK).
     ///
     /// Returns `true` if the blob was found and deleted.
     pub async fn delete_blob(&self, conversation_id: &ConversationId, blob_id: &Uuid) -> bool {
-        if let Some(mut entry) = self.blobs.get_mut(conversation_id) {
-            let before = entry.value().len();
-            entry.value_mut().retain(|b| b.id != *blob_id);
-            let deleted = entry.value().len() < before;
-            if deleted {
-                debug!(blob_id = %blob_id, "Deleted blob on ACK");
-            }
-            return deleted;
+        let Some(mut entry) = self.blobs.get_mut(conversation_id) else {
+            return false;
+        };
+        let queue = entry.value_mut();
+        let Some(position) = queue.blobs.iter().position(|b| b.id == *blob_id) else {
+            return false;
+        };
+
+        let blob = queue.blobs.remove(position);
+        self.schedule
+            .remove_blob(conversation_id, blob.id, blob.expires_at);
+        if queue.blobs.is_empty() {
+            self.schedule_idle(conversation_id, queue);
+        }
+
+        if self.log.detailed() {
+            debug!(blob_id = %blob_id, "Deleted blob on ACK");
+        }
+        true
+    }
+
+    /// Schedule dropping an empty queue once it has been inactive for
+    /// `INACTIVE_TTL_SECS`.
+    fn schedule_idle(&self, conversation_id: &str, queue: &BlobQueue) {
+        let idle_at = chrono::Duration::from_std(self.config.inactive_ttl)
+            .ok()
+            .and_then(|inactive| queue.updated_at.checked_add_signed(inactive));
+        if let Some(idle_at) = idle_at {
+            self.schedule.add_idle(conversation_id, idle_at);
         }
-        false
     }
 
     // =========================================================================
@@ -194,12 +497,21 @@ impl Store {
     // =========================================================================
 
     /// Register a device for push notifications.
+    ///
+    /// The role and device handle keep pushes about a blob off the
+    /// sender's own devices (see [`Store::get_recipient_devices`]).
     pub async fn register_device(
         &self,
         conversation_id: ConversationId,
         device_token: String,
         platform: Platform,
+        options: RegisterOptions,
     ) -> Result<(), StoreError> {
+        let RegisterOptions {
+            role,
+            device_handle,
+        } = options;
+
         if self.is_burned(&conversation_id) {
             return Err(StoreError::ConversationBurned);
         }
@@ -211,6 +523,8 @@ impl Store {
         let registration = DeviceRegistration {
             device_token: device_token.clone(),
             platform,
+            device_handle,
+            role,
             registered_at: now,
             expires_at,
         };
@@ -241,6 +555,21 @@ impl Store {
             .unwrap_or_default()
     }
 
+    /// Get valid device tokens for a conversation, except those of the
+    /// sender of a blob.
+    ///
+    /// Devices registered without a role or handle always receive pushes.
+    pub fn get_recipient_devices(
+        &self,
+        conversation_id: &ConversationId,
+        sender: Option<PartyRole>,
+        sender_device: Option<&str>,
+    ) -> Vec<DeviceRegistration> {
+        let mut devices = self.get_device_tokens(conversation_id);
+        devices.retain(|d| !d.is_sender(sender, sender_device));
+        devices
+    }
+
     // =========================================================================
     // Burn Operations
     // =========================================================================
@@ -248,39 +577,57 @@ impl Store {
     /// Burn a conversation (delete all data, set burn flag).
     pub async fn burn(&self, conversation_id: ConversationId) {
         // Remove all data immediately
-        self.blobs.remove(&conversation_id);
+        if let Some((_, queue)) = self.blobs.remove(&conversation_id) {
+            for blob in &queue.blobs {
+                self.schedule
+                    .remove_blob(&conversation_id, blob.id, blob.expires_at);
+            }
+        }
         self.devices.remove(&conversation_id);
         self.prefs.remove(&conversation_id);
 
-        // Set burn flag with TTL
+        // Set burn flag with TTL, and a tombstone that outlives it
         let now = Utc::now();
         let burn_flag = BurnFlag {
             burned_at: now,
             expires_at: now
                 + chrono::Duration::from_std(self.config.burn_ttl).expect("valid duration"),
         };
-        self.burns.insert(conversation_id, burn_flag);
+        self.burns.insert(conversation_id.clone(), burn_flag);
+        self.tombstones.insert(&conversation_id, now).await;
 
         self.metrics.write().await.total_burns += 1;
         debug!("Burned conversation");
     }
 
     /// Check if a conversation is burned.
-    pub fn is_burned(&self, conversation_id: &ConversationId) -> bool {
-        let now = Utc::now();
-        self.burns
-            .get(conversation_id)
-            .map(|entry| entry.expires_at > now)
-            .unwrap_or(false)
+    ///
+    /// True while the burn flag or the tombstone is live.
+    pub fn is_burned(&self, conversation_id: &str) -> bool {
+        self.get_burn_status(conversation_id).is_some()
     }
 
     /// Get burn status for a conversation.
-    pub fn get_burn_status(&self, conversation_id: &ConversationId) -> Option<BurnFlag> {
+    ///
+    /// Falls back to the tombstone once the burn flag has expired or was
+    /// lost on restart.
+    pub fn get_burn_status(&self, conversation_id: &str) -> Option<BurnFlag> {
         let now = Utc::now();
-        self.burns
+        if let Some(flag) = self
+            .burns
             .get(conversation_id)
             .filter(|entry| entry.expires_at > now)
-            .map(|entry| entry.value().clone())
+        {
+            return Some(flag.value().clone());
+        }
+
+        let burned_at = self.tombstones.burned_at(conversation_id)?;
+        Some(BurnFlag {
+            burned_at,
+            expires_at: burned_at
+                + chrono::Duration::from_std(self.tombstones.retention())
+                    .unwrap_or(chrono::Duration::MAX),
+        })
     }
 
     // =========================================================================
@@ -304,34 +651,81 @@ impl Store {
         self.prefs.get(conversation_id).map(|e| e.value().clone())
     }
 
-    /// Get all conversations with expiry notifications enabled.
-    pub fn get_conversations_with_expiry_notifications(&self) -> Vec<ConversationId> {
-        self.prefs
-            .iter()
-            .filter(|entry| entry.value().notify_message_expiring())
-            .map(|entry| entry.key().clone())
-            .collect()
-    }
-
     // =========================================================================
     // Cleanup
     // =========================================================================
 
-    /// Clean up expired data.
-    async fn cleanup_expired(&self) {
+    /// Flush state that outlives the process (burn tombstones).
+    ///
+    /// Called on shutdown. A no-op when persistence is not configured.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.tombstones.flush()
+    }
+
+    /// Purge blobs whose expiry is due and drop queues that went idle,
+    /// returning the blobs that expired unacknowledged.
+    ///
+    /// Only touches what the schedule reports as due.
+    pub async fn expire_due(&self) -> Vec<ExpiredBlob> {
         let now = Utc::now();
-        let mut expired_blobs = 0u64;
+        let mut expired = Vec::new();
+
+        for due in self.schedule.take_due_expiries(now) {
+            let Some(mut entry) = self.blobs.get_mut(&due.conversation_id) else {
+                continue;
+            };
+            let queue = entry.value_mut();
+            let Some(position) = queue.blobs.iter().position(|b| b.id == due.blob_id) else {
+                continue;
+            };
+
+            let blob = queue.blobs.remove(position);
+            // Drops warnings the worker has not taken yet
+            self.schedule
+                .remove_blob(&due.conversation_id, blob.id, blob.expires_at);
+            if queue.blobs.is_empty() {
+                self.schedule_idle(&due.conversation_id, queue);
+            }
+            drop(entry);
+
+            expired.push(ExpiredBlob {
+                conversation_id: due.conversation_id,
+                blob_id: blob.id,
+                sender: blob.sender,
+                sender_device: blob.sender_device,
+            });
+        }
 
-        // Clean up expired blobs
-        for mut entry in self.blobs.iter_mut() {
-            let before = entry.value().len();
-            entry.value_mut().retain(|b| b.expires_at > now);
-            expired_blobs += (before - entry.value().len()) as u64;
+        // Drop empty queues of conversations that went quiet. Their cursors
+        // and sequences are no longer worth tracking.
+        let inactive =
+            chrono::Duration::from_std(self.config.inactive_ttl).unwrap_or(chrono::Duration::MAX);
+        for conversation_id in self.schedule.take_due_idle(now) {
+            self.blobs.remove_if(&conversation_id, |_, q| {
+                q.blobs.is_empty() && now - q.updated_at >= inactive
+            });
         }
-        self.blobs.retain(|_, v| !v.is_empty());
 
-        // Clean up expired burn flags
+        if !expired.is_empty() {
+            let expired_blobs = expired.len() as u64;
+            self.metrics.write().await.total_blobs_expired += expired_blobs;
+            debug!(expired_blobs, "Purged expired blobs");
+        }
+        expired
+    }
+
+    /// Clean up expired data, returning the blobs that expired
+    /// unacknowledged.
+    ///
+    /// Runs [`Store::expire_due`], then sweeps burn flags, tombstones and
+    /// device registrations.
+    pub async fn cleanup_expired(&self) -> Vec<ExpiredBlob> {
+        let expired = self.expire_due().await;
+        let now = Utc::now();
+
+        // Clean up expired burn flags and tombstones
         self.burns.retain(|_, v| v.expires_at > now);
+        self.tombstones.cleanup();
 
         // Clean up expired device registrations
         for mut entry in self.devices.iter_mut() {
@@ -339,16 +733,49 @@ impl Store {
         }
         self.devices.retain(|_, v| !v.is_empty());
 
-        if expired_blobs > 0 {
-            self.metrics.write().await.total_blobs_expired += expired_blobs;
-            debug!(expired_blobs, "Cleaned up expired data");
-        }
+        expired
     }
 
     /// Get aggregate metrics (no PII).
     pub async fn get_metrics(&self) -> StoreMetrics {
         self.metrics.read().await.clone()
     }
+
+    /// Get current state sizes (no PII).
+    pub fn gauges(&self) -> StoreGauges {
+        StoreGauges {
+            blob_queues: self.blobs.len(),
+            queued_blobs: self.blobs.iter().map(|q| q.blobs.len()).sum(),
+            burn_flags: self.burns.len(),
+            tombstones: self.tombstones.len(),
+            device_registrations: self.devices.iter().map(|d| d.len()).sum(),
+            scheduled_deadlines: self.schedule.len(),
+        }
+    }
+}
+
+/// Sequences after `after` (or from the lowest returned one) up to the
+/// highest of `last` and the returned ones, that are not in `blobs`.
+fn missing_sequences(
+    after: Option<SequenceNumber>,
+    last: Option<SequenceNumber>,
+    blobs: &[StoredBlob],
+) -> Vec<SequenceNumber> {
+    let present: BTreeSet<SequenceNumber> = blobs.iter().filter_map(|b| b.sequence).collect();
+
+    let Some(upper) = last.into_iter().chain(present.last().copied()).max() else {
+        return vec![];
+    };
+    let lower = match (after, present.first()) {
+        (Some(after), _) => after.saturating_add(1),
+        (None, Some(&first)) => first,
+        (None, None) => return vec![],
+    };
+
+    (lower..=upper)
+        .filter(|seq| !present.contains(seq))
+        .take(MAX_MISSING_SEQUENCES)
+        .collect()
 }
 
 // =============================================================================
@@ -367,6 +794,9 @@ pub enum StoreError {
     #[error("message queue full")]
     QueueFull,
 
+    #[error("sequence is not greater than last accepted ({last})")]
+    SequenceNotIncreasing { last: SequenceNumber },
+
     #[error("database error: {0}")]
     DatabaseError(String),
 }
@@ -384,12 +814,29 @@ mod tests {
         Config {
             bind_addr: "127.0.0.1".to_string(),
             port: 8080,
+            tls_cert_path: None,
+            tls_key_path: None,
+            tls_reload_interval: std::time::Duration::from_secs(60),
+            admin_bind_addr: None,
+            admin_key: None,
+            shutdown_grace: std::time::Duration::from_secs(10),
             blob_ttl: std::time::Duration::from_secs(300),
             burn_ttl: std::time::Duration::from_secs(300),
             device_token_ttl: std::time::Duration::from_secs(3600),
             max_ciphertext_size: 8192,
             max_blobs_per_conversation: 50,
+            max_conversations: 100_000,
+            inactive_ttl: std::time::Duration::from_secs(24 * 3600),
+            broadcast_capacity: 1024,
             cleanup_interval: std::time::Duration::from_secs(10),
+            enforce_sequences: false,
+            require_registration_proof: true,
+            tombstone_path: None,
+            tombstone_key_path: None,
+            tombstone_retention: std::time::Duration::from_secs(30 * 24 * 3600),
+            log_mode: crate::log_policy::LogMode::Pseudonymous,
+            log_pseudonym_rotation: std::time::Duration::from_secs(3600),
+            log_activity_interval: std::time::Duration::from_secs(60),
             apns_team_id: None,
             apns_key_id: None,
             apns_key_path: None,
@@ -405,9 +852,17 @@ mod tests {
         let ciphertext = vec![1, 2, 3, 4];
 
         let blob_id = store
-            .store_blob(conv_id.clone(), ciphertext.clone(), Some(1))
+            .submit_blob(
+                conv_id.clone(),
+                ciphertext.clone(),
+                SubmitOptions {
+                    sequence: Some(1),
+                    ..Default::default()
+                },
+            )
             .await
-            .expect("store failed");
+            .expect("store failed")
+            .blob_id;
 
         let (blobs, cursor) = store.get_blobs(&conv_id, None);
         assert_eq!(blobs.len(), 1);
@@ -422,9 +877,10 @@ mod tests {
         let conv_id = "b".repeat(64);
 
         let blob_id = store
-            .store_blob(conv_id.clone(), vec![1], None)
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
             .await
-            .unwrap();
+            .unwrap()
+            .blob_id;
 
         assert!(store.delete_blob(&conv_id, &blob_id).await);
         assert!(!store.delete_blob(&conv_id, &blob_id).await); // Already deleted
@@ -439,7 +895,7 @@ mod tests {
         let conv_id = "c".repeat(64);
 
         store
-            .store_blob(conv_id.clone(), vec![1], None)
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
             .await
             .unwrap();
         store.store_prefs(conv_id.clone(), 0, 300);
@@ -459,15 +915,17 @@ mod tests {
         let conv_id = "d".repeat(64);
 
         store
-            .store_blob(conv_id.clone(), vec![1], None)
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
             .await
             .unwrap();
         store
-            .store_blob(conv_id.clone(), vec![2], None)
+            .submit_blob(conv_id.clone(), vec![2], SubmitOptions::default())
             .await
             .unwrap();
 
-        let result = store.store_blob(conv_id.clone(), vec![3], None).await;
+        let result = store
+            .submit_blob(conv_id.clone(), vec![3], SubmitOptions::default())
+            .await;
         assert!(matches!(result, Err(StoreError::QueueFull)));
     }
 
@@ -478,7 +936,9 @@ mod tests {
         let store = Store::new(config);
         let conv_id = "e".repeat(64);
 
-        let result = store.store_blob(conv_id, vec![0; 100], None).await;
+        let result = store
+            .submit_blob(conv_id, vec![0; 100], SubmitOptions::default())
+            .await;
         assert!(matches!(result, Err(StoreError::PayloadTooLarge)));
     }
 
@@ -489,12 +949,561 @@ mod tests {
 
         store.burn(conv_id.clone()).await;
 
-        let result = store.store_blob(conv_id.clone(), vec![1], None).await;
+        let result = store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await;
         assert!(matches!(result, Err(StoreError::ConversationBurned)));
 
         let result = store
-            .register_device(conv_id, "token".to_string(), Platform::Ios)
+            .register_device(
+                conv_id,
+                "token".to_string(),
+                Platform::Ios,
+                RegisterOptions::default(),
+            )
             .await;
         assert!(matches!(result, Err(StoreError::ConversationBurned)));
     }
+
+    #[tokio::test]
+    async fn tombstone_outlives_burn_flag() {
+        let mut config = test_config();
+        config.burn_ttl = std::time::Duration::ZERO;
+        let store = Store::new(config);
+        let conv_id = "f".repeat(64);
+
+        store.burn(conv_id.clone()).await;
+        store.cleanup_expired().await;
+
+        assert!(store.is_burned(&conv_id));
+        let status = store.get_burn_status(&conv_id).unwrap();
+        assert!(status.expires_at > Utc::now() + chrono::Duration::days(29));
+        assert!(!store.is_burned(&"e".repeat(64)));
+    }
+
+    #[tokio::test]
+    async fn duplicate_sequence_returns_existing_blob() {
+        let store = Store::new(test_config());
+        let conv_id = "1".repeat(64);
+
+        let first = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![1],
+                SubmitOptions {
+                    sequence: Some(7),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let retry = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![1],
+                SubmitOptions {
+                    sequence: Some(7),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!first.duplicate);
+        assert!(retry.duplicate);
+        assert_eq!(retry.blob_id, first.blob_id);
+
+        // Same sequence from the other direction is a different message
+        let other = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![2],
+                SubmitOptions {
+                    sequence: Some(7),
+                    sender: Some(PartyRole::Responder),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!other.duplicate);
+
+        let (blobs, _) = store.get_blobs(&conv_id, None);
+        assert_eq!(blobs.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn enforced_sequences_must_increase_per_sender() {
+        let mut config = test_config();
+        config.enforce_sequences = true;
+        let store = Store::new(config);
+        let conv_id = "2".repeat(64);
+
+        store
+            .submit_blob(
+                conv_id.clone(),
+                vec![1],
+                SubmitOptions {
+                    sequence: Some(5),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let result = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![2],
+                SubmitOptions {
+                    sequence: Some(4),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(StoreError::SequenceNotIncreasing { last: 5 })
+        ));
+
+        // The responder lane is independent
+        store
+            .submit_blob(
+                conv_id.clone(),
+                vec![3],
+                SubmitOptions {
+                    sequence: Some(1),
+                    sender: Some(PartyRole::Responder),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // An acked sequence is still known and cannot be reused
+        let blob_id = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![4],
+                SubmitOptions {
+                    sequence: Some(6),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+            .blob_id;
+        assert!(store.delete_blob(&conv_id, &blob_id).await);
+        let result = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![4],
+                SubmitOptions {
+                    sequence: Some(6),
+                    sender: Some(PartyRole::Initiator),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(StoreError::SequenceNotIncreasing { last: 6 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn unenforced_sequences_accept_reordering() {
+        let store = Store::new(test_config());
+        let conv_id = "3".repeat(64);
+
+        for seq in [3, 1, 2] {
+            store
+                .submit_blob(
+                    conv_id.clone(),
+                    vec![1],
+                    SubmitOptions {
+                        sequence: Some(seq),
+                        sender: Some(PartyRole::Initiator),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        let (blobs, _) = store.get_blobs(&conv_id, None);
+        assert_eq!(blobs.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn poll_after_sequence_reports_gaps() {
+        let store = Store::new(test_config());
+        let conv_id = "4".repeat(64);
+
+        for seq in [1, 2, 4, 6] {
+            store
+                .submit_blob(
+                    conv_id.clone(),
+                    vec![1],
+                    SubmitOptions {
+                        sequence: Some(seq),
+                        sender: Some(PartyRole::Initiator),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+        store
+            .submit_blob(
+                conv_id.clone(),
+                vec![2],
+                SubmitOptions {
+                    sequence: Some(9),
+                    sender: Some(PartyRole::Responder),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let filter = SequenceFilter {
+            sender: Some(PartyRole::Initiator),
+            after: Some(1),
+        };
+        let page = store.poll_blobs(&conv_id, None, filter, None);
+        let sequences: Vec<_> = page.blobs.iter().filter_map(|b| b.sequence).collect();
+        assert_eq!(sequences, vec![2, 4, 6]);
+        assert_eq!(page.missing_sequences, vec![3, 5]);
+
+        // The cursor carries the position within the sender's lane
+        let cursor = page.next_cursor.unwrap();
+        assert_eq!(cursor.last_sequence, Some(6));
+
+        // Acknowledged blobs show up as missing to a poller that asks for them
+        let blob_id = page.blobs[0].id;
+        assert!(store.delete_blob(&conv_id, &blob_id).await);
+        let page = store.poll_blobs(&conv_id, None, filter, None);
+        assert_eq!(page.missing_sequences, vec![2, 3, 5]);
+
+        // Unfiltered polls return every blob and no gap hint
+        let page = store.poll_blobs(&conv_id, None, SequenceFilter::default(), None);
+        assert_eq!(page.blobs.len(), 4);
+        assert!(page.missing_sequences.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cursor_does_not_redeliver_earlier_blobs() {
+        let store = Store::new(test_config());
+        let conv_id = "6".repeat(64);
+
+        for i in 0..3 {
+            store
+                .submit_blob(conv_id.clone(), vec![i], SubmitOptions::default())
+                .await
+                .unwrap();
+        }
+        let (blobs, cursor) = store.get_blobs(&conv_id, None);
+        assert_eq!(blobs.len(), 3);
+
+        // Nothing new after the cursor
+        let (blobs, next) = store.get_blobs(&conv_id, cursor.as_ref());
+        assert!(blobs.is_empty());
+        assert!(next.is_none());
+
+        // Only the new blob after the cursor
+        let blob_id = store
+            .submit_blob(conv_id.clone(), vec![3], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        let (blobs, _) = store.get_blobs(&conv_id, cursor.as_ref());
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].id, blob_id);
+    }
+
+    #[tokio::test]
+    async fn limit_pages_through_queue() {
+        let store = Store::new(test_config());
+        let conv_id = "7".repeat(64);
+
+        for i in 0..5 {
+            store
+                .submit_blob(conv_id.clone(), vec![i], SubmitOptions::default())
+                .await
+                .unwrap();
+        }
+
+        let mut cursor = None;
+        let mut seen = Vec::new();
+        loop {
+            let page = store.poll_blobs(
+                &conv_id,
+                cursor.as_ref(),
+                SequenceFilter::default(),
+                Some(2),
+            );
+            seen.extend(page.blobs.iter().map(|b| b.ciphertext[0]));
+            cursor = page.next_cursor;
+            if !page.has_more {
+                break;
+            }
+        }
+        assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn cleanup_reports_unacked_blobs_with_sender_device() {
+        let mut config = test_config();
+        config.blob_ttl = std::time::Duration::ZERO;
+        let store = Store::new(config);
+        let conv_id = "9".repeat(64);
+        let device = "device-handle-0123456789".to_string();
+
+        let unacked = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![1],
+                SubmitOptions {
+                    sequence: Some(1),
+                    sender: None,
+                    sender_device: Some(device.clone()),
+                },
+            )
+            .await
+            .unwrap()
+            .blob_id;
+        let acked = store
+            .submit_blob(
+                conv_id.clone(),
+                vec![2],
+                SubmitOptions {
+                    sequence: Some(2),
+                    sender: None,
+                    sender_device: Some(device.clone()),
+                },
+            )
+            .await
+            .unwrap()
+            .blob_id;
+        assert!(store.delete_blob(&conv_id, &acked).await);
+
+        let expired = store.cleanup_expired().await;
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].blob_id, unacked);
+        assert_eq!(expired[0].conversation_id, conv_id);
+        assert_eq!(expired[0].sender_device, Some(device));
+        assert!(store.cleanup_expired().await.is_empty());
+        assert_eq!(store.gauges().queued_blobs, 0);
+    }
+
+    #[tokio::test]
+    async fn recipient_devices_exclude_sender() {
+        let store = Store::new(test_config());
+        let conv_id = "5".repeat(64);
+        let handle = "initiator-phone-0123456".to_string();
+
+        for (token, role, device_handle) in [
+            ("initiator", Some(PartyRole::Initiator), None),
+            ("responder", Some(PartyRole::Responder), None),
+            ("handle-only", None, Some(handle.clone())),
+            ("untagged", None, None),
+        ] {
+            store
+                .register_device(
+                    conv_id.clone(),
+                    token.to_string(),
+                    Platform::Ios,
+                    RegisterOptions {
+                        role,
+                        device_handle,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+        let tokens = |sender, sender_device| -> Vec<String> {
+            store
+                .get_recipient_devices(&conv_id, sender, sender_device)
+                .into_iter()
+                .map(|d| d.device_token)
+                .collect()
+        };
+
+        assert_eq!(
+            tokens(Some(PartyRole::Initiator), None),
+            vec!["responder", "handle-only", "untagged"]
+        );
+        assert_eq!(
+            tokens(Some(PartyRole::Responder), Some(handle.as_str())),
+            vec!["initiator", "untagged"]
+        );
+        assert_eq!(tokens(None, None).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn ack_and_burn_unschedule_blobs() {
+        let store = Store::new(test_config());
+        let conv_id = "7".repeat(64);
+
+        let acked = store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        store
+            .submit_blob(conv_id.clone(), vec![2], SubmitOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(store.gauges().scheduled_deadlines, 6);
+
+        // The empty-queue check is only scheduled once the queue is empty
+        assert!(store.delete_blob(&conv_id, &acked).await);
+        assert_eq!(store.gauges().scheduled_deadlines, 3);
+
+        store.burn(conv_id).await;
+        assert_eq!(store.gauges().scheduled_deadlines, 0);
+    }
+
+    #[tokio::test]
+    async fn idle_empty_queue_is_dropped() {
+        let mut config = test_config();
+        config.inactive_ttl = std::time::Duration::ZERO;
+        let store = Store::new(config);
+        let conv_id = "6".repeat(64);
+
+        let blob_id = store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        assert!(store.delete_blob(&conv_id, &blob_id).await);
+        assert_eq!(store.gauges().blob_queues, 1);
+
+        assert!(store.expire_due().await.is_empty());
+        assert_eq!(store.gauges().blob_queues, 0);
+        assert_eq!(store.gauges().scheduled_deadlines, 0);
+    }
+
+    #[tokio::test]
+    async fn recreated_queue_resets_old_cursors() {
+        let mut config = test_config();
+        config.inactive_ttl = std::time::Duration::ZERO;
+        let store = Store::new(config);
+        let conv_id = "6".repeat(64);
+
+        let blob_id = store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        let (_, cursor) = store.get_blobs(&conv_id, None);
+        assert!(store.delete_blob(&conv_id, &blob_id).await);
+        store.expire_due().await;
+        assert_eq!(store.gauges().blob_queues, 0);
+
+        // The new queue starts its indices over; the old cursor must not skip them
+        let blob_id = store
+            .submit_blob(conv_id.clone(), vec![2], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        let page = store.poll_blobs(&conv_id, cursor.as_ref(), SequenceFilter::default(), None);
+        assert!(page.cursor_reset);
+        assert_eq!(page.blobs.len(), 1);
+        assert_eq!(page.blobs[0].id, blob_id);
+    }
+
+    #[tokio::test]
+    async fn insertion_index_survives_empty_queue() {
+        let store = Store::new(test_config());
+        let conv_id = "8".repeat(64);
+
+        let blob_id = store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await
+            .unwrap()
+            .blob_id;
+        let (_, cursor) = store.get_blobs(&conv_id, None);
+        assert!(store.delete_blob(&conv_id, &blob_id).await);
+        store.cleanup_expired().await;
+
+        store
+            .submit_blob(conv_id.clone(), vec![2], SubmitOptions::default())
+            .await
+            .unwrap();
+        let (blobs, _) = store.get_blobs(&conv_id, cursor.as_ref());
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].index, 1);
+    }
+
+    #[tokio::test]
+    async fn stale_cursor_restarts_from_beginning() {
+        let store = Store::new(test_config());
+        let conv_id = "9".repeat(64);
+
+        store
+            .submit_blob(conv_id.clone(), vec![1], SubmitOptions::default())
+            .await
+            .unwrap();
+
+        let (_, cursor) = store.get_blobs(&conv_id, None);
+        let stale = Cursor {
+            position: 10,
+            ..cursor.unwrap()
+        };
+        let page = store.poll_blobs(&conv_id, Some(&stale), SequenceFilter::default(), None);
+        assert!(page.cursor_reset);
+        assert_eq!(page.blobs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn cursors_are_bound_to_store_and_conversation() {
+        let store = Store::new(test_config());
+        let cursor = Cursor {
+            epoch: 1,
+            position: 1,
+            last_sequence: None,
+        };
+        let encoded = store.encode_cursor(&"a".repeat(64), &cursor);
+
+        assert_eq!(store.decode_cursor(&"a".repeat(64), &encoded), Some(cursor));
+        assert!(store.decode_cursor(&"b".repeat(64), &encoded).is_none());
+
+        // A restarted relay has a new key
+        let restarted = Store::new(test_config());
+        assert!(restarted.decode_cursor(&"a".repeat(64), &encoded).is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_sequences_are_capped() {
+        let store = Store::new(test_config());
+        let conv_id = "5".repeat(64);
+
+        store
+            .submit_blob(
+                conv_id.clone(),
+                vec![1],
+                SubmitOptions {
+                    sequence: Some(u64::MAX),
+                    sender: Some(PartyRole::Responder),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let filter = SequenceFilter {
+            sender: Some(PartyRole::Responder),
+            after: Some(0),
+        };
+        let page = store.poll_blobs(&conv_id, None, filter, None);
+        assert_eq!(page.blobs.len(), 1);
+        assert_eq!(page.missing_sequences.len(), MAX_MISSING_SEQUENCES);
+        assert_eq!(page.missing_sequences[0], 1);
+    }
 }
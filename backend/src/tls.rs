@@ -0,0 +1,330 @@
+//! Optional TLS termination with certificate hot reload.
+//!
+//! When `TLS_CERT_PATH` and `TLS_KEY_PATH` are set the binary terminates
+//! TLS itself instead of relying on a reverse proxy. ALPN offers `h2` and
+//! `http/1.1`, so an HTTP/2 client can keep an SSE stream open and submit
+//! messages over the same connection.
+//!
+//! # Hot Reload
+//!
+//! [`CertReloader`] re-reads the certificate and key every
+//! `TLS_RELOAD_INTERVAL_SECS` and swaps them in when their contents
+//! changed. New handshakes use the new certificate; established
+//! connections keep theirs. A pair that fails to load, or whose key does
+//! not match the certificate, is rejected and the previous pair stays in
+//! service, so a renewal that writes the two files one after the other is
+//! picked up once both are in place.
+
+use axum::Router;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use hyper_util::service::TowerToHyperService;
+use ring::digest::{Context, SHA256};
+use rustls::crypto::ring::{default_provider, sign::any_supported_type};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls::ServerConfig;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use tracing::{debug, info, warn};
+
+/// ALPN protocols offered, most preferred first.
+const ALPN_PROTOCOLS: [&[u8]; 2] = [b"h2", b"http/1.1"];
+
+/// Time allowed for a client to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Pause after a failed accept (e.g. file descriptor exhaustion).
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Message signed to check that a key matches its certificate.
+const KEY_CHECK_MESSAGE: &[u8] = b"ash-backend tls key check";
+
+/// TLS setup errors.
+#[derive(Debug, thiserror::Error)]
+pub enum TlsError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("no certificates found in {0}")]
+    NoCertificates(PathBuf),
+
+    #[error("no private key found in {0}")]
+    NoPrivateKey(PathBuf),
+
+    #[error("unsupported private key: {0}")]
+    UnsupportedKey(rustls::Error),
+
+    #[error("private key does not match certificate")]
+    KeyMismatch,
+}
+
+/// Certificate resolver that serves the latest valid cert/key pair.
+#[derive(Debug)]
+pub struct CertReloader {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    /// Pair used for new handshakes.
+    current: RwLock<Arc<CertifiedKey>>,
+    /// SHA-256 over the file contents the current pair was loaded from.
+    fingerprint: Mutex<Vec<u8>>,
+}
+
+impl CertReloader {
+    /// Load the initial certificate chain and key.
+    pub fn new(cert_path: &Path, key_path: &Path) -> Result<Self, TlsError> {
+        let (cert_pem, key_pem) = read_pair(cert_path, key_path)?;
+        let key = load_certified_key(cert_path, &cert_pem, key_path, &key_pem)?;
+
+        Ok(Self {
+            cert_path: cert_path.to_path_buf(),
+            key_path: key_path.to_path_buf(),
+            current: RwLock::new(Arc::new(key)),
+            fingerprint: Mutex::new(fingerprint(&cert_pem, &key_pem)),
+        })
+    }
+
+    /// Reload the pair if either file changed.
+    ///
+    /// Returns `Ok(true)` if a new pair was installed. On error the
+    /// previous pair stays in service and the files are retried next time.
+    pub fn reload_if_changed(&self) -> Result<bool, TlsError> {
+        let (cert_pem, key_pem) = read_pair(&self.cert_path, &self.key_path)?;
+        let new_fingerprint = fingerprint(&cert_pem, &key_pem);
+        if *self.fingerprint.lock().expect("fingerprint lock poisoned") == new_fingerprint {
+            return Ok(false);
+        }
+
+        let key = load_certified_key(&self.cert_path, &cert_pem, &self.key_path, &key_pem)?;
+        *self.current.write().expect("certificate lock poisoned") = Arc::new(key);
+        *self.fingerprint.lock().expect("fingerprint lock poisoned") = new_fingerprint;
+        Ok(true)
+    }
+
+    /// Start a background task that reloads the pair every `interval`.
+    pub fn start_watcher(self: Arc<Self>, interval: Duration) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                match self.reload_if_changed() {
+                    Ok(true) => info!("TLS certificate reloaded"),
+                    Ok(false) => {}
+                    Err(e) => warn!(error = %e, "TLS certificate reload failed, keeping previous"),
+                }
+            }
+        });
+    }
+}
+
+impl ResolvesServerCert for CertReloader {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(
+            self.current
+                .read()
+                .expect("certificate lock poisoned")
+                .clone(),
+        )
+    }
+}
+
+/// Build a rustls server config that resolves certificates through
+/// `resolver` and negotiates HTTP/2 or HTTP/1.1.
+pub fn server_config(resolver: Arc<CertReloader>) -> Arc<ServerConfig> {
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_cert_resolver(resolver);
+    config.alpn_protocols = ALPN_PROTOCOLS.iter().map(|p| p.to_vec()).collect();
+    Arc::new(config)
+}
+
+/// Accept TLS connections on `listener` and serve `app` on each.
+///
+/// Handshake failures only drop the affected connection. When `shutdown`
+/// is cancelled the listener is closed, open connections are shut down
+/// gracefully (HTTP/2 `GOAWAY`, HTTP/1.1 close after the current response)
+/// and this returns once they have all finished.
+pub async fn serve(
+    listener: TcpListener,
+    app: Router,
+    acceptor: TlsAcceptor,
+    shutdown: CancellationToken,
+) {
+    let connections = TaskTracker::new();
+    loop {
+        let accepted = tokio::select! {
+            () = shutdown.cancelled() => break,
+            accepted = listener.accept() => accepted,
+        };
+        let tcp = match accepted {
+            Ok((tcp, _peer)) => tcp,
+            Err(e) => {
+                warn!(error = %e, "Failed to accept connection");
+                tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let app = app.clone();
+        let shutdown = shutdown.clone();
+        connections.spawn(async move {
+            let stream = match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(tcp)).await {
+                Ok(Ok(stream)) => stream,
+                Ok(Err(e)) => {
+                    debug!(error = %e, "TLS handshake failed");
+                    return;
+                }
+                Err(_) => {
+                    debug!("TLS handshake timed out");
+                    return;
+                }
+            };
+
+            let builder = auto::Builder::new(TokioExecutor::new());
+            let service = TowerToHyperService::new(app);
+            let connection = builder.serve_connection_with_upgrades(TokioIo::new(stream), service);
+            tokio::pin!(connection);
+
+            let result = tokio::select! {
+                result = connection.as_mut() => result,
+                () = shutdown.cancelled() => {
+                    connection.as_mut().graceful_shutdown();
+                    connection.await
+                }
+            };
+            if let Err(e) = result {
+                debug!(error = %e, "Connection closed with error");
+            }
+        });
+    }
+
+    drop(listener);
+    connections.close();
+    connections.wait().await;
+}
+
+/// Read both PEM files.
+fn read_pair(cert_path: &Path, key_path: &Path) -> Result<(Vec<u8>, Vec<u8>), TlsError> {
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|source| TlsError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    Ok((read(cert_path)?, read(key_path)?))
+}
+
+/// Fingerprint of the file contents, to detect changes.
+fn fingerprint(cert_pem: &[u8], key_pem: &[u8]) -> Vec<u8> {
+    let mut context = Context::new(&SHA256);
+    context.update(&(cert_pem.len() as u64).to_be_bytes());
+    context.update(cert_pem);
+    context.update(key_pem);
+    context.finish().as_ref().to_vec()
+}
+
+/// Parse a PEM certificate chain and private key into a checked pair.
+fn load_certified_key(
+    cert_path: &Path,
+    cert_pem: &[u8],
+    key_path: &Path,
+    key_pem: &[u8],
+) -> Result<CertifiedKey, TlsError> {
+    let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut &cert_pem[..])
+        .collect::<Result<_, _>>()
+        .map_err(|source| TlsError::Io {
+            path: cert_path.to_path_buf(),
+            source,
+        })?;
+    if certs.is_empty() {
+        return Err(TlsError::NoCertificates(cert_path.to_path_buf()));
+    }
+
+    let key: PrivateKeyDer<'static> = rustls_pemfile::private_key(&mut &key_pem[..])
+        .map_err(|source| TlsError::Io {
+            path: key_path.to_path_buf(),
+            source,
+        })?
+        .ok_or_else(|| TlsError::NoPrivateKey(key_path.to_path_buf()))?;
+    let key = any_supported_type(&key).map_err(TlsError::UnsupportedKey)?;
+
+    let certified = CertifiedKey::new(certs, key);
+    check_key_matches(&certified)?;
+    Ok(certified)
+}
+
+/// Check that the private key belongs to the end-entity certificate by
+/// signing a fixed message and verifying it against the certificate.
+fn check_key_matches(certified: &CertifiedKey) -> Result<(), TlsError> {
+    let algorithms = default_provider().signature_verification_algorithms;
+    let schemes: Vec<_> = algorithms
+        .mapping
+        .iter()
+        .map(|(scheme, _)| *scheme)
+        .collect();
+
+    let signer = certified
+        .key
+        .choose_scheme(&schemes)
+        .ok_or(TlsError::KeyMismatch)?;
+    let signature = signer
+        .sign(KEY_CHECK_MESSAGE)
+        .map_err(|_| TlsError::KeyMismatch)?;
+
+    let cert = certified
+        .end_entity_cert()
+        .map_err(|_| TlsError::KeyMismatch)?;
+    let cert = webpki::EndEntityCert::try_from(cert).map_err(|_| TlsError::KeyMismatch)?;
+
+    let verified = algorithms
+        .mapping
+        .iter()
+        .filter(|(scheme, _)| *scheme == signer.scheme())
+        .flat_map(|(_, algs)| algs.iter())
+        .any(|alg| {
+            cert.verify_signature(*alg, KEY_CHECK_MESSAGE, &signature)
+                .is_ok()
+        });
+
+    if verified {
+        Ok(())
+    } else {
+        Err(TlsError::KeyMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_files_are_reported() {
+        let missing = Path::new("/nonexistent/ash-backend/cert.pem");
+        let result = CertReloader::new(missing, missing);
+        assert!(matches!(result, Err(TlsError::Io { .. })));
+    }
+
+    #[test]
+    fn pem_without_certificates_is_rejected() {
+        let path = Path::new("cert.pem");
+        let result = load_certified_key(path, b"not pem", path, b"not pem");
+        assert!(matches!(result, Err(TlsError::NoCertificates(_))));
+    }
+
+    #[test]
+    fn fingerprint_separates_files() {
+        assert_ne!(fingerprint(b"ab", b"c"), fingerprint(b"a", b"bc"));
+        assert_eq!(fingerprint(b"ab", b"c"), fingerprint(b"ab", b"c"));
+    }
+}
@@ -0,0 +1,501 @@
+//! Durable burn tombstones.
+//!
+//! Burn flags expire after `burn_ttl` and live only in RAM, so an offline
+//! peer could miss a burn entirely. Tombstones remember burned
+//! conversations for a much longer retention and, when a file is
+//! configured, across restarts.
+//!
+//! # Privacy
+//!
+//! Conversation IDs are never stored. Each tombstone is
+//! HMAC-SHA256(key, conversation_id), so the file alone does not reveal
+//! which conversations were burned. The key is kept in a separate file
+//! (`TOMBSTONE_KEY_PATH`) that can live outside backups of the data.
+//!
+//! # File Format
+//!
+//! ```text
+//! "ASHTOMB1" || record*
+//! record = keyed_hash (32 bytes) || burned_at (i64 LE, unix seconds)
+//! ```
+//!
+//! Records are appended on burn and synced before the burn returns, on a
+//! blocking thread so the fsync does not stall the async runtime. Expired
+//! records are dropped by compaction, which rewrites the file atomically.
+//! A truncated trailing record (crash mid-append) is ignored on load.
+//! [`Tombstones::flush`] rewrites the file from memory on shutdown, which
+//! also persists burns whose append failed.
+
+use crate::config::Config;
+use chrono::{DateTime, TimeZone, Utc};
+use dashmap::DashMap;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{error, info};
+
+/// Magic bytes at the start of a tombstone file.
+const MAGIC: &[u8; 8] = b"ASHTOMB1";
+
+/// Size of a keyed conversation hash.
+const HASH_LEN: usize = 32;
+
+/// Size of one record in the file.
+const RECORD_LEN: usize = HASH_LEN + 8;
+
+/// Size of the tombstone key.
+const KEY_LEN: usize = 32;
+
+/// Keyed hash identifying a burned conversation.
+type TombstoneHash = [u8; HASH_LEN];
+
+/// Set of burned conversations, optionally backed by a file.
+pub struct Tombstones {
+    /// Key for hashing conversation IDs.
+    key: hmac::Key,
+    /// Burn time (unix seconds) per keyed hash.
+    entries: DashMap<TombstoneHash, i64>,
+    /// How long a tombstone is kept.
+    retention: Duration,
+    /// Backing file, if durable. Shared with the blocking append task.
+    file: Option<Arc<TombstoneFile>>,
+}
+
+/// Append handle and path of the backing file.
+struct TombstoneFile {
+    path: PathBuf,
+    handle: Mutex<File>,
+}
+
+impl Tombstones {
+    /// Create a RAM-only set with a random key (lost on restart).
+    pub fn in_memory(retention: Duration) -> Self {
+        let mut key = [0u8; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut key)
+            .expect("system randomness available");
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, &key),
+            entries: DashMap::new(),
+            retention,
+            file: None,
+        }
+    }
+
+    /// Open the set described by the configuration.
+    ///
+    /// RAM-only unless `tombstone_path` is set.
+    pub fn from_config(config: &Config) -> io::Result<Self> {
+        match &config.tombstone_path {
+            Some(path) => {
+                let key_path = config
+                    .tombstone_key_path
+                    .clone()
+                    .unwrap_or_else(|| path.with_extension("key"));
+                Self::open(path, &key_path, config.tombstone_retention)
+            }
+            None => Ok(Self::in_memory(config.tombstone_retention)),
+        }
+    }
+
+    /// Open (or create) a durable set at `path`, keyed by the key at `key_path`.
+    ///
+    /// A missing key file is created with a fresh random key. Expired
+    /// records are compacted away on open.
+    pub fn open(path: &Path, key_path: &Path, retention: Duration) -> io::Result<Self> {
+        let key = load_or_create_key(key_path)?;
+        let entries = DashMap::new();
+
+        let mut loaded = 0usize;
+        if path.exists() {
+            let mut bytes = Vec::new();
+            File::open(path)?.read_to_end(&mut bytes)?;
+            if !bytes.starts_with(MAGIC) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "not a tombstone file",
+                ));
+            }
+            for record in bytes[MAGIC.len()..].chunks_exact(RECORD_LEN) {
+                let mut hash = [0u8; HASH_LEN];
+                hash.copy_from_slice(&record[..HASH_LEN]);
+                let burned_at =
+                    i64::from_le_bytes(record[HASH_LEN..].try_into().expect("8-byte field"));
+                entries.insert(hash, burned_at);
+                loaded += 1;
+            }
+        }
+
+        let tombstones = Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, &key),
+            entries,
+            retention,
+            file: None,
+        };
+        tombstones.prune();
+
+        // Rewrite so the file starts compact and well-formed
+        let handle = write_compacted(path, &tombstones.entries)?;
+        info!(
+            loaded,
+            active = tombstones.entries.len(),
+            "Loaded burn tombstones"
+        );
+
+        Ok(Self {
+            file: Some(Arc::new(TombstoneFile {
+                path: path.to_path_buf(),
+                handle: Mutex::new(handle),
+            })),
+            ..tombstones
+        })
+    }
+
+    /// Record a burn.
+    ///
+    /// The in-memory set is updated before the first await, so the burn is
+    /// visible at once. The file append and sync then run on a blocking
+    /// thread. A failed append is logged and the tombstone then only lasts
+    /// until restart (or the next [`Tombstones::flush`]).
+    pub async fn insert(&self, conversation_id: &str, burned_at: DateTime<Utc>) {
+        let hash = self.hash(conversation_id);
+        let burned_at = burned_at.timestamp();
+        self.entries.insert(hash, burned_at);
+
+        if let Some(file) = &self.file {
+            let mut record = [0u8; RECORD_LEN];
+            record[..HASH_LEN].copy_from_slice(&hash);
+            record[HASH_LEN..].copy_from_slice(&burned_at.to_le_bytes());
+
+            let file = Arc::clone(file);
+            let appended = tokio::task::spawn_blocking(move || file.append(&record)).await;
+            if let Err(e) = appended.unwrap_or_else(|e| Err(io::Error::other(e))) {
+                error!(error = %e, "Failed to persist burn tombstone");
+            }
+        }
+    }
+
+    /// When the conversation was burned, if a live tombstone exists.
+    pub fn burned_at(&self, conversation_id: &str) -> Option<DateTime<Utc>> {
+        let burned_at = *self.entries.get(&self.hash(conversation_id))?;
+        (burned_at > self.cutoff())
+            .then(|| Utc.timestamp_opt(burned_at, 0).single().unwrap_or_default())
+    }
+
+    /// Whether a live tombstone exists for the conversation.
+    pub fn contains(&self, conversation_id: &str) -> bool {
+        self.burned_at(conversation_id).is_some()
+    }
+
+    /// How long a tombstone is kept.
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    /// Number of tombstones held, including expired ones not yet pruned.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there are no tombstones.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop expired tombstones and compact the file if any were dropped.
+    pub fn cleanup(&self) {
+        if self.prune() == 0 {
+            return;
+        }
+        if let Some(file) = &self.file {
+            let mut handle = file.handle.lock().expect("tombstone file lock poisoned");
+            match write_compacted(&file.path, &self.entries) {
+                Ok(compacted) => *handle = compacted,
+                Err(e) => error!(error = %e, "Failed to compact burn tombstones"),
+            }
+        }
+    }
+
+    /// Rewrite the file from the in-memory set.
+    ///
+    /// Called on shutdown so that burns whose append failed are not lost.
+    /// A no-op without a file.
+    pub fn flush(&self) -> io::Result<()> {
+        if let Some(file) = &self.file {
+            self.prune();
+            let mut handle = file.handle.lock().expect("tombstone file lock poisoned");
+            *handle = write_compacted(&file.path, &self.entries)?;
+        }
+        Ok(())
+    }
+
+    /// Remove expired entries from memory, returning how many were removed.
+    fn prune(&self) -> usize {
+        let cutoff = self.cutoff();
+        let before = self.entries.len();
+        self.entries.retain(|_, burned_at| *burned_at > cutoff);
+        before - self.entries.len()
+    }
+
+    /// Burn times at or before this are expired.
+    fn cutoff(&self) -> i64 {
+        let retention = i64::try_from(self.retention.as_secs()).unwrap_or(i64::MAX);
+        Utc::now().timestamp().saturating_sub(retention)
+    }
+
+    fn hash(&self, conversation_id: &str) -> TombstoneHash {
+        let tag = hmac::sign(&self.key, conversation_id.as_bytes());
+        let mut hash = [0u8; HASH_LEN];
+        hash.copy_from_slice(tag.as_ref());
+        hash
+    }
+}
+
+impl TombstoneFile {
+    /// Append one record and sync it to disk. Blocking.
+    fn append(&self, record: &[u8; RECORD_LEN]) -> io::Result<()> {
+        let mut handle = self.handle.lock().expect("tombstone file lock poisoned");
+        handle.write_all(record)?;
+        handle.sync_data()
+    }
+}
+
+/// Read the key at `path`, creating it with a random key if missing.
+fn load_or_create_key(path: &Path) -> io::Result<[u8; KEY_LEN]> {
+    let mut key = [0u8; KEY_LEN];
+    match File::open(path) {
+        Ok(mut file) => {
+            file.read_exact(&mut key)?;
+            Ok(key)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            SystemRandom::new()
+                .fill(&mut key)
+                .map_err(|_| io::Error::other("system randomness unavailable"))?;
+            let mut file = create_private(path)?;
+            file.write_all(&key)?;
+            file.sync_all()?;
+            Ok(key)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Create a new file readable only by the owner.
+fn create_private(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+/// Atomically replace `path` with the given entries and return an append
+/// handle to the new file.
+fn write_compacted(path: &Path, entries: &DashMap<TombstoneHash, i64>) -> io::Result<File> {
+    let tmp = path.with_extension("tmp");
+    let _ = fs::remove_file(&tmp);
+    {
+        let mut file = create_private(&tmp)?;
+        let mut bytes = Vec::with_capacity(MAGIC.len() + entries.len() * RECORD_LEN);
+        bytes.extend_from_slice(MAGIC);
+        for entry in entries {
+            bytes.extend_from_slice(entry.key());
+            bytes.extend_from_slice(&entry.value().to_le_bytes());
+        }
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+    OpenOptions::new().append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RETENTION: Duration = Duration::from_secs(30 * 24 * 3600);
+
+    /// Fresh paths under the system temp dir, removed on drop.
+    struct TempPaths {
+        dir: PathBuf,
+    }
+
+    impl TempPaths {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "ash-tombstones-{name}-{}-{}",
+                std::process::id(),
+                Utc::now().timestamp_nanos_opt().unwrap_or_default()
+            ));
+            fs::create_dir_all(&dir).unwrap();
+            Self { dir }
+        }
+
+        fn data(&self) -> PathBuf {
+            self.dir.join("tombstones")
+        }
+
+        fn key(&self) -> PathBuf {
+            self.dir.join("tombstones.key")
+        }
+    }
+
+    impl Drop for TempPaths {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[tokio::test]
+    async fn insert_and_contains() {
+        let tombstones = Tombstones::in_memory(RETENTION);
+        let conv_id = "a".repeat(64);
+
+        assert!(!tombstones.contains(&conv_id));
+        tombstones.insert(&conv_id, Utc::now()).await;
+        assert!(tombstones.contains(&conv_id));
+        assert!(!tombstones.contains(&"b".repeat(64)));
+    }
+
+    #[tokio::test]
+    async fn expired_tombstones_are_ignored_and_pruned() {
+        let tombstones = Tombstones::in_memory(Duration::from_secs(60));
+        let conv_id = "a".repeat(64);
+
+        tombstones
+            .insert(&conv_id, Utc::now() - chrono::Duration::seconds(61))
+            .await;
+        assert!(!tombstones.contains(&conv_id));
+
+        tombstones.cleanup();
+        assert!(tombstones.is_empty());
+    }
+
+    #[tokio::test]
+    async fn survives_reopen() {
+        let paths = TempPaths::new("reopen");
+        let conv_id = "a".repeat(64);
+        let burned_at = Utc::now();
+
+        {
+            let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+            tombstones.insert(&conv_id, burned_at).await;
+        }
+
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+        assert_eq!(
+            tombstones.burned_at(&conv_id).map(|t| t.timestamp()),
+            Some(burned_at.timestamp())
+        );
+        assert!(!tombstones.contains(&"b".repeat(64)));
+    }
+
+    #[tokio::test]
+    async fn burn_is_visible_before_file_sync() {
+        use futures::FutureExt;
+
+        let paths = TempPaths::new("visible");
+        let conv_id = "a".repeat(64);
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+
+        // The first poll updates memory and hands the append to a blocking thread
+        let _ = tombstones.insert(&conv_id, Utc::now()).now_or_never();
+        assert!(tombstones.contains(&conv_id));
+    }
+
+    #[tokio::test]
+    async fn flush_persists_burns_missing_from_file() {
+        let paths = TempPaths::new("flush");
+        let conv_id = "a".repeat(64);
+
+        {
+            let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+            tombstones.insert(&conv_id, Utc::now()).await;
+            // Simulate a lost append: only the header is on disk
+            fs::write(paths.data(), MAGIC).unwrap();
+            tombstones.flush().unwrap();
+        }
+
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+        assert!(tombstones.contains(&conv_id));
+    }
+
+    #[tokio::test]
+    async fn file_does_not_contain_conversation_ids() {
+        let paths = TempPaths::new("keyed");
+        let conv_id = "ab".repeat(32);
+
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+        tombstones.insert(&conv_id, Utc::now()).await;
+
+        let bytes = fs::read(paths.data()).unwrap();
+        assert_eq!(bytes.len(), MAGIC.len() + RECORD_LEN);
+        let raw_id = hex::decode(&conv_id).unwrap();
+        assert!(!bytes.windows(raw_id.len()).any(|w| w == raw_id.as_slice()));
+        assert!(!bytes
+            .windows(conv_id.len())
+            .any(|w| w == conv_id.as_bytes()));
+    }
+
+    #[tokio::test]
+    async fn different_key_does_not_match() {
+        let paths = TempPaths::new("rekey");
+        let conv_id = "a".repeat(64);
+
+        Tombstones::open(&paths.data(), &paths.key(), RETENTION)
+            .unwrap()
+            .insert(&conv_id, Utc::now())
+            .await;
+        fs::remove_file(paths.key()).unwrap();
+
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+        assert!(!tombstones.contains(&conv_id));
+    }
+
+    #[tokio::test]
+    async fn reopen_compacts_expired_and_truncated_records() {
+        let paths = TempPaths::new("compact");
+        let old = "a".repeat(64);
+        let live = "b".repeat(64);
+
+        {
+            let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+            tombstones
+                .insert(&old, Utc::now() - chrono::Duration::days(31))
+                .await;
+            tombstones.insert(&live, Utc::now()).await;
+        }
+        // Simulate a crash in the middle of an append
+        let mut file = OpenOptions::new().append(true).open(paths.data()).unwrap();
+        file.write_all(&[0xFF; RECORD_LEN / 2]).unwrap();
+        drop(file);
+
+        let tombstones = Tombstones::open(&paths.data(), &paths.key(), RETENTION).unwrap();
+        assert_eq!(tombstones.len(), 1);
+        assert!(tombstones.contains(&live));
+        assert_eq!(
+            fs::metadata(paths.data()).unwrap().len(),
+            (MAGIC.len() + RECORD_LEN) as u64
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_file() {
+        let paths = TempPaths::new("foreign");
+        fs::write(paths.data(), b"not tombstones").unwrap();
+
+        let result = Tombstones::open(&paths.data(), &paths.key(), RETENTION);
+        assert_eq!(
+            result.err().map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+    }
+}
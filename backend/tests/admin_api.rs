@@ -0,0 +1,294 @@
+//! Integration tests for the operator API.
+
+use ash_backend::{
+    admin::{self, AdminState},
+    apns, auth, build_router,
+    config::Config,
+    handlers::AppState,
+    store::Store,
+};
+use ash_core::{Pad, PadSize};
+use axum::http::{header, StatusCode};
+use axum_test::TestServer;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing_subscriber::{prelude::*, reload, EnvFilter};
+
+const ADMIN_KEY: &str = "test-admin-key-0123456789abcdef-0123456789";
+
+/// Public and admin servers sharing one app state.
+struct Servers {
+    public: TestServer,
+    admin: TestServer,
+}
+
+async fn build_servers(log_filter: Option<admin::LogFilterHandle>) -> Servers {
+    let config = Config {
+        admin_key: Some(ADMIN_KEY.to_string()),
+        apns_key_id: Some("APNS-KEY-ID-SECRET".to_string()),
+        ..Config::default()
+    };
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+
+    Servers {
+        public: TestServer::new(build_router(state.clone())).unwrap(),
+        admin: TestServer::new(admin::build_admin_router(AdminState::new(
+            state, ADMIN_KEY, log_filter,
+        )))
+        .unwrap(),
+    }
+}
+
+fn admin_auth() -> String {
+    format!("Bearer {ADMIN_KEY}")
+}
+
+/// Register a conversation and submit one message, returning the
+/// conversation ID and the ciphertext sent.
+async fn seed_conversation(server: &TestServer) -> (String, String) {
+    let entropy: Vec<u8> = (0u8..=255)
+        .cycle()
+        .take(PadSize::Small.bytes())
+        .map(|b| b.wrapping_mul(13))
+        .collect();
+    let pad = Pad::new(&entropy, PadSize::Small).unwrap();
+    let (_, auth_token, burn_token) = ash_core::auth::derive_all_tokens(pad.as_bytes()).unwrap();
+    let conversation_id =
+        ash_core::auth::derive_registered_conversation_id(pad.as_bytes()).unwrap();
+    let auth_token_hash = auth::hash_token(&auth_token);
+    let burn_token_hash = auth::hash_token(&burn_token);
+    let registration_proof = ash_core::auth::derive_registration_proof(pad.as_bytes()).unwrap();
+
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": conversation_id,
+            "auth_token_hash": auth_token_hash,
+            "burn_token_hash": burn_token_hash,
+            "registration_proof": registration_proof
+        }))
+        .await
+        .assert_status_ok();
+
+    let ciphertext = "c2VjcmV0LWNpcGhlcnRleHQ=".to_string();
+    server
+        .post("/v1/messages")
+        .add_header(header::AUTHORIZATION, format!("Bearer {auth_token}"))
+        .json(&json!({
+            "conversation_id": conversation_id,
+            "ciphertext": ciphertext,
+            "sequence": 1
+        }))
+        .await
+        .assert_status_ok();
+
+    (conversation_id, ciphertext)
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+#[tokio::test]
+async fn admin_requires_key() {
+    let servers = build_servers(None).await;
+
+    let response = servers.admin.get("/admin/metrics").await;
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    assert_eq!(response.json::<Value>()["code"], "MISSING_AUTH");
+
+    let response = servers
+        .admin
+        .get("/admin/metrics")
+        .add_header(header::AUTHORIZATION, "Bearer wrong-key")
+        .await;
+    response.assert_status(StatusCode::UNAUTHORIZED);
+    assert_eq!(response.json::<Value>()["code"], "UNAUTHORIZED");
+
+    servers
+        .admin
+        .post("/admin/cleanup")
+        .add_header(header::AUTHORIZATION, "Bearer wrong-key")
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn admin_routes_are_not_on_public_router() {
+    let servers = build_servers(None).await;
+
+    servers
+        .public
+        .get("/admin/metrics")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .assert_status(StatusCode::NOT_FOUND);
+}
+
+// =============================================================================
+// Metrics and Capacity
+// =============================================================================
+
+#[tokio::test]
+async fn metrics_are_aggregate_only() {
+    let servers = build_servers(None).await;
+    let (conversation_id, ciphertext) = seed_conversation(&servers.public).await;
+
+    let response = servers
+        .admin
+        .get("/admin/metrics")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await;
+    response.assert_status_ok();
+    let text = response.text();
+    let body: Value = response.json();
+
+    assert_eq!(body["registered_conversations"], 1);
+    assert_eq!(body["totals"]["total_blobs_stored"], 1);
+    assert_eq!(body["state"]["blob_queues"], 1);
+    assert_eq!(body["state"]["queued_blobs"], 1);
+    assert_eq!(body["pending_pushes"], 0);
+
+    assert!(!text.contains(&conversation_id));
+    assert!(!text.contains(&conversation_id[..8]));
+    assert!(!text.contains(&ciphertext));
+}
+
+#[tokio::test]
+async fn capacity_reports_registered_conversations() {
+    let servers = build_servers(None).await;
+    seed_conversation(&servers.public).await;
+
+    let body: Value = servers
+        .admin
+        .get("/admin/capacity")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .json();
+
+    assert_eq!(body["registered_conversations"], 1);
+    assert_eq!(body["max_conversations"], auth::MAX_CONVERSATIONS);
+    assert_eq!(body["at_capacity"], false);
+    assert!(body["utilization"].as_f64().unwrap() > 0.0);
+}
+
+// =============================================================================
+// Configuration, Cleanup and APNS
+// =============================================================================
+
+#[tokio::test]
+async fn config_dump_omits_secrets() {
+    let servers = build_servers(None).await;
+
+    let response = servers
+        .admin
+        .get("/admin/config")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await;
+    response.assert_status_ok();
+    let text = response.text();
+    let body: Value = response.json();
+
+    assert_eq!(body["max_conversations"], auth::MAX_CONVERSATIONS);
+    assert_eq!(body["log_mode"], "pseudonymous");
+    assert!(!text.contains(ADMIN_KEY));
+    assert!(!text.contains("APNS-KEY-ID-SECRET"));
+}
+
+#[tokio::test]
+async fn forced_cleanup_runs() {
+    let servers = build_servers(None).await;
+    seed_conversation(&servers.public).await;
+
+    let response = servers
+        .admin
+        .post("/admin/cleanup")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await;
+    response.assert_status_ok();
+    let body: Value = response.json();
+
+    // Nothing has expired yet
+    assert_eq!(body["expired_blobs"], 0);
+    assert_eq!(body["evicted_conversations"], 0);
+}
+
+#[tokio::test]
+async fn apns_status_reports_disabled_client() {
+    let servers = build_servers(None).await;
+
+    let body: Value = servers
+        .admin
+        .get("/admin/apns")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .json();
+
+    assert_eq!(body["configured"], false);
+    assert_eq!(body["enabled"], false);
+    assert_eq!(body["pending_pushes"], 0);
+}
+
+// =============================================================================
+// Log Level
+// =============================================================================
+
+#[tokio::test]
+async fn log_level_can_be_changed_at_runtime() {
+    let (filter, handle) = reload::Layer::new(EnvFilter::new("ash_backend=info"));
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(filter));
+    let servers = build_servers(Some(handle)).await;
+
+    let body: Value = servers
+        .admin
+        .get("/admin/log-level")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .json();
+    assert_eq!(body["filter"], "ash_backend=info");
+
+    servers
+        .admin
+        .put("/admin/log-level")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .json(&json!({ "filter": "ash_backend=warn" }))
+        .await
+        .assert_status_ok();
+
+    let body: Value = servers
+        .admin
+        .get("/admin/log-level")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .json();
+    assert_eq!(body["filter"], "ash_backend=warn");
+}
+
+#[tokio::test]
+async fn invalid_log_filter_is_rejected() {
+    let (filter, handle) = reload::Layer::new(EnvFilter::new("info"));
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(filter));
+    let servers = build_servers(Some(handle)).await;
+
+    let response = servers
+        .admin
+        .put("/admin/log-level")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .json(&json!({ "filter": "ash_backend=loud" }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    assert_eq!(response.json::<Value>()["code"], "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn log_level_without_handle_is_unavailable() {
+    let servers = build_servers(None).await;
+
+    servers
+        .admin
+        .get("/admin/log-level")
+        .add_header(header::AUTHORIZATION, admin_auth())
+        .await
+        .assert_status(StatusCode::SERVICE_UNAVAILABLE);
+}
@@ -3,20 +3,30 @@
 //! Tests the full HTTP API including authentication, message flow, and burn operations.
 //! Uses ash-core for cryptographic operations and token derivation.
 
-use ash_backend::{apns, auth, build_router, config::Config, handlers::AppState, store::Store};
+use ash_backend::{
+    apns, auth, build_router,
+    config::Config,
+    expiry, handlers,
+    handlers::AppState,
+    models::{Platform, StreamEvent},
+    store::{RegisterOptions, Store, SubmitOptions},
+};
 use ash_core::{self, Pad, PadSize};
 use axum::http::{header, StatusCode};
 use axum_test::TestServer;
 use serde_json::{json, Value};
+use std::future::IntoFuture;
 use std::sync::Arc;
 
 /// Test credentials derived from a pad
 struct TestCredentials {
     conversation_id: String,
+    legacy_conversation_id: String,
     auth_token: String,
     burn_token: String,
     auth_token_hash: String,
     burn_token_hash: String,
+    registration_proof: String,
 }
 
 /// Generate test credentials using core's token derivation
@@ -27,19 +37,30 @@ fn generate_test_credentials() -> TestCredentials {
         .cycle()
         .take(PadSize::Small.bytes())
         .enumerate()
-        .map(|(i, b)| b.wrapping_add((i / 256) as u8))
+        .map(|(i, b)| b.wrapping_add(u8::try_from(i / 256).unwrap()))
         .collect();
 
     let pad = Pad::new(&entropy, PadSize::Small).unwrap();
-    let (conversation_id, auth_token, burn_token) =
-        ash_core::auth::derive_all_tokens(pad.as_bytes()).unwrap();
+    credentials_from_pad(pad.as_bytes())
+}
+
+/// Derive tokens, token hashes and registration proof from pad bytes
+fn credentials_from_pad(pad_bytes: &[u8]) -> TestCredentials {
+    let (legacy_conversation_id, auth_token, burn_token) =
+        ash_core::auth::derive_all_tokens(pad_bytes).unwrap();
+    let conversation_id = ash_core::auth::derive_registered_conversation_id(pad_bytes).unwrap();
+    let auth_token_hash = auth::hash_token(&auth_token);
+    let burn_token_hash = auth::hash_token(&burn_token);
+    let registration_proof = ash_core::auth::derive_registration_proof(pad_bytes).unwrap();
 
     TestCredentials {
-        auth_token_hash: auth::hash_token(&auth_token),
-        burn_token_hash: auth::hash_token(&burn_token),
         conversation_id,
+        legacy_conversation_id,
         auth_token,
         burn_token,
+        auth_token_hash,
+        burn_token_hash,
+        registration_proof,
     }
 }
 
@@ -49,25 +70,23 @@ fn generate_test_credentials_with_seed(seed: u8) -> TestCredentials {
         .cycle()
         .take(PadSize::Small.bytes())
         .enumerate()
-        .map(|(i, b)| b.wrapping_add(seed).wrapping_add((i / 256) as u8))
+        .map(|(i, b)| {
+            b.wrapping_add(seed)
+                .wrapping_add(u8::try_from(i / 256).unwrap())
+        })
         .collect();
 
     let pad = Pad::new(&entropy, PadSize::Small).unwrap();
-    let (conversation_id, auth_token, burn_token) =
-        ash_core::auth::derive_all_tokens(pad.as_bytes()).unwrap();
-
-    TestCredentials {
-        auth_token_hash: auth::hash_token(&auth_token),
-        burn_token_hash: auth::hash_token(&burn_token),
-        conversation_id,
-        auth_token,
-        burn_token,
-    }
+    credentials_from_pad(pad.as_bytes())
 }
 
 /// Build test server with the application router
 async fn build_test_server() -> TestServer {
-    let config = Config::default();
+    build_test_server_with_config(Config::default()).await
+}
+
+/// Build test server with a custom configuration
+async fn build_test_server_with_config(config: Config) -> TestServer {
     let store = Arc::new(Store::new(config.clone()));
     let apns = apns::create_client(&config).await;
     let state = AppState::new(store, apns);
@@ -78,7 +97,7 @@ async fn build_test_server() -> TestServer {
 
 /// Create authorization header value
 fn auth_header(token: &str) -> String {
-    format!("Bearer {}", token)
+    format!("Bearer {token}")
 }
 
 // =============================================================================
@@ -111,7 +130,8 @@ async fn test_register_conversation_success() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await;
 
@@ -131,7 +151,8 @@ async fn test_register_conversation_invalid_id() {
         .json(&json!({
             "conversation_id": "invalid",
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await;
 
@@ -151,7 +172,8 @@ async fn test_register_conversation_idempotent() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await;
     response.assert_status_ok();
@@ -162,12 +184,185 @@ async fn test_register_conversation_idempotent() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await;
     response.assert_status_ok();
 }
 
+#[tokio::test]
+async fn test_register_conversation_requires_proof() {
+    let server = build_test_server_with_config(Config {
+        require_registration_proof: true,
+        ..Config::default()
+    })
+    .await;
+    let creds = generate_test_credentials_with_seed(34);
+
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "INVALID_INPUT");
+
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": "not-a-proof"
+        }))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_register_conversation_without_proof_by_default() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(35);
+
+    // Clients that send no proof register the legacy conversation ID
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.legacy_conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash
+        }))
+        .await
+        .assert_status_ok();
+
+    // A proof sent later is still checked against the conversation ID
+    register(&server, &creds).await;
+    let other = generate_test_credentials_with_seed(36);
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": other.registration_proof
+        }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_register_conversation_respects_configured_limit() {
+    let server = build_test_server_with_config(Config {
+        max_conversations: 1,
+        ..Config::default()
+    })
+    .await;
+    let first = generate_test_credentials_with_seed(43);
+    let second = generate_test_credentials_with_seed(44);
+
+    register(&server, &first).await;
+
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": second.conversation_id,
+            "auth_token_hash": second.auth_token_hash,
+            "burn_token_hash": second.burn_token_hash,
+            "registration_proof": second.registration_proof
+        }))
+        .await;
+    response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "SERVER_AT_CAPACITY");
+
+    // Re-registering the existing conversation is unaffected
+    register(&server, &first).await;
+}
+
+#[tokio::test]
+async fn test_register_conversation_rejects_forged_proof() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(37);
+    let squatter = generate_test_credentials_with_seed(38);
+
+    // Squatter registers the observed conversation ID with its own pad's
+    // hashes and proof
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": squatter.auth_token_hash,
+            "burn_token_hash": squatter.burn_token_hash,
+            "registration_proof": squatter.registration_proof
+        }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "INVALID_REGISTRATION_PROOF");
+
+    // A random proof with its own hashes does not match the ID either
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": squatter.auth_token_hash,
+            "burn_token_hash": squatter.burn_token_hash,
+            "registration_proof": "ab".repeat(32)
+        }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    // Nothing was registered, so the real client still can
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
+        }))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_register_conversation_rejects_mismatch() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(50);
+    let squatter = generate_test_credentials_with_seed(51);
+
+    // Proofs are not required by default, so an unproved registration can
+    // take the ID
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": squatter.auth_token_hash,
+            "burn_token_hash": squatter.burn_token_hash
+        }))
+        .await
+        .assert_status_ok();
+
+    // The real client is told instead of being silently ignored
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
+        }))
+        .await;
+    response.assert_status(StatusCode::CONFLICT);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "REGISTRATION_MISMATCH");
+}
+
 // =============================================================================
 // Message Submission Tests
 // =============================================================================
@@ -183,7 +378,8 @@ async fn test_submit_message_success() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -221,7 +417,8 @@ async fn test_submit_message_unauthorized() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -251,7 +448,8 @@ async fn test_submit_message_missing_auth() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -286,7 +484,8 @@ async fn test_poll_messages_empty() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -317,7 +516,8 @@ async fn test_poll_messages_returns_submitted_messages() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -369,7 +569,8 @@ async fn test_ack_message_success() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -430,7 +631,8 @@ async fn test_ack_nonexistent_message() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -465,7 +667,8 @@ async fn test_burn_conversation_success() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -507,7 +710,8 @@ async fn test_burn_conversation_wrong_token() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -552,7 +756,8 @@ async fn test_burn_deletes_messages() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -601,8 +806,8 @@ async fn test_burn_deletes_messages() {
         .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
         .await;
 
-    // After burn, conversation is removed from auth store, so it should return 404
-    poll_response.assert_status(StatusCode::NOT_FOUND);
+    // After burn, auth entries are removed but the tombstone reports it burned
+    poll_response.assert_status(StatusCode::GONE);
 }
 
 // =============================================================================
@@ -620,7 +825,8 @@ async fn test_burn_status_not_burned() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -651,7 +857,8 @@ async fn test_burn_status_after_burn() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -666,7 +873,7 @@ async fn test_burn_status_after_burn() {
         .await
         .assert_status_ok();
 
-    // Check burn status - auth entries are removed, so this should fail
+    // Check burn status - auth entries are removed, the tombstone answers
     let response = server
         .get(&format!(
             "/v1/burn?conversation_id={}",
@@ -675,8 +882,10 @@ async fn test_burn_status_after_burn() {
         .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
         .await;
 
-    // After burn, conversation is removed from auth store
-    response.assert_status(StatusCode::NOT_FOUND);
+    // After burn, conversation is removed from auth store but still burned
+    response.assert_status(StatusCode::GONE);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "CONVERSATION_BURNED");
 }
 
 // =============================================================================
@@ -694,7 +903,8 @@ async fn test_register_device_success() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -726,7 +936,8 @@ async fn test_register_device_invalid_token() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -760,7 +971,8 @@ async fn test_submit_message_after_burn() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -786,8 +998,8 @@ async fn test_submit_message_after_burn() {
         }))
         .await;
 
-    // Conversation is not found after burn (auth entries removed)
-    response.assert_status(StatusCode::NOT_FOUND);
+    // Conversation is reported burned (auth entries removed, tombstone kept)
+    response.assert_status(StatusCode::GONE);
 }
 
 // =============================================================================
@@ -827,7 +1039,8 @@ async fn test_multiple_messages_ordering() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -836,7 +1049,7 @@ async fn test_multiple_messages_ordering() {
     for seq in [3, 1, 2] {
         let ciphertext = base64::Engine::encode(
             &base64::engine::general_purpose::STANDARD,
-            format!("message-{}", seq).as_bytes(),
+            format!("message-{seq}").as_bytes(),
         );
 
         server
@@ -881,7 +1094,8 @@ async fn test_burn_removes_device_registrations() {
         .json(&json!({
             "conversation_id": creds.conversation_id,
             "auth_token_hash": creds.auth_token_hash,
-            "burn_token_hash": creds.burn_token_hash
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
         }))
         .await
         .assert_status_ok();
@@ -917,5 +1131,917 @@ async fn test_burn_removes_device_registrations() {
         .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
         .await;
 
-    response.assert_status(StatusCode::NOT_FOUND);
+    response.assert_status(StatusCode::GONE);
+}
+
+// =============================================================================
+// Burn Tombstone Tests
+// =============================================================================
+
+/// Burn a registered conversation
+async fn burn(server: &TestServer, creds: &TestCredentials) {
+    server
+        .post("/v1/burn")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.burn_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id
+        }))
+        .await
+        .assert_status_ok();
+}
+
+/// Config persisting tombstones under a fresh temp directory
+fn tombstone_config(name: &str) -> (Config, std::path::PathBuf) {
+    let dir = std::env::temp_dir().join(format!(
+        "ash-backend-{name}-{}-{}",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = Config {
+        tombstone_path: Some(dir.join("tombstones")),
+        tombstone_key_path: None,
+        ..Config::default()
+    };
+    (config, dir)
+}
+
+#[tokio::test]
+async fn test_register_after_burn_flag_expired_reports_burned() {
+    // Burn flag expires immediately, only the tombstone is left
+    let server = build_test_server_with_config(Config {
+        burn_ttl: std::time::Duration::ZERO,
+        ..Config::default()
+    })
+    .await;
+    let creds = generate_test_credentials_with_seed(39);
+
+    register(&server, &creds).await;
+    burn(&server, &creds).await;
+
+    let response = server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
+        }))
+        .await;
+    response.assert_status(StatusCode::GONE);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "CONVERSATION_BURNED");
+}
+
+#[tokio::test]
+async fn test_burn_survives_restart() {
+    let (config, dir) = tombstone_config("restart");
+    let creds = generate_test_credentials_with_seed(40);
+    let other = generate_test_credentials_with_seed(41);
+
+    {
+        let server = build_test_server_with_config(config.clone()).await;
+        register(&server, &creds).await;
+        register(&server, &other).await;
+        burn(&server, &creds).await;
+    }
+
+    // A fresh relay with the same tombstone file
+    let server = build_test_server_with_config(config).await;
+
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
+        }))
+        .await
+        .assert_status(StatusCode::GONE);
+
+    server
+        .post("/v1/messages")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "ciphertext": "YWJj",
+            "sequence": 1
+        }))
+        .await
+        .assert_status(StatusCode::GONE);
+
+    server
+        .get(&format!(
+            "/v1/messages/stream?conversation_id={}",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await
+        .assert_status(StatusCode::GONE);
+
+    // Conversations that were not burned re-register normally
+    register(&server, &other).await;
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+// =============================================================================
+// Sequence Enforcement Tests
+// =============================================================================
+
+/// Submit one message from `sender` with a sequence number
+async fn submit_sequenced(
+    server: &TestServer,
+    creds: &TestCredentials,
+    sender: &str,
+    sequence: u64,
+) -> axum_test::TestResponse {
+    let ciphertext = base64::Engine::encode(
+        &base64::engine::general_purpose::STANDARD,
+        format!("{sender}-{sequence}").as_bytes(),
+    );
+
+    server
+        .post("/v1/messages")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "ciphertext": ciphertext,
+            "sequence": sequence,
+            "sender": sender
+        }))
+        .await
+}
+
+/// Register the conversation for `creds`
+async fn register(server: &TestServer, creds: &TestCredentials) {
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof
+        }))
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_duplicate_sequence_returns_existing_blob() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(23);
+    register(&server, &creds).await;
+
+    let first = submit_sequenced(&server, &creds, "initiator", 1).await;
+    first.assert_status_ok();
+    let first: Value = first.json();
+    assert_eq!(first["duplicate"], false);
+
+    // Retrying the same submission is idempotent
+    let retry = submit_sequenced(&server, &creds, "initiator", 1).await;
+    retry.assert_status_ok();
+    let retry: Value = retry.json();
+    assert_eq!(retry["duplicate"], true);
+    assert_eq!(retry["blob_id"], first["blob_id"]);
+
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    let body: Value = response.json();
+    let messages = body["messages"].as_array().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["sender"], "initiator");
+}
+
+#[tokio::test]
+async fn test_enforced_sequence_rejects_lower_sequence() {
+    let config = Config {
+        enforce_sequences: true,
+        ..Config::default()
+    };
+    let server = build_test_server_with_config(config).await;
+    let creds = generate_test_credentials_with_seed(24);
+    register(&server, &creds).await;
+
+    submit_sequenced(&server, &creds, "responder", 5)
+        .await
+        .assert_status_ok();
+
+    let response = submit_sequenced(&server, &creds, "responder", 3).await;
+    response.assert_status(StatusCode::CONFLICT);
+    let body: Value = response.json();
+    assert_eq!(body["code"], "SEQUENCE_NOT_INCREASING");
+
+    // The other direction has its own counter
+    submit_sequenced(&server, &creds, "initiator", 1)
+        .await
+        .assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_poll_after_sequence_reports_missing_sequences() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(25);
+    register(&server, &creds).await;
+
+    for seq in [1, 2, 5] {
+        submit_sequenced(&server, &creds, "initiator", seq)
+            .await
+            .assert_status_ok();
+    }
+    submit_sequenced(&server, &creds, "responder", 3)
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&sender=initiator&after_sequence=1",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+
+    response.assert_status_ok();
+    let body: Value = response.json();
+    let sequences: Vec<u64> = body["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["sequence"].as_u64().unwrap())
+        .collect();
+    assert_eq!(sequences, vec![2, 5]);
+    assert_eq!(body["missing_sequences"], json!([3, 4]));
+
+    // Following the cursor continues after the last returned sequence
+    let cursor = body["next_cursor"].as_str().unwrap();
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&sender=initiator&cursor={cursor}",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    let body: Value = response.json();
+    assert!(body["messages"].as_array().unwrap().is_empty());
+    assert_eq!(body["missing_sequences"], json!([]));
+}
+
+// =============================================================================
+// Cursor Pagination Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_poll_with_limit_pages_without_redelivery() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(26);
+    register(&server, &creds).await;
+
+    for seq in 1..=5 {
+        submit_sequenced(&server, &creds, "initiator", seq)
+            .await
+            .assert_status_ok();
+    }
+
+    let mut cursor: Option<String> = None;
+    let mut sequences = Vec::new();
+    loop {
+        let url = match &cursor {
+            Some(cursor) => format!(
+                "/v1/messages?conversation_id={}&limit=2&cursor={cursor}",
+                creds.conversation_id
+            ),
+            None => format!(
+                "/v1/messages?conversation_id={}&limit=2",
+                creds.conversation_id
+            ),
+        };
+        let response = server
+            .get(&url)
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .await;
+        response.assert_status_ok();
+        let body: Value = response.json();
+        assert_eq!(body["cursor_reset"], false);
+
+        let messages = body["messages"].as_array().unwrap();
+        assert!(messages.len() <= 2);
+        sequences.extend(messages.iter().map(|m| m["sequence"].as_u64().unwrap()));
+        cursor = body["next_cursor"].as_str().map(str::to_string);
+        if body["has_more"] == false {
+            break;
+        }
+    }
+    assert_eq!(sequences, vec![1, 2, 3, 4, 5]);
+
+    // Polling again with the final cursor returns nothing
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&cursor={}",
+            creds.conversation_id,
+            cursor.unwrap()
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    let body: Value = response.json();
+    assert!(body["messages"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_poll_with_tampered_cursor_restarts() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(27);
+    register(&server, &creds).await;
+
+    submit_sequenced(&server, &creds, "responder", 1)
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    let body: Value = response.json();
+    let cursor = body["next_cursor"].as_str().unwrap();
+
+    // Flip a character of the cursor
+    let mut tampered: Vec<char> = cursor.chars().collect();
+    tampered[2] = if tampered[2] == 'A' { 'B' } else { 'A' };
+    let tampered: String = tampered.into_iter().collect();
+
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&cursor={tampered}",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["cursor_reset"], true);
+    assert_eq!(body["messages"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_poll_rejects_zero_limit() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(28);
+    register(&server, &creds).await;
+
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&limit=0",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+    response.assert_status(StatusCode::BAD_REQUEST);
+}
+
+// =============================================================================
+// Long-Poll Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_long_poll_wakes_on_new_message() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(29);
+    register(&server, &creds).await;
+
+    let poll = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&wait=10",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token));
+    let submit = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        submit_sequenced(&server, &creds, "initiator", 1).await
+    };
+
+    let started = std::time::Instant::now();
+    let (response, submitted) = tokio::join!(poll, submit);
+    submitted.assert_status_ok();
+    response.assert_status_ok();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+
+    let body: Value = response.json();
+    let messages = body["messages"].as_array().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["sequence"], 1);
+}
+
+#[tokio::test]
+async fn test_long_poll_wakes_on_burn() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(30);
+    register(&server, &creds).await;
+
+    let poll = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&wait=10",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token));
+    let burn = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        server
+            .post("/v1/burn")
+            .add_header(header::AUTHORIZATION, auth_header(&creds.burn_token))
+            .json(&json!({ "conversation_id": creds.conversation_id }))
+            .await
+    };
+
+    let (response, burned) = tokio::join!(poll, burn);
+    burned.assert_status_ok();
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["burned"], true);
+    assert!(body["messages"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_long_poll_times_out_empty() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(31);
+    register(&server, &creds).await;
+
+    let started = std::time::Instant::now();
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&wait=1",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+
+    response.assert_status_ok();
+    assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    let body: Value = response.json();
+    assert!(body["messages"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_long_poll_returns_queued_messages_immediately() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(32);
+    register(&server, &creds).await;
+
+    submit_sequenced(&server, &creds, "responder", 1)
+        .await
+        .assert_status_ok();
+
+    let started = std::time::Instant::now();
+    let response = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&wait=10",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await;
+
+    response.assert_status_ok();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    let body: Value = response.json();
+    assert_eq!(body["messages"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_long_poll_caps_waiters_per_conversation() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(33);
+    register(&server, &creds).await;
+
+    let url = format!(
+        "/v1/messages?conversation_id={}&wait=2",
+        creds.conversation_id
+    );
+    let polls = (0..=handlers::MAX_WAITERS_PER_CONVERSATION).map(|_| {
+        server
+            .get(&url)
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .into_future()
+    });
+    let responses = futures::future::join_all(polls).await;
+
+    let rejected: Vec<_> = responses
+        .iter()
+        .filter(|r| r.status_code() == StatusCode::TOO_MANY_REQUESTS)
+        .collect();
+    assert_eq!(rejected.len(), 1);
+    let body: Value = rejected[0].json();
+    assert_eq!(body["code"], "TOO_MANY_WAITERS");
+
+    // Slots are released once the waits end
+    server
+        .get(&url)
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .await
+        .assert_status_ok();
+}
+
+// =============================================================================
+// Shutdown Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_long_poll_returns_on_shutdown() {
+    let config = Config::default();
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+    let server = TestServer::new(build_router(state.clone())).unwrap();
+    let creds = generate_test_credentials_with_seed(42);
+    register(&server, &creds).await;
+
+    let poll = server
+        .get(&format!(
+            "/v1/messages?conversation_id={}&wait=30",
+            creds.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token));
+    let shutdown = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        state.begin_shutdown();
+    };
+
+    let started = std::time::Instant::now();
+    let (response, ()) = tokio::join!(poll, shutdown);
+    response.assert_status_ok();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+
+    let body: Value = response.json();
+    assert!(body["messages"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_drain_pushes_waits_within_deadline() {
+    let config = Config::default();
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+
+    let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+    state.pushes.spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let _ = done_tx.send(());
+    });
+    assert!(state.drain_pushes(std::time::Duration::from_secs(5)).await);
+    assert!(done_rx.await.is_ok());
+
+    state.pushes.spawn(std::future::pending::<()>());
+    assert!(
+        !state
+            .drain_pushes(std::time::Duration::from_millis(50))
+            .await
+    );
+}
+
+// =============================================================================
+// Delivery Failed Tests
+// =============================================================================
+
+/// Device handle of the submitting device in delivery-failed tests
+const SENDER_DEVICE: &str = "sender-device-0123456789";
+
+/// App state whose blobs expire immediately, and a server on it
+async fn build_expiring_state() -> (AppState, TestServer) {
+    let config = Config {
+        blob_ttl: std::time::Duration::ZERO,
+        ..Config::default()
+    };
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+    let server = TestServer::new(build_router(state.clone())).unwrap();
+    (state, server)
+}
+
+/// Submit one message, optionally under a device handle, returning its blob ID
+async fn submit_from_device(
+    server: &TestServer,
+    creds: &TestCredentials,
+    device_handle: Option<&str>,
+) -> String {
+    let response = server
+        .post("/v1/messages")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "ciphertext": "YWJj",
+            "device_handle": device_handle
+        }))
+        .await;
+    response.assert_status_ok();
+    response.json::<Value>()["blob_id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_delivery_failed_reaches_only_sender_device() {
+    let (state, server) = build_expiring_state().await;
+    let creds = generate_test_credentials_with_seed(45);
+    register(&server, &creds).await;
+
+    server
+        .post("/v1/register")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "device_token": "abc123def456",
+            "device_handle": SENDER_DEVICE
+        }))
+        .await
+        .assert_status_ok();
+
+    let mut rx = state.broadcast_tx.subscribe();
+    let failed = submit_from_device(&server, &creds, Some(SENDER_DEVICE)).await;
+    let acked = submit_from_device(&server, &creds, Some(SENDER_DEVICE)).await;
+    submit_from_device(&server, &creds, None).await;
+
+    server
+        .post("/v1/messages/ack")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "blob_ids": [acked]
+        }))
+        .await
+        .assert_status_ok();
+
+    assert_eq!(expiry::cleanup_expired(&state).await, 2);
+
+    let event = loop {
+        let event = rx.try_recv().expect("delivery failed event broadcast");
+        if matches!(event.event, StreamEvent::DeliveryFailed { .. }) {
+            break event;
+        }
+    };
+    assert!(rx.try_recv().is_err(), "one event per sender device");
+
+    // Only the sender's stream receives it
+    assert!(event.is_for(&creds.conversation_id, Some(SENDER_DEVICE)));
+    assert!(!event.is_for(&creds.conversation_id, None));
+    assert!(!event.is_for(&creds.conversation_id, Some("receiver-device-012345")));
+
+    let json = serde_json::to_value(&event.event).unwrap();
+    assert_eq!(json["type"], "delivery_failed");
+    assert_eq!(json["blob_ids"], json!([failed]));
+    assert!(!json.to_string().contains(SENDER_DEVICE));
+}
+
+#[tokio::test]
+async fn test_delivery_failed_respects_notification_flags() {
+    let (state, server) = build_expiring_state().await;
+    let creds = generate_test_credentials_with_seed(46);
+
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof,
+            "notification_flags": 1
+        }))
+        .await
+        .assert_status_ok();
+
+    let mut rx = state.broadcast_tx.subscribe();
+    submit_from_device(&server, &creds, Some(SENDER_DEVICE)).await;
+
+    assert_eq!(expiry::cleanup_expired(&state).await, 1);
+    while let Ok(event) = rx.try_recv() {
+        assert!(
+            !matches!(event.event, StreamEvent::DeliveryFailed { .. }),
+            "NOTIFY_DELIVERY_FAILED is not set"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_delivery_failed_skips_conversation_without_prefs() {
+    let config = Config {
+        blob_ttl: std::time::Duration::ZERO,
+        ..Config::default()
+    };
+    let store = Arc::new(Store::new(config));
+    let state = AppState::new(store, Arc::new(apns::ApnsClient::recording()));
+    let conversation_id = "d".repeat(64);
+
+    // Stored without registering the conversation, so it has no prefs
+    state
+        .store
+        .register_device(
+            conversation_id.clone(),
+            "abc123def456".to_string(),
+            Platform::Ios,
+            RegisterOptions {
+                device_handle: Some(SENDER_DEVICE.to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    state
+        .store
+        .submit_blob(
+            conversation_id.clone(),
+            vec![1],
+            SubmitOptions {
+                sender_device: Some(SENDER_DEVICE.to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(state.store.get_prefs(&conversation_id).is_none());
+
+    let mut rx = state.broadcast_tx.subscribe();
+    assert_eq!(expiry::cleanup_expired(&state).await, 1);
+    assert!(state.drain_pushes(std::time::Duration::from_secs(5)).await);
+    assert!(state.apns.take_recorded().is_empty());
+    while let Ok(event) = rx.try_recv() {
+        assert!(
+            !matches!(event.event, StreamEvent::DeliveryFailed { .. }),
+            "conversation has no prefs"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_device_handle_rejected() {
+    let server = build_test_server().await;
+    let creds = generate_test_credentials_with_seed(47);
+    register(&server, &creds).await;
+
+    for handle in ["short", "has spaces in the handle", &"x".repeat(65)] {
+        server
+            .post("/v1/messages")
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .json(&json!({
+                "conversation_id": creds.conversation_id,
+                "ciphertext": "YWJj",
+                "device_handle": handle
+            }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+
+        server
+            .post("/v1/register")
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .json(&json!({
+                "conversation_id": creds.conversation_id,
+                "device_token": "abc123def456",
+                "device_handle": handle
+            }))
+            .await
+            .assert_status(StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn test_expiry_worker_purges_at_blob_expiry() {
+    let config = Config {
+        blob_ttl: std::time::Duration::from_millis(300),
+        cleanup_interval: std::time::Duration::from_secs(3600),
+        ..Config::default()
+    };
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+    let server = TestServer::new(build_router(state.clone())).unwrap();
+    let creds = generate_test_credentials_with_seed(48);
+    register(&server, &creds).await;
+
+    Arc::new(expiry::ExpiryWorker::new(state.clone())).start();
+    let mut rx = state.broadcast_tx.subscribe();
+    let blob_id = submit_from_device(&server, &creds, Some(SENDER_DEVICE)).await;
+
+    // Woken by the blob's deadline, long before the next periodic sweep
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let event = rx.recv().await.unwrap();
+            if matches!(event.event, StreamEvent::DeliveryFailed { .. }) {
+                break event;
+            }
+        }
+    })
+    .await
+    .expect("delivery failed event broadcast");
+
+    let json = serde_json::to_value(&event.event).unwrap();
+    assert_eq!(json["blob_ids"], json!([blob_id]));
+    assert_eq!(state.store.gauges().queued_blobs, 0);
+}
+
+// =============================================================================
+// Push Targeting Tests
+// =============================================================================
+
+/// Wait for in-flight push sends, then take the device tokens they targeted
+async fn take_pushed_tokens(state: &AppState) -> Vec<String> {
+    assert!(state.drain_pushes(std::time::Duration::from_secs(5)).await);
+    state.pushes.reopen();
+    let mut tokens: Vec<_> = state
+        .apns
+        .take_recorded()
+        .into_iter()
+        .map(|push| push.device_token)
+        .collect();
+    tokens.sort();
+    tokens
+}
+
+#[tokio::test]
+async fn test_push_targets_other_party_in_both_directions() {
+    let config = Config {
+        blob_ttl: std::time::Duration::ZERO,
+        ..Config::default()
+    };
+    let store = Arc::new(Store::new(config));
+    let state = AppState::new(store, Arc::new(apns::ApnsClient::recording()));
+    let server = TestServer::new(build_router(state.clone())).unwrap();
+    let creds = generate_test_credentials_with_seed(49);
+
+    // NOTIFY_NEW_MESSAGE | NOTIFY_MESSAGE_EXPIRED
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "auth_token_hash": creds.auth_token_hash,
+            "burn_token_hash": creds.burn_token_hash,
+            "registration_proof": creds.registration_proof,
+            "notification_flags": 0b101
+        }))
+        .await
+        .assert_status_ok();
+
+    // The initiator's second device registered its handle but no role
+    let initiator_handle = "initiator-tablet-0123456";
+    for (token, role, device_handle) in [
+        ("aaaa1111", Some("initiator"), None),
+        ("bbbb2222", Some("responder"), None),
+        ("cccc3333", None, Some(initiator_handle)),
+    ] {
+        server
+            .post("/v1/register")
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .json(&json!({
+                "conversation_id": creds.conversation_id,
+                "device_token": token,
+                "role": role,
+                "device_handle": device_handle
+            }))
+            .await
+            .assert_status_ok();
+    }
+
+    for (sender, device_handle, recipients) in [
+        ("initiator", Some(initiator_handle), vec!["bbbb2222"]),
+        ("responder", None, vec!["aaaa1111", "cccc3333"]),
+    ] {
+        server
+            .post("/v1/messages")
+            .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+            .json(&json!({
+                "conversation_id": creds.conversation_id,
+                "ciphertext": "YWJj",
+                "sender": sender,
+                "device_handle": device_handle
+            }))
+            .await
+            .assert_status_ok();
+        assert_eq!(
+            take_pushed_tokens(&state).await,
+            recipients,
+            "{sender} submit"
+        );
+
+        // The expiry notification goes to the same devices
+        assert_eq!(expiry::cleanup_expired(&state).await, 1);
+        assert_eq!(
+            take_pushed_tokens(&state).await,
+            recipients,
+            "{sender} expiry"
+        );
+    }
+
+    // Unknown roles are rejected
+    server
+        .post("/v1/register")
+        .add_header(header::AUTHORIZATION, auth_header(&creds.auth_token))
+        .json(&json!({
+            "conversation_id": creds.conversation_id,
+            "device_token": "dddd4444",
+            "role": "observer"
+        }))
+        .await
+        .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
 }
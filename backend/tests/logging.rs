@@ -0,0 +1,264 @@
+//! Log privacy tests.
+//!
+//! Drives a full conversation lifecycle with a capturing subscriber at
+//! TRACE level and checks that nothing in the output links back to the
+//! conversation: no conversation ID (or prefix), tokens, token hashes,
+//! registration proof, device token or ciphertext.
+
+use ash_backend::log_policy::LogMode;
+use ash_backend::{apns, auth, build_router, config::Config, handlers::AppState, store::Store};
+use ash_core::{Pad, PadSize};
+use axum::http::{header, StatusCode};
+use axum_test::TestServer;
+use base64::Engine;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Log output shared between the subscriber and the test.
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Captured {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Captured {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Everything a log line must never contain.
+struct Secrets {
+    conversation_id: String,
+    auth_token: String,
+    burn_token: String,
+    auth_token_hash: String,
+    burn_token_hash: String,
+    registration_proof: String,
+    device_token: String,
+    ciphertext: String,
+}
+
+impl Secrets {
+    fn new(seed: u8) -> Self {
+        let entropy: Vec<u8> = (0u8..=255)
+            .cycle()
+            .take(PadSize::Small.bytes())
+            .map(|b| b.wrapping_mul(7).wrapping_add(seed))
+            .collect();
+        let pad = Pad::new(&entropy, PadSize::Small).unwrap();
+        let (_, auth_token, burn_token) =
+            ash_core::auth::derive_all_tokens(pad.as_bytes()).unwrap();
+        let conversation_id =
+            ash_core::auth::derive_registered_conversation_id(pad.as_bytes()).unwrap();
+        let auth_token_hash = auth::hash_token(&auth_token);
+        let burn_token_hash = auth::hash_token(&burn_token);
+        let registration_proof = ash_core::auth::derive_registration_proof(pad.as_bytes()).unwrap();
+        let ciphertext: Vec<u8> = (0u8..96).map(|b| b.wrapping_mul(31) ^ seed).collect();
+
+        Self {
+            conversation_id,
+            auth_token,
+            burn_token,
+            auth_token_hash,
+            burn_token_hash,
+            registration_proof,
+            device_token: format!("{:064x}", u64::from(seed) * 0x9e37_79b9),
+            ciphertext: base64::engine::general_purpose::STANDARD.encode(ciphertext),
+        }
+    }
+
+    fn assert_absent_from(&self, logs: &str) {
+        let forbidden = [
+            ("conversation ID", self.conversation_id.as_str()),
+            ("conversation ID prefix", &self.conversation_id[..8]),
+            ("auth token", &self.auth_token),
+            ("burn token", &self.burn_token),
+            ("auth token hash", &self.auth_token_hash),
+            ("burn token hash", &self.burn_token_hash),
+            ("registration proof", &self.registration_proof),
+            ("device token", &self.device_token),
+            ("ciphertext", &self.ciphertext),
+        ];
+        for (what, value) in forbidden {
+            assert!(!logs.contains(value), "{what} leaked into logs:\n{logs}");
+        }
+    }
+}
+
+/// Run a full conversation lifecycle, returning the app state.
+async fn run_lifecycle(config: Config, secrets: &Secrets) -> AppState {
+    let store = Arc::new(Store::new(config.clone()));
+    let state = AppState::new(store, apns::create_client(&config).await);
+    let server = TestServer::new(build_router(state.clone())).unwrap();
+    let bearer = |token: &str| format!("Bearer {token}");
+
+    server
+        .post("/v1/conversations")
+        .json(&json!({
+            "conversation_id": secrets.conversation_id,
+            "auth_token_hash": secrets.auth_token_hash,
+            "burn_token_hash": secrets.burn_token_hash,
+            "registration_proof": secrets.registration_proof
+        }))
+        .await
+        .assert_status_ok();
+
+    server
+        .post("/v1/register")
+        .add_header(header::AUTHORIZATION, bearer(&secrets.auth_token))
+        .json(&json!({
+            "conversation_id": secrets.conversation_id,
+            "device_token": secrets.device_token,
+            "platform": "ios"
+        }))
+        .await
+        .assert_status_ok();
+
+    let submit = server
+        .post("/v1/messages")
+        .add_header(header::AUTHORIZATION, bearer(&secrets.auth_token))
+        .json(&json!({
+            "conversation_id": secrets.conversation_id,
+            "ciphertext": secrets.ciphertext,
+            "sequence": 1
+        }))
+        .await;
+    submit.assert_status_ok();
+    let blob_id = submit.json::<Value>()["blob_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    server
+        .get(&format!(
+            "/v1/messages?conversation_id={}",
+            secrets.conversation_id
+        ))
+        .add_header(header::AUTHORIZATION, bearer(&secrets.auth_token))
+        .await
+        .assert_status_ok();
+
+    server
+        .post("/v1/messages/ack")
+        .add_header(header::AUTHORIZATION, bearer(&secrets.auth_token))
+        .json(&json!({
+            "conversation_id": secrets.conversation_id,
+            "blob_ids": [blob_id]
+        }))
+        .await
+        .assert_status_ok();
+
+    // Burning with the auth token fails verification
+    server
+        .post("/v1/burn")
+        .add_header(header::AUTHORIZATION, bearer(&secrets.auth_token))
+        .json(&json!({ "conversation_id": secrets.conversation_id }))
+        .await
+        .assert_status(StatusCode::UNAUTHORIZED);
+
+    server
+        .post("/v1/burn")
+        .add_header(header::AUTHORIZATION, bearer(&secrets.burn_token))
+        .json(&json!({ "conversation_id": secrets.conversation_id }))
+        .await
+        .assert_status_ok();
+
+    state
+}
+
+/// Run the lifecycle with a TRACE-level subscriber capturing all output.
+async fn capture_lifecycle(config: Config, secrets: &Secrets) -> (String, AppState) {
+    let captured = Captured::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .with_writer(captured.clone())
+        .finish();
+
+    let _guard = tracing::subscriber::set_default(subscriber);
+    let state = run_lifecycle(config, secrets).await;
+    (captured.text(), state)
+}
+
+#[tokio::test]
+async fn pseudonymous_logs_contain_no_identifiers() {
+    let secrets = Secrets::new(1);
+    let config = Config {
+        log_mode: LogMode::Pseudonymous,
+        ..Config::default()
+    };
+
+    let (logs, state) = capture_lifecycle(config, &secrets).await;
+
+    assert!(
+        logs.contains("conv="),
+        "expected pseudonymous fields:\n{logs}"
+    );
+    secrets.assert_absent_from(&logs);
+
+    let counts = state.log.take_counts();
+    assert_eq!(counts.registrations, 1);
+    assert_eq!(counts.submissions, 1);
+    assert_eq!(counts.polls, 1);
+    assert_eq!(counts.acks, 1);
+    assert_eq!(counts.burns, 1);
+    assert_eq!(counts.auth_failures, 1);
+}
+
+#[tokio::test]
+async fn pseudonyms_are_consistent_within_a_run() {
+    let secrets = Secrets::new(2);
+    let (logs, state) = capture_lifecycle(Config::default(), &secrets).await;
+
+    let pseudonym = state.log.conv(&secrets.conversation_id).unwrap();
+    assert!(
+        logs.matches(&format!("conv={pseudonym}")).count() >= 4,
+        "expected requests to share a pseudonym:\n{logs}"
+    );
+}
+
+#[tokio::test]
+async fn strict_logs_contain_no_per_request_fields() {
+    let secrets = Secrets::new(3);
+    let config = Config {
+        log_mode: LogMode::Strict,
+        ..Config::default()
+    };
+
+    let (logs, state) = capture_lifecycle(config, &secrets).await;
+
+    assert!(
+        logs.contains("Burn token verification failed"),
+        "expected field-less warnings:\n{logs}"
+    );
+    for field in ["conv=", "blob_id=", "ciphertext_len=", "size=", "sequence="] {
+        assert!(
+            !logs.contains(field),
+            "{field} logged in strict mode:\n{logs}"
+        );
+    }
+    secrets.assert_absent_from(&logs);
+
+    let counts = state.log.take_counts();
+    assert_eq!(counts.submissions, 1);
+    assert_eq!(counts.auth_failures, 1);
+}
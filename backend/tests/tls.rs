@@ -0,0 +1,422 @@
+//! Built-in TLS termination tests.
+//!
+//! Each test generates self-signed certificates, serves the real router
+//! through `tls::serve` on a loopback port and talks to it with a rustls
+//! client that trusts only the expected certificate.
+
+use ash_backend::{apns, build_router, config::Config, handlers::AppState, store::Store, tls};
+use ash_core::{Pad, PadSize};
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::{header, Request, StatusCode};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use rcgen::{CertificateParams, DnType, ExtendedKeyUsagePurpose, KeyPair};
+use rustls::pki_types::{CertificateDer, ServerName};
+use rustls::{ClientConfig, RootCertStore};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+// =============================================================================
+// Test Utilities
+// =============================================================================
+
+/// A self-signed certificate for `localhost` and its private key.
+struct TestCert {
+    cert_pem: Vec<u8>,
+    key_pem: Vec<u8>,
+    der: Vec<u8>,
+}
+
+/// Generate a fresh self-signed P-256 certificate for `localhost`.
+fn self_signed() -> TestCert {
+    let key = KeyPair::generate().unwrap();
+    let mut params = CertificateParams::new(vec!["localhost".to_string()]).unwrap();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, "localhost");
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+    let cert = params.self_signed(&key).unwrap();
+
+    TestCert {
+        cert_pem: cert.pem().into_bytes(),
+        key_pem: key.serialize_pem().into_bytes(),
+        der: cert.der().to_vec(),
+    }
+}
+
+/// Certificate and key files in a fresh temp directory, removed on drop.
+struct CertFiles {
+    dir: PathBuf,
+}
+
+impl CertFiles {
+    fn new(name: &str, cert: &TestCert) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "ash-backend-tls-{name}-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let files = Self { dir };
+        files.write(cert);
+        files
+    }
+
+    fn cert(&self) -> PathBuf {
+        self.dir.join("cert.pem")
+    }
+
+    fn key(&self) -> PathBuf {
+        self.dir.join("key.pem")
+    }
+
+    fn write(&self, cert: &TestCert) {
+        std::fs::write(self.cert(), &cert.cert_pem).unwrap();
+        std::fs::write(self.key(), &cert.key_pem).unwrap();
+    }
+}
+
+impl Drop for CertFiles {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Serve the full router over TLS on a loopback port.
+async fn start_server(reloader: Arc<tls::CertReloader>) -> SocketAddr {
+    spawn_server(reloader).await.0
+}
+
+/// Like [`start_server`], also returning the app state and server task.
+async fn spawn_server(reloader: Arc<tls::CertReloader>) -> (SocketAddr, AppState, JoinHandle<()>) {
+    let config = Config::default();
+    let store = Arc::new(Store::new(config.clone()));
+    let apns = apns::create_client(&config).await;
+    let state = AppState::new(store, apns);
+    let app = build_router(state.clone());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let acceptor = TlsAcceptor::from(tls::server_config(reloader));
+    let server = tokio::spawn(tls::serve(listener, app, acceptor, state.shutdown.clone()));
+    (addr, state, server)
+}
+
+/// Open a TLS connection trusting only `trusted`, offering `alpn`.
+async fn connect(
+    addr: SocketAddr,
+    trusted: &TestCert,
+    alpn: &[&[u8]],
+) -> std::io::Result<TlsStream<TcpStream>> {
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from(trusted.der.clone()))
+        .unwrap();
+    let mut config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    config.alpn_protocols = alpn.iter().map(|p| p.to_vec()).collect();
+
+    let tcp = TcpStream::connect(addr).await?;
+    TlsConnector::from(Arc::new(config))
+        .connect(ServerName::try_from("localhost").unwrap(), tcp)
+        .await
+}
+
+/// HTTP/2 request sender over a fresh TLS connection.
+async fn http2_client(
+    addr: SocketAddr,
+    trusted: &TestCert,
+) -> hyper::client::conn::http2::SendRequest<Full<Bytes>> {
+    let stream = connect(addr, trusted, &[b"h2"]).await.unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"h2"[..]));
+
+    let (sender, conn) =
+        hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+            .await
+            .unwrap();
+    tokio::spawn(conn);
+    sender
+}
+
+fn request(
+    method: &str,
+    path: &str,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> Request<Full<Bytes>> {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(format!("https://localhost{path}"));
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let body = match body {
+        Some(body) => {
+            builder = builder.header(header::CONTENT_TYPE, "application/json");
+            Bytes::from(body.to_string())
+        }
+        None => Bytes::new(),
+    };
+    builder.body(Full::new(body)).unwrap()
+}
+
+/// Open an SSE stream for the conversation.
+async fn open_stream(
+    sender: &mut hyper::client::conn::http2::SendRequest<Full<Bytes>>,
+    conversation_id: &str,
+    auth_token: &str,
+) -> Incoming {
+    let stream = sender
+        .send_request(request(
+            "GET",
+            &format!("/v1/messages/stream?conversation_id={conversation_id}"),
+            Some(auth_token),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(stream.status(), StatusCode::OK);
+    stream.into_body()
+}
+
+/// Read SSE data until `needle` appears, returning everything read.
+async fn read_until(events: &mut Incoming, needle: &str) -> String {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        let mut text = String::new();
+        while let Some(frame) = events.frame().await {
+            if let Some(data) = frame.unwrap().data_ref() {
+                text.push_str(&String::from_utf8_lossy(data));
+                if text.contains(needle) {
+                    return text;
+                }
+            }
+        }
+        text
+    })
+    .await
+    .unwrap_or_else(|_| panic!("{needle} within timeout"))
+}
+
+/// Registration body and auth token for a deterministic test conversation.
+fn test_conversation() -> (Value, String, String) {
+    let entropy: Vec<u8> = (0u8..=255).cycle().take(PadSize::Small.bytes()).collect();
+    let pad = Pad::new(&entropy, PadSize::Small).unwrap();
+    let (_, auth_token, burn_token) = ash_core::auth::derive_all_tokens(pad.as_bytes()).unwrap();
+    let conversation_id =
+        ash_core::auth::derive_registered_conversation_id(pad.as_bytes()).unwrap();
+    let auth_token_hash = ash_backend::auth::hash_token(&auth_token);
+    let burn_token_hash = ash_backend::auth::hash_token(&burn_token);
+    let registration_proof = ash_core::auth::derive_registration_proof(pad.as_bytes()).unwrap();
+
+    let body = json!({
+        "conversation_id": conversation_id,
+        "auth_token_hash": auth_token_hash,
+        "burn_token_hash": burn_token_hash,
+        "registration_proof": registration_proof
+    });
+    (body, conversation_id, auth_token)
+}
+
+// =============================================================================
+// Protocol Tests
+// =============================================================================
+
+#[tokio::test]
+async fn http2_is_negotiated_over_tls() {
+    let cert = self_signed();
+    let files = CertFiles::new("h2", &cert);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let addr = start_server(reloader).await;
+
+    let mut sender = http2_client(addr, &cert).await;
+    let response = sender
+        .send_request(request("GET", "/health", None, None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.version(), hyper::Version::HTTP_2);
+}
+
+#[tokio::test]
+async fn http1_clients_are_still_served() {
+    let cert = self_signed();
+    let files = CertFiles::new("h1", &cert);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let addr = start_server(reloader).await;
+
+    let stream = connect(addr, &cert, &[b"http/1.1"]).await.unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"http/1.1"[..]));
+
+    let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .unwrap();
+    tokio::spawn(conn);
+
+    let request = Request::builder()
+        .uri("/health")
+        .header(header::HOST, "localhost")
+        .body(Full::new(Bytes::new()))
+        .unwrap();
+    let response = sender.send_request(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn sse_and_submit_share_one_http2_connection() {
+    let cert = self_signed();
+    let files = CertFiles::new("shared", &cert);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let addr = start_server(reloader).await;
+    let (registration, conversation_id, auth_token) = test_conversation();
+
+    let mut sender = http2_client(addr, &cert).await;
+    let response = sender
+        .send_request(request(
+            "POST",
+            "/v1/conversations",
+            None,
+            Some(registration),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Open the SSE stream and keep it open
+    let mut events = open_stream(&mut sender, &conversation_id, &auth_token).await;
+
+    // Submit on the same connection while the stream is open
+    let response = sender
+        .send_request(request(
+            "POST",
+            "/v1/messages",
+            Some(&auth_token),
+            Some(json!({
+                "conversation_id": conversation_id,
+                "ciphertext": "YWJj",
+                "sequence": 1
+            })),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let received = read_until(&mut events, r#""type":"message""#).await;
+    assert!(received.contains("YWJj"), "unexpected stream: {received}");
+}
+
+#[tokio::test]
+async fn shutdown_sends_reconnect_and_closes_connections() {
+    let cert = self_signed();
+    let files = CertFiles::new("shutdown", &cert);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let (addr, state, server) = spawn_server(reloader).await;
+    let (registration, conversation_id, auth_token) = test_conversation();
+
+    let mut sender = http2_client(addr, &cert).await;
+    let response = sender
+        .send_request(request(
+            "POST",
+            "/v1/conversations",
+            None,
+            Some(registration),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = open_stream(&mut sender, &conversation_id, &auth_token).await;
+
+    state.begin_shutdown();
+
+    read_until(&mut events, r#""type":"reconnect""#).await;
+    let rest = tokio::time::timeout(Duration::from_secs(5), events.collect())
+        .await
+        .expect("stream should end after reconnect")
+        .unwrap()
+        .to_bytes();
+    assert!(
+        rest.iter().all(u8::is_ascii_whitespace),
+        "data after reconnect: {rest:?}"
+    );
+
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server drains after shutdown")
+        .unwrap();
+    assert!(connect(addr, &cert, &[b"h2"]).await.is_err());
+}
+
+// =============================================================================
+// Hot Reload Tests
+// =============================================================================
+
+#[tokio::test]
+async fn reload_serves_new_certificate() {
+    let old = self_signed();
+    let new = self_signed();
+    let files = CertFiles::new("reload", &old);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let addr = start_server(reloader.clone()).await;
+
+    assert!(connect(addr, &old, &[b"h2"]).await.is_ok());
+    assert!(!reloader.reload_if_changed().unwrap());
+
+    files.write(&new);
+    assert!(reloader.reload_if_changed().unwrap());
+
+    assert!(connect(addr, &new, &[b"h2"]).await.is_ok());
+    assert!(connect(addr, &old, &[b"h2"]).await.is_err());
+}
+
+#[tokio::test]
+async fn mismatched_pair_keeps_previous_certificate() {
+    let old = self_signed();
+    let new = self_signed();
+    let files = CertFiles::new("mismatch", &old);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    let addr = start_server(reloader.clone()).await;
+
+    // New certificate written, key not yet replaced
+    std::fs::write(files.cert(), &new.cert_pem).unwrap();
+    assert!(matches!(
+        reloader.reload_if_changed(),
+        Err(tls::TlsError::KeyMismatch)
+    ));
+    assert!(connect(addr, &old, &[b"h2"]).await.is_ok());
+
+    // Once the key lands the pair is picked up
+    std::fs::write(files.key(), &new.key_pem).unwrap();
+    assert!(reloader.reload_if_changed().unwrap());
+    assert!(connect(addr, &new, &[b"h2"]).await.is_ok());
+}
+
+#[tokio::test]
+async fn watcher_picks_up_renewed_certificate() {
+    let old = self_signed();
+    let new = self_signed();
+    let files = CertFiles::new("watch", &old);
+    let reloader = Arc::new(tls::CertReloader::new(&files.cert(), &files.key()).unwrap());
+    reloader.clone().start_watcher(Duration::from_millis(20));
+    let addr = start_server(reloader).await;
+
+    files.write(&new);
+
+    let renewed = tokio::time::timeout(Duration::from_secs(5), async {
+        while connect(addr, &new, &[b"h2"]).await.is_err() {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await;
+    assert!(
+        renewed.is_ok(),
+        "watcher did not install renewed certificate"
+    );
+}
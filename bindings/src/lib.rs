@@ -3,7 +3,10 @@
 //! This crate provides Swift bindings via Mozilla's UniFFI.
 //! Uses fountain codes for reliable QR ceremony transfer.
 
-use std::sync::Mutex;
+// The generated UniFFI scaffolding leaves a blank line after a doc comment.
+#![allow(clippy::empty_line_after_doc_comments)]
+
+use std::sync::{Arc, Mutex};
 
 // Re-export for UniFFI
 uniffi::include_scaffolding!("ash");
@@ -39,6 +42,32 @@ pub enum AshError {
     AuthenticationFailed,
     #[error("Invalid padding")]
     InvalidPadding,
+    #[error("Invalid ceremony state")]
+    InvalidCeremonyState,
+    #[error("Mnemonic not confirmed")]
+    MnemonicNotConfirmed,
+    #[error("Invalid ceremony payload")]
+    InvalidCeremonyPayload,
+    #[error("Invalid top-up offset")]
+    InvalidTopUpOffset,
+    #[error("Top-up pad mismatch")]
+    TopUpPadMismatch,
+    #[error("Invalid pad state")]
+    InvalidPadState,
+    #[error("Pad state rollback")]
+    PadStateRollback,
+    #[error("Pad counters rolled back")]
+    PadRollback,
+    #[error("Pad range reused")]
+    PadRangeReuse,
+    #[error("Invalid consumption journal")]
+    InvalidJournal,
+    #[error("Message already received")]
+    ReplayedMessage,
+    #[error("Key material wiped")]
+    KeyWiped,
+    #[error("Invalid receive window")]
+    InvalidReceiveWindow,
 }
 
 impl From<ash_core::Error> for AshError {
@@ -51,10 +80,13 @@ impl From<ash_core::Error> for AshError {
             ash_core::Error::EmptyPayload => AshError::EmptyPayload,
             ash_core::Error::FountainBlockTooShort { .. } => AshError::FountainBlockTooShort,
             ash_core::Error::MetadataTooShort { .. } => AshError::MetadataTooShort,
-            ash_core::Error::UnsupportedMetadataVersion { .. } => AshError::UnsupportedMetadataVersion,
+            ash_core::Error::UnsupportedMetadataVersion { .. } => {
+                AshError::UnsupportedMetadataVersion
+            }
             ash_core::Error::MetadataUrlTooLong { .. } => AshError::MetadataUrlTooLong,
             ash_core::Error::InvalidMetadataUrl => AshError::InvalidMetadataUrl,
             ash_core::Error::PadTooSmallForTokens { .. } => AshError::PadTooSmallForTokens,
+            ash_core::Error::KeyWiped { .. } => AshError::KeyWiped,
             // Message auth errors
             ash_core::Error::AuthenticationFailed => AshError::AuthenticationFailed,
             ash_core::Error::PayloadTooLarge { .. } => AshError::EmptyPayload,
@@ -63,6 +95,23 @@ impl From<ash_core::Error> for AshError {
             ash_core::Error::InvalidMessageType { .. } => AshError::InvalidMetadataUrl,
             ash_core::Error::FrameLengthMismatch { .. } => AshError::LengthMismatch,
             ash_core::Error::InvalidPadding { .. } => AshError::InvalidPadding,
+            // Ceremony errors
+            ash_core::Error::InvalidCeremonyState { .. } => AshError::InvalidCeremonyState,
+            ash_core::Error::MnemonicNotConfirmed => AshError::MnemonicNotConfirmed,
+            ash_core::Error::InvalidCeremonyPayload => AshError::InvalidCeremonyPayload,
+            // Top-up errors
+            ash_core::Error::InvalidTopUpOffset { .. } => AshError::InvalidTopUpOffset,
+            ash_core::Error::TopUpPadMismatch { .. } => AshError::TopUpPadMismatch,
+            // Pad state errors
+            ash_core::Error::InvalidPadState { .. } => AshError::InvalidPadState,
+            ash_core::Error::PadStateRollback { .. } => AshError::PadStateRollback,
+            // Journal errors
+            ash_core::Error::PadRollback { .. } => AshError::PadRollback,
+            ash_core::Error::PadRangeReuse { .. } => AshError::PadRangeReuse,
+            ash_core::Error::InvalidJournal { .. } => AshError::InvalidJournal,
+            // Replay errors
+            ash_core::Error::ReplayedMessage { .. } => AshError::ReplayedMessage,
+            ash_core::Error::InvalidReceiveWindow { .. } => AshError::InvalidReceiveWindow,
         }
     }
 }
@@ -132,6 +181,15 @@ impl From<Role> for ash_core::Role {
     }
 }
 
+impl From<ash_core::Role> for Role {
+    fn from(role: ash_core::Role) -> Self {
+        match role {
+            ash_core::Role::Initiator => Role::Initiator,
+            ash_core::Role::Responder => Role::Responder,
+        }
+    }
+}
+
 // === Transfer Method Enum ===
 
 /// Transfer method for QR ceremony.
@@ -168,6 +226,137 @@ impl From<ash_core::frame::TransferMethod> for TransferMethod {
     }
 }
 
+// === Capacity Forecast ===
+
+/// Padding applied to plaintext before encryption
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingPolicy {
+    Unpadded,
+    Padded,
+}
+
+impl From<PaddingPolicy> for ash_core::PaddingPolicy {
+    fn from(policy: PaddingPolicy) -> Self {
+        match policy {
+            PaddingPolicy::Unpadded => ash_core::PaddingPolicy::Unpadded,
+            PaddingPolicy::Padded => ash_core::PaddingPolicy::Padded,
+        }
+    }
+}
+
+/// Settings for forecasting a live pad's capacity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastConfig {
+    pub avg_message_bytes: u32,
+    pub padding: PaddingPolicy,
+    pub reserve_messages: u32,
+    pub control_message_bytes: u32,
+    pub warn_thresholds: Vec<u32>,
+}
+
+impl From<ForecastConfig> for ash_core::ForecastConfig {
+    fn from(c: ForecastConfig) -> Self {
+        ash_core::ForecastConfig::new(c.avg_message_bytes as usize)
+            .with_padding(c.padding.into())
+            .with_reserve(
+                c.reserve_messages as usize,
+                c.control_message_bytes as usize,
+            )
+            .with_warn_thresholds(c.warn_thresholds.into_iter().map(|t| t as usize).collect())
+    }
+}
+
+/// Capacity forecast for a pad in use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityForecast {
+    pub available_bytes: u64,
+    pub reserve_bytes: u64,
+    pub text_budget_bytes: u64,
+    pub max_messages_ours: u64,
+    pub messages_left_ours: u64,
+    pub messages_left_peer: u64,
+    pub warning: Option<u64>,
+}
+
+impl From<ash_core::CapacityForecast> for CapacityForecast {
+    fn from(f: ash_core::CapacityForecast) -> Self {
+        Self {
+            available_bytes: f.available_bytes as u64,
+            reserve_bytes: f.reserve_bytes as u64,
+            text_budget_bytes: f.text_budget_bytes as u64,
+            max_messages_ours: f.max_messages_ours as u64,
+            messages_left_ours: f.messages_left_ours as u64,
+            messages_left_peer: f.messages_left_peer as u64,
+            warning: f.warning.map(|t| t as u64),
+        }
+    }
+}
+
+// === Secret Bytes ===
+
+/// Key material kept in Rust memory (thread-safe wrapper around
+/// ash_core::SecretBytes for FFI)
+///
+/// Wiped by `wipe` or when the foreign handle is released.
+pub struct SecretBytes {
+    inner: Mutex<ash_core::SecretBytes>,
+}
+
+impl SecretBytes {
+    /// Take ownership of the FFI copy of `bytes`
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self::wrap(ash_core::SecretBytes::new(bytes))
+    }
+
+    fn wrap(secret: ash_core::SecretBytes) -> Self {
+        Self {
+            inner: Mutex::new(secret),
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        let secret = self.inner.lock().unwrap();
+        secret.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let secret = self.inner.lock().unwrap();
+        secret.is_empty()
+    }
+
+    /// Plain copy of the bytes; the foreign copy is not wiped
+    pub fn copy_bytes(&self) -> Vec<u8> {
+        let secret = self.inner.lock().unwrap();
+        secret.to_vec()
+    }
+
+    /// Copy `len` bytes at `offset` into a new handle
+    pub fn slice(&self, offset: u64, len: u64) -> Result<Arc<Self>, AshError> {
+        let secret = self.inner.lock().unwrap();
+        let range = usize::try_from(offset)
+            .ok()
+            .zip(usize::try_from(len).ok())
+            .and_then(|(offset, len)| Some(offset..offset.checked_add(len)?))
+            .filter(|range| range.end <= secret.len())
+            .ok_or(AshError::LengthMismatch)?;
+        Ok(Arc::new(Self::wrap(ash_core::SecretBytes::from_slice(
+            &secret[range],
+        ))))
+    }
+
+    /// Wipe the bytes now instead of when the handle is released
+    pub fn wipe(&self) {
+        let mut secret = self.inner.lock().unwrap();
+        // The replaced buffer is wiped on drop
+        *secret = ash_core::SecretBytes::new(Vec::new());
+    }
+
+    fn with_bytes<T>(&self, f: impl FnOnce(&[u8]) -> T) -> T {
+        let secret = self.inner.lock().unwrap();
+        f(&secret)
+    }
+}
+
 // === Pad Wrapper ===
 
 /// Thread-safe wrapper around ash_core::Pad for FFI
@@ -179,6 +368,7 @@ impl Pad {
     /// Create pad from entropy bytes with a specific size.
     /// The entropy length must match the specified pad size.
     pub fn from_entropy(entropy: Vec<u8>, size: PadSize) -> Result<Self, AshError> {
+        let entropy = ash_core::SecretBytes::new(entropy);
         let pad = ash_core::Pad::new(&entropy, size.into())?;
         Ok(Self {
             inner: Mutex::new(pad),
@@ -202,8 +392,14 @@ impl Pad {
     }
 
     pub fn consume(&self, n: u32, role: Role) -> Result<Vec<u8>, AshError> {
+        Ok(self.consume_secret(n, role)?.copy_bytes())
+    }
+
+    pub fn consume_secret(&self, n: u32, role: Role) -> Result<Arc<SecretBytes>, AshError> {
         let mut pad = self.inner.lock().unwrap();
-        Ok(pad.consume(n as usize, role.into())?)
+        Ok(Arc::new(SecretBytes::wrap(
+            pad.consume(n as usize, role.into())?,
+        )))
     }
 
     pub fn remaining(&self) -> u64 {
@@ -241,6 +437,14 @@ impl Pad {
         pad.as_bytes().to_vec()
     }
 
+    /// Copy of the whole pad, kept in Rust memory
+    pub fn as_secret_bytes(&self) -> Arc<SecretBytes> {
+        let pad = self.inner.lock().unwrap();
+        Arc::new(SecretBytes::wrap(ash_core::SecretBytes::from_slice(
+            pad.as_bytes(),
+        )))
+    }
+
     pub fn can_send(&self, length: u32, role: Role) -> bool {
         let pad = self.inner.lock().unwrap();
         pad.can_send(length as usize, role.into())
@@ -251,9 +455,13 @@ impl Pad {
         pad.available_for_sending(role.into()) as u64
     }
 
-    pub fn update_peer_consumption(&self, peer_role: Role, new_consumed: u64) {
+    pub fn update_peer_consumption(
+        &self,
+        peer_role: Role,
+        new_consumed: u64,
+    ) -> Result<(), AshError> {
         let mut pad = self.inner.lock().unwrap();
-        pad.update_peer_consumption(peer_role.into(), new_consumed as usize);
+        Ok(pad.update_peer_consumption(peer_role.into(), new_consumed as usize)?)
     }
 
     pub fn next_send_offset(&self, role: Role) -> u64 {
@@ -261,6 +469,23 @@ impl Pad {
         pad.next_send_offset(role.into()) as u64
     }
 
+    pub fn send_position(&self, role: Role) -> u64 {
+        let pad = self.inner.lock().unwrap();
+        pad.send_position(role.into()) as u64
+    }
+
+    /// Forecast remaining capacity from the live consumption state.
+    pub fn forecast(&self, role: Role, config: ForecastConfig) -> CapacityForecast {
+        let pad = self.inner.lock().unwrap();
+        pad.forecast(role.into(), &config.into()).into()
+    }
+
+    /// Check if a normal message fits without touching the burn/control reserve.
+    pub fn can_send_text(&self, plaintext_len: u32, config: ForecastConfig) -> bool {
+        let pad = self.inner.lock().unwrap();
+        pad.can_send_text(plaintext_len as usize, &config.into())
+    }
+
     /// Securely zero bytes at a specific offset (for forward secrecy).
     ///
     /// When a message expires, this zeros the key material used to encrypt it,
@@ -269,6 +494,21 @@ impl Pad {
         let mut pad = self.inner.lock().unwrap();
         pad.zero_bytes_at(offset as usize, length as usize)
     }
+
+    /// Read the key material of a peer message without consuming it.
+    pub fn key_at(
+        &self,
+        offset: u64,
+        len: u64,
+        peer_role: Role,
+    ) -> Result<Arc<SecretBytes>, AshError> {
+        let pad = self.inner.lock().unwrap();
+        Ok(Arc::new(SecretBytes::wrap(pad.key_at(
+            offset as usize,
+            len as usize,
+            peer_role.into(),
+        )?)))
+    }
 }
 
 // === Ceremony Metadata Types ===
@@ -447,6 +687,722 @@ impl FountainFrameReceiver {
     }
 }
 
+// === Ceremony State Machines ===
+
+/// State of an initiator or responder ceremony
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CeremonyState {
+    Configuring,
+    Transferring,
+    VerifyingMnemonic,
+    Confirmed,
+    Finished,
+    Aborted,
+}
+
+impl From<ash_core::CeremonyState> for CeremonyState {
+    fn from(state: ash_core::CeremonyState) -> Self {
+        match state {
+            ash_core::CeremonyState::Configuring => CeremonyState::Configuring,
+            ash_core::CeremonyState::Transferring => CeremonyState::Transferring,
+            ash_core::CeremonyState::VerifyingMnemonic => CeremonyState::VerifyingMnemonic,
+            ash_core::CeremonyState::Confirmed => CeremonyState::Confirmed,
+            ash_core::CeremonyState::Finished => CeremonyState::Finished,
+            ash_core::CeremonyState::Aborted => CeremonyState::Aborted,
+        }
+    }
+}
+
+/// Everything needed to start messaging after a completed ceremony
+pub struct ConversationBundle {
+    pub role: Role,
+    pub pad: std::sync::Arc<Pad>,
+    pub metadata: CeremonyMetadata,
+    pub tokens: AuthTokens,
+}
+
+impl From<ash_core::ConversationBundle> for ConversationBundle {
+    fn from(b: ash_core::ConversationBundle) -> Self {
+        Self {
+            role: b.role.into(),
+            pad: std::sync::Arc::new(Pad {
+                inner: Mutex::new(b.pad),
+            }),
+            metadata: b.metadata.into(),
+            tokens: AuthTokens {
+                conversation_id: b.conversation_id,
+                auth_token: b.auth_token,
+                burn_token: b.burn_token,
+            },
+        }
+    }
+}
+
+fn words_to_strings(words: Vec<&'static str>) -> Vec<String> {
+    words.into_iter().map(|s| s.to_string()).collect()
+}
+
+/// Thread-safe initiator ceremony (creates the pad, displays QR codes)
+pub struct InitiatorCeremony {
+    inner: Mutex<ash_core::InitiatorCeremony>,
+}
+
+impl InitiatorCeremony {
+    /// Create a new ceremony in the configuring state
+    pub fn new(pad_size: PadSize) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::InitiatorCeremony::new(pad_size.into())),
+        }
+    }
+
+    pub fn state(&self) -> CeremonyState {
+        let ceremony = self.inner.lock().unwrap();
+        ceremony.state().into()
+    }
+
+    pub fn set_metadata(&self, metadata: CeremonyMetadata) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.set_metadata(metadata.into())?)
+    }
+
+    pub fn set_passphrase(&self, passphrase: String) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.set_passphrase(Some(&passphrase))?)
+    }
+
+    pub fn set_block_size(&self, block_size: u32) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.set_block_size(block_size as usize)?)
+    }
+
+    pub fn start_transfer(&self, entropy: Vec<u8>) -> Result<(), AshError> {
+        let entropy = ash_core::SecretBytes::new(entropy);
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.start_transfer(&entropy)?)
+    }
+
+    pub fn next_frame(&self) -> Result<Vec<u8>, AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.next_frame()?)
+    }
+
+    pub fn source_count(&self) -> u32 {
+        let ceremony = self.inner.lock().unwrap();
+        ceremony.source_count() as u32
+    }
+
+    pub fn finish_transfer(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.finish_transfer()?)
+    }
+
+    pub fn mnemonic(&self) -> Result<Vec<String>, AshError> {
+        let ceremony = self.inner.lock().unwrap();
+        Ok(words_to_strings(ceremony.mnemonic()?))
+    }
+
+    pub fn confirm_mnemonic(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.confirm_mnemonic()?)
+    }
+
+    pub fn reject_mnemonic(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.reject_mnemonic()?)
+    }
+
+    pub fn abort(&self) {
+        let mut ceremony = self.inner.lock().unwrap();
+        ceremony.abort();
+    }
+
+    pub fn finish(&self) -> Result<ConversationBundle, AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.finish()?.into())
+    }
+}
+
+/// Thread-safe responder ceremony (scans QR codes, receives the pad)
+pub struct ResponderCeremony {
+    inner: Mutex<ash_core::ResponderCeremony>,
+}
+
+impl ResponderCeremony {
+    /// Create a new ceremony in the configuring state
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(ash_core::ResponderCeremony::new()),
+        }
+    }
+
+    pub fn state(&self) -> CeremonyState {
+        let ceremony = self.inner.lock().unwrap();
+        ceremony.state().into()
+    }
+
+    pub fn set_passphrase(&self, passphrase: String) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.set_passphrase(Some(&passphrase))?)
+    }
+
+    pub fn start_transfer(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.start_transfer()?)
+    }
+
+    pub fn add_frame(&self, frame_bytes: Vec<u8>) -> Result<bool, AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.add_frame(&frame_bytes)?)
+    }
+
+    pub fn progress(&self) -> f64 {
+        let ceremony = self.inner.lock().unwrap();
+        ceremony.progress()
+    }
+
+    pub fn metadata(&self) -> Option<CeremonyMetadata> {
+        let ceremony = self.inner.lock().unwrap();
+        ceremony.metadata().cloned().map(|m| m.into())
+    }
+
+    pub fn mnemonic(&self) -> Result<Vec<String>, AshError> {
+        let ceremony = self.inner.lock().unwrap();
+        Ok(words_to_strings(ceremony.mnemonic()?))
+    }
+
+    pub fn confirm_mnemonic(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.confirm_mnemonic()?)
+    }
+
+    pub fn reject_mnemonic(&self) -> Result<(), AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.reject_mnemonic()?)
+    }
+
+    pub fn abort(&self) {
+        let mut ceremony = self.inner.lock().unwrap();
+        ceremony.abort();
+    }
+
+    pub fn finish(&self) -> Result<ConversationBundle, AshError> {
+        let mut ceremony = self.inner.lock().unwrap();
+        Ok(ceremony.finish()?.into())
+    }
+}
+
+impl Default for ResponderCeremony {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// === Pad Top-Up ===
+
+/// Outcome of a completed top-up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopUpSummary {
+    pub insert_offset: u64,
+    pub segment_len: u64,
+    pub new_total_size: u64,
+}
+
+impl From<ash_core::TopUpSummary> for TopUpSummary {
+    fn from(s: ash_core::TopUpSummary) -> Self {
+        Self {
+            insert_offset: s.insert_offset as u64,
+            segment_len: s.segment_len as u64,
+            new_total_size: s.new_total_size as u64,
+        }
+    }
+}
+
+/// Thread-safe top-up sender (creates the new segment, displays QR codes)
+pub struct TopUpSender {
+    inner: Mutex<ash_core::TopUpSender>,
+}
+
+impl TopUpSender {
+    /// Create a new top-up in the configuring state
+    pub fn new(role: Role, segment_size: PadSize) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::TopUpSender::new(role.into(), segment_size.into())),
+        }
+    }
+
+    pub fn state(&self) -> CeremonyState {
+        let topup = self.inner.lock().unwrap();
+        topup.state().into()
+    }
+
+    pub fn set_passphrase(&self, passphrase: String) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.set_passphrase(Some(&passphrase))?)
+    }
+
+    pub fn set_block_size(&self, block_size: u32) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.set_block_size(block_size as usize)?)
+    }
+
+    pub fn set_transfer_method(&self, method: TransferMethod) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.set_transfer_method(method.into())?)
+    }
+
+    pub fn start_transfer(&self, pad: &Pad, entropy: Vec<u8>) -> Result<(), AshError> {
+        let entropy = ash_core::SecretBytes::new(entropy);
+        let mut topup = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        Ok(topup.start_transfer(&mut pad, &entropy)?)
+    }
+
+    pub fn next_frame(&self) -> Result<Vec<u8>, AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.next_frame()?)
+    }
+
+    pub fn finish_transfer(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.finish_transfer()?)
+    }
+
+    pub fn mnemonic(&self) -> Result<Vec<String>, AshError> {
+        let topup = self.inner.lock().unwrap();
+        Ok(words_to_strings(topup.mnemonic()?))
+    }
+
+    pub fn confirm_mnemonic(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.confirm_mnemonic()?)
+    }
+
+    pub fn reject_mnemonic(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.reject_mnemonic()?)
+    }
+
+    pub fn abort(&self) {
+        let mut topup = self.inner.lock().unwrap();
+        topup.abort();
+    }
+
+    pub fn finish(&self, pad: &Pad) -> Result<TopUpSummary, AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        Ok(topup.finish(&mut pad)?.into())
+    }
+}
+
+/// Thread-safe top-up receiver (scans QR codes, verifies the segment)
+pub struct TopUpReceiver {
+    inner: Mutex<ash_core::TopUpReceiver>,
+}
+
+impl TopUpReceiver {
+    /// Create a new top-up in the configuring state
+    pub fn new(role: Role) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::TopUpReceiver::new(role.into())),
+        }
+    }
+
+    pub fn state(&self) -> CeremonyState {
+        let topup = self.inner.lock().unwrap();
+        topup.state().into()
+    }
+
+    pub fn set_passphrase(&self, passphrase: String) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.set_passphrase(Some(&passphrase))?)
+    }
+
+    pub fn start_transfer(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.start_transfer()?)
+    }
+
+    pub fn add_frame(&self, frame_bytes: Vec<u8>, pad: &Pad) -> Result<bool, AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(topup.add_frame(&frame_bytes, &pad)?)
+    }
+
+    pub fn progress(&self) -> f64 {
+        let topup = self.inner.lock().unwrap();
+        topup.progress()
+    }
+
+    pub fn mnemonic(&self) -> Result<Vec<String>, AshError> {
+        let topup = self.inner.lock().unwrap();
+        Ok(words_to_strings(topup.mnemonic()?))
+    }
+
+    pub fn confirm_mnemonic(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.confirm_mnemonic()?)
+    }
+
+    pub fn reject_mnemonic(&self) -> Result<(), AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        Ok(topup.reject_mnemonic()?)
+    }
+
+    pub fn abort(&self) {
+        let mut topup = self.inner.lock().unwrap();
+        topup.abort();
+    }
+
+    pub fn finish(&self, pad: &Pad) -> Result<TopUpSummary, AshError> {
+        let mut topup = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        Ok(topup.finish(&mut pad)?.into())
+    }
+}
+
+// === Consumption Journal ===
+
+/// Hash-chained record of consumed pad ranges (thread-safe wrapper)
+pub struct ConsumptionJournal {
+    inner: Mutex<ash_core::ConsumptionJournal>,
+}
+
+impl ConsumptionJournal {
+    /// Create an empty journal for our role
+    pub fn new(role: Role) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::ConsumptionJournal::new(role.into())),
+        }
+    }
+
+    /// Decode a stored journal, verifying the hash chain
+    pub fn decode(bytes: Vec<u8>) -> Result<Self, AshError> {
+        Ok(Self {
+            inner: Mutex::new(ash_core::ConsumptionJournal::decode(&bytes)?),
+        })
+    }
+
+    pub fn role(&self) -> Role {
+        let journal = self.inner.lock().unwrap();
+        journal.role().into()
+    }
+
+    pub fn head(&self) -> Vec<u8> {
+        let journal = self.inner.lock().unwrap();
+        journal.head().to_vec()
+    }
+
+    pub fn own_consumed(&self) -> u64 {
+        let journal = self.inner.lock().unwrap();
+        journal.own_consumed() as u64
+    }
+
+    pub fn peer_consumed(&self) -> u64 {
+        let journal = self.inner.lock().unwrap();
+        journal.peer_consumed() as u64
+    }
+
+    pub fn consume(&self, pad: &Pad, n: u32) -> Result<Arc<SecretBytes>, AshError> {
+        let mut journal = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        Ok(Arc::new(SecretBytes::wrap(
+            journal.consume(&mut pad, n as usize)?,
+        )))
+    }
+
+    pub fn record_received(&self, pad: &Pad, offset: u64, len: u64) -> Result<(), AshError> {
+        let mut journal = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        Ok(journal.record_received(&mut pad, offset as usize, len as usize)?)
+    }
+
+    pub fn check(&self, pad: &Pad) -> Result<(), AshError> {
+        let journal = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(journal.check(&pad)?)
+    }
+
+    pub fn check_send(&self, pad: &Pad, n: u32) -> Result<(), AshError> {
+        let journal = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(journal.check_send(&pad, n as usize)?)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let journal = self.inner.lock().unwrap();
+        journal.encode()
+    }
+}
+
+// === Receive Window ===
+
+/// Peer pad range not yet received (lost or still in flight)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiveGap {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Accepted peer message ranges for replay rejection (thread-safe wrapper)
+pub struct ReceiveWindow {
+    inner: Mutex<ash_core::ReceiveWindow>,
+}
+
+impl ReceiveWindow {
+    /// Create an empty window for our role
+    pub fn new(role: Role) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::ReceiveWindow::new(role.into())),
+        }
+    }
+
+    /// Decode a stored window
+    pub fn decode(bytes: Vec<u8>) -> Result<Self, AshError> {
+        Ok(Self {
+            inner: Mutex::new(ash_core::ReceiveWindow::decode(&bytes)?),
+        })
+    }
+
+    pub fn role(&self) -> Role {
+        let window = self.inner.lock().unwrap();
+        window.role().into()
+    }
+
+    pub fn highest(&self) -> u64 {
+        let window = self.inner.lock().unwrap();
+        window.highest() as u64
+    }
+
+    pub fn check(&self, pad: &Pad, offset: u64, len: u64) -> Result<(), AshError> {
+        let window = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(window.check(&pad, offset as usize, len as usize)?)
+    }
+
+    pub fn accept(&self, pad: &Pad, offset: u64, len: u64) -> Result<(), AshError> {
+        let mut window = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(window.accept(&pad, offset as usize, len as usize)?)
+    }
+
+    pub fn gaps(&self) -> Vec<ReceiveGap> {
+        let window = self.inner.lock().unwrap();
+        window
+            .gaps()
+            .into_iter()
+            .map(|(start, end)| ReceiveGap {
+                start: start as u64,
+                end: end as u64,
+            })
+            .collect()
+    }
+
+    /// Verify and decrypt a peer message, in any arrival order
+    pub fn receive(&self, pad: &Pad, encoded_frame: Vec<u8>) -> Result<DecryptedMessage, AshError> {
+        let mut window = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        let frame = ash_core::message::MessageFrame::decode(&encoded_frame)?;
+        let plaintext = window.receive(&mut pad, &frame)?;
+        Ok(DecryptedMessage {
+            plaintext: plaintext.into_vec(),
+            msg_type: frame.msg_type.to_byte(),
+            tag: frame.tag.to_vec(),
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let window = self.inner.lock().unwrap();
+        window.encode()
+    }
+}
+
+// === Key Retention ===
+
+/// When recorded key material is wiped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub wipe_on_delivery: bool,
+    pub disappear_after: Option<u64>,
+    pub expire_after: Option<u64>,
+}
+
+impl From<RetentionPolicy> for ash_core::RetentionPolicy {
+    fn from(p: RetentionPolicy) -> Self {
+        Self {
+            wipe_on_delivery: p.wipe_on_delivery,
+            disappear_after: p.disappear_after,
+            expire_after: p.expire_after,
+        }
+    }
+}
+
+impl From<ash_core::RetentionPolicy> for RetentionPolicy {
+    fn from(p: ash_core::RetentionPolicy) -> Self {
+        Self {
+            wipe_on_delivery: p.wipe_on_delivery,
+            disappear_after: p.disappear_after,
+            expire_after: p.expire_after,
+        }
+    }
+}
+
+/// Why a message's key material was wiped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeReason {
+    Delivered,
+    Disappeared,
+    Expired,
+    Manual,
+}
+
+impl From<ash_core::WipeReason> for WipeReason {
+    fn from(reason: ash_core::WipeReason) -> Self {
+        match reason {
+            ash_core::WipeReason::Delivered => WipeReason::Delivered,
+            ash_core::WipeReason::Disappeared => WipeReason::Disappeared,
+            ash_core::WipeReason::Expired => WipeReason::Expired,
+            ash_core::WipeReason::Manual => WipeReason::Manual,
+        }
+    }
+}
+
+/// A message whose key material was wiped by a sweep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WipedMessage {
+    pub id: u64,
+    pub reason: WipeReason,
+}
+
+/// Pad range of a message whose key is still recoverable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoverableRange {
+    pub id: u64,
+    pub direction: Role,
+    pub start: u64,
+    pub end: u64,
+    pub due_at: Option<u64>,
+}
+
+/// Which consumed key material is still recoverable from the pad
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionSummary {
+    pub recoverable: Vec<RecoverableRange>,
+    pub recoverable_bytes: u64,
+    pub wiped_bytes: u64,
+    pub verified: bool,
+}
+
+impl From<ash_core::RetentionSummary> for RetentionSummary {
+    fn from(s: ash_core::RetentionSummary) -> Self {
+        Self {
+            recoverable: s
+                .recoverable
+                .into_iter()
+                .map(|r| RecoverableRange {
+                    id: r.id,
+                    direction: r.direction.into(),
+                    start: r.start as u64,
+                    end: r.end as u64,
+                    due_at: r.due_at,
+                })
+                .collect(),
+            recoverable_bytes: s.recoverable_bytes as u64,
+            wiped_bytes: s.wiped_bytes as u64,
+            verified: s.verified,
+        }
+    }
+}
+
+/// Records message key ranges and wipes them by policy (thread-safe wrapper)
+pub struct RetentionManager {
+    inner: Mutex<ash_core::RetentionManager>,
+}
+
+impl RetentionManager {
+    /// Create an empty manager for our role
+    pub fn new(role: Role, policy: RetentionPolicy) -> Self {
+        Self {
+            inner: Mutex::new(ash_core::RetentionManager::new(role.into(), policy.into())),
+        }
+    }
+
+    pub fn policy(&self) -> RetentionPolicy {
+        let retention = self.inner.lock().unwrap();
+        (*retention.policy()).into()
+    }
+
+    pub fn set_policy(&self, policy: RetentionPolicy) {
+        let mut retention = self.inner.lock().unwrap();
+        retention.set_policy(policy.into());
+    }
+
+    pub fn record_sent(
+        &self,
+        pad: &Pad,
+        id: u64,
+        offset: u64,
+        len: u64,
+        now: u64,
+    ) -> Result<(), AshError> {
+        let mut retention = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(retention.record_sent(&pad, id, offset as usize, len as usize, now)?)
+    }
+
+    pub fn record_received(
+        &self,
+        pad: &Pad,
+        id: u64,
+        offset: u64,
+        len: u64,
+        now: u64,
+    ) -> Result<(), AshError> {
+        let mut retention = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        Ok(retention.record_received(&pad, id, offset as usize, len as usize, now)?)
+    }
+
+    pub fn mark_delivered(&self, id: u64, now: u64) -> bool {
+        let mut retention = self.inner.lock().unwrap();
+        retention.mark_delivered(id, now)
+    }
+
+    pub fn mark_viewed(&self, id: u64, now: u64) -> bool {
+        let mut retention = self.inner.lock().unwrap();
+        retention.mark_viewed(id, now)
+    }
+
+    pub fn wipe(&self, pad: &Pad, id: u64) -> bool {
+        let mut retention = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        retention.wipe(&mut pad, id)
+    }
+
+    pub fn sweep(&self, pad: &Pad, now: u64) -> Vec<WipedMessage> {
+        let mut retention = self.inner.lock().unwrap();
+        let mut pad = pad.inner.lock().unwrap();
+        retention
+            .sweep(&mut pad, now)
+            .into_iter()
+            .map(|w| WipedMessage {
+                id: w.id,
+                reason: w.reason.into(),
+            })
+            .collect()
+    }
+
+    pub fn next_due(&self) -> Option<u64> {
+        let retention = self.inner.lock().unwrap();
+        retention.next_due()
+    }
+
+    pub fn summary(&self, pad: &Pad) -> RetentionSummary {
+        let retention = self.inner.lock().unwrap();
+        let pad = pad.inner.lock().unwrap();
+        retention.summary(&pad).into()
+    }
+}
+
 // === Free Functions ===
 
 /// Create a fountain frame generator for ceremony.
@@ -459,6 +1415,7 @@ pub fn create_fountain_generator(
     passphrase: String,
     method: TransferMethod,
 ) -> Result<std::sync::Arc<FountainFrameGenerator>, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     let core_metadata: ash_core::CeremonyMetadata = metadata.into();
     let generator = ash_core::frame::create_fountain_ceremony(
         &core_metadata,
@@ -496,26 +1453,67 @@ pub fn encrypt_authenticated(
     encryption_key: Vec<u8>,
     plaintext: Vec<u8>,
     msg_type: u8,
+) -> Result<Vec<u8>, AshError> {
+    encrypt_authenticated_secret(
+        &SecretBytes::from_bytes(auth_key),
+        &SecretBytes::from_bytes(encryption_key),
+        plaintext,
+        msg_type,
+    )
+}
+
+/// Encrypt plaintext with Wegman-Carter authentication, with the keys held
+/// as [`SecretBytes`].
+pub fn encrypt_authenticated_secret(
+    auth_key: &SecretBytes,
+    encryption_key: &SecretBytes,
+    plaintext: Vec<u8>,
+    msg_type: u8,
+) -> Result<Vec<u8>, AshError> {
+    encrypt_frame(None, auth_key, encryption_key, plaintext, msg_type)
+}
+
+/// Encrypt into a version 2 frame that carries the sender's pad offset in
+/// its authenticated header, for [`ReceiveWindow::receive`].
+pub fn encrypt_authenticated_at(
+    offset: u64,
+    auth_key: &SecretBytes,
+    encryption_key: &SecretBytes,
+    plaintext: Vec<u8>,
+    msg_type: u8,
+) -> Result<Vec<u8>, AshError> {
+    encrypt_frame(Some(offset), auth_key, encryption_key, plaintext, msg_type)
+}
+
+fn encrypt_frame(
+    offset: Option<u64>,
+    auth_key: &SecretBytes,
+    encryption_key: &SecretBytes,
+    plaintext: Vec<u8>,
+    msg_type: u8,
 ) -> Result<Vec<u8>, AshError> {
     use ash_core::mac::AuthKey;
     use ash_core::message::{MessageFrame, MessageType};
 
+    // Wipe the plaintext copy handed over by the caller on return
+    let plaintext = ash_core::SecretBytes::new(plaintext);
+
     // Validate auth key size
-    if auth_key.len() != ash_core::mac::AUTH_KEY_SIZE {
+    if auth_key.len() != ash_core::mac::AUTH_KEY_SIZE as u64 {
         return Err(AshError::LengthMismatch);
     }
 
     // Parse message type
-    let message_type = MessageType::from_byte(msg_type)
-        .ok_or(AshError::InvalidMetadataUrl)?; // Reuse error for invalid type
+    let message_type = MessageType::from_byte(msg_type).ok_or(AshError::InvalidMetadataUrl)?; // Reuse error for invalid type
 
     // Create auth key
-    let auth_key_array: [u8; 64] = auth_key.try_into()
-        .map_err(|_| AshError::LengthMismatch)?;
-    let auth = AuthKey::from_bytes(&auth_key_array);
+    let auth = auth_key.with_bytes(AuthKey::from_slice);
 
     // Encrypt and authenticate
-    let frame = MessageFrame::encrypt(message_type, &plaintext, &encryption_key, &auth)?;
+    let frame = encryption_key.with_bytes(|key| match offset {
+        None => MessageFrame::encrypt(message_type, &plaintext, key, &auth),
+        Some(offset) => MessageFrame::encrypt_at(message_type, offset, &plaintext, key, &auth),
+    })?;
 
     // Encode to wire format
     Ok(frame.encode())
@@ -529,28 +1527,40 @@ pub fn decrypt_authenticated(
     auth_key: Vec<u8>,
     encryption_key: Vec<u8>,
     encoded_frame: Vec<u8>,
+) -> Result<DecryptedMessage, AshError> {
+    decrypt_authenticated_secret(
+        &SecretBytes::from_bytes(auth_key),
+        &SecretBytes::from_bytes(encryption_key),
+        encoded_frame,
+    )
+}
+
+/// Decrypt and verify an authenticated message, with the keys held as
+/// [`SecretBytes`].
+pub fn decrypt_authenticated_secret(
+    auth_key: &SecretBytes,
+    encryption_key: &SecretBytes,
+    encoded_frame: Vec<u8>,
 ) -> Result<DecryptedMessage, AshError> {
     use ash_core::mac::AuthKey;
     use ash_core::message::MessageFrame;
 
     // Validate auth key size
-    if auth_key.len() != ash_core::mac::AUTH_KEY_SIZE {
+    if auth_key.len() != ash_core::mac::AUTH_KEY_SIZE as u64 {
         return Err(AshError::LengthMismatch);
     }
 
     // Create auth key
-    let auth_key_array: [u8; 64] = auth_key.try_into()
-        .map_err(|_| AshError::LengthMismatch)?;
-    let auth = AuthKey::from_bytes(&auth_key_array);
+    let auth = auth_key.with_bytes(AuthKey::from_slice);
 
     // Decode frame
     let frame = MessageFrame::decode(&encoded_frame)?;
 
     // Verify and decrypt (verification happens first internally)
-    let plaintext = frame.decrypt(&encryption_key, &auth)?;
+    let plaintext = encryption_key.with_bytes(|key| frame.decrypt(key, &auth))?;
 
     Ok(DecryptedMessage {
-        plaintext,
+        plaintext: plaintext.into_vec(),
         msg_type: frame.msg_type.to_byte(),
         tag: frame.tag.to_vec(),
     })
@@ -563,10 +1573,16 @@ pub fn decrypt_authenticated(
 /// WARNING: No authentication - use encrypt_authenticated for new code.
 /// This raw XOR function is exposed for legacy compatibility.
 pub fn encrypt(key: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, AshError> {
+    let key = ash_core::SecretBytes::new(key);
+    let plaintext = ash_core::SecretBytes::new(plaintext);
     if key.len() != plaintext.len() {
         return Err(AshError::LengthMismatch);
     }
-    Ok(key.iter().zip(plaintext.iter()).map(|(k, p)| k ^ p).collect())
+    Ok(key
+        .iter()
+        .zip(plaintext.iter())
+        .map(|(k, p)| k ^ p)
+        .collect())
 }
 
 /// Decrypt ciphertext using OTP (XOR).
@@ -574,14 +1590,20 @@ pub fn encrypt(key: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, AshError> {
 /// WARNING: No authentication - use decrypt_authenticated for new code.
 /// This raw XOR function is exposed for legacy compatibility.
 pub fn decrypt(key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Vec<u8>, AshError> {
+    let key = ash_core::SecretBytes::new(key);
     if key.len() != ciphertext.len() {
         return Err(AshError::LengthMismatch);
     }
-    Ok(key.iter().zip(ciphertext.iter()).map(|(k, c)| k ^ c).collect())
+    Ok(key
+        .iter()
+        .zip(ciphertext.iter())
+        .map(|(k, c)| k ^ c)
+        .collect())
 }
 
 /// Generate 6-word mnemonic checksum
 pub fn generate_mnemonic(pad_bytes: Vec<u8>) -> Vec<String> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     ash_core::mnemonic::generate_default(&pad_bytes)
         .into_iter()
         .map(|s| s.to_string())
@@ -590,6 +1612,7 @@ pub fn generate_mnemonic(pad_bytes: Vec<u8>) -> Vec<String> {
 
 /// Generate mnemonic with custom word count
 pub fn generate_mnemonic_with_count(pad_bytes: Vec<u8>, word_count: u32) -> Vec<String> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     ash_core::mnemonic::generate(&pad_bytes, word_count as usize)
         .into_iter()
         .map(|s| s.to_string())
@@ -613,23 +1636,26 @@ pub fn get_max_passphrase_length() -> u32 {
 
 /// Derive conversation ID from pad bytes
 pub fn derive_conversation_id(pad_bytes: Vec<u8>) -> Result<String, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     Ok(ash_core::auth::derive_conversation_id(&pad_bytes)?)
 }
 
 /// Derive auth token from pad bytes
 pub fn derive_auth_token(pad_bytes: Vec<u8>) -> Result<String, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     Ok(ash_core::auth::derive_auth_token(&pad_bytes)?)
 }
 
 /// Derive burn token from pad bytes
 pub fn derive_burn_token(pad_bytes: Vec<u8>) -> Result<String, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
     Ok(ash_core::auth::derive_burn_token(&pad_bytes)?)
 }
 
 /// Derive all tokens at once
 pub fn derive_all_tokens(pad_bytes: Vec<u8>) -> Result<AuthTokens, AshError> {
-    let (conversation_id, auth_token, burn_token) =
-        ash_core::auth::derive_all_tokens(&pad_bytes)?;
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
+    let (conversation_id, auth_token, burn_token) = ash_core::auth::derive_all_tokens(&pad_bytes)?;
     Ok(AuthTokens {
         conversation_id,
         auth_token,
@@ -637,8 +1663,30 @@ pub fn derive_all_tokens(pad_bytes: Vec<u8>) -> Result<AuthTokens, AshError> {
     })
 }
 
+/// Derive the conversation ID for a registration that carries a proof
+pub fn derive_registered_conversation_id(pad_bytes: Vec<u8>) -> Result<String, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
+    Ok(ash_core::auth::derive_registered_conversation_id(
+        &pad_bytes,
+    )?)
+}
+
+/// Derive the registration proof sent when registering a conversation
+pub fn derive_registration_proof(pad_bytes: Vec<u8>) -> Result<String, AshError> {
+    let pad_bytes = ash_core::SecretBytes::new(pad_bytes);
+    Ok(ash_core::auth::derive_registration_proof(&pad_bytes)?)
+}
+
+/// Hash a token as sent to the relay at registration
+pub fn hash_token(token: String) -> String {
+    ash_core::auth::hash_token(&token)
+}
+
 /// Securely zero a byte array using volatile writes.
 /// This prevents the compiler from optimizing away the zeroing.
+///
+/// Only the copy passed across the FFI boundary is zeroed; the caller's
+/// own array is left as it was.
 pub fn secure_zero_bytes(mut data: Vec<u8>) {
     // Use volatile writes to prevent optimization (same pattern as pad.rs)
     for byte in data.iter_mut() {
@@ -651,6 +1699,70 @@ pub fn secure_zero_bytes(mut data: Vec<u8>) {
     std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
 }
 
+// === Sealed Pad State ===
+
+/// Pad restored from a sealed container
+pub struct PadState {
+    pub role: Role,
+    pub generation: u64,
+    pub pad: std::sync::Arc<Pad>,
+}
+
+/// Seal pad state under a 32-byte wrapping key with a fresh 12-byte nonce
+pub fn seal_pad_state(
+    pad: &Pad,
+    role: Role,
+    generation: u64,
+    wrapping_key: Vec<u8>,
+    nonce: Vec<u8>,
+) -> Result<Vec<u8>, AshError> {
+    let wrapping_key = ash_core::SecretBytes::new(wrapping_key);
+    let wrapping_key: &[u8; 32] = wrapping_key[..]
+        .try_into()
+        .map_err(|_| AshError::LengthMismatch)?;
+    let nonce: [u8; 12] = nonce.try_into().map_err(|_| AshError::LengthMismatch)?;
+    let pad = pad.inner.lock().unwrap();
+    Ok(ash_core::seal_pad_state(
+        &pad,
+        role.into(),
+        generation,
+        wrapping_key,
+        &nonce,
+    ))
+}
+
+/// Open a sealed pad state, rejecting generations older than `min_generation`
+pub fn open_pad_state(
+    sealed: Vec<u8>,
+    wrapping_key: Vec<u8>,
+    min_generation: u64,
+) -> Result<PadState, AshError> {
+    let wrapping_key = ash_core::SecretBytes::new(wrapping_key);
+    let wrapping_key: &[u8; 32] = wrapping_key[..]
+        .try_into()
+        .map_err(|_| AshError::LengthMismatch)?;
+    let state = ash_core::open_pad_state(&sealed, wrapping_key, min_generation)?;
+    Ok(PadState {
+        role: state.role.into(),
+        generation: state.generation,
+        pad: std::sync::Arc::new(Pad {
+            inner: Mutex::new(state.pad),
+        }),
+    })
+}
+
+// === Key Retention Functions ===
+
+/// Retention policy matching the conversation settings from the ceremony
+pub fn retention_policy_from_metadata(metadata: CeremonyMetadata) -> RetentionPolicy {
+    let metadata = ash_core::CeremonyMetadata {
+        ttl_seconds: metadata.ttl_seconds,
+        disappearing_messages_seconds: metadata.disappearing_messages_seconds,
+        ..Default::default()
+    };
+    ash_core::RetentionPolicy::from_metadata(&metadata).into()
+}
+
 // === Pad Calculator Functions ===
 
 /// Calculate source blocks (K) for given pad size and block size.
@@ -661,7 +1773,7 @@ pub fn calculate_source_blocks(pad_bytes: u64, block_size: u32) -> u32 {
     if block_size == 0 {
         return 0;
     }
-    ((total_data + block_size - 1) / block_size) as u32
+    total_data.div_ceil(block_size) as u32
 }
 
 /// Calculate expected frames needed for successful transfer.
@@ -678,7 +1790,11 @@ pub fn calculate_redundancy_blocks(source_blocks: u32, method: TransferMethod) -
 }
 
 /// Calculate total frames to pre-generate (source + redundancy).
-pub fn calculate_frames_to_generate(pad_bytes: u64, block_size: u32, method: TransferMethod) -> u32 {
+pub fn calculate_frames_to_generate(
+    pad_bytes: u64,
+    block_size: u32,
+    method: TransferMethod,
+) -> u32 {
     let source_blocks = calculate_source_blocks(pad_bytes, block_size);
     let redundancy = calculate_redundancy_blocks(source_blocks, method);
     source_blocks + redundancy
@@ -713,6 +1829,194 @@ mod tests {
         assert_eq!(pad.remaining(), size.bytes() - 100);
     }
 
+    #[test]
+    fn test_secret_bytes() {
+        let pad = Pad::from_bytes((0..100).collect());
+        let key = pad.consume_secret(10, Role::Initiator).unwrap();
+        assert_eq!(key.copy_bytes(), (0..10).collect::<Vec<u8>>());
+
+        let tail = key.slice(8, 2).unwrap();
+        assert_eq!(tail.copy_bytes(), vec![8, 9]);
+        assert!(matches!(key.slice(8, 3), Err(AshError::LengthMismatch)));
+        assert!(matches!(
+            key.slice(u64::MAX, 2),
+            Err(AshError::LengthMismatch)
+        ));
+
+        key.wipe();
+        assert!(key.is_empty());
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn test_pad_forecast() {
+        let pad = Pad::from_bytes(vec![0u8; 10_000]);
+        let config = ForecastConfig {
+            avg_message_bytes: 33,
+            padding: PaddingPolicy::Padded,
+            reserve_messages: 0,
+            control_message_bytes: 0,
+            warn_thresholds: vec![60],
+        };
+
+        let forecast = pad.forecast(Role::Initiator, config.clone());
+        assert_eq!(forecast.messages_left_ours, 50);
+        assert_eq!(forecast.warning, Some(60));
+        assert!(pad.can_send_text(100, config));
+    }
+
+    #[test]
+    fn test_pad_state_seal_open() {
+        let pad = Pad::from_bytes(vec![0x42; 1024]);
+        pad.consume(100, Role::Initiator).unwrap();
+        pad.zero_bytes_at(0, 100);
+
+        let sealed = seal_pad_state(&pad, Role::Initiator, 2, vec![7; 32], vec![1; 12]).unwrap();
+        let state = open_pad_state(sealed.clone(), vec![7; 32], 2).unwrap();
+        assert_eq!(state.role, Role::Initiator);
+        assert_eq!(state.pad.consumed_front(), 100);
+        assert_eq!(state.pad.as_bytes(), pad.as_bytes());
+
+        assert!(matches!(
+            open_pad_state(sealed.clone(), vec![7; 32], 3),
+            Err(AshError::PadStateRollback)
+        ));
+        assert!(matches!(
+            open_pad_state(sealed, vec![8; 32], 0),
+            Err(AshError::AuthenticationFailed)
+        ));
+        assert!(matches!(
+            seal_pad_state(&pad, Role::Initiator, 2, vec![7; 16], vec![1; 12]),
+            Err(AshError::LengthMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_consumption_journal() {
+        let pad = Pad::from_bytes(vec![0x42; 1024]);
+        let journal = ConsumptionJournal::new(Role::Initiator);
+        journal.consume(&pad, 100).unwrap();
+        journal.record_received(&pad, 0, 50).unwrap();
+        assert_eq!(journal.own_consumed(), 100);
+        assert_eq!(journal.peer_consumed(), 50);
+
+        let restored = ConsumptionJournal::decode(journal.encode()).unwrap();
+        assert_eq!(restored.head(), journal.head());
+
+        let stale = Pad::from_bytes(vec![0x42; 1024]);
+        assert!(matches!(restored.check(&stale), Err(AshError::PadRollback)));
+        assert!(restored.check(&pad).is_ok());
+    }
+
+    #[test]
+    fn test_receive_window() {
+        let pad = Pad::from_bytes(vec![0x42; 1024]);
+        let window = ReceiveWindow::new(Role::Responder);
+        window.check(&pad, 0, 100).unwrap();
+        window.accept(&pad, 0, 100).unwrap();
+        assert!(matches!(
+            window.check(&pad, 0, 100),
+            Err(AshError::ReplayedMessage)
+        ));
+        assert_eq!(pad.consumed(), 0);
+
+        let restored = ReceiveWindow::decode(window.encode()).unwrap();
+        assert_eq!(restored.highest(), 100);
+        assert!(matches!(
+            restored.accept(&pad, 50, 100),
+            Err(AshError::ReplayedMessage)
+        ));
+    }
+
+    #[test]
+    fn test_receive_out_of_order() {
+        let sender = Pad::from_bytes(vec![0x42; 1024]);
+        let receiver = Pad::from_bytes(vec![0x42; 1024]);
+        let window = ReceiveWindow::new(Role::Responder);
+
+        let mut messages = Vec::new();
+        for text in [&b"first"[..], b"second"] {
+            let offset = sender.send_position(Role::Initiator);
+            let key = sender
+                .consume_secret(64 + text.len() as u32, Role::Initiator)
+                .unwrap();
+            let auth_key = key.slice(0, 64).unwrap();
+            let encryption_key = key.slice(64, text.len() as u64).unwrap();
+            let frame =
+                encrypt_authenticated_at(offset, &auth_key, &encryption_key, text.to_vec(), 1)
+                    .unwrap();
+            messages.push(frame);
+        }
+
+        assert_eq!(
+            window
+                .receive(&receiver, messages[1].clone())
+                .unwrap()
+                .plaintext,
+            b"second"
+        );
+        assert_eq!(window.gaps(), vec![ReceiveGap { start: 0, end: 69 }]);
+
+        assert_eq!(receiver.key_at(0, 69, Role::Initiator).unwrap().len(), 69);
+        assert_eq!(
+            window
+                .receive(&receiver, messages[0].clone())
+                .unwrap()
+                .plaintext,
+            b"first"
+        );
+        assert!(window.gaps().is_empty());
+        assert!(matches!(
+            window.receive(&receiver, messages[0].clone()),
+            Err(AshError::ReplayedMessage)
+        ));
+
+        // Frames without an offset are refused
+        let key = sender.consume_secret(64 + 4, Role::Initiator).unwrap();
+        let frame = encrypt_authenticated_secret(
+            &key.slice(0, 64).unwrap(),
+            &key.slice(64, 4).unwrap(),
+            b"none".to_vec(),
+            1,
+        )
+        .unwrap();
+        assert!(matches!(
+            window.receive(&receiver, frame),
+            Err(AshError::UnsupportedMetadataVersion)
+        ));
+    }
+
+    #[test]
+    fn test_retention_manager() {
+        let pad = Pad::from_bytes(vec![0x42; 1024]);
+        let policy = retention_policy_from_metadata(CeremonyMetadata {
+            version: 1,
+            ttl_seconds: 300,
+            disappearing_messages_seconds: 30,
+            notification_flags: 0,
+            transfer_method: TransferMethod::Raptor,
+            relay_url: String::new(),
+        });
+        assert_eq!(policy.disappear_after, Some(30));
+        let retention = RetentionManager::new(Role::Initiator, policy);
+
+        let offset = pad.send_position(Role::Initiator);
+        pad.consume(100, Role::Initiator).unwrap();
+        retention.record_sent(&pad, 1, offset, 100, 1_000).unwrap();
+        retention.record_received(&pad, 2, 0, 80, 1_000).unwrap();
+        assert_eq!(retention.next_due(), Some(1_300));
+
+        retention.mark_delivered(1, 1_005);
+        let wiped = retention.sweep(&pad, 1_005);
+        assert_eq!(wiped.len(), 1);
+        assert_eq!(wiped[0].reason, WipeReason::Delivered);
+
+        let summary = retention.summary(&pad);
+        assert!(summary.verified);
+        assert_eq!(summary.wiped_bytes, 100);
+        assert_eq!(summary.recoverable[0].start, 944);
+    }
+
     #[test]
     fn test_pad_size_validation() {
         // Wrong entropy size for specified pad size
@@ -780,14 +2084,9 @@ mod tests {
                 transfer_method: method,
                 relay_url: "https://relay.test".to_string(),
             };
-            let generator = create_fountain_generator(
-                metadata,
-                pad.clone(),
-                256,
-                passphrase.clone(),
-                method,
-            )
-            .unwrap();
+            let generator =
+                create_fountain_generator(metadata, pad.clone(), 256, passphrase.clone(), method)
+                    .unwrap();
             let receiver = FountainFrameReceiver::new(passphrase.clone());
 
             let max_blocks = generator.source_count() as usize * 2;
@@ -805,6 +2104,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ceremony_state_machines() {
+        let initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator
+            .set_passphrase("test-passphrase".to_string())
+            .unwrap();
+        initiator
+            .start_transfer(vec![0x42; PadSize::Tiny.bytes() as usize])
+            .unwrap();
+
+        let responder = ResponderCeremony::new();
+        responder
+            .set_passphrase("test-passphrase".to_string())
+            .unwrap();
+        responder.start_transfer().unwrap();
+        while !responder
+            .add_frame(initiator.next_frame().unwrap())
+            .unwrap()
+        {}
+        initiator.finish_transfer().unwrap();
+
+        assert_eq!(responder.state(), CeremonyState::VerifyingMnemonic);
+        assert_eq!(initiator.mnemonic().unwrap(), responder.mnemonic().unwrap());
+        assert!(matches!(
+            initiator.finish(),
+            Err(AshError::MnemonicNotConfirmed)
+        ));
+
+        initiator.confirm_mnemonic().unwrap();
+        responder.confirm_mnemonic().unwrap();
+        let ours = initiator.finish().unwrap();
+        let theirs = responder.finish().unwrap();
+
+        assert_eq!(ours.role, Role::Initiator);
+        assert_eq!(theirs.role, Role::Responder);
+        assert_eq!(ours.tokens.conversation_id, theirs.tokens.conversation_id);
+        assert_eq!(ours.pad.as_bytes(), theirs.pad.as_bytes());
+        assert_eq!(initiator.state(), CeremonyState::Finished);
+    }
+
+    #[test]
+    fn test_topup() {
+        let bytes: Vec<u8> = (0..4096u32).map(|i| i as u8).collect();
+        let alice = Pad::from_bytes(bytes.clone());
+        let bob = Pad::from_bytes(bytes);
+
+        let sender = TopUpSender::new(Role::Initiator, PadSize::Tiny);
+        sender
+            .start_transfer(&alice, vec![0x42; PadSize::Tiny.bytes() as usize])
+            .unwrap();
+
+        let receiver = TopUpReceiver::new(Role::Responder);
+        receiver.start_transfer().unwrap();
+        while !receiver
+            .add_frame(sender.next_frame().unwrap(), &bob)
+            .unwrap()
+        {}
+        sender.finish_transfer().unwrap();
+
+        assert_eq!(sender.mnemonic().unwrap(), receiver.mnemonic().unwrap());
+        sender.confirm_mnemonic().unwrap();
+        receiver.confirm_mnemonic().unwrap();
+        let ours = sender.finish(&alice).unwrap();
+        let theirs = receiver.finish(&bob).unwrap();
+
+        assert_eq!(ours, theirs);
+        assert_eq!(alice.total_size(), 4096 + PadSize::Tiny.bytes());
+        assert_eq!(alice.as_bytes(), bob.as_bytes());
+    }
+
     #[test]
     fn test_encrypt_decrypt() {
         let key = vec![0xDE, 0xAD, 0xBE, 0xEF];
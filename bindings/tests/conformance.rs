@@ -0,0 +1,242 @@
+//! Conformance of the UniFFI surface against the ash-core test vectors.
+//!
+//! The vectors live in `core/vectors/ash_core_vectors.json`. Sections the
+//! bindings do not expose (`mac`, `padding`) are covered by the WASM runner.
+
+use ash_bindings::{
+    create_fountain_generator, decrypt_authenticated, decrypt_authenticated_secret,
+    derive_all_tokens, derive_auth_token, derive_burn_token, derive_conversation_id,
+    derive_registered_conversation_id, derive_registration_proof, encrypt_authenticated,
+    encrypt_authenticated_secret, generate_mnemonic_with_count, hash_token, CeremonyMetadata,
+    FountainFrameReceiver, SecretBytes, TransferMethod,
+};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("../../core/vectors/ash_core_vectors.json");
+
+fn vectors() -> Value {
+    serde_json::from_str(VECTORS).expect("vector file is valid JSON")
+}
+
+fn section(name: &str) -> Vec<Value> {
+    vectors()[name]
+        .as_array()
+        .expect("section is an array")
+        .clone()
+}
+
+fn hex(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("hex string");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("valid hex"))
+        .collect()
+}
+
+fn text(value: &Value) -> String {
+    value.as_str().expect("string").to_string()
+}
+
+fn number(value: &Value) -> u64 {
+    value.as_u64().expect("number")
+}
+
+fn transfer_method(byte: u64) -> TransferMethod {
+    match byte {
+        0 => TransferMethod::Raptor,
+        1 => TransferMethod::LT,
+        2 => TransferMethod::Sequential,
+        other => panic!("unknown transfer method {}", other),
+    }
+}
+
+fn metadata(value: &Value) -> CeremonyMetadata {
+    CeremonyMetadata {
+        version: number(&value["version"]) as u8,
+        ttl_seconds: number(&value["ttl_seconds"]),
+        disappearing_messages_seconds: number(&value["disappearing_messages_seconds"]) as u32,
+        notification_flags: number(&value["notification_flags"]) as u16,
+        transfer_method: transfer_method(number(&value["transfer_method"])),
+        relay_url: text(&value["relay_url"]),
+    }
+}
+
+#[test]
+fn vectors_version() {
+    assert_eq!(number(&vectors()["version"]), 1);
+}
+
+#[test]
+fn message_frame() {
+    for v in section("message_frame") {
+        let name = text(&v["name"]);
+        let msg_type = number(&v["msg_type"]) as u8;
+        let (auth_key, encryption_key) = (hex(&v["auth_key"]), hex(&v["encryption_key"]));
+        let encoded = encrypt_authenticated(
+            auth_key.clone(),
+            encryption_key.clone(),
+            hex(&v["plaintext"]),
+            msg_type,
+        )
+        .unwrap();
+        assert_eq!(encoded, hex(&v["encoded"]), "{}", name);
+
+        let decrypted =
+            decrypt_authenticated(auth_key.clone(), encryption_key.clone(), encoded.clone())
+                .unwrap();
+        assert_eq!(decrypted.plaintext, hex(&v["plaintext"]), "{}", name);
+        assert_eq!(decrypted.msg_type, msg_type, "{}", name);
+
+        // The SecretBytes variants produce the same frame
+        let auth_key = SecretBytes::from_bytes(auth_key);
+        let encryption_key = SecretBytes::from_bytes(encryption_key);
+        let secret_encoded = encrypt_authenticated_secret(
+            &auth_key,
+            &encryption_key,
+            hex(&v["plaintext"]),
+            msg_type,
+        )
+        .unwrap();
+        assert_eq!(secret_encoded, encoded, "{}", name);
+        let decrypted =
+            decrypt_authenticated_secret(&auth_key, &encryption_key, secret_encoded).unwrap();
+        assert_eq!(decrypted.plaintext, hex(&v["plaintext"]), "{}", name);
+    }
+}
+
+#[test]
+fn auth_tokens() {
+    for v in section("auth") {
+        let name = text(&v["name"]);
+        let pad = hex(&v["pad"]);
+        let tokens = derive_all_tokens(pad.clone()).unwrap();
+        assert_eq!(
+            tokens.conversation_id,
+            text(&v["conversation_id"]),
+            "{}",
+            name
+        );
+        assert_eq!(tokens.auth_token, text(&v["auth_token"]), "{}", name);
+        assert_eq!(tokens.burn_token, text(&v["burn_token"]), "{}", name);
+
+        assert_eq!(
+            derive_conversation_id(pad.clone()).unwrap(),
+            tokens.conversation_id
+        );
+        assert_eq!(derive_auth_token(pad.clone()).unwrap(), tokens.auth_token);
+        assert_eq!(derive_burn_token(pad).unwrap(), tokens.burn_token);
+    }
+}
+
+#[test]
+fn registration_proofs() {
+    for v in section("registration") {
+        let name = text(&v["name"]);
+        let pad = hex(&v["pad"]);
+        let tokens = derive_all_tokens(pad.clone()).unwrap();
+        assert_eq!(
+            hash_token(tokens.auth_token),
+            text(&v["auth_token_hash"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            hash_token(tokens.burn_token),
+            text(&v["burn_token_hash"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            derive_registered_conversation_id(pad.clone()).unwrap(),
+            text(&v["conversation_id"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            derive_registration_proof(pad).unwrap(),
+            text(&v["registration_proof"]),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn mnemonic() {
+    for v in section("mnemonic") {
+        let words: Vec<String> = v["words"].as_array().unwrap().iter().map(text).collect();
+        let generated =
+            generate_mnemonic_with_count(hex(&v["pad"]), number(&v["word_count"]) as u32);
+        assert_eq!(generated, words, "{}", text(&v["name"]));
+    }
+}
+
+#[test]
+fn fountain_frames() {
+    for v in section("fountain") {
+        let name = text(&v["name"]);
+        let passphrase = text(&v["passphrase"]);
+        let expected_metadata = metadata(&v["metadata"]);
+        let generator = create_fountain_generator(
+            expected_metadata.clone(),
+            hex(&v["pad"]),
+            number(&v["block_size"]) as u32,
+            passphrase.clone(),
+            transfer_method(number(&v["transfer_method"])),
+        )
+        .unwrap();
+        assert_eq!(
+            u64::from(generator.source_count()),
+            number(&v["source_count"]),
+            "{}",
+            name
+        );
+
+        let frames: Vec<Vec<u8>> = v["frames"].as_array().unwrap().iter().map(hex).collect();
+        for (index, frame) in frames.iter().enumerate() {
+            assert_eq!(
+                &generator.generate_frame(index as u32),
+                frame,
+                "{} frame {}",
+                name,
+                index
+            );
+        }
+
+        // Decode newest first so repair frames (and the Raptor pre-code)
+        // take part in recovery
+        let receiver = FountainFrameReceiver::new(passphrase);
+        for frame in frames.iter().rev() {
+            if receiver.add_frame(frame.clone()).unwrap() {
+                break;
+            }
+        }
+        let result = receiver.get_result().expect(&name);
+        assert_eq!(result.pad, hex(&v["pad"]), "{}", name);
+        assert_eq!(result.metadata, expected_metadata, "{}", name);
+    }
+}
+
+#[test]
+fn ceremony_metadata_survives_transfer() {
+    // The bindings have no direct encoder; check the field mapping by
+    // sending each metadata vector through a fountain transfer
+    for v in section("ceremony_metadata") {
+        let expected = metadata(&v);
+        let generator = create_fountain_generator(
+            expected.clone(),
+            vec![0x5a; 200],
+            256,
+            "metadata vectors".to_string(),
+            expected.transfer_method,
+        )
+        .unwrap();
+        let receiver = FountainFrameReceiver::new("metadata vectors".to_string());
+        let mut index = 0;
+        while !receiver.add_frame(generator.generate_frame(index)).unwrap() {
+            index += 1;
+        }
+        let result = receiver.get_result().unwrap();
+        assert_eq!(result.metadata, expected, "{}", text(&v["name"]));
+    }
+}
@@ -0,0 +1,74 @@
+//! The ash-core pad model suite run over the UniFFI `Pad` wrapper.
+
+#[path = "../../core/tests/pad_model/mod.rs"]
+mod pad_model;
+
+use ash_bindings::{Pad, Role};
+use pad_model::{PadUnderTest, Side};
+use proptest::prelude::*;
+
+fn role(side: Side) -> Role {
+    match side {
+        Side::Initiator => Role::Initiator,
+        Side::Responder => Role::Responder,
+    }
+}
+
+impl PadUnderTest for Pad {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Pad::from_bytes(bytes)
+    }
+
+    fn restore(&self) -> Self {
+        Pad::from_bytes_with_state(self.as_bytes(), self.consumed_front(), self.consumed_back())
+    }
+
+    fn consume(&mut self, n: usize, side: Side) -> Option<Vec<u8>> {
+        Pad::consume(self, n as u32, role(side)).ok()
+    }
+
+    fn update_peer_consumption(&mut self, peer: Side, consumed: usize) -> bool {
+        Pad::update_peer_consumption(self, role(peer), consumed as u64).is_ok()
+    }
+
+    fn zero_bytes_at(&mut self, offset: usize, len: usize) -> bool {
+        Pad::zero_bytes_at(self, offset as u64, len as u64)
+    }
+
+    fn can_send(&self, n: usize, side: Side) -> bool {
+        Pad::can_send(self, n as u32, role(side))
+    }
+
+    fn next_send_offset(&self, side: Side) -> usize {
+        Pad::next_send_offset(self, role(side)) as usize
+    }
+
+    fn consumed_front(&self) -> usize {
+        Pad::consumed_front(self) as usize
+    }
+
+    fn consumed_back(&self) -> usize {
+        Pad::consumed_back(self) as usize
+    }
+
+    fn remaining(&self) -> usize {
+        Pad::remaining(self) as usize
+    }
+
+    fn total_size(&self) -> usize {
+        Pad::total_size(self) as usize
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.as_bytes()
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn bindings_pad_matches_model((bytes, ops) in pad_model::scenario()) {
+        pad_model::run::<Pad>(bytes, &ops)?;
+    }
+}
@@ -0,0 +1,9 @@
+//! Print the ash-core test vectors as JSON.
+//!
+//! ```text
+//! cargo run --features vectors --example generate_vectors > vectors/ash_core_vectors.json
+//! ```
+
+fn main() {
+    print!("{}", ash_core::vectors::generate());
+}
@@ -0,0 +1,19 @@
+//! `CeremonyMetadata::decode` on the metadata prefix of a ceremony payload.
+//!
+//! Decoding normalises unknown flags and transfer methods, so the property
+//! is that encode then decode reproduces the decoded value.
+
+#![no_main]
+
+use ash_core::CeremonyMetadata;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(metadata) = CeremonyMetadata::decode(data) else {
+        return;
+    };
+
+    assert!(metadata.relay_url.len() <= data.len());
+    let encoded = metadata.encode();
+    assert_eq!(CeremonyMetadata::decode(&encoded).unwrap(), metadata);
+});
@@ -0,0 +1,20 @@
+//! `EncodedBlock::decode` on bytes from a scanned QR code.
+//!
+//! A decoded block must re-encode to the prefix it was parsed from, and its
+//! data must be exactly `block_size` bytes.
+
+#![no_main]
+
+use ash_core::EncodedBlock;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(block) = EncodedBlock::decode(data) else {
+        return;
+    };
+
+    assert_eq!(block.data.len(), block.block_size as usize);
+    let encoded = block.encode();
+    assert_eq!(encoded, &data[..encoded.len()]);
+    assert_eq!(EncodedBlock::decode(&encoded).unwrap(), block);
+});
@@ -0,0 +1,33 @@
+//! `FountainFrameReceiver::add_frame` fed a sequence of scanned frames.
+//!
+//! The receiver must never panic, and whatever it reconstructs can be no
+//! larger than the frames it was given.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ash_core::frame::FountainFrameReceiver;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    passphrase: Option<String>,
+    frames: Vec<Vec<u8>>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut receiver = FountainFrameReceiver::new(input.passphrase.as_deref());
+    let total: usize = input.frames.iter().map(Vec::len).sum();
+
+    for frame in &input.frames {
+        if let Ok(true) = receiver.add_frame(frame) {
+            break;
+        }
+    }
+
+    assert!(receiver.progress() <= 1.0);
+    assert!(receiver.unique_blocks_received() <= input.frames.len());
+    if let Some(result) = receiver.get_result() {
+        assert!(result.pad.len() <= total);
+    }
+});
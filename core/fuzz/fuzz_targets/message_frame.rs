@@ -0,0 +1,21 @@
+//! `MessageFrame::decode` on bytes from the relay.
+//!
+//! Any frame that decodes must encode back to exactly the same bytes, and
+//! decrypting it with an unrelated key must fail cleanly.
+
+#![no_main]
+
+use ash_core::{AuthKey, MessageFrame};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(frame) = MessageFrame::decode(data) else {
+        return;
+    };
+
+    assert_eq!(frame.encode(), data);
+    assert_eq!(frame.encoded_size(), data.len());
+
+    let key = vec![0u8; frame.ciphertext.len()];
+    let _ = frame.decrypt(&key, &AuthKey::from_bytes(&[0u8; 64]));
+});
@@ -0,0 +1,18 @@
+//! `unpad_message` on decrypted (attacker-influenced) plaintext.
+//!
+//! The content is a slice of the input, and padding it again must round-trip.
+
+#![no_main]
+
+use ash_core::message::{pad_message, unpad_message};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = unpad_message(data) else {
+        return;
+    };
+
+    assert_eq!(&content[..], &data[3..3 + content.len()]);
+    let padded = pad_message(&content).unwrap();
+    assert_eq!(unpad_message(&padded).unwrap(), &content[..]);
+});
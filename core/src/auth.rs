@@ -8,6 +8,18 @@
 //!
 //! - **Auth Token**: Required for all API operations (messages, polling)
 //! - **Burn Token**: Required specifically for burning conversations
+//! - **Registration Proof**: One-way hash of a reserved pad region, sent
+//!   when registering the conversation with the relay
+//!
+//! # Conversation ID
+//!
+//! [`derive_conversation_id`] mixes the first 32 pad bytes, as it always
+//! has; existing conversations keep that ID. Clients that send a
+//! registration proof use [`derive_registered_conversation_id`] instead, a
+//! SHA-256 commitment to both token hashes and the proof. The relay
+//! recomputes it from a registration request, so only a holder of the pad
+//! can register such an ID first, and only with the token hashes derived
+//! from that pad.
 //!
 //! # Security Properties
 //!
@@ -24,6 +36,7 @@
 //! the pad.
 
 use crate::error::{Error, Result};
+use crate::sha256::Sha256;
 
 /// Size of derived tokens in bytes (256 bits).
 pub const TOKEN_SIZE: usize = 32;
@@ -40,16 +53,30 @@ const AUTH_TOKEN_RANGE: std::ops::Range<usize> = 32..96;
 /// Uses bytes 96-159 (64 bytes, mixed down to 32).
 const BURN_TOKEN_RANGE: std::ops::Range<usize> = 96..160;
 
+/// Byte range for registration proof derivation.
+/// Uses bytes 160-223 (64 bytes, hashed down to 32).
+const REGISTRATION_RANGE: std::ops::Range<usize> = 160..224;
+
 /// Minimum pad size to support token derivation.
 /// Must have at least 160 bytes for all token ranges.
 pub const MIN_PAD_SIZE_FOR_TOKENS: usize = 160;
 
+/// Minimum pad size to support the registration proof.
+/// Must have at least 224 bytes for the registration proof range.
+pub const MIN_PAD_SIZE_FOR_REGISTRATION: usize = 224;
+
+/// Domain separation prefix for the registration proof hash.
+const REGISTRATION_PROOF_CONTEXT: &[u8] = b"ash-registration-proof-v1";
+
+/// Domain separation prefix for the conversation ID commitment.
+const CONVERSATION_ID_CONTEXT: &[u8] = b"ash-conversation-id-v1";
+
 /// Domain separation constants for token derivation.
 /// XORed into the mixing process to ensure different tokens
 /// even if the same byte range were used.
+const DOMAIN_CONV: u8 = 0xC3;
 const DOMAIN_AUTH: u8 = 0xA1;
 const DOMAIN_BURN: u8 = 0xB2;
-const DOMAIN_CONV: u8 = 0xC3;
 
 /// Derive conversation ID from pad bytes.
 ///
@@ -79,6 +106,32 @@ pub fn derive_conversation_id(pad_bytes: &[u8]) -> Result<String> {
     Ok(hex_encode(&bytes))
 }
 
+/// Derive the conversation ID for a registration that carries a proof.
+///
+/// The ID commits to the token hashes and the registration proof (see
+/// [`conversation_id_for_registration`]), so the relay can check that a
+/// registration matches it. It differs from [`derive_conversation_id`];
+/// both parties must agree on which one they use.
+///
+/// # Arguments
+///
+/// * `pad_bytes` - The full pad bytes
+///
+/// # Returns
+///
+/// A 32-byte conversation ID, hex-encoded as a 64-character string.
+///
+/// # Errors
+///
+/// Returns error if pad is too small for the registration proof.
+pub fn derive_registered_conversation_id(pad_bytes: &[u8]) -> Result<String> {
+    Ok(conversation_id_for_registration(
+        &hash_token(&derive_auth_token(pad_bytes)?),
+        &hash_token(&derive_burn_token(pad_bytes)?),
+        &derive_registration_proof(pad_bytes)?,
+    ))
+}
+
 /// Derive auth token from pad bytes.
 ///
 /// The auth token is required for all API operations except burning.
@@ -159,6 +212,78 @@ pub fn derive_all_tokens(pad_bytes: &[u8]) -> Result<(String, String, String)> {
     ))
 }
 
+/// Derive the registration proof for a conversation.
+///
+/// The proof is a SHA-256 hash of a pad region reserved for registration.
+/// Both parties compute the same proof and send it when registering; the
+/// relay checks it against the conversation ID from
+/// [`derive_registered_conversation_id`]. Publishing the proof reveals
+/// only a hash of the region, not its bytes.
+///
+/// # Arguments
+///
+/// * `pad_bytes` - The full pad bytes
+///
+/// # Returns
+///
+/// A 32-byte hash, hex-encoded as a 64-character string.
+///
+/// # Errors
+///
+/// Returns error if pad is too small for the registration proof.
+pub fn derive_registration_proof(pad_bytes: &[u8]) -> Result<String> {
+    if pad_bytes.len() < MIN_PAD_SIZE_FOR_REGISTRATION {
+        return Err(Error::PadTooSmallForTokens {
+            size: pad_bytes.len(),
+            minimum: MIN_PAD_SIZE_FOR_REGISTRATION,
+        });
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(REGISTRATION_PROOF_CONTEXT);
+    hasher.update(&pad_bytes[REGISTRATION_RANGE]);
+    Ok(hex_encode(&hasher.finalize()))
+}
+
+/// Compute the conversation ID a registration commits to.
+///
+/// The ID is SHA-256 over a domain prefix and the lowercase hex of the
+/// auth token hash, burn token hash and registration proof. The relay
+/// accepts a registration only if this matches the conversation ID, so
+/// a registration with a forged proof or different token hashes fails.
+///
+/// # Arguments
+///
+/// * `auth_token_hash` - Hex hash of the auth token, as sent to the relay
+/// * `burn_token_hash` - Hex hash of the burn token, as sent to the relay
+/// * `registration_proof` - Hex registration proof, as sent to the relay
+///
+/// # Returns
+///
+/// A 32-byte conversation ID, hex-encoded as a 64-character string.
+pub fn conversation_id_for_registration(
+    auth_token_hash: &str,
+    burn_token_hash: &str,
+    registration_proof: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(CONVERSATION_ID_CONTEXT);
+    for part in [auth_token_hash, burn_token_hash, registration_proof] {
+        hasher.update(part.to_ascii_lowercase().as_bytes());
+    }
+    hex_encode(&hasher.finalize())
+}
+
+/// Hash a token the way it is sent to the relay at registration.
+///
+/// Returns SHA-256 of the token string, hex-encoded as a 64-character
+/// string.
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex_encode(&hasher.finalize())
+}
+
 /// Mix input bytes with domain separation to produce a fixed-size token.
 ///
 /// Uses a simple but effective mixing function:
@@ -323,6 +448,103 @@ mod tests {
         assert_ne!(auth, burn);
     }
 
+    #[test]
+    fn registration_proof_works() {
+        let pad = make_test_pad(1000);
+        let proof = derive_registration_proof(&pad).unwrap();
+
+        assert_eq!(proof.len(), 64);
+        assert!(proof.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(proof, derive_registration_proof(&pad).unwrap());
+
+        // Token ranges are untouched by the registration range
+        let mut other = pad.clone();
+        other[REGISTRATION_RANGE.start] ^= 0x01;
+        assert_ne!(proof, derive_registration_proof(&other).unwrap());
+        assert_eq!(
+            derive_auth_token(&pad).unwrap(),
+            derive_auth_token(&other).unwrap()
+        );
+        assert_eq!(
+            derive_burn_token(&pad).unwrap(),
+            derive_burn_token(&other).unwrap()
+        );
+    }
+
+    #[test]
+    fn conversation_id_commits_to_registration() {
+        let pad = make_test_pad(1000);
+        let (legacy, auth, burn) = derive_all_tokens(&pad).unwrap();
+        let conv = derive_registered_conversation_id(&pad).unwrap();
+        assert_ne!(conv, legacy);
+        let (auth_hash, burn_hash) = (hash_token(&auth), hash_token(&burn));
+        let proof = derive_registration_proof(&pad).unwrap();
+
+        assert_eq!(
+            conversation_id_for_registration(&auth_hash, &burn_hash, &proof),
+            conv
+        );
+        assert_eq!(
+            conversation_id_for_registration(
+                &auth_hash.to_uppercase(),
+                &burn_hash,
+                &proof.to_uppercase()
+            ),
+            conv
+        );
+
+        // A forged proof or swapped hashes commit to a different ID
+        let forged = "0".repeat(64);
+        assert_ne!(
+            conversation_id_for_registration(&auth_hash, &burn_hash, &forged),
+            conv
+        );
+        assert_ne!(
+            conversation_id_for_registration(&burn_hash, &auth_hash, &proof),
+            conv
+        );
+
+        // Every derived region changes the ID
+        for index in [
+            AUTH_TOKEN_RANGE.start,
+            BURN_TOKEN_RANGE.start,
+            REGISTRATION_RANGE.start,
+        ] {
+            let mut other = pad.clone();
+            other[index] ^= 0x01;
+            assert_ne!(derive_registered_conversation_id(&other).unwrap(), conv);
+        }
+    }
+
+    #[test]
+    fn registration_proof_requires_registration_range() {
+        let pad = make_test_pad(MIN_PAD_SIZE_FOR_REGISTRATION - 1);
+
+        assert!(matches!(
+            derive_registration_proof(&pad),
+            Err(Error::PadTooSmallForTokens {
+                minimum: MIN_PAD_SIZE_FOR_REGISTRATION,
+                ..
+            })
+        ));
+        assert!(derive_registered_conversation_id(&pad).is_err());
+        assert!(
+            derive_registered_conversation_id(&make_test_pad(MIN_PAD_SIZE_FOR_REGISTRATION))
+                .is_ok()
+        );
+
+        // Tokens and the legacy ID still only need the token ranges
+        assert!(derive_all_tokens(&make_test_pad(MIN_PAD_SIZE_FOR_TOKENS)).is_ok());
+    }
+
+    #[test]
+    fn hash_token_is_sha256_hex() {
+        assert_eq!(
+            hash_token(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
     #[test]
     fn hex_roundtrip() {
         let bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
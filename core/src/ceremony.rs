@@ -13,9 +13,22 @@
 //!
 //! Notification preferences are encoded as a 16-bit bitfield for extensibility.
 //! Each bit represents a specific notification type that can be enabled/disabled.
+//!
+//! ## Ceremony State Machines
+//!
+//! [`InitiatorCeremony`] and [`ResponderCeremony`] drive the whole pairing flow
+//! (configure, transfer, verify mnemonic, confirm) and refuse out-of-order steps.
+//! Tokens are only derived once the mnemonic has been confirmed.
+
+use std::fmt;
 
+use crate::auth;
 use crate::error::{Error, Result};
-use crate::frame::TransferMethod;
+use crate::frame::{
+    self, FountainFrameGenerator, FountainFrameReceiver, TransferMethod, DEFAULT_BLOCK_SIZE,
+};
+use crate::mnemonic;
+use crate::pad::{Pad, PadSize, Role};
 
 /// Maximum relay URL length in bytes.
 const MAX_RELAY_URL_LEN: usize = 256;
@@ -372,6 +385,590 @@ impl CeremonyMetadata {
     }
 }
 
+// ============================================================================
+// Ceremony State Machines
+// ============================================================================
+
+/// State of an [`InitiatorCeremony`] or [`ResponderCeremony`].
+///
+/// Both sides walk through the same states in order:
+///
+/// ```text
+/// Configuring ──▶ Transferring ──▶ VerifyingMnemonic ──▶ Confirmed ──▶ Finished
+///      │               │                  │
+///      └───────────────┴──────────────────┴──────▶ Aborted
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CeremonyState {
+    /// Settings (metadata, passphrase, block size) can still be changed.
+    Configuring,
+    /// QR frames are being displayed or scanned.
+    Transferring,
+    /// Pad is transferred; both parties compare mnemonics.
+    VerifyingMnemonic,
+    /// Mnemonics matched; the conversation bundle can be produced.
+    Confirmed,
+    /// Conversation bundle was handed out. Terminal.
+    Finished,
+    /// Ceremony was cancelled or the mnemonic was rejected. Terminal.
+    Aborted,
+}
+
+impl CeremonyState {
+    /// Get the state name for display and error messages.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CeremonyState::Configuring => "configuring",
+            CeremonyState::Transferring => "transferring",
+            CeremonyState::VerifyingMnemonic => "verifying_mnemonic",
+            CeremonyState::Confirmed => "confirmed",
+            CeremonyState::Finished => "finished",
+            CeremonyState::Aborted => "aborted",
+        }
+    }
+}
+
+/// Result of a completed ceremony.
+///
+/// Holds everything a client needs to start messaging: the pad, the agreed
+/// settings and the relay credentials derived from the pad.
+pub struct ConversationBundle {
+    /// Our role in the conversation (determines pad consumption direction).
+    pub role: Role,
+    /// The shared pad, with no bytes consumed yet.
+    pub pad: Pad,
+    /// Settings agreed during the ceremony.
+    pub metadata: CeremonyMetadata,
+    /// Conversation ID (hex-encoded, 64 chars).
+    pub conversation_id: String,
+    /// Auth token for relay API operations (hex-encoded, 64 chars).
+    pub auth_token: String,
+    /// Burn token for burn operations (hex-encoded, 64 chars).
+    pub burn_token: String,
+}
+
+impl ConversationBundle {
+    /// Derive the tokens from the pad in `slot` and move the pad into the
+    /// bundle. On error the pad stays in `slot`.
+    fn take_from(
+        role: Role,
+        slot: &mut Option<Pad>,
+        metadata: CeremonyMetadata,
+    ) -> Result<Option<Self>> {
+        let Some(pad) = slot.as_ref() else {
+            return Ok(None);
+        };
+        let (conversation_id, auth_token, burn_token) = auth::derive_all_tokens(pad.as_bytes())?;
+        Ok(slot.take().map(|pad| Self {
+            role,
+            pad,
+            metadata,
+            conversation_id,
+            auth_token,
+            burn_token,
+        }))
+    }
+}
+
+impl fmt::Debug for ConversationBundle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConversationBundle")
+            .field("role", &self.role)
+            .field("pad", &self.pad)
+            .field("metadata", &self.metadata)
+            .field("conversation_id", &self.conversation_id)
+            .field("auth_token", &"[REDACTED]")
+            .field("burn_token", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Ceremony driver for the party that creates the pad and displays QR codes.
+///
+/// # Flow
+///
+/// 1. Configure metadata, passphrase and block size
+/// 2. [`start_transfer`](Self::start_transfer) with caller-provided entropy
+/// 3. Display [`next_frame`](Self::next_frame) until the responder is done
+/// 4. [`finish_transfer`](Self::finish_transfer), then compare [`mnemonic`](Self::mnemonic)
+/// 5. [`confirm_mnemonic`](Self::confirm_mnemonic) and [`finish`](Self::finish)
+///
+/// Tokens are never derived before the mnemonic is confirmed.
+///
+/// # Example
+///
+/// ```
+/// use ash_core::ceremony::{InitiatorCeremony, ResponderCeremony};
+/// use ash_core::PadSize;
+///
+/// let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+/// initiator.set_passphrase(Some("secret")).unwrap();
+/// initiator.start_transfer(&vec![7u8; PadSize::Tiny.bytes()]).unwrap();
+///
+/// let mut responder = ResponderCeremony::new();
+/// responder.set_passphrase(Some("secret")).unwrap();
+/// responder.start_transfer().unwrap();
+/// while !responder.add_frame(&initiator.next_frame().unwrap()).unwrap() {}
+///
+/// initiator.finish_transfer().unwrap();
+/// assert_eq!(initiator.mnemonic().unwrap(), responder.mnemonic().unwrap());
+///
+/// initiator.confirm_mnemonic().unwrap();
+/// responder.confirm_mnemonic().unwrap();
+///
+/// let ours = initiator.finish().unwrap();
+/// let theirs = responder.finish().unwrap();
+/// assert_eq!(ours.conversation_id, theirs.conversation_id);
+/// ```
+pub struct InitiatorCeremony {
+    state: CeremonyState,
+    pad_size: PadSize,
+    metadata: CeremonyMetadata,
+    passphrase: Option<String>,
+    block_size: usize,
+    pad: Option<Pad>,
+    generator: Option<FountainFrameGenerator>,
+}
+
+impl InitiatorCeremony {
+    /// Create a new initiator ceremony in the `Configuring` state.
+    ///
+    /// Uses default metadata, no passphrase and [`DEFAULT_BLOCK_SIZE`].
+    pub fn new(pad_size: PadSize) -> Self {
+        Self {
+            state: CeremonyState::Configuring,
+            pad_size,
+            metadata: CeremonyMetadata::default(),
+            passphrase: None,
+            block_size: DEFAULT_BLOCK_SIZE,
+            pad: None,
+            generator: None,
+        }
+    }
+
+    /// Current ceremony state.
+    pub fn state(&self) -> CeremonyState {
+        self.state
+    }
+
+    /// Pad size this ceremony will generate.
+    pub fn pad_size(&self) -> PadSize {
+        self.pad_size
+    }
+
+    /// Metadata that will be transferred with the pad.
+    pub fn metadata(&self) -> &CeremonyMetadata {
+        &self.metadata
+    }
+
+    /// Set the ceremony metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_metadata(&mut self, metadata: CeremonyMetadata) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_metadata")?;
+        self.metadata = metadata;
+        Ok(())
+    }
+
+    /// Set the passphrase used to encrypt QR frames (`None` disables encryption).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_passphrase(&mut self, passphrase: Option<&str>) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_passphrase")?;
+        self.passphrase = passphrase.map(String::from);
+        Ok(())
+    }
+
+    /// Set the QR block size in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_block_size(&mut self, block_size: usize) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_block_size")?;
+        self.block_size = block_size;
+        Ok(())
+    }
+
+    /// Create the pad from entropy and start generating QR frames.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCeremonyState`] unless `Configuring`
+    /// - [`Error::InvalidEntropySize`] if entropy doesn't match the pad size
+    pub fn start_transfer(&mut self, entropy: &[u8]) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "start_transfer")?;
+        let pad = Pad::new(entropy, self.pad_size)?;
+        let generator = frame::create_fountain_ceremony(
+            &self.metadata,
+            pad.as_bytes(),
+            self.block_size,
+            self.passphrase.as_deref(),
+            self.metadata.transfer_method,
+        )?;
+        self.pad = Some(pad);
+        self.generator = Some(generator);
+        self.state = CeremonyState::Transferring;
+        Ok(())
+    }
+
+    /// Generate the next QR frame to display.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Transferring`.
+    pub fn next_frame(&mut self) -> Result<Vec<u8>> {
+        self.expect_state(CeremonyState::Transferring, "next_frame")?;
+        match self.generator.as_mut() {
+            Some(generator) => Ok(generator.next_frame()),
+            None => Err(self.invalid_state("next_frame")),
+        }
+    }
+
+    /// Number of source blocks the responder needs (0 unless `Transferring`).
+    pub fn source_count(&self) -> usize {
+        self.generator.as_ref().map_or(0, |g| g.source_count())
+    }
+
+    /// Stop displaying frames and move on to mnemonic verification.
+    ///
+    /// Called once the responder signals (out of band) that scanning is done.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Transferring`.
+    pub fn finish_transfer(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::Transferring, "finish_transfer")?;
+        self.generator = None;
+        self.state = CeremonyState::VerifyingMnemonic;
+        Ok(())
+    }
+
+    /// Mnemonic checksum to compare with the responder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic` or `Confirmed`.
+    pub fn mnemonic(&self) -> Result<Vec<&'static str>> {
+        mnemonic_for(self.state, self.pad.as_ref())
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn confirm_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "confirm_mnemonic")?;
+        self.state = CeremonyState::Confirmed;
+        Ok(())
+    }
+
+    /// Record that the mnemonics differ. Aborts the ceremony.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn reject_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "reject_mnemonic")?;
+        self.abort();
+        Ok(())
+    }
+
+    /// Abort the ceremony and wipe the pad. Safe to call in any state.
+    ///
+    /// A `Finished` ceremony stays `Finished`: the pad belongs to the bundle.
+    pub fn abort(&mut self) {
+        if self.state == CeremonyState::Finished {
+            return;
+        }
+        self.generator = None;
+        self.pad = None;
+        self.state = CeremonyState::Aborted;
+    }
+
+    /// Derive tokens and hand out the conversation bundle.
+    ///
+    /// The pad moves into the bundle and the ceremony becomes `Finished`.
+    /// If token derivation fails the ceremony stays `Confirmed` with its pad.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MnemonicNotConfirmed`] if called while `VerifyingMnemonic`
+    /// - [`Error::InvalidCeremonyState`] in any other state except `Confirmed`
+    /// - [`Error::PadTooSmallForTokens`] if the pad is too small
+    pub fn finish(&mut self) -> Result<ConversationBundle> {
+        check_finish(self.state)?;
+        let bundle =
+            ConversationBundle::take_from(Role::Initiator, &mut self.pad, self.metadata.clone())?
+                .ok_or_else(|| self.invalid_state("finish"))?;
+        self.state = CeremonyState::Finished;
+        Ok(bundle)
+    }
+
+    fn expect_state(&self, expected: CeremonyState, operation: &'static str) -> Result<()> {
+        if self.state == expected {
+            Ok(())
+        } else {
+            Err(self.invalid_state(operation))
+        }
+    }
+
+    fn invalid_state(&self, operation: &'static str) -> Error {
+        Error::InvalidCeremonyState {
+            operation,
+            state: self.state,
+        }
+    }
+}
+
+impl fmt::Debug for InitiatorCeremony {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitiatorCeremony")
+            .field("state", &self.state)
+            .field("pad_size", &self.pad_size)
+            .field("block_size", &self.block_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Ceremony driver for the party that scans QR codes and receives the pad.
+///
+/// # Flow
+///
+/// 1. Configure the passphrase
+/// 2. [`start_transfer`](Self::start_transfer), then feed scans to [`add_frame`](Self::add_frame)
+/// 3. Once decoding completes, compare [`mnemonic`](Self::mnemonic)
+/// 4. [`confirm_mnemonic`](Self::confirm_mnemonic) and [`finish`](Self::finish)
+///
+/// See [`InitiatorCeremony`] for a full example.
+pub struct ResponderCeremony {
+    state: CeremonyState,
+    passphrase: Option<String>,
+    receiver: Option<FountainFrameReceiver>,
+    metadata: Option<CeremonyMetadata>,
+    pad: Option<Pad>,
+}
+
+impl ResponderCeremony {
+    /// Create a new responder ceremony in the `Configuring` state.
+    pub fn new() -> Self {
+        Self {
+            state: CeremonyState::Configuring,
+            passphrase: None,
+            receiver: None,
+            metadata: None,
+            pad: None,
+        }
+    }
+
+    /// Current ceremony state.
+    pub fn state(&self) -> CeremonyState {
+        self.state
+    }
+
+    /// Metadata received from the initiator (`None` until the transfer completes).
+    pub fn metadata(&self) -> Option<&CeremonyMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Set the passphrase used to decrypt QR frames (`None` if unencrypted).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_passphrase(&mut self, passphrase: Option<&str>) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_passphrase")?;
+        self.passphrase = passphrase.map(String::from);
+        Ok(())
+    }
+
+    /// Start accepting scanned QR frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn start_transfer(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "start_transfer")?;
+        self.receiver = Some(FountainFrameReceiver::new(self.passphrase.as_deref()));
+        self.state = CeremonyState::Transferring;
+        Ok(())
+    }
+
+    /// Add a scanned QR frame.
+    ///
+    /// Returns `Ok(true)` once the pad is fully decoded; the ceremony then
+    /// moves to `VerifyingMnemonic`. Invalid frames are rejected without
+    /// changing state, so scanning can simply continue.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCeremonyState`] unless `Transferring`
+    /// - Frame decoding errors (e.g. [`Error::CrcMismatch`])
+    /// - [`Error::InvalidCeremonyPayload`] if the decoded data is malformed
+    ///   (the ceremony is aborted)
+    pub fn add_frame(&mut self, frame_bytes: &[u8]) -> Result<bool> {
+        self.expect_state(CeremonyState::Transferring, "add_frame")?;
+        let receiver = match self.receiver.as_mut() {
+            Some(receiver) => receiver,
+            None => return Err(self.invalid_state("add_frame")),
+        };
+
+        if !receiver.add_frame(frame_bytes)? {
+            return Ok(false);
+        }
+
+        match receiver.get_result() {
+            Some(result) => {
+                self.metadata = Some(result.metadata);
+                self.pad = Some(Pad::from_bytes(result.pad));
+                self.receiver = None;
+                self.state = CeremonyState::VerifyingMnemonic;
+                Ok(true)
+            }
+            None => {
+                self.abort();
+                Err(Error::InvalidCeremonyPayload)
+            }
+        }
+    }
+
+    /// Decoding progress (0.0 to 1.0).
+    pub fn progress(&self) -> f64 {
+        match self.state {
+            CeremonyState::Transferring => self.receiver.as_ref().map_or(0.0, |r| r.progress()),
+            CeremonyState::VerifyingMnemonic
+            | CeremonyState::Confirmed
+            | CeremonyState::Finished => 1.0,
+            CeremonyState::Configuring | CeremonyState::Aborted => 0.0,
+        }
+    }
+
+    /// Mnemonic checksum to compare with the initiator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic` or `Confirmed`.
+    pub fn mnemonic(&self) -> Result<Vec<&'static str>> {
+        mnemonic_for(self.state, self.pad.as_ref())
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn confirm_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "confirm_mnemonic")?;
+        self.state = CeremonyState::Confirmed;
+        Ok(())
+    }
+
+    /// Record that the mnemonics differ. Aborts the ceremony.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn reject_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "reject_mnemonic")?;
+        self.abort();
+        Ok(())
+    }
+
+    /// Abort the ceremony and wipe the received pad. Safe to call in any state.
+    ///
+    /// A `Finished` ceremony stays `Finished`: the pad belongs to the bundle.
+    pub fn abort(&mut self) {
+        if self.state == CeremonyState::Finished {
+            return;
+        }
+        self.receiver = None;
+        self.pad = None;
+        self.metadata = None;
+        self.state = CeremonyState::Aborted;
+    }
+
+    /// Derive tokens and hand out the conversation bundle.
+    ///
+    /// The pad moves into the bundle and the ceremony becomes `Finished`.
+    /// If token derivation fails the ceremony stays `Confirmed` with its pad.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MnemonicNotConfirmed`] if called while `VerifyingMnemonic`
+    /// - [`Error::InvalidCeremonyState`] in any other state except `Confirmed`
+    /// - [`Error::PadTooSmallForTokens`] if the received pad is too small
+    pub fn finish(&mut self) -> Result<ConversationBundle> {
+        check_finish(self.state)?;
+        let Some(metadata) = self.metadata.clone() else {
+            return Err(self.invalid_state("finish"));
+        };
+        let bundle = ConversationBundle::take_from(Role::Responder, &mut self.pad, metadata)?
+            .ok_or_else(|| self.invalid_state("finish"))?;
+        self.state = CeremonyState::Finished;
+        Ok(bundle)
+    }
+
+    fn expect_state(&self, expected: CeremonyState, operation: &'static str) -> Result<()> {
+        if self.state == expected {
+            Ok(())
+        } else {
+            Err(self.invalid_state(operation))
+        }
+    }
+
+    fn invalid_state(&self, operation: &'static str) -> Error {
+        Error::InvalidCeremonyState {
+            operation,
+            state: self.state,
+        }
+    }
+}
+
+impl Default for ResponderCeremony {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ResponderCeremony {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponderCeremony")
+            .field("state", &self.state)
+            .field("metadata", &self.metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Shared mnemonic lookup: only available once the pad has been transferred.
+fn mnemonic_for(state: CeremonyState, pad: Option<&Pad>) -> Result<Vec<&'static str>> {
+    match (state, pad) {
+        (CeremonyState::VerifyingMnemonic | CeremonyState::Confirmed, Some(pad)) => {
+            Ok(mnemonic::generate_default(pad.as_bytes()))
+        }
+        _ => Err(Error::InvalidCeremonyState {
+            operation: "mnemonic",
+            state,
+        }),
+    }
+}
+
+/// Shared guard for `finish`: tokens are only derived after confirmation.
+pub(crate) fn check_finish(state: CeremonyState) -> Result<()> {
+    match state {
+        CeremonyState::Confirmed => Ok(()),
+        CeremonyState::VerifyingMnemonic => Err(Error::MnemonicNotConfirmed),
+        _ => Err(Error::InvalidCeremonyState {
+            operation: "finish",
+            state,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -549,4 +1146,233 @@ mod tests {
         assert_eq!(decoded.transfer_method, TransferMethod::LT);
     }
 
+    // ========================================================================
+    // Ceremony State Machine Tests
+    // ========================================================================
+
+    fn test_entropy() -> Vec<u8> {
+        (0..=255u8).cycle().take(PadSize::Tiny.bytes()).collect()
+    }
+
+    /// Run both ceremonies through the transfer phase.
+    fn transferred_pair(passphrase: Option<&str>) -> (InitiatorCeremony, ResponderCeremony) {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator.set_passphrase(passphrase).unwrap();
+        initiator.set_block_size(512).unwrap();
+        initiator.start_transfer(&test_entropy()).unwrap();
+
+        let mut responder = ResponderCeremony::new();
+        responder.set_passphrase(passphrase).unwrap();
+        responder.start_transfer().unwrap();
+
+        while !responder
+            .add_frame(&initiator.next_frame().unwrap())
+            .unwrap()
+        {}
+        initiator.finish_transfer().unwrap();
+
+        (initiator, responder)
+    }
+
+    #[test]
+    fn ceremony_full_flow_produces_matching_bundles() {
+        let (mut initiator, mut responder) = transferred_pair(Some("secret"));
+
+        assert_eq!(initiator.state(), CeremonyState::VerifyingMnemonic);
+        assert_eq!(responder.state(), CeremonyState::VerifyingMnemonic);
+        assert_eq!(initiator.mnemonic().unwrap(), responder.mnemonic().unwrap());
+
+        initiator.confirm_mnemonic().unwrap();
+        responder.confirm_mnemonic().unwrap();
+
+        let ours = initiator.finish().unwrap();
+        let theirs = responder.finish().unwrap();
+
+        assert_eq!(ours.role, Role::Initiator);
+        assert_eq!(theirs.role, Role::Responder);
+        assert_eq!(ours.pad.as_bytes(), theirs.pad.as_bytes());
+        assert_eq!(ours.metadata, theirs.metadata);
+        assert_eq!(ours.conversation_id, theirs.conversation_id);
+        assert_eq!(ours.auth_token, theirs.auth_token);
+        assert_eq!(ours.burn_token, theirs.burn_token);
+    }
+
+    #[test]
+    fn ceremony_metadata_reaches_responder() {
+        let metadata = CeremonyMetadata::with_all(
+            600,
+            30,
+            NotificationFlags::none(),
+            TransferMethod::LT,
+            "https://relay.ash.test".to_string(),
+        )
+        .unwrap();
+
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator.set_metadata(metadata.clone()).unwrap();
+        initiator.start_transfer(&test_entropy()).unwrap();
+
+        let mut responder = ResponderCeremony::new();
+        assert!(responder.metadata().is_none());
+        responder.start_transfer().unwrap();
+        while !responder
+            .add_frame(&initiator.next_frame().unwrap())
+            .unwrap()
+        {}
+
+        assert_eq!(responder.metadata(), Some(&metadata));
+        assert_eq!(responder.progress(), 1.0);
+    }
+
+    #[test]
+    fn ceremony_finish_refused_before_confirmation() {
+        let (mut initiator, mut responder) = transferred_pair(None);
+
+        assert_eq!(initiator.finish().unwrap_err(), Error::MnemonicNotConfirmed);
+        assert_eq!(responder.finish().unwrap_err(), Error::MnemonicNotConfirmed);
+        assert_eq!(initiator.state(), CeremonyState::VerifyingMnemonic);
+    }
+
+    #[test]
+    fn ceremony_finish_only_once() {
+        let (mut initiator, _) = transferred_pair(None);
+        initiator.confirm_mnemonic().unwrap();
+        initiator.finish().unwrap();
+
+        assert_eq!(initiator.state(), CeremonyState::Finished);
+        assert!(matches!(
+            initiator.finish(),
+            Err(Error::InvalidCeremonyState {
+                state: CeremonyState::Finished,
+                ..
+            })
+        ));
+        initiator.abort();
+        assert_eq!(initiator.state(), CeremonyState::Finished);
+    }
+
+    #[test]
+    fn ceremony_failed_finish_keeps_pad() {
+        let (mut initiator, mut responder) = transferred_pair(None);
+        initiator.confirm_mnemonic().unwrap();
+        responder.confirm_mnemonic().unwrap();
+
+        // A pad too small for the token regions fails derivation
+        let small = vec![1u8; 16];
+        initiator.pad = Some(Pad::from_bytes(small.clone()));
+        responder.pad = Some(Pad::from_bytes(small.clone()));
+
+        for result in [initiator.finish(), responder.finish()] {
+            assert!(matches!(result, Err(Error::PadTooSmallForTokens { .. })));
+        }
+        assert_eq!(initiator.state(), CeremonyState::Confirmed);
+        assert_eq!(responder.state(), CeremonyState::Confirmed);
+        assert_eq!(initiator.pad.as_ref().unwrap().as_bytes(), small.as_slice());
+        assert_eq!(responder.pad.as_ref().unwrap().as_bytes(), small.as_slice());
+    }
+
+    #[test]
+    fn ceremony_finish_refused_during_transfer() {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator.start_transfer(&test_entropy()).unwrap();
+
+        assert_eq!(
+            initiator.finish().unwrap_err(),
+            Error::InvalidCeremonyState {
+                operation: "finish",
+                state: CeremonyState::Transferring,
+            }
+        );
+    }
+
+    #[test]
+    fn ceremony_enforces_step_order() {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        assert!(matches!(
+            initiator.next_frame(),
+            Err(Error::InvalidCeremonyState { .. })
+        ));
+        assert!(initiator.mnemonic().is_err());
+        assert!(initiator.confirm_mnemonic().is_err());
+        assert!(initiator.finish_transfer().is_err());
+
+        initiator.start_transfer(&test_entropy()).unwrap();
+        assert!(initiator.set_passphrase(Some("late")).is_err());
+        assert!(initiator.set_metadata(CeremonyMetadata::default()).is_err());
+        assert!(initiator.start_transfer(&test_entropy()).is_err());
+        assert!(initiator.mnemonic().is_err());
+
+        let mut responder = ResponderCeremony::new();
+        assert!(responder.add_frame(&[0u8; 32]).is_err());
+        responder.start_transfer().unwrap();
+        assert!(responder.set_passphrase(Some("late")).is_err());
+        assert!(responder.confirm_mnemonic().is_err());
+    }
+
+    #[test]
+    fn ceremony_invalid_entropy_stays_configuring() {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        let result = initiator.start_transfer(&[0u8; 100]);
+
+        assert!(matches!(result, Err(Error::InvalidEntropySize { .. })));
+        assert_eq!(initiator.state(), CeremonyState::Configuring);
+    }
+
+    #[test]
+    fn ceremony_bad_frame_keeps_transferring() {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator.start_transfer(&test_entropy()).unwrap();
+
+        let mut responder = ResponderCeremony::new();
+        responder.start_transfer().unwrap();
+
+        let mut frame = initiator.next_frame().unwrap();
+        frame[15] ^= 0xFF;
+        assert!(matches!(
+            responder.add_frame(&frame),
+            Err(Error::CrcMismatch { .. })
+        ));
+        assert_eq!(responder.state(), CeremonyState::Transferring);
+    }
+
+    #[test]
+    fn ceremony_reject_mnemonic_aborts() {
+        let (mut initiator, mut responder) = transferred_pair(None);
+
+        initiator.reject_mnemonic().unwrap();
+        responder.reject_mnemonic().unwrap();
+
+        assert_eq!(initiator.state(), CeremonyState::Aborted);
+        assert_eq!(responder.state(), CeremonyState::Aborted);
+        assert!(initiator.mnemonic().is_err());
+        assert!(matches!(
+            responder.finish(),
+            Err(Error::InvalidCeremonyState {
+                state: CeremonyState::Aborted,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn ceremony_abort_is_terminal() {
+        let mut initiator = InitiatorCeremony::new(PadSize::Tiny);
+        initiator.abort();
+
+        assert_eq!(initiator.state(), CeremonyState::Aborted);
+        assert!(initiator.start_transfer(&test_entropy()).is_err());
+        assert!(initiator.set_block_size(256).is_err());
+    }
+
+    #[test]
+    fn ceremony_bundle_debug_redacts_tokens() {
+        let (mut initiator, _) = transferred_pair(None);
+        initiator.confirm_mnemonic().unwrap();
+        let bundle = initiator.finish().unwrap();
+
+        let debug = format!("{:?}", bundle);
+        assert!(!debug.contains(&bundle.auth_token));
+        assert!(!debug.contains(&bundle.burn_token));
+        assert!(debug.contains("[REDACTED]"));
+    }
 }
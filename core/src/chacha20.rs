@@ -0,0 +1,146 @@
+//! ChaCha20 stream cipher (RFC 8439).
+//!
+//! Used only for sealing pad state at rest under a device-held wrapping key.
+//! Message encryption never uses this module; messages are OTP-encrypted
+//! with pad bytes.
+//!
+//! # Security Note
+//!
+//! A (key, nonce) pair must never be reused. Callers supply a fresh random
+//! nonce for every seal.
+
+/// Key size in bytes (256 bits).
+pub const KEY_SIZE: usize = 32;
+
+/// Nonce size in bytes (96 bits, IETF variant).
+pub const NONCE_SIZE: usize = 12;
+
+/// Keystream block size in bytes.
+pub const BLOCK_SIZE: usize = 64;
+
+/// "expand 32-byte k"
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+/// Compute one 64-byte keystream block.
+pub fn block(key: &[u8; KEY_SIZE], counter: u32, nonce: &[u8; NONCE_SIZE]) -> [u8; BLOCK_SIZE] {
+    let word = |bytes: &[u8], i: usize| {
+        u32::from_le_bytes([
+            bytes[i * 4],
+            bytes[i * 4 + 1],
+            bytes[i * 4 + 2],
+            bytes[i * 4 + 3],
+        ])
+    };
+
+    let mut initial = [0u32; 16];
+    initial[..4].copy_from_slice(&CONSTANTS);
+    for i in 0..8 {
+        initial[4 + i] = word(key, i);
+    }
+    initial[12] = counter;
+    for i in 0..3 {
+        initial[13 + i] = word(nonce, i);
+    }
+
+    let mut state = initial;
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut out = [0u8; BLOCK_SIZE];
+    for i in 0..16 {
+        let value = state[i].wrapping_add(initial[i]);
+        out[i * 4..i * 4 + 4].copy_from_slice(&value.to_le_bytes());
+    }
+    out
+}
+
+/// XOR `data` in place with the keystream starting at block `counter`.
+pub fn apply_keystream(
+    key: &[u8; KEY_SIZE],
+    counter: u32,
+    nonce: &[u8; NONCE_SIZE],
+    data: &mut [u8],
+) {
+    for (i, chunk) in data.chunks_mut(BLOCK_SIZE).enumerate() {
+        let mut keystream = block(key, counter.wrapping_add(i as u32), nonce);
+        for (byte, k) in chunk.iter_mut().zip(keystream.iter()) {
+            *byte ^= k;
+        }
+        crate::pad::secure_zero(&mut keystream);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn rfc_key() -> [u8; KEY_SIZE] {
+        let mut key = [0u8; KEY_SIZE];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        key
+    }
+
+    #[test]
+    fn rfc8439_block_function() {
+        // RFC 8439 section 2.3.2
+        let nonce: [u8; NONCE_SIZE] = hex("000000090000004a00000000").try_into().unwrap();
+        let expected = hex(
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e",
+        );
+        assert_eq!(block(&rfc_key(), 1, &nonce).to_vec(), expected);
+    }
+
+    #[test]
+    fn rfc8439_encryption() {
+        // RFC 8439 section 2.4.2
+        let nonce: [u8; NONCE_SIZE] = hex("000000000000004a00000000").try_into().unwrap();
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you \
+only one tip for the future, sunscreen would be it.";
+        let expected = hex(
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b
+             f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8
+             07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736
+             5af90bbf74a35be6b40b8eedf2785e42874d",
+        );
+
+        let mut data = plaintext.to_vec();
+        apply_keystream(&rfc_key(), 1, &nonce, &mut data);
+        assert_eq!(data, expected);
+
+        apply_keystream(&rfc_key(), 1, &nonce, &mut data);
+        assert_eq!(data, plaintext.to_vec());
+    }
+}
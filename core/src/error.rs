@@ -5,14 +5,21 @@
 //!
 //! # Error Categories
 //!
-//! - **Pad errors**: `InsufficientPadBytes`, `InvalidEntropySize`, `PadTooSmallForTokens`
+//! - **Pad errors**: `InsufficientPadBytes`, `InvalidEntropySize`, `PadTooSmallForTokens`, `KeyWiped`
 //! - **OTP errors**: `LengthMismatch`
 //! - **Fountain errors**: `FountainBlockTooShort`, `CrcMismatch`, `EmptyPayload`
 //! - **Metadata errors**: `MetadataTooShort`, `UnsupportedMetadataVersion`, `MetadataUrlTooLong`, `InvalidMetadataUrl`
+//! - **Ceremony errors**: `InvalidCeremonyState`, `MnemonicNotConfirmed`, `InvalidCeremonyPayload`
+//! - **Top-up errors**: `InvalidTopUpOffset`, `TopUpPadMismatch`
+//! - **Pad state errors**: `InvalidPadState`, `PadStateRollback`
+//! - **Journal errors**: `PadRollback`, `PadRangeReuse`, `InvalidJournal`
+//! - **Replay errors**: `ReplayedMessage`, `InvalidReceiveWindow`
 
 use std::error::Error as StdError;
 use std::fmt;
 
+use crate::ceremony::CeremonyState;
+
 /// Result type alias for ash-core operations.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -53,6 +60,16 @@ pub enum Error {
         minimum: usize,
     },
 
+    /// Key material for the range was wiped with `zero_bytes_at`.
+    ///
+    /// The message was already received, or its key expired.
+    KeyWiped {
+        /// Start of the range, from the origin of its direction.
+        start: usize,
+        /// End of the range (exclusive).
+        end: usize,
+    },
+
     // ==================== OTP Errors ====================
     /// Key and data lengths don't match for OTP operation.
     ///
@@ -175,6 +192,113 @@ pub enum Error {
         /// Description of what's wrong.
         reason: String,
     },
+
+    // ==================== Ceremony Errors ====================
+    /// Ceremony step called in the wrong state.
+    ///
+    /// Ceremony steps must run in order: configure, transfer, verify, finish.
+    InvalidCeremonyState {
+        /// The operation that was attempted.
+        operation: &'static str,
+        /// The state the ceremony was in.
+        state: CeremonyState,
+    },
+
+    /// Mnemonic has not been confirmed yet.
+    ///
+    /// Tokens are only derived (and top-up segments only applied) after
+    /// both parties confirm matching mnemonics.
+    MnemonicNotConfirmed,
+
+    /// Decoded ceremony payload is malformed.
+    ///
+    /// All frames decoded but the metadata or pad could not be parsed.
+    InvalidCeremonyPayload,
+
+    // ==================== Top-Up Errors ====================
+    /// Top-up offset falls outside the unconsumed region of the pad.
+    ///
+    /// The pads are out of sync, bytes were consumed during the top-up,
+    /// or the top-up was already applied.
+    InvalidTopUpOffset {
+        /// Offset used by the top-up.
+        offset: usize,
+        /// Start of the unconsumed region.
+        gap_start: usize,
+        /// End of the unconsumed region.
+        gap_end: usize,
+    },
+
+    /// Top-up was created for a pad of a different size.
+    ///
+    /// Both parties must have applied the same previous top-ups.
+    TopUpPadMismatch {
+        /// Pad size the top-up was created for.
+        expected: usize,
+        /// Local pad size.
+        actual: usize,
+    },
+
+    // ==================== Pad State Errors ====================
+    /// Sealed pad state container is malformed or has an unknown version.
+    InvalidPadState {
+        /// What was wrong with the container.
+        reason: &'static str,
+    },
+
+    /// Sealed pad state is older than the last state the caller recorded.
+    ///
+    /// Restoring it would reuse pad bytes that were already consumed.
+    PadStateRollback {
+        /// Generation stored in the container.
+        generation: u64,
+        /// Minimum generation the caller accepts.
+        minimum: u64,
+    },
+
+    // ==================== Journal Errors ====================
+    /// Pad consumption counter is behind the consumption journal.
+    ///
+    /// The pad state was restored from an older backup.
+    PadRollback {
+        /// Consumption recorded in the journal.
+        recorded: usize,
+        /// Consumption according to the pad.
+        actual: usize,
+    },
+
+    /// Pad range was already consumed by the other direction.
+    ///
+    /// Sending would reuse one-time pad bytes.
+    PadRangeReuse {
+        /// Start of the range, from the origin of its direction.
+        start: usize,
+        /// End of the range (exclusive).
+        end: usize,
+    },
+
+    /// Stored consumption journal is malformed or its hash chain is broken.
+    InvalidJournal {
+        /// What was wrong with the journal.
+        reason: &'static str,
+    },
+
+    // ==================== Replay Errors ====================
+    /// Message overlaps one that was already accepted.
+    ///
+    /// The relay delivered the same ciphertext again.
+    ReplayedMessage {
+        /// Start of the range, from the origin of the peer's direction.
+        start: usize,
+        /// End of the range (exclusive).
+        end: usize,
+    },
+
+    /// Stored receive window is malformed.
+    InvalidReceiveWindow {
+        /// What was wrong with the window.
+        reason: &'static str,
+    },
 }
 
 impl fmt::Display for Error {
@@ -201,6 +325,9 @@ impl fmt::Display for Error {
                     size, minimum
                 )
             }
+            Error::KeyWiped { start, end } => {
+                write!(f, "key material for {}..{} was wiped", start, end)
+            }
             Error::LengthMismatch { pad_len, data_len } => {
                 write!(
                     f,
@@ -254,11 +381,7 @@ impl fmt::Display for Error {
                 )
             }
             Error::FrameTooShort { size, minimum } => {
-                write!(
-                    f,
-                    "frame too short: {} bytes, minimum is {}",
-                    size, minimum
-                )
+                write!(f, "frame too short: {} bytes, minimum is {}", size, minimum)
             }
             Error::UnsupportedFrameVersion { version } => {
                 write!(f, "unsupported frame version: {}", version)
@@ -276,6 +399,73 @@ impl fmt::Display for Error {
             Error::InvalidPadding { reason } => {
                 write!(f, "invalid padding: {}", reason)
             }
+            Error::InvalidCeremonyState { operation, state } => {
+                write!(
+                    f,
+                    "invalid ceremony state: cannot {} while {}",
+                    operation,
+                    state.name()
+                )
+            }
+            Error::MnemonicNotConfirmed => {
+                write!(
+                    f,
+                    "mnemonic must be confirmed before finishing the ceremony"
+                )
+            }
+            Error::InvalidCeremonyPayload => {
+                write!(f, "invalid ceremony payload")
+            }
+            Error::InvalidTopUpOffset {
+                offset,
+                gap_start,
+                gap_end,
+            } => {
+                write!(
+                    f,
+                    "invalid top-up offset: {} outside unconsumed range {}..{}",
+                    offset, gap_start, gap_end
+                )
+            }
+            Error::TopUpPadMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "top-up pad mismatch: created for {} bytes, local pad has {}",
+                    expected, actual
+                )
+            }
+            Error::InvalidPadState { reason } => {
+                write!(f, "invalid pad state: {}", reason)
+            }
+            Error::PadStateRollback {
+                generation,
+                minimum,
+            } => {
+                write!(
+                    f,
+                    "pad state rollback: generation {} is older than {}",
+                    generation, minimum
+                )
+            }
+            Error::PadRollback { recorded, actual } => {
+                write!(
+                    f,
+                    "pad rollback: journal recorded {} bytes consumed, pad has {}",
+                    recorded, actual
+                )
+            }
+            Error::PadRangeReuse { start, end } => {
+                write!(f, "pad range {}..{} was already consumed", start, end)
+            }
+            Error::InvalidJournal { reason } => {
+                write!(f, "invalid consumption journal: {}", reason)
+            }
+            Error::ReplayedMessage { start, end } => {
+                write!(f, "message at {}..{} was already received", start, end)
+            }
+            Error::InvalidReceiveWindow { reason } => {
+                write!(f, "invalid receive window: {}", reason)
+            }
         }
     }
 }
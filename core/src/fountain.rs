@@ -76,6 +76,10 @@ impl EncodedBlock {
         let block_size = u16::from_be_bytes([bytes[6], bytes[7]]);
         let original_len = u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
 
+        if block_size == 0 {
+            return Err(Error::EmptyPayload);
+        }
+
         let data_end = 12 + block_size as usize;
         if bytes.len() < data_end + 4 {
             return Err(Error::FountainBlockTooShort {
@@ -336,6 +340,11 @@ impl LTDecoder {
             return true;
         }
 
+        // Ignore blocks from a different transfer
+        if !self.accepts(block) {
+            return false;
+        }
+
         // Skip duplicates
         if self.seen_indices.contains(&block.index) {
             return self.is_complete();
@@ -500,6 +509,14 @@ impl LTDecoder {
         k
     }
 
+    /// Whether `block` has the parameters this decoder was created with.
+    fn accepts(&self, block: &EncodedBlock) -> bool {
+        block.source_count as usize == self.k
+            && block.block_size as usize == self.block_size
+            && block.original_len as usize == self.original_len
+            && block.data.len() == self.block_size
+    }
+
     /// Check if all source blocks are decoded.
     pub fn is_complete(&self) -> bool {
         self.decoded.iter().all(|b| b.is_some())
@@ -623,7 +640,7 @@ mod tests {
             block_size: 64,
             original_len: 300,
             data: vec![0x12; 64],
-            checksum: crc::compute(&vec![0x12; 64]),
+            checksum: crc::compute(&[0x12; 64]),
         };
 
         let mut encoded = block.encode();
@@ -635,6 +652,40 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn encoded_block_rejects_zero_block_size() {
+        let mut encoded = vec![0u8; 12];
+        encoded.extend_from_slice(&crc::compute(&[]).to_be_bytes());
+        encoded.push(0);
+
+        assert_eq!(EncodedBlock::decode(&encoded), Err(Error::EmptyPayload));
+    }
+
+    #[test]
+    fn lt_ignores_foreign_blocks() {
+        let data = b"Blocks from another transfer";
+        let encoder = LTEncoder::new(data, 8);
+        let k = encoder.source_count();
+        let mut decoder = LTDecoder::new(k as u16, 8, data.len());
+
+        // Wrong block size, wrong source count and truncated data
+        let mut wrong_size = LTEncoder::new(data, 4).generate_block(0);
+        wrong_size.source_count = k as u16;
+        let mut wrong_count = encoder.generate_block(0);
+        wrong_count.source_count += 1;
+        let mut truncated = encoder.generate_block(0);
+        truncated.data.pop();
+        for block in [&wrong_size, &wrong_count, &truncated] {
+            assert!(!decoder.add_block(block));
+        }
+        assert_eq!(decoder.unique_blocks_received(), 0);
+
+        for index in 0..k as u32 {
+            decoder.add_block(&encoder.generate_block(index));
+        }
+        assert_eq!(decoder.get_data().unwrap(), data);
+    }
+
     #[test]
     fn lt_small_data() {
         let data = b"Hello, LT codes!";
@@ -733,20 +784,16 @@ mod tests {
 
         // Add every other block
         for (i, block) in blocks.iter().enumerate() {
-            if i % 2 == 0 {
-                if decoder.add_block(block) {
-                    break;
-                }
+            if i % 2 == 0 && decoder.add_block(block) {
+                break;
             }
         }
 
         // If not complete, add the rest
         if !decoder.is_complete() {
             for (i, block) in blocks.iter().enumerate() {
-                if i % 2 == 1 {
-                    if decoder.add_block(block) {
-                        break;
-                    }
+                if i % 2 == 1 && decoder.add_block(block) {
+                    break;
                 }
             }
         }
@@ -160,7 +160,7 @@ impl SequentialEncoder {
     /// Create a new sequential encoder.
     fn new(data: &[u8], block_size: usize) -> Self {
         let original_len = data.len();
-        let source_count = (data.len() + block_size - 1) / block_size;
+        let source_count = data.len().div_ceil(block_size);
 
         let mut source_blocks = Vec::with_capacity(source_count);
         for i in 0..source_count {
@@ -465,6 +465,9 @@ fn encrypt_fountain_frame(frame: &[u8], passphrase: &str) -> Vec<u8> {
     // Account for method byte prefix
     let method_len = 1;
     let header_len = method_len + 12; // method(1) + index(4) + source_count(2) + block_size(2) + original_len(4)
+    if frame.len() < header_len {
+        return result; // Invalid frame, return as-is
+    }
     let block_size = u16::from_be_bytes([frame[method_len + 6], frame[method_len + 7]]) as usize;
     let payload_end = header_len + block_size;
 
@@ -882,20 +885,16 @@ mod tests {
         // Receive every other block
         let mut receiver = FountainFrameReceiver::new(None);
         for (i, frame) in frames.iter().enumerate() {
-            if i % 2 == 0 {
-                if receiver.add_frame(frame).unwrap() {
-                    break;
-                }
+            if i % 2 == 0 && receiver.add_frame(frame).unwrap() {
+                break;
             }
         }
 
         // Add remaining if needed
         if !receiver.is_complete() {
             for (i, frame) in frames.iter().enumerate() {
-                if i % 2 == 1 {
-                    if receiver.add_frame(frame).unwrap() {
-                        break;
-                    }
+                if i % 2 == 1 && receiver.add_frame(frame).unwrap() {
+                    break;
                 }
             }
         }
@@ -953,6 +952,15 @@ mod tests {
         assert!(matches!(result, Err(Error::EmptyPayload)));
     }
 
+    #[test]
+    fn truncated_frame_with_passphrase_is_rejected() {
+        let mut receiver = FountainFrameReceiver::new(Some("secret"));
+        for len in 1..14 {
+            assert!(receiver.add_frame(&vec![0u8; len]).is_err());
+        }
+        assert_eq!(receiver.blocks_received(), 0);
+    }
+
     #[test]
     fn fountain_generator_deterministic() {
         let metadata = crate::CeremonyMetadata::default();
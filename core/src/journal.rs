@@ -0,0 +1,506 @@
+//! Consumption journal for detecting pad reuse after a rollback.
+//!
+//! Restoring an old backup with [`Pad::from_bytes_with_state`] rewinds the
+//! consumption counters, and the next send silently reuses pad bytes. For a
+//! one-time pad that is catastrophic: two ciphertexts under the same key
+//! leak the XOR of their plaintexts.
+//!
+//! The journal keeps a monotonic, hash-chained record of every range
+//! consumed in each direction:
+//!
+//! - Outgoing messages go through [`ConsumptionJournal::consume`]
+//! - Ranges of authenticated messages received from the peer are recorded
+//!   with [`ConsumptionJournal::record_received`]
+//!
+//! Before each send the journal compares the pad against what it has seen.
+//! A counter behind the journal fails with [`Error::PadRollback`], and a
+//! range the peer is known to have consumed fails with
+//! [`Error::PadRangeReuse`].
+//!
+//! # Offsets
+//!
+//! Ranges are measured from the origin of their direction: from the start
+//! of the pad for the Initiator and from the end for the Responder. They stay
+//! valid when a top-up splices bytes into the middle of the pad.
+//!
+//! # Hash Chain
+//!
+//! ```text
+//! genesis = SHA-256("ash-journal-v1" || role)
+//! hash_i  = SHA-256("ash-journal-v1" || hash_{i-1} || direction || start || len)
+//! ```
+//!
+//! Apps persist the journal separately from the pad (see [`encode`]) and can
+//! keep [`head`] somewhere with different backup semantics, such as the
+//! platform keychain, to notice when the journal itself was rolled back.
+//!
+//! [`encode`]: ConsumptionJournal::encode
+//! [`head`]: ConsumptionJournal::head
+//!
+//! # Example
+//!
+//! ```
+//! use ash_core::journal::ConsumptionJournal;
+//! use ash_core::pad::{Pad, Role};
+//! use ash_core::Error;
+//!
+//! let bytes = vec![0x42; 1000];
+//! let mut pad = Pad::from_bytes(bytes.clone());
+//! let mut journal = ConsumptionJournal::new(Role::Initiator);
+//!
+//! journal.consume(&mut pad, 100).unwrap();
+//! let backup = pad.serialize_state();
+//! journal.consume(&mut pad, 100).unwrap();
+//!
+//! // Restoring the older backup is caught before any byte is reused
+//! let mut restored = Pad::from_bytes_with_state(backup.0, backup.1, backup.2);
+//! assert!(matches!(
+//!     journal.consume(&mut restored, 50),
+//!     Err(Error::PadRollback { .. })
+//! ));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::pad::{read_u64_be, Pad, Role};
+use crate::secret::SecretBytes;
+use crate::sha256::{Sha256, DIGEST_SIZE};
+
+/// Magic bytes identifying an encoded journal.
+const JOURNAL_MAGIC: &[u8; 4] = b"ASHJ";
+
+/// Current journal encoding version.
+pub const JOURNAL_VERSION: u8 = 1;
+
+/// Domain separation for chain hashes.
+const JOURNAL_DOMAIN: &[u8] = b"ash-journal-v1";
+
+/// Encoded size of the journal header.
+const HEADER_LEN: usize = 4 + 1 + 1 + 8;
+
+/// Encoded size of one entry.
+const ENTRY_LEN: usize = 1 + 8 + 8 + DIGEST_SIZE;
+
+/// One consumed range in the journal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Direction the bytes were consumed in.
+    pub direction: Role,
+    /// Start of the range, in bytes from the direction's origin.
+    pub start: usize,
+    /// Number of bytes in the range.
+    pub len: usize,
+    /// Chain hash covering this entry and all earlier ones.
+    pub hash: [u8; DIGEST_SIZE],
+}
+
+/// Monotonic, hash-chained record of pad consumption in both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumptionJournal {
+    role: Role,
+    entries: Vec<JournalEntry>,
+    /// Highest end offset recorded in our direction
+    own_end: usize,
+    /// Highest end offset observed in the peer's direction
+    peer_end: usize,
+}
+
+impl ConsumptionJournal {
+    /// Create an empty journal for our role.
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            entries: Vec::new(),
+            own_end: 0,
+            peer_end: 0,
+        }
+    }
+
+    /// Our role in the conversation.
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// All recorded entries, oldest first.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Hash of the latest entry (the genesis hash while empty).
+    pub fn head(&self) -> [u8; DIGEST_SIZE] {
+        self.entries
+            .last()
+            .map_or_else(|| genesis(self.role), |entry| entry.hash)
+    }
+
+    /// Bytes recorded as consumed in our direction.
+    pub fn own_consumed(&self) -> usize {
+        self.own_end
+    }
+
+    /// Bytes the peer has been observed consuming in its direction.
+    pub fn peer_consumed(&self) -> usize {
+        self.peer_end
+    }
+
+    /// Consume pad bytes for an outgoing message and record the range.
+    ///
+    /// Use this instead of [`Pad::consume`] for everything we send.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::PadRollback`] if the pad's counter is behind the journal
+    /// - [`Error::PadRangeReuse`] if the range was already consumed by the peer
+    /// - [`Error::InsufficientPadBytes`] if the pad has too few bytes left
+    pub fn consume(&mut self, pad: &mut Pad, n: usize) -> Result<SecretBytes> {
+        self.check_send(pad, n)?;
+        let start = consumed(pad, self.role);
+        let bytes = pad.consume(n, self.role)?;
+        self.append(self.role, start, n);
+        Ok(bytes)
+    }
+
+    /// Record the range of an authenticated message received from the peer.
+    ///
+    /// Call this only after the message's tag verified, so the range is
+    /// known to come from the peer. The pad's view of the peer's consumption
+    /// is advanced as with [`Pad::update_peer_consumption`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pad` - Our pad
+    /// * `offset` - The peer's [`Pad::send_position`] when it sent the message
+    /// * `len` - Pad bytes the message consumed (auth key + ciphertext)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PadRangeReuse`] if the range overlaps bytes recorded
+    /// as consumed in our direction. The pad has been used twice; the
+    /// conversation should be burned.
+    pub fn record_received(&mut self, pad: &mut Pad, offset: usize, len: usize) -> Result<()> {
+        let peer = self.role.peer();
+        let total = pad.total_size();
+        let (start, end) = match offset.checked_add(len) {
+            Some(end) if end <= total => (offset, end),
+            _ => {
+                return Err(Error::InsufficientPadBytes {
+                    needed: len,
+                    available: pad.remaining(),
+                })
+            }
+        };
+
+        if end + self.own_end > total {
+            return Err(Error::PadRangeReuse { start, end });
+        }
+
+        if end > self.peer_end {
+            self.append(peer, start, len);
+        }
+        pad.update_peer_consumption(peer, end)?;
+        Ok(())
+    }
+
+    /// Check that the pad has not been rolled back behind the journal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PadRollback`] if either consumption counter is lower
+    /// than the journal's record.
+    pub fn check(&self, pad: &Pad) -> Result<()> {
+        for (direction, recorded) in [(self.role, self.own_end), (self.role.peer(), self.peer_end)]
+        {
+            let actual = consumed(pad, direction);
+            if actual < recorded {
+                return Err(Error::PadRollback { recorded, actual });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that sending `n` bytes would not reuse any recorded range.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::PadRollback`] if our counter is behind the journal
+    /// - [`Error::PadRangeReuse`] if the range reaches into bytes the peer
+    ///   has been seen consuming, even though the pad thinks they are free
+    pub fn check_send(&self, pad: &Pad, n: usize) -> Result<()> {
+        let start = consumed(pad, self.role);
+        if start < self.own_end {
+            return Err(Error::PadRollback {
+                recorded: self.own_end,
+                actual: start,
+            });
+        }
+
+        let end = start.saturating_add(n);
+        if end.saturating_add(self.peer_end) > pad.total_size() && pad.can_send(n, self.role) {
+            return Err(Error::PadRangeReuse { start, end });
+        }
+        Ok(())
+    }
+
+    /// Encode the journal for storage.
+    ///
+    /// Format: `[magic: "ASHJ"][version: u8][role: u8][count: u64 BE]`
+    /// followed by `[direction: u8][start: u64 BE][len: u64 BE][hash: 32]` per entry.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.entries.len() * ENTRY_LEN);
+        bytes.extend_from_slice(JOURNAL_MAGIC);
+        bytes.push(JOURNAL_VERSION);
+        bytes.push(self.role.to_byte());
+        bytes.extend_from_slice(&(self.entries.len() as u64).to_be_bytes());
+        for entry in &self.entries {
+            bytes.push(entry.direction.to_byte());
+            bytes.extend_from_slice(&(entry.start as u64).to_be_bytes());
+            bytes.extend_from_slice(&(entry.len as u64).to_be_bytes());
+            bytes.extend_from_slice(&entry.hash);
+        }
+        bytes
+    }
+
+    /// Decode a stored journal, verifying the whole hash chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidJournal`] if the encoding is malformed or any
+    /// entry does not match its chain hash.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let invalid = |reason| Error::InvalidJournal { reason };
+
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != JOURNAL_MAGIC {
+            return Err(invalid("bad header"));
+        }
+        if bytes[4] != JOURNAL_VERSION {
+            return Err(invalid("unsupported version"));
+        }
+        let role = Role::from_byte(bytes[5]).ok_or(invalid("unknown role"))?;
+        let count = read_u64_be(bytes, 6);
+        let body = &bytes[HEADER_LEN..];
+        if body.len() as u64 != count.saturating_mul(ENTRY_LEN as u64) {
+            return Err(invalid("entry count mismatch"));
+        }
+
+        let mut journal = Self::new(role);
+        for chunk in body.chunks_exact(ENTRY_LEN) {
+            let direction = Role::from_byte(chunk[0]).ok_or(invalid("unknown role"))?;
+            let start =
+                usize::try_from(read_u64_be(chunk, 1)).map_err(|_| invalid("range overflow"))?;
+            let len =
+                usize::try_from(read_u64_be(chunk, 9)).map_err(|_| invalid("range overflow"))?;
+            if start.checked_add(len).is_none() {
+                return Err(invalid("range overflow"));
+            }
+
+            journal.append(direction, start, len);
+            if journal.head()[..] != chunk[17..] {
+                return Err(invalid("hash chain broken"));
+            }
+        }
+        Ok(journal)
+    }
+
+    fn append(&mut self, direction: Role, start: usize, len: usize) {
+        let mut hasher = Sha256::new();
+        hasher.update(JOURNAL_DOMAIN);
+        hasher.update(&self.head());
+        hasher.update(&[direction.to_byte()]);
+        hasher.update(&(start as u64).to_be_bytes());
+        hasher.update(&(len as u64).to_be_bytes());
+        let hash = hasher.finalize();
+
+        let end = start + len;
+        if direction == self.role {
+            self.own_end = self.own_end.max(end);
+        } else {
+            self.peer_end = self.peer_end.max(end);
+        }
+        self.entries.push(JournalEntry {
+            direction,
+            start,
+            len,
+            hash,
+        });
+    }
+}
+
+fn genesis(role: Role) -> [u8; DIGEST_SIZE] {
+    let mut hasher = Sha256::new();
+    hasher.update(JOURNAL_DOMAIN);
+    hasher.update(&[role.to_byte()]);
+    hasher.finalize()
+}
+
+/// Bytes consumed in `direction`, measured from its origin.
+fn consumed(pad: &Pad, direction: Role) -> usize {
+    match direction {
+        Role::Initiator => pad.consumed_front(),
+        Role::Responder => pad.consumed_back(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pad() -> Pad {
+        Pad::from_bytes((0..1000u32).map(|i| i as u8).collect())
+    }
+
+    #[test]
+    fn journal_records_sends() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Responder);
+
+        let first = journal.consume(&mut pad, 100).unwrap();
+        let second = journal.consume(&mut pad, 50).unwrap();
+
+        assert_eq!(first, pad.as_bytes()[900..1000]);
+        assert_eq!(second, pad.as_bytes()[850..900]);
+        assert_eq!(journal.own_consumed(), 150);
+        assert_eq!(journal.entries().len(), 2);
+        assert_eq!(journal.entries()[1].start, 100);
+        assert_ne!(journal.entries()[0].hash, journal.entries()[1].hash);
+    }
+
+    #[test]
+    fn journal_detects_own_rollback() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+        journal.consume(&mut pad, 100).unwrap();
+
+        let mut restored = Pad::from_bytes(pad.as_bytes().to_vec());
+        assert_eq!(
+            journal.consume(&mut restored, 10).unwrap_err(),
+            Error::PadRollback {
+                recorded: 100,
+                actual: 0
+            }
+        );
+        assert_eq!(restored.consumed_front(), 0);
+        assert_eq!(journal.entries().len(), 1);
+    }
+
+    #[test]
+    fn journal_refuses_range_seen_from_peer() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+
+        // Peer (Responder) sent a message over the last 600 bytes
+        journal.record_received(&mut pad, 0, 600).unwrap();
+        assert_eq!(pad.consumed_back(), 600);
+        assert_eq!(journal.peer_consumed(), 600);
+
+        // A rolled-back pad thinks those bytes are still free
+        let mut restored = Pad::from_bytes(pad.as_bytes().to_vec());
+        assert!(matches!(
+            journal.check(&restored),
+            Err(Error::PadRollback { .. })
+        ));
+        assert_eq!(
+            journal.consume(&mut restored, 500).unwrap_err(),
+            Error::PadRangeReuse { start: 0, end: 500 }
+        );
+        assert_eq!(restored.consumed_front(), 0);
+
+        // Sends that stay clear of the peer's range are fine
+        journal.consume(&mut restored, 400).unwrap();
+    }
+
+    #[test]
+    fn journal_rejects_peer_overlapping_own_range() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Responder);
+        journal.consume(&mut pad, 300).unwrap();
+
+        // Peer (Initiator) claims bytes 600..800, which we already used
+        assert_eq!(
+            journal.record_received(&mut pad, 600, 200).unwrap_err(),
+            Error::PadRangeReuse {
+                start: 600,
+                end: 800
+            }
+        );
+        journal.record_received(&mut pad, 0, 200).unwrap();
+        assert_eq!(pad.consumed_front(), 200);
+    }
+
+    #[test]
+    fn journal_out_of_order_receive_is_not_rerecorded() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Responder);
+        journal.record_received(&mut pad, 100, 100).unwrap();
+        journal.record_received(&mut pad, 0, 100).unwrap();
+
+        assert_eq!(journal.entries().len(), 1);
+        assert_eq!(journal.peer_consumed(), 200);
+    }
+
+    #[test]
+    fn journal_insufficient_bytes_passthrough() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+        assert!(matches!(
+            journal.consume(&mut pad, 1001),
+            Err(Error::InsufficientPadBytes { .. })
+        ));
+        assert!(journal.entries().is_empty());
+    }
+
+    #[test]
+    fn journal_encode_decode_roundtrip() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+        journal.consume(&mut pad, 64).unwrap();
+        journal.record_received(&mut pad, 0, 96).unwrap();
+        journal.consume(&mut pad, 80).unwrap();
+
+        let decoded = ConsumptionJournal::decode(&journal.encode()).unwrap();
+        assert_eq!(decoded, journal);
+        assert_eq!(decoded.head(), journal.head());
+    }
+
+    #[test]
+    fn journal_decode_detects_edits() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+        journal.consume(&mut pad, 64).unwrap();
+        journal.consume(&mut pad, 80).unwrap();
+        let encoded = journal.encode();
+
+        // Rewriting a range breaks the chain
+        let mut edited = encoded.clone();
+        edited[HEADER_LEN + ENTRY_LEN + 16] ^= 0x01;
+        assert_eq!(
+            ConsumptionJournal::decode(&edited).unwrap_err(),
+            Error::InvalidJournal {
+                reason: "hash chain broken"
+            }
+        );
+
+        // Dropping the last entry decodes, but the head no longer matches
+        let mut truncated = encoded[..HEADER_LEN + ENTRY_LEN].to_vec();
+        truncated[6..14].copy_from_slice(&1u64.to_be_bytes());
+        let older = ConsumptionJournal::decode(&truncated).unwrap();
+        assert_ne!(older.head(), journal.head());
+
+        assert!(ConsumptionJournal::decode(&encoded[..HEADER_LEN + 10]).is_err());
+    }
+
+    #[test]
+    fn journal_offsets_survive_topup() {
+        let mut pad = pad();
+        let mut journal = ConsumptionJournal::new(Role::Initiator);
+        journal.record_received(&mut pad, 0, 100).unwrap();
+        journal.consume(&mut pad, 100).unwrap();
+
+        pad.insert_segment(500, &[0u8; 200]).unwrap();
+        journal.check(&pad).unwrap();
+
+        // Peer's next message continues where it left off
+        let offset = pad.send_position(Role::Responder);
+        assert_eq!(offset, 100);
+        journal.record_received(&mut pad, offset, 100).unwrap();
+        assert_eq!(pad.consumed_back(), 200);
+    }
+}
@@ -5,6 +5,9 @@
 //! - Authenticated encryption (OTP + Wegman-Carter MAC)
 //! - Fountain code encoding for reliable QR transfer
 //! - Mnemonic checksum generation
+//! - Sealed pad state for storage at rest (caller-held wrapping key)
+//! - Key retention that wipes message keys after delivery or expiry
+//! - Replay rejection for messages redelivered by the relay
 //!
 //! # Security Properties
 //!
@@ -110,25 +113,45 @@ pub mod error;
 pub mod fountain;
 pub mod frame;
 pub mod gf128;
+pub mod journal;
 pub mod mac;
 pub mod mnemonic;
 pub mod message;
 pub mod pad;
 pub mod pad_calculator;
+pub mod pad_state;
 pub mod passphrase;
 pub mod poly_hash;
 pub mod raptor;
+pub mod replay;
+pub mod retention;
+pub mod secret;
+pub mod topup;
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors;
 pub mod wordlist;
 
 // Internal modules - not part of public API
 // OTP is low-level XOR; always use MessageFrame for authenticated encryption
 pub(crate) mod otp;
 
+// ChaCha20 only seals pad state at rest; messages never use it
+pub(crate) mod chacha20;
+// SHA-256 chains consumption journal entries and derives relay auth values
+pub(crate) mod sha256;
+
 // Re-export main types at crate root
-pub use ceremony::{CeremonyMetadata, NotificationFlags, DEFAULT_TTL_SECONDS, METADATA_VERSION};
+pub use ceremony::{
+    CeremonyMetadata, CeremonyState, ConversationBundle, InitiatorCeremony, NotificationFlags,
+    ResponderCeremony, DEFAULT_TTL_SECONDS, METADATA_VERSION,
+};
 pub use error::{Error, Result};
+pub use journal::{ConsumptionJournal, JournalEntry};
 pub use fountain::{EncodedBlock, FountainDecoder, FountainEncoder, LegacyLTEncoder, LegacyLTDecoder};
 pub use raptor::{RaptorDecoder, RaptorEncoder};
+pub use replay::ReceiveWindow;
+pub use retention::{RetentionManager, RetentionPolicy, RetentionSummary, WipeReason};
+pub use topup::{TopUpReceiver, TopUpSender, TopUpSummary};
 pub use frame::{
     create_fountain_ceremony, FountainCeremonyResult, FountainFrameGenerator,
     FountainFrameReceiver, TransferMethod, DEFAULT_BLOCK_SIZE,
@@ -137,15 +160,18 @@ pub use frame::{
 // Re-export pad calculator types for convenience
 pub use pad_calculator::{
     calculate_pad_stats, calculate_pad_stats_with_qr_size, expected_frames, redundancy_blocks,
-    PadCalculator, PadStats, METADATA_OVERHEAD, DEFAULT_QR_BLOCK_SIZE,
+    CapacityForecast, ForecastConfig, PadCalculator, PadStats, METADATA_OVERHEAD,
+    DEFAULT_QR_BLOCK_SIZE,
 };
 pub use pad::{Pad, PadSize, Role};
+pub use pad_state::{open_pad_state, seal_pad_state, PadState};
+pub use secret::SecretBytes;
 
 // Re-export authenticated message types - the primary API for encryption
 pub use mac::{AuthKey, AUTH_KEY_SIZE, TAG_SIZE};
 pub use message::{
-    pad_message, unpad_message, MessageFrame, MessageType,
-    HEADER_SIZE, MIN_FRAME_SIZE, MIN_PADDED_SIZE,
+    pad_message, unpad_message, MessageFrame, MessageType, PaddingPolicy,
+    HEADER_SIZE, MIN_FRAME_SIZE, MIN_PADDED_SIZE, OFFSET_HEADER_SIZE,
 };
 
 /// Library version.
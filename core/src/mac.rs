@@ -57,6 +57,7 @@
 //! ```
 
 use crate::gf128::{constant_time_eq_32, xor_bytes_16};
+use crate::pad::secure_zero;
 use crate::poly_hash::poly_hash_segments;
 
 /// Size of authentication tag in bytes (256 bits).
@@ -70,8 +71,8 @@ pub const AUTH_KEY_SIZE: usize = 64;
 /// Authentication key for Wegman-Carter MAC.
 ///
 /// Contains two hash keys and two one-time masks for 256-bit security.
-/// Each instance must be used for exactly one message.
-#[derive(Clone)]
+/// Each instance must be used for exactly one message, so it cannot be
+/// cloned. The key is wiped when dropped.
 pub struct AuthKey {
     /// First polynomial hash key
     r1: [u8; 16],
@@ -124,8 +125,25 @@ impl AuthKey {
             "AuthKey requires exactly {} bytes",
             AUTH_KEY_SIZE
         );
-        let arr: [u8; AUTH_KEY_SIZE] = bytes.try_into().unwrap();
-        Self::from_bytes(&arr)
+        let mut arr: [u8; AUTH_KEY_SIZE] = bytes.try_into().unwrap();
+        let key = Self::from_bytes(&arr);
+        secure_zero(&mut arr);
+        key
+    }
+}
+
+impl Drop for AuthKey {
+    fn drop(&mut self) {
+        for part in [&mut self.r1, &mut self.r2, &mut self.s1, &mut self.s2] {
+            secure_zero(part);
+        }
+    }
+}
+
+impl std::fmt::Debug for AuthKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't expose key material in debug output
+        f.write_str("AuthKey([REDACTED])")
     }
 }
 
@@ -510,4 +528,11 @@ mod tests {
         let low: [u8; 16] = tag[16..32].try_into().unwrap();
         assert_ne!(high, low);
     }
+
+    #[test]
+    fn debug_does_not_leak_key() {
+        let key = AuthKey::from_bytes(&[0xAB; AUTH_KEY_SIZE]);
+        let debug = format!("{:?}", key);
+        assert_eq!(debug, "AuthKey([REDACTED])");
+    }
 }
@@ -18,12 +18,24 @@
 //!                  Full frame
 //! ```
 //!
-//! - **version**: Frame format version (currently 1)
+//! - **version**: Frame format version (1 or 2)
 //! - **type**: Message type (text, location, etc.)
 //! - **length**: Ciphertext length in bytes (big-endian u16)
 //! - **ciphertext**: OTP-encrypted payload
 //! - **tag**: 256-bit Wegman-Carter authentication tag
 //!
+//! # Frame Format (v2)
+//!
+//! Version 2 adds the sender's pad offset to the header, so the receiver can
+//! locate the key material without relying on anything outside the frame:
+//!
+//! ```text
+//! [version: 1][type: 1][length: 2][offset: 8 (BE)][ciphertext: N][tag: 32]
+//! ```
+//!
+//! - **offset**: The sender's [`Pad::send_position`](crate::pad::Pad::send_position)
+//!   for this message, authenticated with the rest of the header
+//!
 //! # Security Properties
 //!
 //! - **Confidentiality**: OTP encryption (information-theoretic)
@@ -66,6 +78,7 @@
 use crate::error::{Error, Result};
 use crate::mac::{compute_tag, verify_tag, AuthKey, TAG_SIZE};
 use crate::otp;
+use crate::secret::SecretBytes;
 
 /// Minimum padded message size (32 bytes).
 pub const MIN_PADDED_SIZE: usize = 32;
@@ -79,9 +92,15 @@ pub const MAX_MESSAGE_CONTENT_LEN: usize = u16::MAX as usize;
 /// Frame format version.
 pub const FRAME_VERSION: u8 = 1;
 
+/// Frame format version whose header also carries the pad offset.
+pub const OFFSET_FRAME_VERSION: u8 = 2;
+
 /// Header size in bytes (version + type + length).
 pub const HEADER_SIZE: usize = 4;
 
+/// Header size of a version 2 frame (version + type + length + offset).
+pub const OFFSET_HEADER_SIZE: usize = HEADER_SIZE + 8;
+
 /// Minimum frame size (header + tag, no ciphertext).
 pub const MIN_FRAME_SIZE: usize = HEADER_SIZE + TAG_SIZE;
 
@@ -122,6 +141,8 @@ impl MessageType {
 pub struct MessageFrame {
     /// Message type.
     pub msg_type: MessageType,
+    /// Sender's pad offset, carried in the header of version 2 frames.
+    pub offset: Option<u64>,
     /// Encrypted payload.
     pub ciphertext: Vec<u8>,
     /// 256-bit authentication tag.
@@ -151,6 +172,32 @@ impl MessageFrame {
         plaintext: &[u8],
         encryption_key: &[u8],
         auth_key: &AuthKey,
+    ) -> Result<Self> {
+        Self::seal(msg_type, None, plaintext, encryption_key, auth_key)
+    }
+
+    /// Create a version 2 frame that carries the sender's pad offset.
+    ///
+    /// `offset` is the sender's [`Pad::send_position`](crate::pad::Pad::send_position)
+    /// before consuming the message's key material. It is covered by the
+    /// tag, so the receiver can use it to look up the key. Other arguments
+    /// and errors are as for [`encrypt`](Self::encrypt).
+    pub fn encrypt_at(
+        msg_type: MessageType,
+        offset: u64,
+        plaintext: &[u8],
+        encryption_key: &[u8],
+        auth_key: &AuthKey,
+    ) -> Result<Self> {
+        Self::seal(msg_type, Some(offset), plaintext, encryption_key, auth_key)
+    }
+
+    fn seal(
+        msg_type: MessageType,
+        offset: Option<u64>,
+        plaintext: &[u8],
+        encryption_key: &[u8],
+        auth_key: &AuthKey,
     ) -> Result<Self> {
         if plaintext.len() > MAX_CIPHERTEXT_LEN {
             return Err(Error::PayloadTooLarge {
@@ -163,13 +210,14 @@ impl MessageFrame {
         let ciphertext = otp::encrypt(encryption_key, plaintext)?;
 
         // Build header for authentication
-        let header = Self::build_header(msg_type, ciphertext.len());
+        let header = Self::build_header(msg_type, offset, ciphertext.len());
 
         // Compute authentication tag over header || ciphertext
         let tag = compute_tag(auth_key, &header, &ciphertext);
 
         Ok(Self {
             msg_type,
+            offset,
             ciphertext,
             tag,
         })
@@ -187,15 +235,15 @@ impl MessageFrame {
     ///
     /// # Returns
     ///
-    /// Decrypted plaintext if authentication succeeds.
+    /// Decrypted plaintext if authentication succeeds, wiped when dropped.
     ///
     /// # Errors
     ///
     /// - `AuthenticationFailed` if the tag doesn't verify
     /// - `LengthMismatch` if encryption key length doesn't match ciphertext
-    pub fn decrypt(&self, encryption_key: &[u8], auth_key: &AuthKey) -> Result<Vec<u8>> {
+    pub fn decrypt(&self, encryption_key: &[u8], auth_key: &AuthKey) -> Result<SecretBytes> {
         // Build header for verification
-        let header = Self::build_header(self.msg_type, self.ciphertext.len());
+        let header = self.header();
 
         // Verify authentication FIRST (before any decryption)
         if !verify_tag(auth_key, &header, &self.ciphertext, &self.tag) {
@@ -203,7 +251,7 @@ impl MessageFrame {
         }
 
         // Decrypt only after successful verification
-        otp::decrypt(encryption_key, &self.ciphertext)
+        otp::decrypt(encryption_key, &self.ciphertext).map(SecretBytes::new)
     }
 
     /// Encode the frame to bytes for transmission.
@@ -211,16 +259,13 @@ impl MessageFrame {
     /// # Wire Format
     ///
     /// ```text
-    /// [version: 1][type: 1][length: 2][ciphertext: N][tag: 32]
+    /// [version: 1][type: 1][length: 2][offset: 8, v2 only][ciphertext: N][tag: 32]
     /// ```
     pub fn encode(&self) -> Vec<u8> {
-        let len = self.ciphertext.len() as u16;
-        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.ciphertext.len() + TAG_SIZE);
+        let mut bytes = Vec::with_capacity(self.encoded_size());
 
         // Header
-        bytes.push(FRAME_VERSION);
-        bytes.push(self.msg_type.to_byte());
-        bytes.extend_from_slice(&len.to_be_bytes());
+        bytes.extend_from_slice(&self.header());
 
         // Ciphertext
         bytes.extend_from_slice(&self.ciphertext);
@@ -252,8 +297,16 @@ impl MessageFrame {
 
         // Parse header
         let version = bytes[0];
-        if version != FRAME_VERSION {
-            return Err(Error::UnsupportedFrameVersion { version });
+        let header_size = match version {
+            FRAME_VERSION => HEADER_SIZE,
+            OFFSET_FRAME_VERSION => OFFSET_HEADER_SIZE,
+            _ => return Err(Error::UnsupportedFrameVersion { version }),
+        };
+        if bytes.len() < header_size + TAG_SIZE {
+            return Err(Error::FrameTooShort {
+                size: bytes.len(),
+                minimum: header_size + TAG_SIZE,
+            });
         }
 
         let msg_type = MessageType::from_byte(bytes[1]).ok_or(Error::InvalidMessageType {
@@ -261,43 +314,63 @@ impl MessageFrame {
         })?;
 
         let declared_len = u16::from_be_bytes([bytes[2], bytes[3]]) as usize;
+        let offset = (version == OFFSET_FRAME_VERSION).then(|| {
+            u64::from_be_bytes(
+                bytes[HEADER_SIZE..OFFSET_HEADER_SIZE]
+                    .try_into()
+                    .expect("header size already validated"),
+            )
+        });
 
         // Validate total length
-        let expected_total = HEADER_SIZE + declared_len + TAG_SIZE;
+        let expected_total = header_size + declared_len + TAG_SIZE;
         if bytes.len() != expected_total {
             return Err(Error::FrameLengthMismatch {
                 declared: declared_len,
-                actual: bytes.len().saturating_sub(HEADER_SIZE + TAG_SIZE),
+                actual: bytes.len().saturating_sub(header_size + TAG_SIZE),
             });
         }
 
         // Extract ciphertext and tag
-        let ciphertext = bytes[HEADER_SIZE..HEADER_SIZE + declared_len].to_vec();
-        let tag: [u8; TAG_SIZE] = bytes[HEADER_SIZE + declared_len..]
+        let ciphertext = bytes[header_size..header_size + declared_len].to_vec();
+        let tag: [u8; TAG_SIZE] = bytes[header_size + declared_len..]
             .try_into()
             .expect("tag size already validated");
 
         Ok(Self {
             msg_type,
+            offset,
             ciphertext,
             tag,
         })
     }
 
     /// Build the header bytes for authentication.
-    fn build_header(msg_type: MessageType, ciphertext_len: usize) -> [u8; HEADER_SIZE] {
-        let len_bytes = (ciphertext_len as u16).to_be_bytes();
-        [FRAME_VERSION, msg_type.to_byte(), len_bytes[0], len_bytes[1]]
+    fn build_header(msg_type: MessageType, offset: Option<u64>, ciphertext_len: usize) -> Vec<u8> {
+        let version = match offset {
+            None => FRAME_VERSION,
+            Some(_) => OFFSET_FRAME_VERSION,
+        };
+        let mut header = vec![version, msg_type.to_byte()];
+        header.extend_from_slice(&(ciphertext_len as u16).to_be_bytes());
+        if let Some(offset) = offset {
+            header.extend_from_slice(&offset.to_be_bytes());
+        }
+        header
     }
 
     /// Get the header bytes of this frame.
-    pub fn header(&self) -> [u8; HEADER_SIZE] {
-        Self::build_header(self.msg_type, self.ciphertext.len())
+    pub fn header(&self) -> Vec<u8> {
+        Self::build_header(self.msg_type, self.offset, self.ciphertext.len())
     }
 
     /// Total size of the encoded frame in bytes.
     pub fn encoded_size(&self) -> usize {
-        HEADER_SIZE + self.ciphertext.len() + TAG_SIZE
+        let header_size = match self.offset {
+            None => HEADER_SIZE,
+            Some(_) => OFFSET_HEADER_SIZE,
+        };
+        header_size + self.ciphertext.len() + TAG_SIZE
     }
 }
 
@@ -313,6 +386,52 @@ pub const fn pad_consumption(plaintext_len: usize) -> usize {
     crate::mac::AUTH_KEY_SIZE + plaintext_len
 }
 
+/// Padding applied to plaintext before encryption.
+///
+/// Determines the real pad cost of a message: with [`pad_message`] a 2-byte
+/// text still consumes [`MIN_PADDED_SIZE`] encryption bytes.
+///
+/// # Example
+///
+/// ```
+/// use ash_core::message::PaddingPolicy;
+///
+/// assert_eq!(PaddingPolicy::Padded.padded_len(2), 32);
+/// assert_eq!(PaddingPolicy::Unpadded.padded_len(2), 2);
+/// assert_eq!(PaddingPolicy::Padded.pad_consumption(2), 64 + 32);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaddingPolicy {
+    /// Plaintext is encrypted as-is.
+    Unpadded,
+    /// Plaintext is wrapped with [`pad_message`] (default).
+    #[default]
+    Padded,
+}
+
+impl PaddingPolicy {
+    /// Length of the encrypted payload for a plaintext of `plaintext_len` bytes.
+    pub const fn padded_len(self, plaintext_len: usize) -> usize {
+        match self {
+            PaddingPolicy::Unpadded => plaintext_len,
+            PaddingPolicy::Padded => {
+                // marker + length + content
+                let needed = 3 + plaintext_len;
+                if needed < MIN_PADDED_SIZE {
+                    MIN_PADDED_SIZE
+                } else {
+                    needed
+                }
+            }
+        }
+    }
+
+    /// Total pad bytes consumed by one message under this policy.
+    pub const fn pad_consumption(self, plaintext_len: usize) -> usize {
+        pad_consumption(self.padded_len(plaintext_len))
+    }
+}
+
 /// Pad a message to minimum 32 bytes for traffic analysis protection.
 ///
 /// # Format
@@ -376,7 +495,7 @@ pub fn pad_message(message: &[u8]) -> Result<Vec<u8>> {
 ///
 /// # Returns
 ///
-/// Original message bytes.
+/// Original message bytes, wiped when dropped.
 ///
 /// # Errors
 ///
@@ -390,9 +509,9 @@ pub fn pad_message(message: &[u8]) -> Result<Vec<u8>> {
 /// let original = b"Hello!";
 /// let padded = pad_message(original).unwrap();
 /// let recovered = unpad_message(&padded).unwrap();
-/// assert_eq!(recovered, original);
+/// assert_eq!(&recovered[..], original);
 /// ```
-pub fn unpad_message(padded: &[u8]) -> Result<Vec<u8>> {
+pub fn unpad_message(padded: &[u8]) -> Result<SecretBytes> {
     if padded.len() < 3 {
         return Err(Error::InvalidPadding {
             reason: "padded message too short (< 3 bytes)".to_string(),
@@ -421,7 +540,7 @@ pub fn unpad_message(padded: &[u8]) -> Result<Vec<u8>> {
     }
 
     // Extract content
-    Ok(padded[3..3 + content_len].to_vec())
+    Ok(SecretBytes::from_slice(&padded[3..3 + content_len]))
 }
 
 #[cfg(test)]
@@ -485,6 +604,63 @@ mod tests {
         assert_eq!(encoded.len(), HEADER_SIZE + 3 + TAG_SIZE);
     }
 
+    #[test]
+    fn offset_frame_roundtrip() {
+        let plaintext = b"ABC";
+        let (enc_key, auth_key) = make_test_keys(plaintext.len());
+
+        let frame =
+            MessageFrame::encrypt_at(MessageType::Text, 0x0102, plaintext, &enc_key, &auth_key)
+                .expect("encryption should succeed");
+        let encoded = frame.encode();
+
+        assert_eq!(encoded[0], OFFSET_FRAME_VERSION);
+        assert_eq!(
+            encoded[HEADER_SIZE..OFFSET_HEADER_SIZE],
+            0x0102u64.to_be_bytes()
+        );
+        assert_eq!(encoded.len(), OFFSET_HEADER_SIZE + 3 + TAG_SIZE);
+        assert_eq!(frame.encoded_size(), encoded.len());
+
+        let decoded = MessageFrame::decode(&encoded).expect("decoding should succeed");
+        assert_eq!(decoded.offset, Some(0x0102));
+        assert_eq!(decoded.decrypt(&enc_key, &auth_key).unwrap(), plaintext);
+
+        // Version 1 frames carry no offset
+        let v1 = MessageFrame::encrypt(MessageType::Text, plaintext, &enc_key, &auth_key).unwrap();
+        assert_eq!(MessageFrame::decode(&v1.encode()).unwrap().offset, None);
+    }
+
+    #[test]
+    fn tampered_offset_fails() {
+        let plaintext = b"Secret message";
+        let (enc_key, auth_key) = make_test_keys(plaintext.len());
+
+        let mut frame =
+            MessageFrame::encrypt_at(MessageType::Text, 500, plaintext, &enc_key, &auth_key)
+                .expect("encryption should succeed");
+        frame.offset = Some(400);
+        assert!(matches!(
+            frame.decrypt(&enc_key, &auth_key),
+            Err(Error::AuthenticationFailed)
+        ));
+
+        // Dropping the offset changes the authenticated header too
+        frame.offset = None;
+        assert!(frame.decrypt(&enc_key, &auth_key).is_err());
+    }
+
+    #[test]
+    fn offset_frame_too_short() {
+        let mut frame = vec![0u8; MIN_FRAME_SIZE];
+        frame[0] = OFFSET_FRAME_VERSION;
+        frame[1] = 0x01;
+        assert!(matches!(
+            MessageFrame::decode(&frame),
+            Err(Error::FrameTooShort { .. })
+        ));
+    }
+
     #[test]
     fn tampered_ciphertext_fails() {
         let plaintext = b"Secret message";
@@ -652,6 +828,16 @@ mod tests {
         assert_eq!(pad_consumption(1000), AUTH_KEY_SIZE + 1000);
     }
 
+    #[test]
+    fn padding_policy_matches_pad_message() {
+        for len in [0, 1, 28, 29, 30, 100, 1000] {
+            let padded = pad_message(&vec![0x41; len]).unwrap();
+            assert_eq!(PaddingPolicy::Padded.padded_len(len), padded.len());
+            assert_eq!(PaddingPolicy::Unpadded.padded_len(len), len);
+        }
+        assert_eq!(PaddingPolicy::default(), PaddingPolicy::Padded);
+    }
+
     #[test]
     fn header_method() {
         let plaintext = b"Test";
@@ -22,6 +22,8 @@
 //! ```
 
 use crate::error::{Error, Result};
+use crate::pad_calculator::{CapacityForecast, ForecastConfig};
+use crate::secret::SecretBytes;
 
 /// Available pad sizes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -65,12 +67,39 @@ pub enum Role {
     Responder,
 }
 
+impl Role {
+    /// Convert from the byte used in serialized formats.
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x01 => Some(Self::Initiator),
+            0x02 => Some(Self::Responder),
+            _ => None,
+        }
+    }
+
+    /// Convert to the byte used in serialized formats.
+    pub fn to_byte(self) -> u8 {
+        match self {
+            Self::Initiator => 0x01,
+            Self::Responder => 0x02,
+        }
+    }
+
+    /// The other party's role.
+    pub fn peer(self) -> Self {
+        match self {
+            Self::Initiator => Self::Responder,
+            Self::Responder => Self::Initiator,
+        }
+    }
+}
+
 /// Securely zero memory, preventing compiler optimization.
 ///
 /// Uses volatile writes to ensure the zeroing is not optimized away,
 /// followed by a compiler fence to prevent reordering.
 #[inline(never)]
-fn secure_zero(data: &mut [u8]) {
+pub(crate) fn secure_zero(data: &mut [u8]) {
     // Use volatile writes to prevent optimization
     for byte in data.iter_mut() {
         // SAFETY: We're writing to valid, aligned memory that we own
@@ -82,6 +111,15 @@ fn secure_zero(data: &mut [u8]) {
     std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
 }
 
+/// Read the big-endian `u64` at `at` in a serialized format.
+///
+/// Panics if fewer than eight bytes follow `at`; callers check lengths first.
+pub(crate) fn read_u64_be(bytes: &[u8], at: usize) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[at..at + 8]);
+    u64::from_be_bytes(buf)
+}
+
 /// A One-Time Pad with bidirectional consumption semantics.
 ///
 /// # Bidirectional Consumption
@@ -122,6 +160,8 @@ pub struct Pad {
     consumed_front: usize,
     /// Number of bytes consumed from the end (Responder)
     consumed_back: usize,
+    /// Sorted, non-overlapping `[start, end)` ranges wiped by `zero_bytes_at`
+    zeroed: Vec<(usize, usize)>,
 }
 
 impl Drop for Pad {
@@ -153,6 +193,7 @@ impl Pad {
             bytes: entropy.to_vec(),
             consumed_front: 0,
             consumed_back: 0,
+            zeroed: Vec::new(),
         })
     }
 
@@ -165,6 +206,7 @@ impl Pad {
             bytes,
             consumed_front: 0,
             consumed_back: 0,
+            zeroed: Vec::new(),
         }
     }
 
@@ -180,6 +222,24 @@ impl Pad {
             bytes,
             consumed_front,
             consumed_back,
+            zeroed: Vec::new(),
+        }
+    }
+
+    /// Restore a pad with its consumption state and zeroed ranges.
+    ///
+    /// `zeroed` must be sorted and non-overlapping.
+    pub(crate) fn from_parts(
+        bytes: Vec<u8>,
+        consumed_front: usize,
+        consumed_back: usize,
+        zeroed: Vec<(usize, usize)>,
+    ) -> Self {
+        Self {
+            bytes,
+            consumed_front,
+            consumed_back,
+            zeroed,
         }
     }
 
@@ -191,12 +251,13 @@ impl Pad {
     /// # Security
     ///
     /// Each byte can only be consumed once. After consumption,
-    /// those bytes cannot be retrieved again.
+    /// those bytes cannot be retrieved again. The returned key material
+    /// is wiped when dropped.
     ///
     /// # Errors
     ///
     /// Returns `Error::InsufficientPadBytes` if fewer than `n` bytes remain.
-    pub fn consume(&mut self, n: usize, role: Role) -> Result<Vec<u8>> {
+    pub fn consume(&mut self, n: usize, role: Role) -> Result<SecretBytes> {
         let available = self.remaining();
         if n > available {
             return Err(Error::InsufficientPadBytes {
@@ -209,20 +270,76 @@ impl Pad {
             Role::Initiator => {
                 let start = self.consumed_front;
                 let end = start + n;
-                let slice = self.bytes[start..end].to_vec();
+                let slice = SecretBytes::from_slice(&self.bytes[start..end]);
                 self.consumed_front = end;
                 Ok(slice)
             }
             Role::Responder => {
                 let end = self.bytes.len() - self.consumed_back;
                 let start = end - n;
-                let slice = self.bytes[start..end].to_vec();
+                let slice = SecretBytes::from_slice(&self.bytes[start..end]);
                 self.consumed_back += n;
                 Ok(slice)
             }
         }
     }
 
+    /// Read the key material of a message the peer sent, without consuming it.
+    ///
+    /// Lets messages be decrypted in any order: the range only has to lie on
+    /// the peer's side of the pad, not at its current consumption front.
+    /// Counters are not moved; advance them with
+    /// [`update_peer_consumption`](Pad::update_peer_consumption) once the
+    /// message authenticated.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The peer's [`send_position`](Pad::send_position) when it
+    ///   sent the message
+    /// * `len` - Pad bytes the message consumed (auth key + ciphertext)
+    /// * `peer_role` - The peer's role (opposite of ours)
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientPadBytes` if the range lies outside the pad
+    /// - `PadRangeReuse` if the range reaches into bytes we consumed
+    /// - `KeyWiped` if any of the bytes were zeroed
+    pub fn key_at(&self, offset: usize, len: usize, peer_role: Role) -> Result<SecretBytes> {
+        let total = self.bytes.len();
+        let end = match offset.checked_add(len) {
+            Some(end) if end <= total => end,
+            _ => {
+                return Err(Error::InsufficientPadBytes {
+                    needed: len,
+                    available: self.remaining(),
+                })
+            }
+        };
+
+        // Offsets count from the origin of the peer's direction, and errors
+        // report the range the same way
+        let own_consumed = match peer_role {
+            Role::Initiator => self.consumed_back,
+            Role::Responder => self.consumed_front,
+        };
+        if end > total.saturating_sub(own_consumed) {
+            return Err(Error::PadRangeReuse { start: offset, end });
+        }
+        let range = match peer_role {
+            Role::Initiator => offset..end,
+            Role::Responder => total - end..total - offset,
+        };
+        if self
+            .zeroed
+            .iter()
+            .any(|&(a, b)| range.start < b && a < range.end)
+        {
+            return Err(Error::KeyWiped { start: offset, end });
+        }
+
+        Ok(SecretBytes::from_slice(&self.bytes[range]))
+    }
+
     /// Get the number of bytes remaining in the pad.
     ///
     /// This is the total unused bytes in the middle, available to either role.
@@ -304,7 +421,9 @@ impl Pad {
     ///
     /// # Returns
     ///
-    /// Number of bytes available for sending.
+    /// Number of bytes available for sending. Both roles draw from the same
+    /// gap, so the result does not depend on `role`; use
+    /// [`forecast`](Pad::forecast) for per-direction estimates.
     #[inline]
     pub fn available_for_sending(&self, _role: Role) -> usize {
         let total = self.bytes.len();
@@ -315,6 +434,47 @@ impl Pad {
         total - total_consumed
     }
 
+    /// Forecast remaining capacity from the live consumption state.
+    ///
+    /// Uses the real per-message cost (auth overhead plus padded payload)
+    /// and the peer consumption observed via [`update_peer_consumption`].
+    /// The per-direction counts exclude the burn/control reserve.
+    ///
+    /// [`update_peer_consumption`]: Pad::update_peer_consumption
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ash_core::pad::{Pad, PadSize, Role};
+    /// use ash_core::pad_calculator::ForecastConfig;
+    ///
+    /// let pad = Pad::new(&vec![0u8; PadSize::Tiny.bytes()], PadSize::Tiny).unwrap();
+    /// let forecast = pad.forecast(Role::Initiator, &ForecastConfig::new(100));
+    ///
+    /// assert_eq!(forecast.text_budget_bytes + forecast.reserve_bytes, pad.remaining());
+    /// assert!(forecast.messages_left_ours > 0);
+    /// assert_eq!(forecast.warning, None);
+    /// ```
+    pub fn forecast(&self, role: Role, config: &ForecastConfig) -> CapacityForecast {
+        let (ours, peer) = match role {
+            Role::Initiator => (self.consumed_front, self.consumed_back),
+            Role::Responder => (self.consumed_back, self.consumed_front),
+        };
+        config.forecast(self.remaining(), ours, peer)
+    }
+
+    /// Check if a normal message fits without touching the burn/control reserve.
+    ///
+    /// Unlike [`can_send`](Pad::can_send), `plaintext_len` is the size before
+    /// padding and authentication overhead; the cost is derived from `config`.
+    pub fn can_send_text(&self, plaintext_len: usize, config: &ForecastConfig) -> bool {
+        config
+            .padding
+            .pad_consumption(plaintext_len)
+            .saturating_add(config.reserve_bytes())
+            <= self.remaining()
+    }
+
     /// Update peer's consumption based on a received message.
     ///
     /// When we receive a message from the peer, we learn how much of the pad
@@ -329,21 +489,27 @@ impl Pad {
     ///
     /// This only updates if `new_consumed` is greater than current tracking,
     /// preventing replay attacks from reducing the known consumption.
-    pub fn update_peer_consumption(&mut self, peer_role: Role, new_consumed: usize) {
-        match peer_role {
-            Role::Initiator => {
-                // Peer is initiator, they consume from front
-                if new_consumed > self.consumed_front {
-                    self.consumed_front = new_consumed;
-                }
-            }
-            Role::Responder => {
-                // Peer is responder, they consume from back
-                if new_consumed > self.consumed_back {
-                    self.consumed_back = new_consumed;
-                }
-            }
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PadRangeReuse`] if the claim reaches into bytes we
+    /// have consumed from our own end. The counters are left unchanged; the
+    /// pad has been used twice and the conversation should be burned.
+    pub fn update_peer_consumption(&mut self, peer_role: Role, new_consumed: usize) -> Result<()> {
+        let (peer_consumed, own_consumed) = match peer_role {
+            Role::Initiator => (&mut self.consumed_front, self.consumed_back),
+            Role::Responder => (&mut self.consumed_back, self.consumed_front),
+        };
+        if new_consumed > self.bytes.len().saturating_sub(own_consumed) {
+            return Err(Error::PadRangeReuse {
+                start: *peer_consumed,
+                end: new_consumed,
+            });
+        }
+        if new_consumed > *peer_consumed {
+            *peer_consumed = new_consumed;
         }
+        Ok(())
     }
 
     /// Get the offset for the next message we send.
@@ -366,10 +532,27 @@ impl Pad {
         }
     }
 
+    /// Get the position of the next message we send within our direction.
+    ///
+    /// This is the number of bytes consumed so far from our end of the pad.
+    /// Unlike [`next_send_offset`](Pad::next_send_offset) it does not move
+    /// when a top-up grows the pad, so it is the offset a message carries to
+    /// identify its key material (see [`key_at`](Pad::key_at)).
+    #[inline]
+    pub fn send_position(&self, role: Role) -> usize {
+        match role {
+            Role::Initiator => self.consumed_front,
+            Role::Responder => self.consumed_back,
+        }
+    }
+
     /// Serialize pad state for persistent storage.
     ///
     /// Returns a tuple of (bytes, consumed_front, consumed_back) that can be
     /// stored and later restored with `from_bytes_with_state`.
+    ///
+    /// Prefer [`seal_pad_state`](crate::pad_state::seal_pad_state), which also
+    /// keeps zeroed ranges and detects tampering and rollback.
     pub fn serialize_state(&self) -> (Vec<u8>, usize, usize) {
         (self.bytes.clone(), self.consumed_front, self.consumed_back)
     }
@@ -381,6 +564,9 @@ impl Pad {
     /// Even if an attacker later gains access to the pad, they cannot
     /// decrypt messages whose keys have been zeroed.
     ///
+    /// [`RetentionManager`](crate::retention::RetentionManager) tracks message
+    /// ranges and calls this when their keys are no longer needed.
+    ///
     /// # Arguments
     ///
     /// * `offset` - Starting offset in the pad
@@ -400,9 +586,91 @@ impl Pad {
             return false;
         }
         secure_zero(&mut self.bytes[offset..end]);
+        self.record_zeroed(offset, end);
         true
     }
 
+    /// Ranges wiped with [`zero_bytes_at`](Pad::zero_bytes_at).
+    ///
+    /// Returned as sorted, non-overlapping `(start, end)` pairs (end exclusive).
+    /// Only ranges wiped since the pad was created or restored are tracked.
+    pub fn zeroed_ranges(&self) -> &[(usize, usize)] {
+        &self.zeroed
+    }
+
+    /// Merge `[start, end)` into the zeroed range list.
+    fn record_zeroed(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        let (mut start, mut end) = (start, end);
+        let mut merged = Vec::with_capacity(self.zeroed.len() + 1);
+        for &(a, b) in &self.zeroed {
+            if b < start || a > end {
+                merged.push((a, b));
+            } else {
+                start = start.min(a);
+                end = end.max(b);
+            }
+        }
+        merged.push((start, end));
+        merged.sort_unstable();
+        self.zeroed = merged;
+    }
+
+    /// Splice a new segment into the unconsumed region of the pad.
+    ///
+    /// Used by the top-up ceremony. The segment is inserted at `offset`,
+    /// which must lie between the two consumption fronts, so the unused
+    /// bytes stay contiguous and both counters keep their meaning:
+    ///
+    /// ```text
+    /// Before: [front consumed][   gap   ][back consumed]
+    /// After:  [front consumed][ gap | segment | gap ][back consumed]
+    /// ```
+    ///
+    /// Offsets in the back-consumed region shift by `segment.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidTopUpOffset` if `offset` is outside the gap.
+    pub(crate) fn insert_segment(&mut self, offset: usize, segment: &[u8]) -> Result<()> {
+        let gap_start = self.consumed_front;
+        let gap_end = self.bytes.len().saturating_sub(self.consumed_back);
+        if offset < gap_start || offset > gap_end {
+            return Err(Error::InvalidTopUpOffset {
+                offset,
+                gap_start,
+                gap_end,
+            });
+        }
+
+        let mut bytes = Vec::with_capacity(self.bytes.len() + segment.len());
+        bytes.extend_from_slice(&self.bytes[..offset]);
+        bytes.extend_from_slice(segment);
+        bytes.extend_from_slice(&self.bytes[offset..]);
+
+        // Wipe the old buffer before it is dropped
+        secure_zero(&mut self.bytes);
+        self.bytes = bytes;
+
+        // Zeroed ranges past the insertion point move with their bytes
+        let n = segment.len();
+        let mut shifted = Vec::with_capacity(self.zeroed.len() + 1);
+        for &(a, b) in &self.zeroed {
+            if b <= offset {
+                shifted.push((a, b));
+            } else if a >= offset {
+                shifted.push((a + n, b + n));
+            } else {
+                shifted.push((a, offset));
+                shifted.push((offset + n, b + n));
+            }
+        }
+        self.zeroed = shifted;
+        Ok(())
+    }
+
     /// Get the raw pad bytes (for ceremony transfer).
     ///
     /// # Security
@@ -429,6 +697,7 @@ impl std::fmt::Debug for Pad {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message::PaddingPolicy;
 
     #[test]
     fn pad_size_bytes() {
@@ -439,6 +708,21 @@ mod tests {
         assert_eq!(PadSize::Huge.bytes(), 1048576); // 1 MB
     }
 
+    #[test]
+    fn role_peer_and_byte_roundtrip() {
+        for role in [Role::Initiator, Role::Responder] {
+            assert_ne!(role.peer(), role);
+            assert_eq!(role.peer().peer(), role);
+            assert_eq!(Role::from_byte(role.to_byte()), Some(role));
+        }
+    }
+
+    #[test]
+    fn read_u64_be_reads_at_offset() {
+        let bytes = [0xff, 0, 0, 0, 0, 0, 0, 0x01, 0x02, 0xff];
+        assert_eq!(read_u64_be(&bytes, 1), 0x0102);
+    }
+
     #[test]
     fn pad_new_valid() {
         let entropy = vec![0xAB; PadSize::Small.bytes()];
@@ -615,20 +899,127 @@ mod tests {
 
         // We are responder, peer is initiator
         // Peer has consumed 40 bytes from front
-        pad.update_peer_consumption(Role::Initiator, 40);
+        pad.update_peer_consumption(Role::Initiator, 40).unwrap();
         assert_eq!(pad.consumed_front(), 40);
         assert_eq!(pad.consumed_back(), 0);
         assert_eq!(pad.available_for_sending(Role::Responder), 60);
 
         // Peer consumption should only increase, not decrease (replay protection)
-        pad.update_peer_consumption(Role::Initiator, 30);
+        pad.update_peer_consumption(Role::Initiator, 30).unwrap();
         assert_eq!(pad.consumed_front(), 40); // Still 40
 
         // Peer consumed more
-        pad.update_peer_consumption(Role::Initiator, 50);
+        pad.update_peer_consumption(Role::Initiator, 50).unwrap();
         assert_eq!(pad.consumed_front(), 50);
     }
 
+    #[test]
+    fn update_peer_consumption_rejects_overlap() {
+        let mut pad = Pad::from_bytes(vec![0u8; 100]);
+        pad.consume(30, Role::Responder).unwrap();
+        pad.update_peer_consumption(Role::Initiator, 20).unwrap();
+
+        // A peer claiming more than the gap would reuse our bytes
+        let err = pad
+            .update_peer_consumption(Role::Initiator, 71)
+            .unwrap_err();
+        assert_eq!(err, Error::PadRangeReuse { start: 20, end: 71 });
+        assert_eq!(pad.consumed_front(), 20);
+        assert_eq!(pad.consumed_back(), 30);
+
+        // Exactly filling the gap is fine
+        pad.update_peer_consumption(Role::Initiator, 70).unwrap();
+        assert_eq!(pad.remaining(), 0);
+    }
+
+    #[test]
+    fn update_peer_consumption_rejects_overlap_from_back() {
+        let mut pad = Pad::from_bytes(vec![0u8; 100]);
+        pad.consume(60, Role::Initiator).unwrap();
+
+        assert!(matches!(
+            pad.update_peer_consumption(Role::Responder, 41),
+            Err(Error::PadRangeReuse { start: 0, end: 41 })
+        ));
+        assert_eq!(pad.consumed_back(), 0);
+
+        // Still rejected once the peer counter has advanced
+        pad.update_peer_consumption(Role::Responder, 40).unwrap();
+        assert!(pad.update_peer_consumption(Role::Responder, 1000).is_err());
+        assert_eq!(pad.consumed_back(), 40);
+    }
+
+    #[test]
+    fn key_at_matches_peer_consume() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut sender = Pad::from_bytes(bytes.clone());
+        let receiver = Pad::from_bytes(bytes);
+
+        for role in [Role::Initiator, Role::Responder] {
+            let offset = sender.send_position(role);
+            let key = sender.consume(100, role).unwrap();
+            assert_eq!(receiver.key_at(offset, 100, role).unwrap(), key.expose());
+        }
+        assert_eq!(receiver.consumed(), 0);
+    }
+
+    #[test]
+    fn key_at_out_of_order() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut sender = Pad::from_bytes(bytes.clone());
+        let mut receiver = Pad::from_bytes(bytes);
+        let first = sender.consume(100, Role::Responder).unwrap();
+        let second = sender.consume(100, Role::Responder).unwrap();
+
+        // The second message arrives first and advances the peer counter
+        assert_eq!(
+            receiver.key_at(100, 100, Role::Responder).unwrap(),
+            second.expose()
+        );
+        receiver
+            .update_peer_consumption(Role::Responder, 200)
+            .unwrap();
+
+        // The first is still readable behind the counter
+        assert_eq!(
+            receiver.key_at(0, 100, Role::Responder).unwrap(),
+            first.expose()
+        );
+    }
+
+    #[test]
+    fn key_at_rejects_bad_ranges() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        pad.consume(100, Role::Responder).unwrap();
+
+        // Outside the pad
+        assert!(matches!(
+            pad.key_at(950, 100, Role::Initiator),
+            Err(Error::InsufficientPadBytes { .. })
+        ));
+        assert!(matches!(
+            pad.key_at(950, 100, Role::Responder),
+            Err(Error::InsufficientPadBytes { .. })
+        ));
+
+        // Reaching into our own consumed bytes
+        assert_eq!(
+            pad.key_at(850, 100, Role::Initiator),
+            Err(Error::PadRangeReuse {
+                start: 850,
+                end: 950
+            })
+        );
+
+        // Wiped after an earlier delivery
+        pad.zero_bytes_at(10, 20);
+        assert_eq!(
+            pad.key_at(0, 64, Role::Initiator),
+            Err(Error::KeyWiped { start: 0, end: 64 })
+        );
+        assert!(pad.key_at(30, 64, Role::Initiator).is_ok());
+    }
+
     #[test]
     fn next_send_offset() {
         let entropy: Vec<u8> = (0..100).collect();
@@ -855,7 +1246,7 @@ mod tests {
         assert_eq!(pad.available_for_sending(Role::Initiator), 1000);
 
         // We receive a message from Bob (peer is Responder), he consumed 600 bytes
-        pad.update_peer_consumption(Role::Responder, 600);
+        pad.update_peer_consumption(Role::Responder, 600).unwrap();
 
         // Now Alice can only send 400 bytes
         assert_eq!(pad.available_for_sending(Role::Initiator), 400);
@@ -937,4 +1328,124 @@ mod tests {
         // Overflow protection
         assert!(!pad.zero_bytes_at(usize::MAX, 1));
     }
+
+    #[test]
+    fn insert_segment_outside_gap_rejected() {
+        let mut pad = Pad::from_bytes(vec![0u8; 100]);
+        pad.consume(10, Role::Initiator).unwrap();
+        pad.consume(10, Role::Responder).unwrap();
+
+        assert!(pad.insert_segment(5, &[1u8; 4]).is_err());
+        assert!(pad.insert_segment(95, &[1u8; 4]).is_err());
+        pad.insert_segment(90, &[1u8; 4]).unwrap();
+        assert_eq!(pad.total_size(), 104);
+        assert_eq!(pad.remaining(), 84);
+    }
+
+    #[test]
+    fn forecast_splits_by_observed_consumption() {
+        let mut pad = Pad::from_bytes(vec![0u8; 10_000]);
+        let config = ForecastConfig::new(33).with_reserve(0, 0); // 64 + 36 bytes per message
+
+        let fresh = pad.forecast(Role::Initiator, &config);
+        assert_eq!(fresh.messages_left_ours, 50);
+        assert_eq!(fresh.messages_left_peer, 50);
+        assert_eq!(fresh.max_messages_ours, 100);
+
+        // Peer has sent three times as much as we have
+        pad.consume(1000, Role::Initiator).unwrap();
+        pad.update_peer_consumption(Role::Responder, 3000).unwrap();
+        let forecast = pad.forecast(Role::Initiator, &config);
+        assert_eq!(forecast.available_bytes, 6000);
+        assert_eq!(forecast.messages_left_ours, 15);
+        assert_eq!(forecast.messages_left_peer, 45);
+
+        let mirrored = pad.forecast(Role::Responder, &config);
+        assert_eq!(mirrored.messages_left_ours, 45);
+        assert_eq!(mirrored.messages_left_peer, 15);
+    }
+
+    #[test]
+    fn forecast_uses_padded_cost() {
+        let pad = Pad::from_bytes(vec![0u8; 9600]);
+        let padded = ForecastConfig::new(2).with_reserve(0, 0);
+        let unpadded = padded.clone().with_padding(PaddingPolicy::Unpadded);
+
+        // 64 + 32 vs 64 + 2 bytes per message
+        let forecast = pad.forecast(Role::Initiator, &padded);
+        assert_eq!(forecast.max_messages_ours, 100);
+        let forecast = pad.forecast(Role::Initiator, &unpadded);
+        assert_eq!(forecast.max_messages_ours, 145);
+    }
+
+    #[test]
+    fn forecast_warns_at_thresholds() {
+        let mut pad = Pad::from_bytes(vec![0u8; 10_000]);
+        let config = ForecastConfig::new(33)
+            .with_reserve(0, 0)
+            .with_warn_thresholds(vec![40, 10]);
+
+        assert_eq!(pad.forecast(Role::Initiator, &config).warning, None);
+
+        pad.consume(3000, Role::Initiator).unwrap(); // 70 left, all ours
+        assert_eq!(pad.forecast(Role::Initiator, &config).warning, None);
+
+        pad.consume(3500, Role::Initiator).unwrap(); // 35 left
+        assert_eq!(pad.forecast(Role::Initiator, &config).warning, Some(40));
+
+        pad.consume(3000, Role::Initiator).unwrap(); // 5 left
+        assert_eq!(pad.forecast(Role::Initiator, &config).warning, Some(10));
+    }
+
+    #[test]
+    fn text_cannot_eat_into_reserve() {
+        let config = ForecastConfig::new(100).with_reserve(2, 0);
+        assert_eq!(config.reserve_bytes(), 2 * 96);
+
+        // 64 + 103 bytes for the text itself
+        let pad = Pad::from_bytes(vec![0u8; 167 + 192]);
+        assert!(pad.can_send_text(100, &config));
+        assert!(!pad.can_send_text(101, &config));
+
+        // Reserve still fits burn/control messages
+        assert!(pad.can_send(192, Role::Responder));
+
+        let forecast = pad.forecast(Role::Responder, &config);
+        assert_eq!(forecast.reserve_bytes, 192);
+        assert_eq!(forecast.text_budget_bytes, 167);
+
+        let tiny = Pad::from_bytes(vec![0u8; 100]);
+        let forecast = tiny.forecast(Role::Initiator, &config);
+        assert_eq!(forecast.reserve_bytes, 100);
+        assert!(forecast.is_reserve_only());
+        assert!(!tiny.can_send_text(0, &config));
+    }
+
+    #[test]
+    fn zeroed_ranges_are_merged() {
+        let mut pad = Pad::from_bytes(vec![0xFF; 100]);
+        assert!(pad.zeroed_ranges().is_empty());
+
+        pad.zero_bytes_at(10, 10);
+        pad.zero_bytes_at(40, 5);
+        pad.zero_bytes_at(15, 10); // overlaps first
+        pad.zero_bytes_at(45, 5); // adjacent to second
+        pad.zero_bytes_at(60, 0); // empty, ignored
+        assert!(!pad.zero_bytes_at(95, 10)); // out of bounds, ignored
+
+        assert_eq!(pad.zeroed_ranges(), &[(10, 25), (40, 50)]);
+    }
+
+    #[test]
+    fn insert_segment_shifts_zeroed_ranges() {
+        let mut pad = Pad::from_bytes(vec![0xFF; 100]);
+        pad.consume(10, Role::Initiator).unwrap();
+        pad.consume(20, Role::Responder).unwrap();
+        pad.zero_bytes_at(0, 10);
+        pad.zero_bytes_at(85, 10);
+
+        pad.insert_segment(50, &[1u8; 8]).unwrap();
+        assert_eq!(pad.zeroed_ranges(), &[(0, 10), (93, 103)]);
+        assert!(pad.as_bytes()[93..103].iter().all(|&b| b == 0));
+    }
 }
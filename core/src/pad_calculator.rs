@@ -5,6 +5,7 @@
 //! - QR code count for ceremony transfer
 //! - Authentication overhead impact
 //! - Pad exhaustion estimates
+//! - Live forecasts for a pad in use (see [`Pad::forecast`](crate::pad::Pad::forecast))
 //!
 //! # Security Model
 //!
@@ -28,7 +29,7 @@
 
 use crate::frame::TransferMethod;
 use crate::mac::AUTH_KEY_SIZE;
-use crate::message::HEADER_SIZE;
+use crate::message::{PaddingPolicy, HEADER_SIZE};
 
 /// Authentication overhead per message in bytes.
 ///
@@ -236,7 +237,7 @@ pub fn calculate_pad_stats_with_qr_size(pad_size: usize, qr_block_size: usize) -
     let source_blocks = if qr_block_size == 0 {
         0
     } else {
-        (total_data + qr_block_size - 1) / qr_block_size
+        total_data.div_ceil(qr_block_size)
     };
 
     // qr_codes_needed is deprecated, kept for compatibility (same as source_blocks)
@@ -302,7 +303,7 @@ impl PadCalculator {
         let source_blocks = if self.qr_block_size == 0 {
             0
         } else {
-            (total_data + self.qr_block_size - 1) / self.qr_block_size
+            total_data.div_ceil(self.qr_block_size)
         };
 
         let qr_codes_needed = source_blocks;
@@ -340,6 +341,149 @@ impl PadCalculator {
     }
 }
 
+/// Default number of messages held back for burn and control traffic.
+pub const DEFAULT_RESERVE_MESSAGES: usize = 4;
+
+/// Default warning thresholds, in messages left in our direction.
+pub const DEFAULT_WARN_THRESHOLDS: [usize; 3] = [50, 20, 5];
+
+/// Settings for forecasting the capacity of a pad in use.
+///
+/// Message cost is computed with [`PaddingPolicy::pad_consumption`], so
+/// padded short texts are counted at their real size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastConfig {
+    /// Expected plaintext size of a normal message in bytes.
+    pub avg_message_bytes: usize,
+    /// Padding applied before encryption.
+    pub padding: PaddingPolicy,
+    /// Number of burn/control messages normal text may not eat into.
+    pub reserve_messages: usize,
+    /// Plaintext size of a single burn/control message in bytes.
+    pub control_message_bytes: usize,
+    /// Warn when messages left in our direction drop to these values.
+    pub warn_thresholds: Vec<usize>,
+}
+
+impl Default for ForecastConfig {
+    fn default() -> Self {
+        Self {
+            avg_message_bytes: 100,
+            padding: PaddingPolicy::default(),
+            reserve_messages: DEFAULT_RESERVE_MESSAGES,
+            control_message_bytes: 0,
+            warn_thresholds: DEFAULT_WARN_THRESHOLDS.to_vec(),
+        }
+    }
+}
+
+impl ForecastConfig {
+    /// Create a config for the given average message size with default settings.
+    pub fn new(avg_message_bytes: usize) -> Self {
+        Self {
+            avg_message_bytes,
+            ..Self::default()
+        }
+    }
+
+    /// Set the padding policy.
+    pub fn with_padding(mut self, padding: PaddingPolicy) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the burn/control reserve.
+    pub fn with_reserve(mut self, messages: usize, control_message_bytes: usize) -> Self {
+        self.reserve_messages = messages;
+        self.control_message_bytes = control_message_bytes;
+        self
+    }
+
+    /// Set the warning thresholds (messages left).
+    pub fn with_warn_thresholds(mut self, thresholds: Vec<usize>) -> Self {
+        self.warn_thresholds = thresholds;
+        self
+    }
+
+    /// Pad bytes consumed by one average message.
+    pub fn message_cost(&self) -> usize {
+        self.padding.pad_consumption(self.avg_message_bytes)
+    }
+
+    /// Pad bytes held back for burn/control messages.
+    pub fn reserve_bytes(&self) -> usize {
+        self.reserve_messages
+            .saturating_mul(self.padding.pad_consumption(self.control_message_bytes))
+    }
+
+    /// Smallest threshold at or above `messages_left`, if any.
+    fn warning_for(&self, messages_left: usize) -> Option<usize> {
+        self.warn_thresholds
+            .iter()
+            .copied()
+            .filter(|&t| messages_left <= t)
+            .min()
+    }
+
+    /// Build a forecast from a live pad's counters.
+    ///
+    /// `ours` and `peer` are the bytes consumed by each direction so far;
+    /// the remaining text budget is split between the directions in the
+    /// same ratio (evenly while nothing has been sent).
+    pub(crate) fn forecast(&self, available: usize, ours: usize, peer: usize) -> CapacityForecast {
+        let reserve_bytes = self.reserve_bytes().min(available);
+        let text_budget_bytes = available - reserve_bytes;
+        let cost = self.message_cost().max(1);
+
+        let our_budget = match ours.checked_add(peer) {
+            Some(0) | None => text_budget_bytes / 2,
+            Some(total) => (text_budget_bytes as u128 * ours as u128 / total as u128) as usize,
+        };
+        let peer_budget = text_budget_bytes - our_budget;
+
+        let messages_left_ours = our_budget / cost;
+        CapacityForecast {
+            available_bytes: available,
+            reserve_bytes,
+            text_budget_bytes,
+            max_messages_ours: text_budget_bytes / cost,
+            messages_left_ours,
+            messages_left_peer: peer_budget / cost,
+            warning: self.warning_for(messages_left_ours),
+        }
+    }
+}
+
+/// Capacity forecast for a pad in use.
+///
+/// Both directions draw from the same unconsumed gap, so the per-direction
+/// counts are estimates based on the observed send ratio. The counts only
+/// cover normal messages; `reserve_bytes` is kept for burn/control messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityForecast {
+    /// Unconsumed bytes between the two consumption fronts.
+    pub available_bytes: usize,
+    /// Bytes held back for burn/control messages.
+    pub reserve_bytes: usize,
+    /// Bytes normal messages may use (`available_bytes - reserve_bytes`).
+    pub text_budget_bytes: usize,
+    /// Messages we could send if the peer sent nothing more.
+    pub max_messages_ours: usize,
+    /// Estimated messages left in our direction.
+    pub messages_left_ours: usize,
+    /// Estimated messages left in the peer's direction.
+    pub messages_left_peer: usize,
+    /// Lowest warning threshold reached, if any.
+    pub warning: Option<usize>,
+}
+
+impl CapacityForecast {
+    /// Check if only the burn/control reserve is left.
+    pub fn is_reserve_only(&self) -> bool {
+        self.max_messages_ours == 0
+    }
+}
+
 /// Format bytes as human-readable string.
 pub fn format_bytes(bytes: usize) -> String {
     if bytes >= 1024 * 1024 * 1024 {
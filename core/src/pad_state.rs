@@ -0,0 +1,418 @@
+//! Encrypted-at-rest pad state container.
+//!
+//! [`Pad::serialize_state`] only returns raw bytes and counters. This module
+//! defines a versioned container that carries everything needed to restore a
+//! pad, sealed under a caller-supplied wrapping key. Apps keep the wrapping
+//! key in the platform keychain and can store the container anywhere.
+//!
+//! # Container Format (v1)
+//!
+//! ```text
+//! ┌───────┬─────────┬──────┬────────────┬───────┬────────────────────┬─────┐
+//! │ magic │ version │ role │ generation │ nonce │ encrypted body     │ tag │
+//! │ "ASHS"│ 1 byte  │ 1    │ 8 (BE)     │ 12    │ N bytes            │ 32  │
+//! └───────┴─────────┴──────┴────────────┴───────┴────────────────────┴─────┘
+//!
+//! body = [total_size: u64 BE][consumed_front: u64 BE][consumed_back: u64 BE]
+//!        [zeroed bitmap: ceil(total_size / 8) bytes][pad bytes not zeroed]
+//! ```
+//!
+//! - **role**: `0x01` Initiator, `0x02` Responder
+//! - **generation**: Incremented by the caller on every seal
+//! - **zeroed bitmap**: Bit `i` (LSB first) marks byte `i` as wiped by
+//!   [`Pad::zero_bytes_at`]; wiped bytes are not stored
+//!
+//! # Sealing
+//!
+//! The body is encrypted with ChaCha20 (keystream from block 1). Block 0 of
+//! the keystream is the 64-byte Wegman-Carter key that authenticates the
+//! header and encrypted body, so any change to the role, the counters or the
+//! pad bytes fails with [`Error::AuthenticationFailed`].
+//!
+//! # Rollback
+//!
+//! An old container is still validly sealed. The caller records the latest
+//! generation next to the wrapping key and passes it to [`open_pad_state`],
+//! which rejects anything older with [`Error::PadStateRollback`].
+//!
+//! # Example
+//!
+//! ```
+//! use ash_core::pad::{Pad, Role};
+//! use ash_core::pad_state::{open_pad_state, seal_pad_state};
+//!
+//! let mut pad = Pad::from_bytes(vec![0x42; 1024]);
+//! pad.consume(100, Role::Initiator).unwrap();
+//!
+//! let key = [7u8; 32]; // from the platform keychain
+//! let nonce = [1u8; 12]; // fresh random bytes for every seal
+//! let sealed = seal_pad_state(&pad, Role::Initiator, 5, &key, &nonce);
+//!
+//! let state = open_pad_state(&sealed, &key, 5).unwrap();
+//! assert_eq!(state.role, Role::Initiator);
+//! assert_eq!(state.pad.consumed_front(), 100);
+//!
+//! // A newer state was recorded since, so this one is a rollback
+//! assert!(open_pad_state(&sealed, &key, 6).is_err());
+//! ```
+
+use crate::chacha20;
+use crate::error::{Error, Result};
+use crate::mac::{self, AuthKey, TAG_SIZE};
+use crate::pad::{read_u64_be, secure_zero, Pad, Role};
+
+/// Magic bytes identifying a sealed pad state.
+const PAD_STATE_MAGIC: &[u8; 4] = b"ASHS";
+
+/// Current container version.
+pub const PAD_STATE_VERSION: u8 = 1;
+
+/// Wrapping key size in bytes.
+pub const WRAPPING_KEY_SIZE: usize = chacha20::KEY_SIZE;
+
+/// Nonce size in bytes. Must be fresh random bytes for every seal.
+pub const PAD_STATE_NONCE_SIZE: usize = chacha20::NONCE_SIZE;
+
+/// Size of the plaintext header.
+const HEADER_LEN: usize = 4 + 1 + 1 + 8 + PAD_STATE_NONCE_SIZE;
+
+/// Size of the counters at the start of the body.
+const COUNTERS_LEN: usize = 3 * 8;
+
+/// A pad restored from a sealed container.
+#[derive(Debug)]
+pub struct PadState {
+    /// Our role in the conversation.
+    pub role: Role,
+    /// Generation the container was sealed with.
+    pub generation: u64,
+    /// The restored pad, including its zeroed ranges.
+    pub pad: Pad,
+}
+
+/// Seal pad state under a wrapping key.
+///
+/// # Arguments
+///
+/// * `pad` - Pad to persist
+/// * `role` - Our role in the conversation
+/// * `generation` - Must be higher than any previously sealed generation
+/// * `wrapping_key` - Device-held key (e.g., from the platform keychain)
+/// * `nonce` - Fresh random bytes; never reuse a nonce with the same key
+pub fn seal_pad_state(
+    pad: &Pad,
+    role: Role,
+    generation: u64,
+    wrapping_key: &[u8; WRAPPING_KEY_SIZE],
+    nonce: &[u8; PAD_STATE_NONCE_SIZE],
+) -> Vec<u8> {
+    let header = encode_header(role, generation, nonce);
+    let mut body = encode_body(pad);
+    chacha20::apply_keystream(wrapping_key, 1, nonce, &mut body);
+
+    let auth_key = mac_key(wrapping_key, nonce);
+    let tag = mac::compute_tag(&auth_key, &header, &body);
+
+    let mut sealed = Vec::with_capacity(HEADER_LEN + body.len() + TAG_SIZE);
+    sealed.extend_from_slice(&header);
+    sealed.extend_from_slice(&body);
+    sealed.extend_from_slice(&tag);
+    sealed
+}
+
+/// Open a sealed pad state.
+///
+/// # Arguments
+///
+/// * `sealed` - Container produced by [`seal_pad_state`]
+/// * `wrapping_key` - Key the container was sealed with
+/// * `min_generation` - Latest generation the caller recorded
+///
+/// # Errors
+///
+/// - [`Error::InvalidPadState`] if the container is malformed or has an unknown version
+/// - [`Error::AuthenticationFailed`] if the key is wrong or the container was modified
+/// - [`Error::PadStateRollback`] if the generation is older than `min_generation`
+pub fn open_pad_state(
+    sealed: &[u8],
+    wrapping_key: &[u8; WRAPPING_KEY_SIZE],
+    min_generation: u64,
+) -> Result<PadState> {
+    if sealed.len() < HEADER_LEN + COUNTERS_LEN + TAG_SIZE {
+        return Err(Error::InvalidPadState {
+            reason: "container too short",
+        });
+    }
+    if &sealed[0..4] != PAD_STATE_MAGIC {
+        return Err(Error::InvalidPadState {
+            reason: "bad magic",
+        });
+    }
+    if sealed[4] != PAD_STATE_VERSION {
+        return Err(Error::InvalidPadState {
+            reason: "unsupported version",
+        });
+    }
+
+    let (header, rest) = sealed.split_at(HEADER_LEN);
+    let (body, tag) = rest.split_at(rest.len() - TAG_SIZE);
+    let nonce: [u8; PAD_STATE_NONCE_SIZE] = header[14..HEADER_LEN].try_into().unwrap();
+
+    let auth_key = mac_key(wrapping_key, &nonce);
+    if !mac::verify_tag_slice(&auth_key, header, body, tag) {
+        return Err(Error::AuthenticationFailed);
+    }
+
+    let role = Role::from_byte(header[5]).ok_or(Error::InvalidPadState {
+        reason: "unknown role",
+    })?;
+    let generation = read_u64_be(header, 6);
+    if generation < min_generation {
+        return Err(Error::PadStateRollback {
+            generation,
+            minimum: min_generation,
+        });
+    }
+
+    let mut body = body.to_vec();
+    chacha20::apply_keystream(wrapping_key, 1, &nonce, &mut body);
+    let pad = decode_body(&body);
+    secure_zero(&mut body);
+
+    Ok(PadState {
+        role,
+        generation,
+        pad: pad?,
+    })
+}
+
+fn encode_header(
+    role: Role,
+    generation: u64,
+    nonce: &[u8; PAD_STATE_NONCE_SIZE],
+) -> [u8; HEADER_LEN] {
+    let mut header = [0u8; HEADER_LEN];
+    header[0..4].copy_from_slice(PAD_STATE_MAGIC);
+    header[4] = PAD_STATE_VERSION;
+    header[5] = role.to_byte();
+    header[6..14].copy_from_slice(&generation.to_be_bytes());
+    header[14..HEADER_LEN].copy_from_slice(nonce);
+    header
+}
+
+fn encode_body(pad: &Pad) -> Vec<u8> {
+    let bytes = pad.as_bytes();
+    let zeroed = pad.zeroed_ranges();
+    let wiped: usize = zeroed.iter().map(|(start, end)| end - start).sum();
+    let bitmap_len = bytes.len().div_ceil(8);
+
+    let mut body = Vec::with_capacity(COUNTERS_LEN + bitmap_len + bytes.len() - wiped);
+    body.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+    body.extend_from_slice(&(pad.consumed_front() as u64).to_be_bytes());
+    body.extend_from_slice(&(pad.consumed_back() as u64).to_be_bytes());
+
+    let mut bitmap = vec![0u8; bitmap_len];
+    for &(start, end) in zeroed {
+        for i in start..end {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+    }
+    body.extend_from_slice(&bitmap);
+
+    let mut kept = 0;
+    for &(start, end) in zeroed {
+        body.extend_from_slice(&bytes[kept..start]);
+        kept = end;
+    }
+    body.extend_from_slice(&bytes[kept..]);
+    body
+}
+
+fn decode_body(body: &[u8]) -> Result<Pad> {
+    let total_size = usize::try_from(read_u64_be(body, 0)).ok();
+    let consumed_front = usize::try_from(read_u64_be(body, 8)).ok();
+    let consumed_back = usize::try_from(read_u64_be(body, 16)).ok();
+    let invalid = |reason| Error::InvalidPadState { reason };
+
+    let (total_size, consumed_front, consumed_back) =
+        match (total_size, consumed_front, consumed_back) {
+            (Some(t), Some(f), Some(b)) if f.checked_add(b).is_some_and(|c| c <= t) => (t, f, b),
+            _ => return Err(invalid("consumption counters exceed pad size")),
+        };
+
+    let bitmap_len = total_size.div_ceil(8);
+    let rest = &body[COUNTERS_LEN..];
+    if rest.len() < bitmap_len {
+        return Err(invalid("truncated zeroed bitmap"));
+    }
+    let (bitmap, stored) = rest.split_at(bitmap_len);
+    let is_zeroed = |i: usize| bitmap[i / 8] & (1 << (i % 8)) != 0;
+
+    let wiped = (0..total_size).filter(|&i| is_zeroed(i)).count();
+    if stored.len() != total_size - wiped {
+        return Err(invalid("pad length does not match zeroed bitmap"));
+    }
+
+    let mut bytes = Vec::with_capacity(total_size);
+    let mut zeroed: Vec<(usize, usize)> = Vec::new();
+    let mut stored = stored.iter();
+    for i in 0..total_size {
+        if is_zeroed(i) {
+            bytes.push(0);
+            match zeroed.last_mut() {
+                Some((_, end)) if *end == i => *end = i + 1,
+                _ => zeroed.push((i, i + 1)),
+            }
+        } else {
+            bytes.push(*stored.next().unwrap());
+        }
+    }
+
+    Ok(Pad::from_parts(
+        bytes,
+        consumed_front,
+        consumed_back,
+        zeroed,
+    ))
+}
+
+/// One-time MAC key for this (key, nonce): keystream block 0.
+fn mac_key(wrapping_key: &[u8; WRAPPING_KEY_SIZE], nonce: &[u8; PAD_STATE_NONCE_SIZE]) -> AuthKey {
+    let mut block = chacha20::block(wrapping_key, 0, nonce);
+    let key = AuthKey::from_bytes(&block);
+    secure_zero(&mut block);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; WRAPPING_KEY_SIZE] = [0x5A; WRAPPING_KEY_SIZE];
+    const NONCE: [u8; PAD_STATE_NONCE_SIZE] = [0x01; PAD_STATE_NONCE_SIZE];
+
+    fn test_pad() -> Pad {
+        let bytes: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8 + 1).collect();
+        let mut pad = Pad::from_bytes(bytes);
+        pad.consume(120, Role::Initiator).unwrap();
+        pad.consume(80, Role::Responder).unwrap();
+        pad
+    }
+
+    #[test]
+    fn pad_state_roundtrip() {
+        let mut pad = test_pad();
+        pad.zero_bytes_at(0, 64);
+        pad.zero_bytes_at(930, 70);
+
+        let sealed = seal_pad_state(&pad, Role::Responder, 3, &KEY, &NONCE);
+        let state = open_pad_state(&sealed, &KEY, 3).unwrap();
+
+        assert_eq!(state.role, Role::Responder);
+        assert_eq!(state.generation, 3);
+        assert_eq!(state.pad.as_bytes(), pad.as_bytes());
+        assert_eq!(state.pad.consumed_front(), 120);
+        assert_eq!(state.pad.consumed_back(), 80);
+        assert_eq!(state.pad.zeroed_ranges(), &[(0, 64), (930, 1000)]);
+    }
+
+    #[test]
+    fn pad_state_wiped_bytes_not_stored() {
+        let mut pad = test_pad();
+        let full = seal_pad_state(&pad, Role::Initiator, 1, &KEY, &NONCE);
+        pad.zero_bytes_at(0, 120);
+        let wiped = seal_pad_state(&pad, Role::Initiator, 1, &KEY, &NONCE);
+
+        assert_eq!(full.len() - wiped.len(), 120);
+        assert_eq!(
+            full.len(),
+            HEADER_LEN + COUNTERS_LEN + 125 + 1000 + TAG_SIZE
+        );
+    }
+
+    #[test]
+    fn pad_state_hides_pad_bytes() {
+        let pad = test_pad();
+        let sealed = seal_pad_state(&pad, Role::Initiator, 1, &KEY, &NONCE);
+        let body = &sealed[HEADER_LEN..sealed.len() - TAG_SIZE];
+        assert!(!body.windows(16).any(|w| w == &pad.as_bytes()[200..216]));
+    }
+
+    #[test]
+    fn pad_state_tampering_detected() {
+        let sealed = seal_pad_state(&test_pad(), Role::Initiator, 1, &KEY, &NONCE);
+
+        // Role, generation, counters, pad bytes and tag are all covered
+        for at in [5, 6, HEADER_LEN + 15, HEADER_LEN + 200, sealed.len() - 1] {
+            let mut tampered = sealed.clone();
+            tampered[at] ^= 0x01;
+            assert_eq!(
+                open_pad_state(&tampered, &KEY, 0).unwrap_err(),
+                Error::AuthenticationFailed,
+                "byte {} not authenticated",
+                at
+            );
+        }
+    }
+
+    #[test]
+    fn pad_state_wrong_key() {
+        let sealed = seal_pad_state(&test_pad(), Role::Initiator, 1, &KEY, &NONCE);
+        let other = [0xA5; WRAPPING_KEY_SIZE];
+        assert_eq!(
+            open_pad_state(&sealed, &other, 0).unwrap_err(),
+            Error::AuthenticationFailed
+        );
+    }
+
+    #[test]
+    fn pad_state_rollback_detected() {
+        let mut pad = test_pad();
+        let old = seal_pad_state(&pad, Role::Initiator, 7, &KEY, &NONCE);
+        pad.consume(50, Role::Initiator).unwrap();
+        let new = seal_pad_state(&pad, Role::Initiator, 8, &KEY, &[0x02; 12]);
+
+        assert!(open_pad_state(&new, &KEY, 8).is_ok());
+        assert_eq!(
+            open_pad_state(&old, &KEY, 8).unwrap_err(),
+            Error::PadStateRollback {
+                generation: 7,
+                minimum: 8
+            }
+        );
+    }
+
+    #[test]
+    fn pad_state_rejects_malformed() {
+        let sealed = seal_pad_state(&test_pad(), Role::Initiator, 1, &KEY, &NONCE);
+
+        assert!(matches!(
+            open_pad_state(&sealed[..40], &KEY, 0),
+            Err(Error::InvalidPadState { .. })
+        ));
+
+        let mut bad_magic = sealed.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(
+            open_pad_state(&bad_magic, &KEY, 0),
+            Err(Error::InvalidPadState { .. })
+        ));
+
+        let mut bad_version = sealed;
+        bad_version[4] = 99;
+        assert_eq!(
+            open_pad_state(&bad_version, &KEY, 0).unwrap_err(),
+            Error::InvalidPadState {
+                reason: "unsupported version"
+            }
+        );
+    }
+
+    #[test]
+    fn pad_state_empty_pad() {
+        let pad = Pad::from_bytes(Vec::new());
+        let sealed = seal_pad_state(&pad, Role::Initiator, 0, &KEY, &NONCE);
+        let state = open_pad_state(&sealed, &KEY, 0).unwrap();
+        assert_eq!(state.pad.total_size(), 0);
+    }
+}
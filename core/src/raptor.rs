@@ -125,7 +125,7 @@ impl RaptorEncoder {
         let k = source.len();
         let mut parity = vec![vec![0u8; block_size]; p];
 
-        for i in 0..p {
+        for (i, block) in parity.iter_mut().enumerate() {
             // FROZEN: seed = i + 0x12345678
             let mut rng = PseudoRng::new(i as u64 + 0x12345678);
 
@@ -137,7 +137,7 @@ impl RaptorEncoder {
                 if indices.is_empty() { break; }
                 let idx = rng.next_usize() % indices.len();
                 let src_idx = indices.swap_remove(idx);
-                xor_block(&mut parity[i], &source[src_idx]);
+                xor_block(block, &source[src_idx]);
             }
         }
 
@@ -226,8 +226,8 @@ impl RaptorEncoder {
         else if r < 0.45 { 2 }
         else if r < 0.75 { 3 }
         else if r < 0.90 { 4 }
-        else if r < 0.97 { (n / 4).max(5).min(10) }
-        else { (n / 2).max(10).min(20) }
+        else if r < 0.97 { (n / 4).clamp(5, 10) }
+        else { (n / 2).clamp(10, 20) }
     }
 
     /// Number of source blocks (K).
@@ -307,6 +307,11 @@ impl RaptorDecoder {
             return true;
         }
 
+        // Ignore blocks from a different transfer
+        if !self.accepts(block) {
+            return false;
+        }
+
         // Skip duplicates
         if self.seen_indices.contains(&block.index) {
             return self.is_complete();
@@ -392,8 +397,8 @@ impl RaptorDecoder {
         else if r < 0.45 { 2 }
         else if r < 0.75 { 3 }
         else if r < 0.90 { 4 }
-        else if r < 0.97 { (n / 4).max(5).min(10) }
-        else { (n / 2).max(10).min(20) }
+        else if r < 0.97 { (n / 4).clamp(5, 10) }
+        else { (n / 2).clamp(10, 20) }
     }
 
     fn get_block(&self, idx: usize) -> Option<&Vec<u8>> {
@@ -513,6 +518,14 @@ impl RaptorDecoder {
         false
     }
 
+    /// Whether `block` has the parameters this decoder was created with.
+    fn accepts(&self, block: &EncodedBlock) -> bool {
+        block.source_count as usize == self.k
+            && block.block_size as usize == self.block_size
+            && block.original_len as usize == self.original_len
+            && block.data.len() == self.block_size
+    }
+
     /// Check if all source symbols are decoded.
     pub fn is_complete(&self) -> bool {
         self.decoded_source.iter().all(|s| s.is_some())
@@ -613,7 +626,7 @@ mod tests {
             block_size: 64,
             original_len: 500,
             data: vec![0xAB; 64],
-            checksum: crc::compute(&vec![0xAB; 64]),
+            checksum: crc::compute(&[0xAB; 64]),
         };
 
         let encoded = block.encode();
@@ -645,7 +658,7 @@ mod tests {
             "Small: {} blocks for K={} (overhead: {})",
             blocks_used,
             encoder.source_count(),
-            blocks_used as i32 - encoder.source_count() as i32
+            blocks_used - encoder.source_count() as i32
         );
     }
 
@@ -673,7 +686,7 @@ mod tests {
             "Medium: {} blocks for K={} (overhead: {})",
             blocks_used,
             encoder.source_count(),
-            blocks_used as i32 - encoder.source_count() as i32
+            blocks_used - encoder.source_count() as i32
         );
     }
 
@@ -724,6 +737,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn raptor_ignores_foreign_blocks() {
+        let data = b"Blocks from another transfer";
+        let encoder = RaptorEncoder::new(data, 8);
+        let k = encoder.source_count();
+        let mut decoder = RaptorDecoder::new(k as u16, 8, data.len());
+
+        // Wrong block size, wrong source count and truncated data
+        let mut wrong_size = RaptorEncoder::new(data, 4).generate_block(0);
+        wrong_size.source_count = k as u16;
+        let mut wrong_count = encoder.generate_block(0);
+        wrong_count.source_count += 1;
+        let mut truncated = encoder.generate_block(0);
+        truncated.data.pop();
+        for block in [&wrong_size, &wrong_count, &truncated] {
+            assert!(!decoder.add_block(block));
+        }
+        assert_eq!(decoder.unique_blocks_received(), 0);
+
+        for index in 0..k as u32 {
+            decoder.add_block(&encoder.generate_block(index));
+        }
+        assert_eq!(decoder.get_data().unwrap(), data);
+    }
+
     #[test]
     fn raptor_out_of_order() {
         let data = b"Out of order Raptor test!";
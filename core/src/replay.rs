@@ -0,0 +1,575 @@
+//! Receive window for replay rejection and out-of-order delivery.
+//!
+//! The relay is untrusted and can hand back a blob it already delivered.
+//! Every message from the peer occupies its own range of the peer's side of
+//! the pad, so a message that overlaps a range we already accepted is a
+//! replay. [`ReceiveWindow::check`] rejects it before any pad bytes are
+//! read or consumed.
+//!
+//! Accept a message with [`ReceiveWindow::accept`] only after its tag
+//! verified. Accepted ranges are merged as they become contiguous, so a
+//! conversation delivered in order is a single range. Messages arriving out
+//! of order leave [gaps](ReceiveWindow::gaps) that a late message can still
+//! fill; at most [`MAX_RANGES`] ranges are kept, after which the oldest gap
+//! is closed and a late message for it is treated as a replay.
+//!
+//! [`ReceiveWindow::receive`] runs the whole receive path: replay check,
+//! key lookup with [`Pad::key_at`], verification and decryption.
+//!
+//! # Offsets
+//!
+//! `offset` is the peer's [`Pad::send_position`] when it sent the
+//! message, counted from the origin of the peer's direction. It travels in
+//! the authenticated header of a version 2 [`MessageFrame`]
+//! (see [`MessageFrame::encrypt_at`]), not in anything the relay assigns.
+//! Ranges are stored the same way, like the
+//! [consumption journal](crate::journal), so they survive top-ups.
+//!
+//! # Example
+//!
+//! ```
+//! use ash_core::pad::{Pad, Role};
+//! use ash_core::replay::ReceiveWindow;
+//! use ash_core::Error;
+//!
+//! let pad = Pad::from_bytes(vec![0u8; 1000]);
+//! let mut window = ReceiveWindow::new(Role::Responder);
+//!
+//! // First delivery of the peer's message at offset 0
+//! window.check(&pad, 0, 100).unwrap();
+//! // ... look up the key, verify the tag, decrypt ...
+//! window.accept(&pad, 0, 100).unwrap();
+//!
+//! // The relay replays it
+//! assert!(matches!(
+//!     window.check(&pad, 0, 100),
+//!     Err(Error::ReplayedMessage { .. })
+//! ));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::mac::{AuthKey, AUTH_KEY_SIZE};
+use crate::message::{pad_consumption, MessageFrame, FRAME_VERSION};
+use crate::pad::{read_u64_be, Pad, Role};
+use crate::secret::SecretBytes;
+
+/// Magic bytes identifying an encoded receive window.
+const WINDOW_MAGIC: &[u8; 4] = b"ASHW";
+
+/// Current receive window encoding version.
+pub const WINDOW_VERSION: u8 = 1;
+
+/// Encoded size of the header.
+const HEADER_LEN: usize = 4 + 1 + 1 + 4;
+
+/// Encoded size of one range.
+const RANGE_LEN: usize = 8 + 8;
+
+/// Maximum number of disjoint accepted ranges kept.
+pub const MAX_RANGES: usize = 64;
+
+/// Accepted ranges of the peer's pad consumption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiveWindow {
+    role: Role,
+    /// Sorted, disjoint, non-adjacent `[start, end)` ranges from the peer's origin
+    ranges: Vec<(usize, usize)>,
+}
+
+impl ReceiveWindow {
+    /// Create an empty window for our role.
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Our role in the conversation.
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// Accepted ranges as `(start, end)` pairs from the peer's origin.
+    pub fn accepted(&self) -> &[(usize, usize)] {
+        &self.ranges
+    }
+
+    /// End of the furthest accepted range (0 while empty).
+    pub fn highest(&self) -> usize {
+        self.ranges.last().map_or(0, |&(_, end)| end)
+    }
+
+    /// Ranges below [`highest`](Self::highest) not yet received.
+    ///
+    /// Each gap is a message that was lost or is still in flight.
+    pub fn gaps(&self) -> Vec<(usize, usize)> {
+        let mut gaps = Vec::new();
+        let mut previous_end = 0;
+        for &(start, end) in &self.ranges {
+            if start > previous_end {
+                gaps.push((previous_end, start));
+            }
+            previous_end = end;
+        }
+        gaps
+    }
+
+    /// Check that a message from the peer has not been accepted before.
+    ///
+    /// Does not touch the pad; call it before looking up key material.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::ReplayedMessage`] if the range overlaps an accepted message
+    /// - [`Error::InsufficientPadBytes`] if the range lies outside the pad
+    pub fn check(&self, pad: &Pad, offset: usize, len: usize) -> Result<()> {
+        let (start, end) = peer_range(pad, offset, len)?;
+        if self.overlaps(start, end) {
+            return Err(Error::ReplayedMessage { start, end });
+        }
+        Ok(())
+    }
+
+    /// Record an authenticated message from the peer.
+    ///
+    /// Call this only after the message's tag verified.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`check`](Self::check); the window is unchanged on error.
+    pub fn accept(&mut self, pad: &Pad, offset: usize, len: usize) -> Result<()> {
+        self.check(pad, offset, len)?;
+        let (start, end) = peer_range(pad, offset, len)?;
+        self.insert(start, end);
+        Ok(())
+    }
+
+    /// Verify and decrypt a message from the peer, in any arrival order.
+    ///
+    /// Replays are rejected before the pad is read. The key is looked up
+    /// with [`Pad::key_at`], so any message whose range lies on the peer's
+    /// side of the pad can be decrypted, including ones behind a message
+    /// that arrived earlier. Once the tag verified, the range is accepted
+    /// and the pad's view of the peer's consumption is advanced.
+    ///
+    /// The offset is read from the frame header, which the tag covers.
+    ///
+    /// # Arguments
+    ///
+    /// * `pad` - Our pad
+    /// * `frame` - The decoded message frame, created with
+    ///   [`MessageFrame::encrypt_at`]
+    ///
+    /// # Returns
+    ///
+    /// The plaintext as encrypted (still padded if the sender padded it).
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::UnsupportedFrameVersion`] if the frame carries no offset
+    /// - [`Error::ReplayedMessage`] if the message was already accepted
+    /// - [`Error::PadRangeReuse`] or [`Error::KeyWiped`] from the key lookup
+    /// - [`Error::AuthenticationFailed`] if the tag does not verify; the
+    ///   window and pad are unchanged
+    pub fn receive(&mut self, pad: &mut Pad, frame: &MessageFrame) -> Result<SecretBytes> {
+        let len = pad_consumption(frame.ciphertext.len());
+        let offset = frame.offset.ok_or(Error::UnsupportedFrameVersion {
+            version: FRAME_VERSION,
+        })?;
+        let offset = usize::try_from(offset).map_err(|_| Error::InsufficientPadBytes {
+            needed: len,
+            available: pad.remaining(),
+        })?;
+        self.check(pad, offset, len)?;
+
+        let key = pad.key_at(offset, len, self.role.peer())?;
+        let auth_key = AuthKey::from_slice(&key[..AUTH_KEY_SIZE]);
+        let plaintext = frame.decrypt(&key[AUTH_KEY_SIZE..], &auth_key)?;
+
+        let (start, end) = peer_range(pad, offset, len)?;
+        self.insert(start, end);
+        pad.update_peer_consumption(self.role.peer(), end)?;
+        Ok(plaintext)
+    }
+
+    /// Encode the window for storage next to the pad state.
+    ///
+    /// Format: `[magic: "ASHW"][version: u8][role: u8][count: u32 BE]`
+    /// followed by `[start: u64 BE][end: u64 BE]` per range.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.ranges.len() * RANGE_LEN);
+        bytes.extend_from_slice(WINDOW_MAGIC);
+        bytes.push(WINDOW_VERSION);
+        bytes.push(self.role.to_byte());
+        bytes.extend_from_slice(&(self.ranges.len() as u32).to_be_bytes());
+        for &(start, end) in &self.ranges {
+            bytes.extend_from_slice(&(start as u64).to_be_bytes());
+            bytes.extend_from_slice(&(end as u64).to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a stored window.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidReceiveWindow`] if the encoding is malformed
+    /// or the ranges are not sorted and disjoint.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let invalid = |reason| Error::InvalidReceiveWindow { reason };
+
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != WINDOW_MAGIC {
+            return Err(invalid("bad header"));
+        }
+        if bytes[4] != WINDOW_VERSION {
+            return Err(invalid("unsupported version"));
+        }
+        let role = Role::from_byte(bytes[5]).ok_or(invalid("unknown role"))?;
+        let count = u32::from_be_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]) as usize;
+        let body = &bytes[HEADER_LEN..];
+        if count > MAX_RANGES || body.len() != count * RANGE_LEN {
+            return Err(invalid("range count mismatch"));
+        }
+
+        let mut ranges = Vec::with_capacity(count);
+        let mut previous_end = None;
+        for chunk in body.chunks_exact(RANGE_LEN) {
+            let start = read_usize(chunk, 0).ok_or(invalid("range overflow"))?;
+            let end = read_usize(chunk, 8).ok_or(invalid("range overflow"))?;
+            if start >= end || previous_end.is_some_and(|previous| start <= previous) {
+                return Err(invalid("ranges not sorted"));
+            }
+            previous_end = Some(end);
+            ranges.push((start, end));
+        }
+        Ok(Self { role, ranges })
+    }
+
+    fn overlaps(&self, start: usize, end: usize) -> bool {
+        start < end && self.ranges.iter().any(|&(a, b)| start < b && a < end)
+    }
+
+    /// Merge `[start, end)` into the ranges, closing the oldest gap if full.
+    fn insert(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        let (mut start, mut end) = (start, end);
+        let mut merged = Vec::with_capacity(self.ranges.len() + 1);
+        for &(a, b) in &self.ranges {
+            if b < start || a > end {
+                merged.push((a, b));
+            } else {
+                start = start.min(a);
+                end = end.max(b);
+            }
+        }
+        merged.push((start, end));
+        merged.sort_unstable();
+
+        if merged.len() > MAX_RANGES {
+            let (_, second_end) = merged.remove(1);
+            merged[0].1 = second_end;
+        }
+        self.ranges = merged;
+    }
+}
+
+/// Range `[start, end)` of a peer message, measured from the peer's origin.
+fn peer_range(pad: &Pad, offset: usize, len: usize) -> Result<(usize, usize)> {
+    match offset.checked_add(len) {
+        Some(end) if end <= pad.total_size() => Ok((offset, end)),
+        _ => Err(Error::InsufficientPadBytes {
+            needed: len,
+            available: pad.remaining(),
+        }),
+    }
+}
+
+fn read_usize(bytes: &[u8], at: usize) -> Option<usize> {
+    usize::try_from(read_u64_be(bytes, at)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessageType;
+
+    fn pad() -> Pad {
+        Pad::from_bytes((0..=255).cycle().take(1000).collect())
+    }
+
+    #[test]
+    fn replay_is_rejected() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+
+        window.accept(&pad, 0, 100).unwrap();
+        assert_eq!(
+            window.check(&pad, 0, 100),
+            Err(Error::ReplayedMessage { start: 0, end: 100 })
+        );
+        assert!(window.accept(&pad, 50, 100).is_err());
+        window.check(&pad, 100, 100).unwrap();
+    }
+
+    #[test]
+    fn check_does_not_consume() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        window.accept(&pad, 0, 100).unwrap();
+
+        let _ = window.check(&pad, 0, 100);
+        let _ = window.check(&pad, 100, 100);
+        assert_eq!(pad.consumed(), 0);
+    }
+
+    #[test]
+    fn in_order_messages_merge() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        for offset in (0..500).step_by(100) {
+            window.accept(&pad, offset, 100).unwrap();
+        }
+        assert_eq!(window.accepted(), &[(0, 500)]);
+        assert_eq!(window.highest(), 500);
+    }
+
+    #[test]
+    fn out_of_order_fills_gap() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        window.accept(&pad, 0, 100).unwrap();
+        window.accept(&pad, 200, 100).unwrap();
+        assert_eq!(window.accepted(), &[(0, 100), (200, 300)]);
+
+        window.accept(&pad, 100, 100).unwrap();
+        assert_eq!(window.accepted(), &[(0, 300)]);
+    }
+
+    #[test]
+    fn responder_offsets_count_from_its_own_end() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Initiator);
+
+        // Peer responder sent 100 bytes ending at the end of the pad
+        window.accept(&pad, 0, 100).unwrap();
+        window.accept(&pad, 100, 50).unwrap();
+        assert_eq!(window.accepted(), &[(0, 150)]);
+        assert!(window.check(&pad, 0, 100).is_err());
+        assert!(window.check(&pad, 995, 10).is_err());
+    }
+
+    #[test]
+    fn range_outside_pad_is_rejected() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        assert!(matches!(
+            window.accept(&pad, 950, 100),
+            Err(Error::InsufficientPadBytes { .. })
+        ));
+        assert!(window.check(&pad, usize::MAX, 2).is_err());
+        assert!(window.accepted().is_empty());
+    }
+
+    #[test]
+    fn full_window_closes_oldest_gap() {
+        let pad = Pad::from_bytes(vec![0u8; 10_000]);
+        let mut window = ReceiveWindow::new(Role::Responder);
+        for i in 0..=MAX_RANGES {
+            window.accept(&pad, i * 20, 10).unwrap();
+        }
+        assert_eq!(window.accepted().len(), MAX_RANGES);
+        assert_eq!(window.accepted()[0], (0, 30));
+
+        // The lost message in the closed gap now counts as seen
+        assert!(window.check(&pad, 10, 10).is_err());
+        window.check(&pad, 30, 10).unwrap();
+    }
+
+    /// Encrypt `text` as the peer would, with its offset in the frame.
+    fn send(sender: &mut Pad, role: Role, text: &[u8]) -> MessageFrame {
+        let offset = sender.send_position(role) as u64;
+        let key = sender.consume(pad_consumption(text.len()), role).unwrap();
+        let auth_key = AuthKey::from_slice(&key[..AUTH_KEY_SIZE]);
+        MessageFrame::encrypt_at(
+            MessageType::Text,
+            offset,
+            text,
+            &key[AUTH_KEY_SIZE..],
+            &auth_key,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn receive_in_order() {
+        for (ours, peer) in [
+            (Role::Initiator, Role::Responder),
+            (Role::Responder, Role::Initiator),
+        ] {
+            let mut sender = pad();
+            let mut receiver = pad();
+            let mut window = ReceiveWindow::new(ours);
+
+            for text in [&b"one"[..], b"two", b"three"] {
+                let frame = send(&mut sender, peer, text);
+                let plaintext = window.receive(&mut receiver, &frame).unwrap();
+                assert_eq!(plaintext, text);
+            }
+            assert_eq!(window.accepted().len(), 1);
+            assert_eq!(
+                receiver.next_send_offset(peer),
+                sender.next_send_offset(peer)
+            );
+        }
+    }
+
+    #[test]
+    fn receive_out_of_order_tracks_gaps() {
+        let mut sender = pad();
+        let mut receiver = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let messages: Vec<_> = [&b"first"[..], b"second", b"third"]
+            .iter()
+            .map(|text| send(&mut sender, Role::Initiator, text))
+            .collect();
+
+        // The first message is lost for now; later ones still decrypt
+        assert_eq!(
+            window.receive(&mut receiver, &messages[2]).unwrap(),
+            b"third"
+        );
+        assert_eq!(
+            window.receive(&mut receiver, &messages[1]).unwrap(),
+            b"second"
+        );
+        assert_eq!(window.gaps(), vec![(0, 69)]);
+
+        assert_eq!(
+            window.receive(&mut receiver, &messages[0]).unwrap(),
+            b"first"
+        );
+        assert!(window.gaps().is_empty());
+        assert_eq!(receiver.consumed_front(), sender.consumed_front());
+    }
+
+    #[test]
+    fn receive_rejects_replay_without_touching_pad() {
+        let mut sender = pad();
+        let mut receiver = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let frame = send(&mut sender, Role::Initiator, b"hello");
+        window.receive(&mut receiver, &frame).unwrap();
+
+        // Wipe the key as retention would; the replay is caught first
+        receiver.zero_bytes_at(0, pad_consumption(5));
+        assert!(matches!(
+            window.receive(&mut receiver, &frame),
+            Err(Error::ReplayedMessage { .. })
+        ));
+        assert_eq!(receiver.consumed_front(), 69);
+    }
+
+    #[test]
+    fn receive_forgery_leaves_state_unchanged() {
+        let mut sender = pad();
+        let mut receiver = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let mut frame = send(&mut sender, Role::Initiator, b"hello");
+        frame.ciphertext[0] ^= 1;
+
+        assert_eq!(
+            window.receive(&mut receiver, &frame),
+            Err(Error::AuthenticationFailed)
+        );
+        assert!(window.accepted().is_empty());
+        assert_eq!(receiver.consumed(), 0);
+    }
+
+    #[test]
+    fn receive_takes_offset_from_authenticated_header() {
+        let mut sender = pad();
+        let mut receiver = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let first = send(&mut sender, Role::Initiator, b"first");
+        let mut second = send(&mut sender, Role::Initiator, b"other");
+
+        // Pointing the second message at the first message's key fails
+        second.offset = first.offset;
+        assert_eq!(
+            window.receive(&mut receiver, &second),
+            Err(Error::AuthenticationFailed)
+        );
+        assert!(window.accepted().is_empty());
+    }
+
+    #[test]
+    fn receive_requires_offset_frame() {
+        let mut receiver = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let mut frame = send(&mut pad(), Role::Initiator, b"hello");
+        frame.offset = None;
+
+        assert_eq!(
+            window.receive(&mut receiver, &frame),
+            Err(Error::UnsupportedFrameVersion {
+                version: FRAME_VERSION
+            })
+        );
+        assert_eq!(receiver.consumed(), 0);
+    }
+
+    #[test]
+    fn receive_rejects_range_in_our_direction() {
+        let mut sender = pad();
+        let mut receiver = pad();
+        receiver.consume(990, Role::Responder).unwrap();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        let frame = send(&mut sender, Role::Initiator, b"hello");
+
+        assert!(matches!(
+            window.receive(&mut receiver, &frame),
+            Err(Error::PadRangeReuse { .. })
+        ));
+    }
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Initiator);
+        window.accept(&pad, 0, 100).unwrap();
+        window.accept(&pad, 300, 100).unwrap();
+
+        let encoded = window.encode();
+        assert_eq!(encoded.len(), HEADER_LEN + 2 * RANGE_LEN);
+        assert_eq!(ReceiveWindow::decode(&encoded).unwrap(), window);
+    }
+
+    #[test]
+    fn decode_rejects_malformed() {
+        let pad = pad();
+        let mut window = ReceiveWindow::new(Role::Responder);
+        window.accept(&pad, 0, 100).unwrap();
+        window.accept(&pad, 200, 100).unwrap();
+        let encoded = window.encode();
+
+        assert!(ReceiveWindow::decode(&encoded[..HEADER_LEN - 1]).is_err());
+        assert!(ReceiveWindow::decode(&encoded[..encoded.len() - 1]).is_err());
+
+        let mut bad_version = encoded.clone();
+        bad_version[4] = 9;
+        assert!(ReceiveWindow::decode(&bad_version).is_err());
+
+        // Second range moved before the first
+        let mut unsorted = encoded.clone();
+        unsorted[HEADER_LEN + RANGE_LEN + 7] = 50;
+        assert_eq!(
+            ReceiveWindow::decode(&unsorted),
+            Err(Error::InvalidReceiveWindow {
+                reason: "ranges not sorted"
+            })
+        );
+    }
+}
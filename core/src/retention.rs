@@ -0,0 +1,624 @@
+//! Key retention for forward secrecy.
+//!
+//! [`Pad::zero_bytes_at`] wipes key material, but something has to decide
+//! which bytes and when. [`RetentionManager`] records the pad range of every
+//! sent and received message and wipes it once the message no longer needs
+//! its key:
+//!
+//! - **Delivery**: the peer acknowledged a sent message, or a received
+//!   message was decrypted and handed to the app
+//! - **Disappearance**: the disappearing-messages timer ran out after the
+//!   message was viewed
+//! - **Expiry**: the message TTL passed, whether or not it was delivered
+//!
+//! Which triggers apply is set by [`RetentionPolicy`], normally derived from
+//! the conversation's [`CeremonyMetadata`].
+//!
+//! # Time
+//!
+//! The core has no clock. Every call takes `now` as Unix seconds from the
+//! caller, and [`RetentionManager::next_due`] tells the app when to sweep
+//! next.
+//!
+//! # Offsets
+//!
+//! Like the [consumption journal](crate::journal), ranges are stored from
+//! the origin of their direction, so they stay valid across top-ups.
+//!
+//! # Example
+//!
+//! ```
+//! use ash_core::pad::{Pad, Role};
+//! use ash_core::retention::{RetentionManager, RetentionPolicy, WipeReason};
+//!
+//! let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+//! let mut retention = RetentionManager::new(Role::Initiator, RetentionPolicy::default());
+//!
+//! let offset = pad.send_position(Role::Initiator);
+//! pad.consume(100, Role::Initiator).unwrap();
+//! retention.record_sent(&pad, 1, offset, 100, 1_000).unwrap();
+//!
+//! retention.mark_delivered(1, 1_010);
+//! let wiped = retention.sweep(&mut pad, 1_010);
+//! assert_eq!(wiped[0].reason, WipeReason::Delivered);
+//! assert!(retention.summary(&pad).verified);
+//! ```
+
+use crate::ceremony::{CeremonyMetadata, DEFAULT_TTL_SECONDS};
+use crate::error::{Error, Result};
+use crate::pad::{Pad, Role};
+
+/// When recorded key material is wiped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Wipe as soon as the message is marked delivered.
+    pub wipe_on_delivery: bool,
+    /// Wipe this many seconds after the message is marked viewed.
+    pub disappear_after: Option<u64>,
+    /// Wipe this many seconds after the message was recorded.
+    pub expire_after: Option<u64>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            wipe_on_delivery: true,
+            disappear_after: None,
+            expire_after: Some(DEFAULT_TTL_SECONDS),
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Policy matching the conversation settings agreed in the ceremony.
+    ///
+    /// Keys are wiped on delivery, after the disappearing-messages timer
+    /// (if enabled), and at the latest when the relay TTL runs out.
+    pub fn from_metadata(metadata: &CeremonyMetadata) -> Self {
+        let disappear = metadata.disappearing_messages_seconds;
+        Self {
+            wipe_on_delivery: true,
+            disappear_after: (disappear > 0).then_some(u64::from(disappear)),
+            expire_after: Some(metadata.ttl_seconds),
+        }
+    }
+}
+
+/// Why a message's key material was wiped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeReason {
+    /// The message was delivered.
+    Delivered,
+    /// The disappearing-messages timer ran out.
+    Disappeared,
+    /// The message TTL passed.
+    Expired,
+    /// The app asked for the key to be wiped.
+    Manual,
+}
+
+/// Key material of one message that is still recoverable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetainedMessage {
+    /// Caller-assigned message ID.
+    pub id: u64,
+    /// Direction the pad bytes were consumed in.
+    pub direction: Role,
+    /// Start of the range, in bytes from the direction's origin.
+    pub start: usize,
+    /// Number of pad bytes the message consumed.
+    pub len: usize,
+    /// When the message was recorded.
+    pub recorded_at: u64,
+    /// When the message was marked delivered.
+    pub delivered_at: Option<u64>,
+    /// When the message was marked viewed.
+    pub viewed_at: Option<u64>,
+}
+
+impl RetainedMessage {
+    /// When and why this message's key is due to be wiped under `policy`.
+    ///
+    /// Returns `None` if no trigger has fired or been scheduled yet.
+    pub fn due(&self, policy: &RetentionPolicy) -> Option<(u64, WipeReason)> {
+        let delivered = self
+            .delivered_at
+            .filter(|_| policy.wipe_on_delivery)
+            .map(|at| (at, WipeReason::Delivered));
+        let disappeared = self
+            .viewed_at
+            .zip(policy.disappear_after)
+            .map(|(at, after)| (at.saturating_add(after), WipeReason::Disappeared));
+        let expired = policy
+            .expire_after
+            .map(|after| (self.recorded_at.saturating_add(after), WipeReason::Expired));
+
+        [delivered, disappeared, expired]
+            .into_iter()
+            .flatten()
+            .min_by_key(|&(at, _)| at)
+    }
+
+    /// Absolute `(start, end)` of the range in a pad of `total` bytes.
+    fn absolute(&self, total: usize) -> (usize, usize) {
+        absolute_range(self.direction, self.start, self.len, total)
+    }
+}
+
+/// A message whose key material was wiped by a sweep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WipedMessage {
+    /// Caller-assigned message ID.
+    pub id: u64,
+    /// Why the key was wiped.
+    pub reason: WipeReason,
+}
+
+/// A recoverable range in a [`RetentionSummary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoverableRange {
+    /// Caller-assigned message ID.
+    pub id: u64,
+    /// Direction the pad bytes were consumed in.
+    pub direction: Role,
+    /// Absolute start offset in the pad.
+    pub start: usize,
+    /// Absolute end offset in the pad (exclusive).
+    pub end: usize,
+    /// When the key is due to be wiped, if scheduled.
+    pub due_at: Option<u64>,
+}
+
+/// Which consumed key material is still recoverable from the pad.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionSummary {
+    /// Ranges of recorded messages whose keys are still in the pad.
+    pub recoverable: Vec<RecoverableRange>,
+    /// Total bytes in `recoverable`.
+    pub recoverable_bytes: usize,
+    /// Absolute, sorted, non-overlapping ranges wiped by the manager.
+    pub wiped: Vec<(usize, usize)>,
+    /// Total bytes in `wiped`.
+    pub wiped_bytes: usize,
+    /// Whether every wiped range reads back as zeros in the pad.
+    pub verified: bool,
+}
+
+/// Records message key ranges and wipes them according to a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionManager {
+    role: Role,
+    policy: RetentionPolicy,
+    retained: Vec<RetainedMessage>,
+    /// Wiped ranges as `(direction, start, end)` from the direction's
+    /// origin, merged per direction
+    wiped: Vec<(Role, usize, usize)>,
+}
+
+impl RetentionManager {
+    /// Create an empty manager for our role.
+    pub fn new(role: Role, policy: RetentionPolicy) -> Self {
+        Self {
+            role,
+            policy,
+            retained: Vec::new(),
+            wiped: Vec::new(),
+        }
+    }
+
+    /// Our role in the conversation.
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    /// The active policy.
+    pub fn policy(&self) -> &RetentionPolicy {
+        &self.policy
+    }
+
+    /// Replace the policy, e.g. after the disappearing timer was changed.
+    ///
+    /// Takes effect at the next [`sweep`](Self::sweep).
+    pub fn set_policy(&mut self, policy: RetentionPolicy) {
+        self.policy = policy;
+    }
+
+    /// Messages whose key material has not been wiped yet.
+    pub fn retained(&self) -> &[RetainedMessage] {
+        &self.retained
+    }
+
+    /// Record the pad range of a message we sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `pad` - Our pad
+    /// * `id` - Caller-assigned message ID
+    /// * `offset` - Our [`Pad::send_position`] before the message consumed its bytes
+    /// * `len` - Pad bytes the message consumed (auth key + ciphertext)
+    /// * `now` - Current Unix time in seconds
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InsufficientPadBytes`] if the range lies outside the pad.
+    pub fn record_sent(
+        &mut self,
+        pad: &Pad,
+        id: u64,
+        offset: usize,
+        len: usize,
+        now: u64,
+    ) -> Result<()> {
+        self.record(pad, self.role, id, offset, len, now)
+    }
+
+    /// Record the pad range of a message received from the peer.
+    ///
+    /// `offset` is the peer's [`Pad::send_position`] when it sent the
+    /// message; other arguments are as for [`record_sent`](Self::record_sent).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InsufficientPadBytes`] if the range lies outside the pad.
+    pub fn record_received(
+        &mut self,
+        pad: &Pad,
+        id: u64,
+        offset: usize,
+        len: usize,
+        now: u64,
+    ) -> Result<()> {
+        self.record(pad, self.role.peer(), id, offset, len, now)
+    }
+
+    fn record(
+        &mut self,
+        pad: &Pad,
+        direction: Role,
+        id: u64,
+        offset: usize,
+        len: usize,
+        now: u64,
+    ) -> Result<()> {
+        let start = match offset.checked_add(len) {
+            Some(end) if end <= pad.total_size() => offset,
+            _ => {
+                return Err(Error::InsufficientPadBytes {
+                    needed: len,
+                    available: pad.remaining(),
+                })
+            }
+        };
+
+        self.retained.push(RetainedMessage {
+            id,
+            direction,
+            start,
+            len,
+            recorded_at: now,
+            delivered_at: None,
+            viewed_at: None,
+        });
+        Ok(())
+    }
+
+    /// Mark a message as delivered.
+    ///
+    /// For sent messages this is the peer's acknowledgement; for received
+    /// messages it is the point the plaintext was handed to the app.
+    /// Returns `false` if no retained message has this ID.
+    pub fn mark_delivered(&mut self, id: u64, now: u64) -> bool {
+        self.mark(id, |message| {
+            message.delivered_at.get_or_insert(now);
+        })
+    }
+
+    /// Mark a message as viewed, starting its disappearing timer.
+    ///
+    /// Returns `false` if no retained message has this ID.
+    pub fn mark_viewed(&mut self, id: u64, now: u64) -> bool {
+        self.mark(id, |message| {
+            message.viewed_at.get_or_insert(now);
+        })
+    }
+
+    fn mark(&mut self, id: u64, update: impl Fn(&mut RetainedMessage)) -> bool {
+        let mut found = false;
+        for message in self.retained.iter_mut().filter(|m| m.id == id) {
+            update(message);
+            found = true;
+        }
+        found
+    }
+
+    /// Wipe a message's key material now, regardless of policy.
+    ///
+    /// Returns `false` if no retained message has this ID.
+    pub fn wipe(&mut self, pad: &mut Pad, id: u64) -> bool {
+        let (due, keep): (Vec<_>, Vec<_>) = std::mem::take(&mut self.retained)
+            .into_iter()
+            .partition(|m| m.id == id);
+        self.retained = keep;
+        for message in &due {
+            self.wipe_range(pad, message);
+        }
+        !due.is_empty()
+    }
+
+    /// Wipe every message whose key is due at `now`.
+    ///
+    /// Returns the wiped messages with the trigger that fired first.
+    pub fn sweep(&mut self, pad: &mut Pad, now: u64) -> Vec<WipedMessage> {
+        let policy = self.policy;
+        let mut wiped = Vec::new();
+        let mut keep = Vec::with_capacity(self.retained.len());
+
+        for message in std::mem::take(&mut self.retained) {
+            match message.due(&policy) {
+                Some((at, reason)) if at <= now => {
+                    self.wipe_range(pad, &message);
+                    wiped.push(WipedMessage {
+                        id: message.id,
+                        reason,
+                    });
+                }
+                _ => keep.push(message),
+            }
+        }
+
+        self.retained = keep;
+        wiped
+    }
+
+    /// Earliest time a retained message becomes due, if any is scheduled.
+    pub fn next_due(&self) -> Option<u64> {
+        self.retained
+            .iter()
+            .filter_map(|m| m.due(&self.policy))
+            .map(|(at, _)| at)
+            .min()
+    }
+
+    /// Summarize which key material is still recoverable from `pad`.
+    ///
+    /// `verified` re-reads every wiped range from the pad, so a pad restored
+    /// from before a sweep shows up as unverified.
+    pub fn summary(&self, pad: &Pad) -> RetentionSummary {
+        let total = pad.total_size();
+
+        let recoverable: Vec<RecoverableRange> = self
+            .retained
+            .iter()
+            .map(|m| {
+                let (start, end) = m.absolute(total);
+                RecoverableRange {
+                    id: m.id,
+                    direction: m.direction,
+                    start,
+                    end,
+                    due_at: m.due(&self.policy).map(|(at, _)| at),
+                }
+            })
+            .collect();
+
+        let mut wiped: Vec<(usize, usize)> = self
+            .wiped
+            .iter()
+            .map(|&(direction, start, end)| absolute_range(direction, start, end - start, total))
+            .collect();
+        wiped.sort_unstable();
+
+        let bytes = pad.as_bytes();
+        let verified = wiped
+            .iter()
+            .all(|&(start, end)| end <= total && bytes[start..end].iter().all(|&b| b == 0));
+
+        RetentionSummary {
+            recoverable_bytes: recoverable.iter().map(|r| r.end - r.start).sum(),
+            recoverable,
+            wiped_bytes: wiped.iter().map(|(start, end)| end - start).sum(),
+            wiped,
+            verified,
+        }
+    }
+
+    fn wipe_range(&mut self, pad: &mut Pad, message: &RetainedMessage) {
+        let (start, end) = message.absolute(pad.total_size());
+        pad.zero_bytes_at(start, end - start);
+
+        let (mut start, mut end) = (message.start, message.start + message.len);
+        let direction = message.direction;
+        self.wiped.retain(|&(d, a, b)| {
+            if d != direction || b < start || a > end {
+                return true;
+            }
+            start = start.min(a);
+            end = end.max(b);
+            false
+        });
+        self.wiped.push((direction, start, end));
+    }
+}
+
+/// Absolute `(start, end)` of a range measured from `direction`'s origin.
+fn absolute_range(direction: Role, start: usize, len: usize, total: usize) -> (usize, usize) {
+    match direction {
+        Role::Initiator => (start, start + len),
+        Role::Responder => (total - start - len, total - start),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn send(pad: &mut Pad, retention: &mut RetentionManager, id: u64, len: usize, now: u64) {
+        let role = retention.role();
+        let offset = pad.send_position(role);
+        pad.consume(len, role).unwrap();
+        retention.record_sent(pad, id, offset, len, now).unwrap();
+    }
+
+    fn policy(delivery: bool, disappear: Option<u64>, expire: Option<u64>) -> RetentionPolicy {
+        RetentionPolicy {
+            wipe_on_delivery: delivery,
+            disappear_after: disappear,
+            expire_after: expire,
+        }
+    }
+
+    #[test]
+    fn wipes_sent_range_on_delivery() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Initiator, RetentionPolicy::default());
+        send(&mut pad, &mut retention, 1, 100, 0);
+        send(&mut pad, &mut retention, 2, 50, 0);
+
+        assert!(retention.sweep(&mut pad, 10).is_empty());
+        assert!(retention.mark_delivered(1, 10));
+        let wiped = retention.sweep(&mut pad, 10);
+
+        assert_eq!(
+            wiped,
+            vec![WipedMessage {
+                id: 1,
+                reason: WipeReason::Delivered
+            }]
+        );
+        assert!(pad.as_bytes()[..100].iter().all(|&b| b == 0));
+        assert!(pad.as_bytes()[100..].iter().all(|&b| b == 0x42));
+        assert_eq!(retention.retained().len(), 1);
+    }
+
+    #[test]
+    fn received_responder_range_is_wiped_from_back() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Initiator, RetentionPolicy::default());
+
+        // Peer (Responder) sent a 64-byte message from the end of the pad
+        retention.record_received(&pad, 7, 0, 64, 0).unwrap();
+        retention.mark_delivered(7, 1);
+        retention.sweep(&mut pad, 1);
+
+        assert!(pad.as_bytes()[936..].iter().all(|&b| b == 0));
+        assert!(pad.as_bytes()[..936].iter().all(|&b| b == 0x42));
+        assert_eq!(retention.summary(&pad).wiped, vec![(936, 1000)]);
+    }
+
+    #[test]
+    fn disappearing_timer_starts_when_viewed() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Responder, policy(false, Some(30), None));
+        send(&mut pad, &mut retention, 1, 100, 0);
+
+        assert_eq!(retention.next_due(), None);
+        retention.mark_viewed(1, 100);
+        assert_eq!(retention.next_due(), Some(130));
+        assert!(retention.sweep(&mut pad, 129).is_empty());
+
+        let wiped = retention.sweep(&mut pad, 130);
+        assert_eq!(wiped[0].reason, WipeReason::Disappeared);
+        assert!(pad.as_bytes()[900..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn undelivered_message_expires() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Initiator, policy(true, None, Some(300)));
+        send(&mut pad, &mut retention, 1, 100, 1_000);
+
+        assert_eq!(retention.next_due(), Some(1_300));
+        let wiped = retention.sweep(&mut pad, 1_300);
+        assert_eq!(wiped[0].reason, WipeReason::Expired);
+    }
+
+    #[test]
+    fn earliest_trigger_wins() {
+        let message = RetainedMessage {
+            id: 1,
+            direction: Role::Initiator,
+            start: 0,
+            len: 10,
+            recorded_at: 0,
+            delivered_at: Some(50),
+            viewed_at: Some(10),
+        };
+
+        let both = policy(true, Some(5), Some(300));
+        assert_eq!(message.due(&both), Some((15, WipeReason::Disappeared)));
+        let no_delivery = policy(false, None, Some(300));
+        assert_eq!(message.due(&no_delivery), Some((300, WipeReason::Expired)));
+        assert_eq!(message.due(&policy(false, None, None)), None);
+    }
+
+    #[test]
+    fn manual_wipe() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Initiator, policy(false, None, None));
+        send(&mut pad, &mut retention, 1, 100, 0);
+
+        assert!(!retention.wipe(&mut pad, 2));
+        assert!(retention.wipe(&mut pad, 1));
+        assert!(retention.retained().is_empty());
+        assert_eq!(retention.summary(&pad).wiped_bytes, 100);
+    }
+
+    #[test]
+    fn summary_detects_restored_key_material() {
+        let bytes = vec![0x42; 1000];
+        let mut pad = Pad::from_bytes(bytes.clone());
+        let mut retention = RetentionManager::new(Role::Initiator, RetentionPolicy::default());
+        send(&mut pad, &mut retention, 1, 100, 0);
+        send(&mut pad, &mut retention, 2, 40, 0);
+        retention.mark_delivered(1, 1);
+        retention.sweep(&mut pad, 1);
+
+        let summary = retention.summary(&pad);
+        assert!(summary.verified);
+        assert_eq!(summary.recoverable_bytes, 40);
+        assert_eq!(summary.recoverable[0].start, 100);
+        assert_eq!(summary.recoverable[0].due_at, Some(DEFAULT_TTL_SECONDS));
+
+        // Pad restored from a backup taken before the sweep
+        let restored = Pad::from_bytes_with_state(bytes, 140, 0);
+        assert!(!retention.summary(&restored).verified);
+    }
+
+    #[test]
+    fn adjacent_wipes_merge() {
+        let mut pad = Pad::from_bytes(vec![0x42; 1000]);
+        let mut retention = RetentionManager::new(Role::Responder, RetentionPolicy::default());
+        for id in 0..3 {
+            send(&mut pad, &mut retention, id, 50, 0);
+            retention.mark_delivered(id, 0);
+        }
+        retention.sweep(&mut pad, 0);
+
+        let summary = retention.summary(&pad);
+        assert_eq!(summary.wiped, vec![(850, 1000)]);
+        assert_eq!(summary.wiped_bytes, 150);
+    }
+
+    #[test]
+    fn record_rejects_out_of_range() {
+        let pad = Pad::from_bytes(vec![0x42; 100]);
+        let mut retention = RetentionManager::new(Role::Initiator, RetentionPolicy::default());
+        assert!(retention.record_sent(&pad, 1, 90, 20, 0).is_err());
+        assert!(retention.record_received(&pad, 1, 200, 10, 0).is_err());
+    }
+
+    #[test]
+    fn policy_from_metadata() {
+        let mut metadata = CeremonyMetadata::default();
+        assert_eq!(
+            RetentionPolicy::from_metadata(&metadata),
+            RetentionPolicy::default()
+        );
+
+        metadata.disappearing_messages_seconds = 60;
+        metadata.ttl_seconds = 3600;
+        let policy = RetentionPolicy::from_metadata(&metadata);
+        assert_eq!(policy.disappear_after, Some(60));
+        assert_eq!(policy.expire_after, Some(3600));
+    }
+}
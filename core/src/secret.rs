@@ -0,0 +1,183 @@
+//! Heap buffer for key material and plaintext.
+//!
+//! [`SecretBytes`] wraps a `Vec<u8>` that is wiped with volatile writes when
+//! dropped, prints as redacted in `Debug`, and cannot be cloned. Consumed
+//! pad bytes and decrypted plaintext are returned in it, so they do not
+//! linger in freed heap memory.
+//!
+//! The bytes are readable through `Deref<Target = [u8]>`. Copying them out
+//! (with `to_vec()` or [`into_vec`](SecretBytes::into_vec)) hands the
+//! responsibility for wiping to the caller.
+//!
+//! # Example
+//!
+//! ```
+//! use ash_core::pad::{Pad, Role};
+//!
+//! let mut pad = Pad::from_bytes(vec![0x42; 100]);
+//! let key = pad.consume(10, Role::Initiator).unwrap();
+//!
+//! assert_eq!(key.len(), 10);
+//! assert_eq!(&key[..], &[0x42; 10]);
+//! assert!(!format!("{:?}", key).contains("42"));
+//! // key is wiped here
+//! ```
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use crate::pad::secure_zero;
+
+/// Secret bytes that are zeroed on drop.
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    /// Take ownership of `bytes`.
+    ///
+    /// Only the buffer as passed in is wiped on drop. Copies made earlier,
+    /// for example by growing the `Vec`, are not.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Copy `bytes` into a new secret buffer.
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// Borrow the secret bytes.
+    pub fn expose(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Move the bytes out without wiping them.
+    ///
+    /// Used at FFI boundaries where the bytes must be handed to foreign
+    /// code as a plain buffer. The caller becomes responsible for wiping.
+    pub fn into_vec(mut self) -> Vec<u8> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        secure_zero(&mut self.0);
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl DerefMut for SecretBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for SecretBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+/// Compare in constant time for equal lengths.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    std::hint::black_box(diff) == 0
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        ct_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl PartialEq<[u8]> for SecretBytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        ct_eq(&self.0, other)
+    }
+}
+
+impl PartialEq<&[u8]> for SecretBytes {
+    fn eq(&self, other: &&[u8]) -> bool {
+        ct_eq(&self.0, other)
+    }
+}
+
+impl PartialEq<Vec<u8>> for SecretBytes {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        ct_eq(&self.0, other)
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for SecretBytes {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        ct_eq(&self.0, other)
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for SecretBytes {
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        ct_eq(&self.0, *other)
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never print the bytes themselves
+        write!(f, "SecretBytes([REDACTED; {}])", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_is_redacted() {
+        let secret = SecretBytes::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        let debug = format!("{:?}", secret);
+        assert_eq!(debug, "SecretBytes([REDACTED; 4])");
+    }
+
+    #[test]
+    fn deref_exposes_bytes() {
+        let mut secret = SecretBytes::from_slice(&[1, 2, 3]);
+        assert_eq!(secret.len(), 3);
+        assert_eq!(&secret[..], &[1, 2, 3]);
+        secret[0] = 9;
+        assert_eq!(secret.expose(), &[9, 2, 3]);
+    }
+
+    #[test]
+    fn compares_by_content() {
+        let secret = SecretBytes::from_slice(b"key");
+        assert_eq!(secret, SecretBytes::from_slice(b"key"));
+        assert_eq!(secret, b"key");
+        assert_eq!(secret, b"key".to_vec());
+        assert_ne!(secret, b"kez");
+        assert_ne!(secret, b"keys".to_vec());
+    }
+
+    #[test]
+    fn into_vec_keeps_bytes() {
+        let secret = SecretBytes::new(vec![7; 8]);
+        assert_eq!(secret.into_vec(), vec![7; 8]);
+    }
+}
@@ -0,0 +1,1060 @@
+//! Pad top-up ceremony.
+//!
+//! Extends an existing conversation with a fresh pad segment instead of
+//! burning it and starting over. Message history, the conversation ID and
+//! the relay tokens all stay the same.
+//!
+//! # Flow
+//!
+//! ```text
+//! [TopUpSender]                                        [TopUpReceiver]
+//!  consume auth key from own direction of old pad
+//!  tag = MAC(header || segment)
+//!  frames ─────────────── QR (fountain codes) ──────────▶ add_frame()
+//!                                                         verify tag with old pad
+//!  mnemonic(tag) ◀────────── compare verbally ──────────▶ mnemonic(tag)
+//!  finish(): splice segment into pad                      finish(): same splice
+//! ```
+//!
+//! # Binding
+//!
+//! The tag is a Wegman-Carter MAC keyed with 64 bytes taken from the sender's
+//! direction of the *old* pad. Only the other party holds those bytes, so a
+//! segment cannot be injected into someone else's conversation, and an applied
+//! top-up cannot be replayed (its key bytes are then already consumed).
+//!
+//! # Placement
+//!
+//! The segment is spliced into the unconsumed region at the sender's
+//! consumption boundary (see [`Pad`]). Initiator consumption stays indexed from
+//! the start and Responder consumption from the end, so in-flight messages in
+//! either direction still decrypt at their [`Pad::send_position`] offset.
+//!
+//! # Payload Format
+//!
+//! ```text
+//! [magic: "ASHT"][version: u8][role: u8][pad_len: u64 BE][key_offset: u64 BE][segment_len: u32 BE]
+//! [tag: 32 bytes][segment: N bytes]
+//! ```
+//!
+//! The payload travels as the pad data of a regular fountain ceremony.
+
+use std::fmt;
+
+use crate::auth::MIN_PAD_SIZE_FOR_TOKENS;
+use crate::ceremony::{check_finish, CeremonyMetadata, CeremonyState};
+use crate::error::{Error, Result};
+use crate::frame::{
+    self, FountainFrameGenerator, FountainFrameReceiver, TransferMethod, DEFAULT_BLOCK_SIZE,
+};
+use crate::mac::{self, AuthKey, AUTH_KEY_SIZE, TAG_SIZE};
+use crate::mnemonic;
+use crate::pad::{read_u64_be, secure_zero, Pad, PadSize, Role};
+
+/// Magic bytes identifying a top-up payload.
+const TOPUP_MAGIC: &[u8; 4] = b"ASHT";
+
+/// Current top-up payload version.
+pub const TOPUP_VERSION: u8 = 1;
+
+/// Size of the authenticated header (before the tag).
+const HEADER_LEN: usize = 4 + 1 + 1 + 8 + 8 + 4;
+
+/// Summary of an applied top-up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopUpSummary {
+    /// Offset where the segment was spliced into the pad.
+    pub insert_offset: usize,
+    /// Number of bytes added.
+    pub segment_len: usize,
+    /// Pad size after the top-up.
+    pub new_total_size: usize,
+}
+
+/// Decoded top-up payload.
+struct TopUpPayload {
+    sender_role: Role,
+    pad_len: usize,
+    key_offset: usize,
+    tag: [u8; TAG_SIZE],
+    segment: Vec<u8>,
+}
+
+impl Drop for TopUpPayload {
+    fn drop(&mut self) {
+        secure_zero(&mut self.segment);
+    }
+}
+
+impl TopUpPayload {
+    fn header(&self) -> [u8; HEADER_LEN] {
+        let mut header = [0u8; HEADER_LEN];
+        header[0..4].copy_from_slice(TOPUP_MAGIC);
+        header[4] = TOPUP_VERSION;
+        header[5] = self.sender_role.to_byte();
+        header[6..14].copy_from_slice(&(self.pad_len as u64).to_be_bytes());
+        header[14..22].copy_from_slice(&(self.key_offset as u64).to_be_bytes());
+        header[22..26].copy_from_slice(&(self.segment.len() as u32).to_be_bytes());
+        header
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + TAG_SIZE + self.segment.len());
+        bytes.extend_from_slice(&self.header());
+        bytes.extend_from_slice(&self.tag);
+        bytes.extend_from_slice(&self.segment);
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN + TAG_SIZE || &bytes[0..4] != TOPUP_MAGIC {
+            return Err(Error::InvalidCeremonyPayload);
+        }
+        if bytes[4] != TOPUP_VERSION {
+            return Err(Error::InvalidCeremonyPayload);
+        }
+
+        let sender_role = Role::from_byte(bytes[5]).ok_or(Error::InvalidCeremonyPayload)?;
+
+        // Offsets come from untrusted bytes and are checked before the MAC,
+        // so they must not overflow when the key range is computed
+        let read_usize = |at: usize| {
+            usize::try_from(read_u64_be(bytes, at)).map_err(|_| Error::InvalidCeremonyPayload)
+        };
+        let pad_len = read_usize(6)?;
+        let key_offset = read_usize(14)?;
+        let segment_len = u32::from_be_bytes([bytes[22], bytes[23], bytes[24], bytes[25]]) as usize;
+
+        let segment = &bytes[HEADER_LEN + TAG_SIZE..];
+        if segment.is_empty() || segment.len() != segment_len {
+            return Err(Error::InvalidCeremonyPayload);
+        }
+        if key_offset.checked_add(AUTH_KEY_SIZE).is_none()
+            || pad_len.checked_add(segment_len).is_none()
+        {
+            return Err(Error::InvalidCeremonyPayload);
+        }
+
+        let mut tag = [0u8; TAG_SIZE];
+        tag.copy_from_slice(&bytes[HEADER_LEN..HEADER_LEN + TAG_SIZE]);
+
+        Ok(Self {
+            sender_role,
+            pad_len,
+            key_offset,
+            tag,
+            segment: segment.to_vec(),
+        })
+    }
+
+    /// End of the binding key range.
+    fn key_end(&self) -> Result<usize> {
+        self.key_offset
+            .checked_add(AUTH_KEY_SIZE)
+            .ok_or(Error::InvalidCeremonyPayload)
+    }
+
+    /// Where the segment goes: right behind the sender's consumption boundary.
+    ///
+    /// The Initiator side never inserts inside the token derivation range,
+    /// so the conversation ID and tokens can still be re-derived from the pad.
+    fn insert_offset(&self) -> Result<usize> {
+        match self.sender_role {
+            Role::Initiator => Ok(self.key_end()?.max(MIN_PAD_SIZE_FOR_TOKENS)),
+            Role::Responder => Ok(self.key_offset),
+        }
+    }
+
+    fn summary(&self) -> Result<TopUpSummary> {
+        Ok(TopUpSummary {
+            insert_offset: self.insert_offset()?,
+            segment_len: self.segment.len(),
+            new_total_size: self
+                .pad_len
+                .checked_add(self.segment.len())
+                .ok_or(Error::InvalidCeremonyPayload)?,
+        })
+    }
+}
+
+/// Check the pad is the one the top-up was created for.
+fn check_pad_len(payload: &TopUpPayload, pad: &Pad) -> Result<()> {
+    if payload.pad_len != pad.total_size() {
+        return Err(Error::TopUpPadMismatch {
+            expected: payload.pad_len,
+            actual: pad.total_size(),
+        });
+    }
+    Ok(())
+}
+
+/// Check `start..end` lies within the unconsumed region of the pad.
+fn check_in_gap(pad: &Pad, start: usize, end: usize) -> Result<()> {
+    let gap_start = pad.consumed_front();
+    let gap_end = pad.total_size().saturating_sub(pad.consumed_back());
+    if start < gap_start || end > gap_end {
+        return Err(Error::InvalidTopUpOffset {
+            offset: start,
+            gap_start,
+            gap_end,
+        });
+    }
+    Ok(())
+}
+
+/// Top-up driver for the party that generates the new segment.
+///
+/// Either conversation role can send a top-up.
+///
+/// # Flow
+///
+/// 1. Configure passphrase, block size and transfer method
+/// 2. [`start_transfer`](Self::start_transfer) with the conversation pad and fresh entropy
+/// 3. Display [`next_frame`](Self::next_frame) until the peer is done
+/// 4. [`finish_transfer`](Self::finish_transfer), then compare [`mnemonic`](Self::mnemonic)
+/// 5. [`confirm_mnemonic`](Self::confirm_mnemonic) and [`finish`](Self::finish)
+///
+/// Don't send messages between `start_transfer` and `finish`: the segment is
+/// placed at the consumption boundary captured when the transfer started.
+///
+/// # Example
+///
+/// ```
+/// use ash_core::topup::{TopUpReceiver, TopUpSender};
+/// use ash_core::{Pad, PadSize, Role};
+///
+/// let mut alice = Pad::from_bytes(vec![1u8; 4096]);
+/// let mut bob = Pad::from_bytes(vec![1u8; 4096]);
+///
+/// let mut sender = TopUpSender::new(Role::Initiator, PadSize::Tiny);
+/// sender.start_transfer(&mut alice, &vec![9u8; PadSize::Tiny.bytes()]).unwrap();
+///
+/// let mut receiver = TopUpReceiver::new(Role::Responder);
+/// receiver.start_transfer().unwrap();
+/// while !receiver.add_frame(&sender.next_frame().unwrap(), &bob).unwrap() {}
+///
+/// sender.finish_transfer().unwrap();
+/// assert_eq!(sender.mnemonic().unwrap(), receiver.mnemonic().unwrap());
+///
+/// sender.confirm_mnemonic().unwrap();
+/// receiver.confirm_mnemonic().unwrap();
+/// sender.finish(&mut alice).unwrap();
+/// receiver.finish(&mut bob).unwrap();
+///
+/// assert_eq!(alice.as_bytes(), bob.as_bytes());
+/// ```
+pub struct TopUpSender {
+    state: CeremonyState,
+    role: Role,
+    segment_size: PadSize,
+    passphrase: Option<String>,
+    block_size: usize,
+    method: TransferMethod,
+    payload: Option<TopUpPayload>,
+    generator: Option<FountainFrameGenerator>,
+}
+
+impl TopUpSender {
+    /// Create a new top-up sender in the `Configuring` state.
+    ///
+    /// `role` is our role in the existing conversation.
+    pub fn new(role: Role, segment_size: PadSize) -> Self {
+        Self {
+            state: CeremonyState::Configuring,
+            role,
+            segment_size,
+            passphrase: None,
+            block_size: DEFAULT_BLOCK_SIZE,
+            method: TransferMethod::default(),
+            payload: None,
+            generator: None,
+        }
+    }
+
+    /// Current ceremony state.
+    pub fn state(&self) -> CeremonyState {
+        self.state
+    }
+
+    /// Set the passphrase used to encrypt QR frames (`None` disables encryption).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_passphrase(&mut self, passphrase: Option<&str>) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_passphrase")?;
+        self.passphrase = passphrase.map(String::from);
+        Ok(())
+    }
+
+    /// Set the QR block size in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_block_size(&mut self, block_size: usize) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_block_size")?;
+        self.block_size = block_size;
+        Ok(())
+    }
+
+    /// Set the QR transfer method.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_transfer_method(&mut self, method: TransferMethod) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_transfer_method")?;
+        self.method = method;
+        Ok(())
+    }
+
+    /// Authenticate a new segment with the conversation pad and start generating frames.
+    ///
+    /// Consumes [`AUTH_KEY_SIZE`] bytes from our direction of `pad` for the
+    /// binding tag. These bytes are spent even if the top-up is later aborted.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCeremonyState`] unless `Configuring`
+    /// - [`Error::InvalidEntropySize`] if entropy doesn't match the segment size
+    /// - [`Error::InsufficientPadBytes`] / [`Error::InvalidTopUpOffset`] if the
+    ///   pad has no room left for the binding key
+    pub fn start_transfer(&mut self, pad: &mut Pad, entropy: &[u8]) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "start_transfer")?;
+
+        let expected = self.segment_size.bytes();
+        if entropy.len() != expected {
+            return Err(Error::InvalidEntropySize {
+                size: entropy.len(),
+                expected,
+            });
+        }
+
+        let key_offset = match self.role {
+            Role::Initiator => pad.next_send_offset(Role::Initiator),
+            Role::Responder => pad
+                .next_send_offset(Role::Responder)
+                .saturating_sub(AUTH_KEY_SIZE),
+        };
+        let mut payload = TopUpPayload {
+            sender_role: self.role,
+            pad_len: pad.total_size(),
+            key_offset,
+            tag: [0u8; TAG_SIZE],
+            segment: entropy.to_vec(),
+        };
+
+        // Make sure the segment will fit before spending the key
+        let insert_offset = payload.insert_offset()?;
+        check_in_gap(
+            pad,
+            key_offset.min(insert_offset),
+            payload.key_end()?.max(insert_offset),
+        )?;
+
+        let key_bytes = pad.consume(AUTH_KEY_SIZE, self.role)?;
+        let auth_key = AuthKey::from_slice(&key_bytes);
+        payload.tag = mac::compute_tag(&auth_key, &payload.header(), &payload.segment);
+
+        let metadata = CeremonyMetadata::default();
+        let generator = frame::create_fountain_ceremony(
+            &metadata,
+            &payload.encode(),
+            self.block_size,
+            self.passphrase.as_deref(),
+            self.method,
+        )?;
+
+        self.payload = Some(payload);
+        self.generator = Some(generator);
+        self.state = CeremonyState::Transferring;
+        Ok(())
+    }
+
+    /// Generate the next QR frame to display.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Transferring`.
+    pub fn next_frame(&mut self) -> Result<Vec<u8>> {
+        self.expect_state(CeremonyState::Transferring, "next_frame")?;
+        match self.generator.as_mut() {
+            Some(generator) => Ok(generator.next_frame()),
+            None => Err(self.invalid_state("next_frame")),
+        }
+    }
+
+    /// Stop displaying frames and move on to mnemonic verification.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Transferring`.
+    pub fn finish_transfer(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::Transferring, "finish_transfer")?;
+        self.generator = None;
+        self.state = CeremonyState::VerifyingMnemonic;
+        Ok(())
+    }
+
+    /// Mnemonic checksum of the binding tag, to compare with the receiver.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic` or `Confirmed`.
+    pub fn mnemonic(&self) -> Result<Vec<&'static str>> {
+        mnemonic_for(self.state, self.payload.as_ref())
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn confirm_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "confirm_mnemonic")?;
+        self.state = CeremonyState::Confirmed;
+        Ok(())
+    }
+
+    /// Record that the mnemonics differ. Aborts the top-up.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn reject_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "reject_mnemonic")?;
+        self.abort();
+        Ok(())
+    }
+
+    /// Abort the top-up and wipe the segment. Safe to call in any state.
+    pub fn abort(&mut self) {
+        if self.state == CeremonyState::Finished {
+            return;
+        }
+        self.payload = None;
+        self.generator = None;
+        self.state = CeremonyState::Aborted;
+    }
+
+    /// Splice the segment into the conversation pad.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MnemonicNotConfirmed`] if called while `VerifyingMnemonic`
+    /// - [`Error::InvalidCeremonyState`] in any other state except `Confirmed`
+    /// - [`Error::TopUpPadMismatch`] / [`Error::InvalidTopUpOffset`] if `pad`
+    ///   changed since the transfer started
+    pub fn finish(&mut self, pad: &mut Pad) -> Result<TopUpSummary> {
+        check_finish(self.state)?;
+        let payload = match self.payload.as_ref() {
+            Some(payload) => payload,
+            None => return Err(self.invalid_state("finish")),
+        };
+        check_pad_len(payload, pad)?;
+        let summary = payload.summary()?;
+        pad.insert_segment(summary.insert_offset, &payload.segment)?;
+
+        self.payload = None;
+        self.state = CeremonyState::Finished;
+        Ok(summary)
+    }
+
+    fn expect_state(&self, expected: CeremonyState, operation: &'static str) -> Result<()> {
+        if self.state == expected {
+            Ok(())
+        } else {
+            Err(self.invalid_state(operation))
+        }
+    }
+
+    fn invalid_state(&self, operation: &'static str) -> Error {
+        Error::InvalidCeremonyState {
+            operation,
+            state: self.state,
+        }
+    }
+}
+
+impl fmt::Debug for TopUpSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TopUpSender")
+            .field("state", &self.state)
+            .field("role", &self.role)
+            .field("segment_size", &self.segment_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Top-up driver for the party that scans the new segment.
+///
+/// # Flow
+///
+/// 1. Configure the passphrase
+/// 2. [`start_transfer`](Self::start_transfer), then feed scans to [`add_frame`](Self::add_frame);
+///    the binding tag is verified against the conversation pad once decoding completes
+/// 3. Compare [`mnemonic`](Self::mnemonic)
+/// 4. [`confirm_mnemonic`](Self::confirm_mnemonic) and [`finish`](Self::finish)
+///
+/// See [`TopUpSender`] for a full example.
+pub struct TopUpReceiver {
+    state: CeremonyState,
+    role: Role,
+    passphrase: Option<String>,
+    receiver: Option<FountainFrameReceiver>,
+    payload: Option<TopUpPayload>,
+}
+
+impl TopUpReceiver {
+    /// Create a new top-up receiver in the `Configuring` state.
+    ///
+    /// `role` is our role in the existing conversation.
+    pub fn new(role: Role) -> Self {
+        Self {
+            state: CeremonyState::Configuring,
+            role,
+            passphrase: None,
+            receiver: None,
+            payload: None,
+        }
+    }
+
+    /// Current ceremony state.
+    pub fn state(&self) -> CeremonyState {
+        self.state
+    }
+
+    /// Set the passphrase used to decrypt QR frames (`None` if unencrypted).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn set_passphrase(&mut self, passphrase: Option<&str>) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "set_passphrase")?;
+        self.passphrase = passphrase.map(String::from);
+        Ok(())
+    }
+
+    /// Start accepting scanned QR frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `Configuring`.
+    pub fn start_transfer(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::Configuring, "start_transfer")?;
+        self.receiver = Some(FountainFrameReceiver::new(self.passphrase.as_deref()));
+        self.state = CeremonyState::Transferring;
+        Ok(())
+    }
+
+    /// Add a scanned QR frame.
+    ///
+    /// Returns `Ok(true)` once the segment is decoded and its binding tag
+    /// verified against `pad`; the ceremony then moves to `VerifyingMnemonic`.
+    /// `pad` is only read here.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidCeremonyState`] unless `Transferring`
+    /// - Frame decoding errors (e.g. [`Error::CrcMismatch`]), state unchanged
+    /// - On a complete but unacceptable payload the top-up is aborted with
+    ///   [`Error::InvalidCeremonyPayload`], [`Error::TopUpPadMismatch`],
+    ///   [`Error::InvalidTopUpOffset`] or [`Error::AuthenticationFailed`]
+    pub fn add_frame(&mut self, frame_bytes: &[u8], pad: &Pad) -> Result<bool> {
+        self.expect_state(CeremonyState::Transferring, "add_frame")?;
+        let receiver = match self.receiver.as_mut() {
+            Some(receiver) => receiver,
+            None => return Err(self.invalid_state("add_frame")),
+        };
+
+        if !receiver.add_frame(frame_bytes)? {
+            return Ok(false);
+        }
+
+        let verified = receiver
+            .get_result()
+            .ok_or(Error::InvalidCeremonyPayload)
+            .and_then(|result| TopUpPayload::decode(&result.pad))
+            .and_then(|payload| self.verify(payload, pad));
+
+        match verified {
+            Ok(payload) => {
+                self.payload = Some(payload);
+                self.receiver = None;
+                self.state = CeremonyState::VerifyingMnemonic;
+                Ok(true)
+            }
+            Err(e) => {
+                self.abort();
+                Err(e)
+            }
+        }
+    }
+
+    /// Check the payload belongs to this conversation and fits the pad.
+    fn verify(&self, payload: TopUpPayload, pad: &Pad) -> Result<TopUpPayload> {
+        if payload.sender_role == self.role {
+            return Err(Error::InvalidCeremonyPayload);
+        }
+        check_pad_len(&payload, pad)?;
+
+        // Key bytes must still be unconsumed in our view, otherwise this is
+        // a replay of an applied top-up or the pads are out of sync.
+        let key_end = payload.key_end()?;
+        check_in_gap(pad, payload.key_offset, key_end)?;
+        let insert_offset = payload.insert_offset()?;
+        check_in_gap(
+            pad,
+            payload.key_offset.min(insert_offset),
+            key_end.max(insert_offset),
+        )?;
+
+        let auth_key = AuthKey::from_slice(&pad.as_bytes()[payload.key_offset..key_end]);
+        if !mac::verify_tag(&auth_key, &payload.header(), &payload.segment, &payload.tag) {
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(payload)
+    }
+
+    /// Decoding progress (0.0 to 1.0).
+    pub fn progress(&self) -> f64 {
+        match self.state {
+            CeremonyState::Transferring => self.receiver.as_ref().map_or(0.0, |r| r.progress()),
+            CeremonyState::VerifyingMnemonic
+            | CeremonyState::Confirmed
+            | CeremonyState::Finished => 1.0,
+            CeremonyState::Configuring | CeremonyState::Aborted => 0.0,
+        }
+    }
+
+    /// Mnemonic checksum of the binding tag, to compare with the sender.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic` or `Confirmed`.
+    pub fn mnemonic(&self) -> Result<Vec<&'static str>> {
+        mnemonic_for(self.state, self.payload.as_ref())
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn confirm_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "confirm_mnemonic")?;
+        self.state = CeremonyState::Confirmed;
+        Ok(())
+    }
+
+    /// Record that the mnemonics differ. Aborts the top-up.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCeremonyState`] unless `VerifyingMnemonic`.
+    pub fn reject_mnemonic(&mut self) -> Result<()> {
+        self.expect_state(CeremonyState::VerifyingMnemonic, "reject_mnemonic")?;
+        self.abort();
+        Ok(())
+    }
+
+    /// Abort the top-up and wipe the received segment. Safe to call in any state.
+    pub fn abort(&mut self) {
+        if self.state == CeremonyState::Finished {
+            return;
+        }
+        self.payload = None;
+        self.receiver = None;
+        self.state = CeremonyState::Aborted;
+    }
+
+    /// Mark the sender's binding key as consumed and splice the segment into `pad`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::MnemonicNotConfirmed`] if called while `VerifyingMnemonic`
+    /// - [`Error::InvalidCeremonyState`] in any other state except `Confirmed`
+    /// - [`Error::TopUpPadMismatch`] / [`Error::InvalidTopUpOffset`] if `pad`
+    ///   changed since the segment was verified
+    pub fn finish(&mut self, pad: &mut Pad) -> Result<TopUpSummary> {
+        check_finish(self.state)?;
+        let payload = match self.payload.as_ref() {
+            Some(payload) => payload,
+            None => return Err(self.invalid_state("finish")),
+        };
+        check_pad_len(payload, pad)?;
+        let key_end = payload.key_end()?;
+        check_in_gap(pad, payload.key_offset, key_end)?;
+
+        // Check placement before touching the pad so a failure leaves it as-is
+        let summary = payload.summary()?;
+        let insert_offset = summary.insert_offset;
+        check_in_gap(
+            pad,
+            payload.key_offset.min(insert_offset),
+            key_end.max(insert_offset),
+        )?;
+
+        match payload.sender_role {
+            Role::Initiator => pad.update_peer_consumption(Role::Initiator, key_end)?,
+            Role::Responder => {
+                let consumed_back = pad.total_size() - payload.key_offset;
+                pad.update_peer_consumption(Role::Responder, consumed_back)?;
+            }
+        }
+        pad.insert_segment(insert_offset, &payload.segment)?;
+
+        self.payload = None;
+        self.state = CeremonyState::Finished;
+        Ok(summary)
+    }
+
+    fn expect_state(&self, expected: CeremonyState, operation: &'static str) -> Result<()> {
+        if self.state == expected {
+            Ok(())
+        } else {
+            Err(self.invalid_state(operation))
+        }
+    }
+
+    fn invalid_state(&self, operation: &'static str) -> Error {
+        Error::InvalidCeremonyState {
+            operation,
+            state: self.state,
+        }
+    }
+}
+
+impl fmt::Debug for TopUpReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TopUpReceiver")
+            .field("state", &self.state)
+            .field("role", &self.role)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Mnemonic over the binding tag: only available once the segment is transferred.
+fn mnemonic_for(state: CeremonyState, payload: Option<&TopUpPayload>) -> Result<Vec<&'static str>> {
+    match (state, payload) {
+        (CeremonyState::VerifyingMnemonic | CeremonyState::Confirmed, Some(payload)) => {
+            Ok(mnemonic::generate_default(&payload.tag))
+        }
+        _ => Err(Error::InvalidCeremonyState {
+            operation: "mnemonic",
+            state,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth;
+    use crate::message::{pad_consumption, MessageFrame, MessageType};
+    use crate::replay::ReceiveWindow;
+    use crate::secret::SecretBytes;
+
+    fn pad_pair(seed: u8) -> (Pad, Pad) {
+        let bytes: Vec<u8> = (0..4096u32)
+            .map(|i| (i as u8).wrapping_mul(31).wrapping_add(seed))
+            .collect();
+        (Pad::from_bytes(bytes.clone()), Pad::from_bytes(bytes))
+    }
+
+    fn segment_entropy() -> Vec<u8> {
+        (0..PadSize::Tiny.bytes())
+            .map(|i| (i % 251) as u8)
+            .collect()
+    }
+
+    /// Run a top-up from `sender_pad` to `receiver_pad` up to the mnemonic check.
+    fn transfer(
+        sender_role: Role,
+        sender_pad: &mut Pad,
+        receiver_pad: &Pad,
+    ) -> (TopUpSender, TopUpReceiver) {
+        let receiver_role = sender_role.peer();
+
+        let mut sender = TopUpSender::new(sender_role, PadSize::Tiny);
+        sender.set_passphrase(Some("topup")).unwrap();
+        sender.set_block_size(1024).unwrap();
+        sender
+            .start_transfer(sender_pad, &segment_entropy())
+            .unwrap();
+
+        let mut receiver = TopUpReceiver::new(receiver_role);
+        receiver.set_passphrase(Some("topup")).unwrap();
+        receiver.start_transfer().unwrap();
+        while !receiver
+            .add_frame(&sender.next_frame().unwrap(), receiver_pad)
+            .unwrap()
+        {}
+        sender.finish_transfer().unwrap();
+
+        (sender, receiver)
+    }
+
+    fn send(pad: &mut Pad, role: Role, text: &[u8]) -> Vec<u8> {
+        let auth = AuthKey::from_slice(&pad.consume(AUTH_KEY_SIZE, role).unwrap());
+        let key = pad.consume(text.len(), role).unwrap();
+        MessageFrame::encrypt(MessageType::Text, text, &key, &auth)
+            .unwrap()
+            .encode()
+    }
+
+    fn receive(pad: &mut Pad, peer: Role, wire: &[u8]) -> SecretBytes {
+        let frame = MessageFrame::decode(wire).unwrap();
+        let auth = AuthKey::from_slice(&pad.consume(AUTH_KEY_SIZE, peer).unwrap());
+        let key = pad.consume(frame.ciphertext.len(), peer).unwrap();
+        frame.decrypt(&key, &auth).unwrap()
+    }
+
+    fn complete(
+        mut sender: TopUpSender,
+        sender_pad: &mut Pad,
+        mut receiver: TopUpReceiver,
+        receiver_pad: &mut Pad,
+    ) -> TopUpSummary {
+        assert_eq!(sender.mnemonic().unwrap(), receiver.mnemonic().unwrap());
+        sender.confirm_mnemonic().unwrap();
+        receiver.confirm_mnemonic().unwrap();
+        let ours = sender.finish(sender_pad).unwrap();
+        let theirs = receiver.finish(receiver_pad).unwrap();
+        assert_eq!(ours, theirs);
+        ours
+    }
+
+    #[test]
+    fn topup_from_initiator_extends_both_pads() {
+        let (mut alice, mut bob) = pad_pair(1);
+        let wire = send(&mut alice, Role::Initiator, b"before top-up");
+        receive(&mut bob, Role::Initiator, &wire);
+
+        let (sender, receiver) = transfer(Role::Initiator, &mut alice, &bob);
+        let summary = complete(sender, &mut alice, receiver, &mut bob);
+
+        assert_eq!(summary.segment_len, PadSize::Tiny.bytes());
+        assert_eq!(alice.total_size(), 4096 + PadSize::Tiny.bytes());
+        assert_eq!(alice.as_bytes(), bob.as_bytes());
+        assert_eq!(alice.consumed_front(), bob.consumed_front());
+        assert_eq!(alice.remaining(), bob.remaining());
+    }
+
+    #[test]
+    fn topup_from_responder_extends_both_pads() {
+        let (mut alice, mut bob) = pad_pair(2);
+        let wire = send(&mut bob, Role::Responder, b"before top-up");
+        receive(&mut alice, Role::Responder, &wire);
+
+        let (sender, receiver) = transfer(Role::Responder, &mut bob, &alice);
+        complete(sender, &mut bob, receiver, &mut alice);
+
+        assert_eq!(alice.as_bytes(), bob.as_bytes());
+        assert_eq!(alice.consumed_back(), bob.consumed_back());
+    }
+
+    #[test]
+    fn topup_keeps_conversation_tokens() {
+        let (mut alice, mut bob) = pad_pair(3);
+        let before = auth::derive_all_tokens(alice.as_bytes()).unwrap();
+
+        let (sender, receiver) = transfer(Role::Initiator, &mut alice, &bob);
+        complete(sender, &mut alice, receiver, &mut bob);
+
+        assert_eq!(auth::derive_all_tokens(alice.as_bytes()).unwrap(), before);
+        assert_eq!(auth::derive_all_tokens(bob.as_bytes()).unwrap(), before);
+    }
+
+    #[test]
+    fn topup_in_flight_message_still_decrypts() {
+        let (mut alice, mut bob) = pad_pair(4);
+
+        // Bob sends before the top-up, Alice only receives after
+        let wire = send(&mut bob, Role::Responder, b"in flight");
+
+        let (sender, receiver) = transfer(Role::Initiator, &mut alice, &bob);
+        complete(sender, &mut alice, receiver, &mut bob);
+
+        assert_eq!(receive(&mut alice, Role::Responder, &wire), b"in flight");
+
+        // And the new bytes are usable in both directions
+        let wire = send(&mut alice, Role::Initiator, b"after top-up");
+        assert_eq!(receive(&mut bob, Role::Initiator, &wire), b"after top-up");
+    }
+
+    #[test]
+    fn topup_in_flight_message_decrypts_at_its_offset() {
+        let (mut alice, mut bob) = pad_pair(13);
+        let mut window = ReceiveWindow::new(Role::Initiator);
+
+        // The offset travels with the message and predates the top-up
+        let offset = bob.send_position(Role::Responder) as u64;
+        let key = bob
+            .consume(pad_consumption(b"in flight".len()), Role::Responder)
+            .unwrap();
+        let auth = AuthKey::from_slice(&key[..AUTH_KEY_SIZE]);
+        let frame = MessageFrame::encrypt_at(
+            MessageType::Text,
+            offset,
+            b"in flight",
+            &key[AUTH_KEY_SIZE..],
+            &auth,
+        )
+        .unwrap();
+
+        let (sender, receiver) = transfer(Role::Initiator, &mut alice, &bob);
+        complete(sender, &mut alice, receiver, &mut bob);
+
+        assert_eq!(window.receive(&mut alice, &frame).unwrap(), b"in flight");
+        assert_eq!(alice.consumed_back(), bob.consumed_back());
+    }
+
+    #[test]
+    fn topup_rejected_for_other_conversation() {
+        let (mut alice, _) = pad_pair(5);
+        let (_, stranger) = pad_pair(6);
+
+        let mut sender = TopUpSender::new(Role::Initiator, PadSize::Tiny);
+        sender
+            .start_transfer(&mut alice, &segment_entropy())
+            .unwrap();
+
+        let mut receiver = TopUpReceiver::new(Role::Responder);
+        receiver.start_transfer().unwrap();
+        let result = loop {
+            match receiver.add_frame(&sender.next_frame().unwrap(), &stranger) {
+                Ok(false) => continue,
+                other => break other,
+            }
+        };
+
+        assert_eq!(result, Err(Error::AuthenticationFailed));
+        assert_eq!(receiver.state(), CeremonyState::Aborted);
+    }
+
+    #[test]
+    fn topup_replay_rejected() {
+        let (mut alice, mut bob) = pad_pair(7);
+
+        let mut sender = TopUpSender::new(Role::Initiator, PadSize::Tiny);
+        sender
+            .start_transfer(&mut alice, &segment_entropy())
+            .unwrap();
+        let frames: Vec<Vec<u8>> = (0..64).map(|_| sender.next_frame().unwrap()).collect();
+
+        let mut receiver = TopUpReceiver::new(Role::Responder);
+        receiver.start_transfer().unwrap();
+        for frame in &frames {
+            if receiver.add_frame(frame, &bob).unwrap() {
+                break;
+            }
+        }
+        sender.finish_transfer().unwrap();
+        complete(sender, &mut alice, receiver, &mut bob);
+
+        // Same frames again against the extended pad
+        let mut replay = TopUpReceiver::new(Role::Responder);
+        replay.start_transfer().unwrap();
+        let result = frames
+            .iter()
+            .map(|frame| replay.add_frame(frame, &bob))
+            .find(|r| r != &Ok(false))
+            .unwrap();
+        assert!(matches!(result, Err(Error::TopUpPadMismatch { .. })));
+    }
+
+    #[test]
+    fn topup_finish_refused_before_confirmation() {
+        let (mut alice, mut bob) = pad_pair(8);
+        let (mut sender, mut receiver) = transfer(Role::Initiator, &mut alice, &bob);
+
+        assert_eq!(sender.finish(&mut alice), Err(Error::MnemonicNotConfirmed));
+        assert_eq!(receiver.finish(&mut bob), Err(Error::MnemonicNotConfirmed));
+        assert_eq!(alice.total_size(), 4096);
+        assert_eq!(bob.total_size(), 4096);
+    }
+
+    #[test]
+    fn topup_sending_during_transfer_detected() {
+        let (mut alice, mut bob) = pad_pair(9);
+        let (mut sender, mut receiver) = transfer(Role::Initiator, &mut alice, &bob);
+        sender.confirm_mnemonic().unwrap();
+        receiver.confirm_mnemonic().unwrap();
+
+        // Alice sends a message that lands past the insertion point
+        send(&mut alice, Role::Initiator, &[0u8; 200]);
+
+        assert!(matches!(
+            sender.finish(&mut alice),
+            Err(Error::InvalidTopUpOffset { .. })
+        ));
+        receiver.finish(&mut bob).unwrap();
+    }
+
+    #[test]
+    fn topup_wrong_entropy_size() {
+        let (mut alice, _) = pad_pair(10);
+        let mut sender = TopUpSender::new(Role::Initiator, PadSize::Tiny);
+
+        let result = sender.start_transfer(&mut alice, &[0u8; 10]);
+        assert!(matches!(result, Err(Error::InvalidEntropySize { .. })));
+        assert_eq!(sender.state(), CeremonyState::Configuring);
+        assert_eq!(alice.consumed(), 0);
+    }
+
+    #[test]
+    fn topup_payload_rejects_garbage() {
+        assert!(TopUpPayload::decode(&[0u8; 10]).is_err());
+
+        let mut bytes = vec![0u8; HEADER_LEN + TAG_SIZE + 4];
+        bytes[0..4].copy_from_slice(TOPUP_MAGIC);
+        bytes[4] = TOPUP_VERSION;
+        bytes[5] = 0x07; // unknown role
+        assert!(matches!(
+            TopUpPayload::decode(&bytes),
+            Err(Error::InvalidCeremonyPayload)
+        ));
+    }
+
+    /// Header with the given offsets, a Responder sender and a 4-byte segment.
+    fn crafted_payload(pad_len: u64, key_offset: u64) -> Vec<u8> {
+        let mut bytes = vec![0u8; HEADER_LEN + TAG_SIZE + 4];
+        bytes[0..4].copy_from_slice(TOPUP_MAGIC);
+        bytes[4] = TOPUP_VERSION;
+        bytes[5] = Role::Responder.to_byte();
+        bytes[6..14].copy_from_slice(&pad_len.to_be_bytes());
+        bytes[14..22].copy_from_slice(&key_offset.to_be_bytes());
+        bytes[22..26].copy_from_slice(&4u32.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn topup_payload_rejects_overflowing_offsets() {
+        for (pad_len, key_offset) in [(4096, u64::MAX), (4096, u64::MAX - 1), (u64::MAX, 0)] {
+            assert!(matches!(
+                TopUpPayload::decode(&crafted_payload(pad_len, key_offset)),
+                Err(Error::InvalidCeremonyPayload)
+            ));
+        }
+    }
+
+    #[test]
+    fn topup_verify_rejects_overflowing_key_offset() {
+        let (_, bob) = pad_pair(12);
+        let receiver = TopUpReceiver::new(Role::Initiator);
+        let payload = TopUpPayload {
+            sender_role: Role::Responder,
+            pad_len: bob.total_size(),
+            key_offset: usize::MAX,
+            tag: [0u8; TAG_SIZE],
+            segment: vec![0u8; 4],
+        };
+
+        assert!(matches!(
+            receiver.verify(payload, &bob),
+            Err(Error::InvalidCeremonyPayload)
+        ));
+    }
+}
@@ -0,0 +1,550 @@
+//! Deterministic test vectors for cross-language conformance.
+//!
+//! The Swift, Kotlin and WASM clients wrap ash-core with their own glue.
+//! [`generate`] emits canonical JSON vectors for every primitive and wire
+//! format they depend on, so each client can check it produces the same
+//! bytes:
+//!
+//! | Section | Covers |
+//! |---------|--------|
+//! | `mac` | [`mac::compute_tag`] |
+//! | `padding` | [`message::pad_message`] |
+//! | `message_frame` | [`MessageFrame::encrypt`] and [`MessageFrame::encode`] |
+//! | `ceremony_metadata` | [`CeremonyMetadata::encode`] |
+//! | `auth` | [`auth::derive_all_tokens`] |
+//! | `registration` | [`auth::derive_registration_proof`] and [`auth::derive_registered_conversation_id`] |
+//! | `mnemonic` | [`mnemonic::generate`] |
+//! | `fountain` | [`FountainFrameGenerator::generate_frame`] for each transfer method |
+//!
+//! Byte strings are lowercase hex. Every section records the format
+//! version it was generated for.
+//!
+//! The module is only built for tests and with the `vectors` feature; it
+//! is not part of the library API. Client conformance tests read the
+//! committed file, `core/vectors/ash_core_vectors.json`.
+//!
+//! Regenerate the file with:
+//!
+//! ```text
+//! cargo run --features vectors --example generate_vectors > vectors/ash_core_vectors.json
+//! ```
+//!
+//! A test in this module fails if the generated output drifts from the
+//! committed file. Frames from the frozen Raptor pre-code are included, so
+//! a change to the encoder shows up as a vector mismatch rather than as
+//! clients failing to decode each other's QR codes.
+//!
+//! [`FountainFrameGenerator::generate_frame`]: crate::frame::FountainFrameGenerator::generate_frame
+
+use crate::ceremony::{CeremonyMetadata, NotificationFlags, METADATA_VERSION};
+use crate::frame::{self, TransferMethod};
+use crate::mac::{self, AuthKey, AUTH_KEY_SIZE};
+use crate::message::{self, MessageFrame, MessageType, FRAME_VERSION};
+use crate::{auth, mnemonic};
+
+/// Version of the vector file layout.
+pub const VECTORS_VERSION: u32 = 1;
+
+/// Passphrase used for all fountain vectors.
+const FOUNTAIN_PASSPHRASE: &str = "test vector passphrase";
+
+/// Repair frames emitted past the source blocks for fountain methods.
+const REPAIR_FRAMES: u32 = 3;
+
+/// Generate the full vector file as pretty-printed JSON.
+pub fn generate() -> String {
+    let document = Json::Object(vec![
+        ("version", Json::Number(u64::from(VECTORS_VERSION))),
+        (
+            "description",
+            Json::string("Deterministic ash-core test vectors. Byte strings are lowercase hex."),
+        ),
+        ("mac", Json::Array(mac_vectors())),
+        ("padding", Json::Array(padding_vectors())),
+        ("message_frame", Json::Array(message_frame_vectors())),
+        ("ceremony_metadata", Json::Array(metadata_vectors())),
+        ("auth", Json::Array(auth_vectors())),
+        ("registration", Json::Array(registration_vectors())),
+        ("mnemonic", Json::Array(mnemonic_vectors())),
+        ("fountain", Json::Array(fountain_vectors())),
+    ]);
+
+    let mut out = String::new();
+    document.write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+fn mac_vectors() -> Vec<Json> {
+    let cases = [
+        ("empty", test_bytes(1, AUTH_KEY_SIZE), vec![], vec![]),
+        (
+            "header_only",
+            test_bytes(2, AUTH_KEY_SIZE),
+            vec![0x01, 0x01, 0x00, 0x00],
+            vec![],
+        ),
+        (
+            "one_block",
+            test_bytes(3, AUTH_KEY_SIZE),
+            vec![],
+            test_bytes(30, 16),
+        ),
+        (
+            "partial_block",
+            test_bytes(4, AUTH_KEY_SIZE),
+            vec![0x01, 0x02],
+            test_bytes(40, 17),
+        ),
+        (
+            "long",
+            test_bytes(5, AUTH_KEY_SIZE),
+            vec![0x01, 0x01, 0x03, 0xe8],
+            test_bytes(50, 1000),
+        ),
+        (
+            "all_ones_key",
+            vec![0xff; AUTH_KEY_SIZE],
+            vec![0xff; 4],
+            vec![0xff; 33],
+        ),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, key, header, data)| {
+            let auth_key = AuthKey::from_slice(&key);
+            let tag = mac::compute_tag(&auth_key, &header, &data);
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("key", Json::hex(&key)),
+                ("header", Json::hex(&header)),
+                ("data", Json::hex(&data)),
+                ("tag", Json::hex(&tag)),
+            ])
+        })
+        .collect()
+}
+
+fn padding_vectors() -> Vec<Json> {
+    let cases: [(&str, Vec<u8>); 5] = [
+        ("empty", vec![]),
+        ("short", b"hi".to_vec()),
+        ("exact_minimum", vec![b'a'; 29]),
+        ("one_over_minimum", vec![b'b'; 30]),
+        ("long", test_bytes(60, 200)),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, plaintext)| {
+            let padded = message::pad_message(&plaintext).expect("vector fits");
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("message", Json::hex(&plaintext)),
+                ("padded", Json::hex(&padded)),
+            ])
+        })
+        .collect()
+}
+
+fn message_frame_vectors() -> Vec<Json> {
+    let cases: [(&str, MessageType, Vec<u8>); 4] = [
+        ("text", MessageType::Text, b"Hello, ASH!".to_vec()),
+        (
+            "text_padded",
+            MessageType::Text,
+            message::pad_message(b"hi").expect("vector fits"),
+        ),
+        ("location", MessageType::Location, test_bytes(70, 16)),
+        ("text_long", MessageType::Text, test_bytes(71, 1000)),
+    ];
+
+    cases
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, msg_type, plaintext))| {
+            let seed = 100 + 2 * i as u32;
+            let auth_key = test_bytes(seed, AUTH_KEY_SIZE);
+            let encryption_key = test_bytes(seed + 1, plaintext.len());
+            let frame = MessageFrame::encrypt(
+                msg_type,
+                &plaintext,
+                &encryption_key,
+                &AuthKey::from_slice(&auth_key),
+            )
+            .expect("vector fits");
+
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("frame_version", Json::Number(u64::from(FRAME_VERSION))),
+                ("msg_type", Json::Number(u64::from(msg_type.to_byte()))),
+                ("plaintext", Json::hex(&plaintext)),
+                ("auth_key", Json::hex(&auth_key)),
+                ("encryption_key", Json::hex(&encryption_key)),
+                ("encoded", Json::hex(&frame.encode())),
+            ])
+        })
+        .collect()
+}
+
+fn metadata_cases() -> Vec<(&'static str, CeremonyMetadata)> {
+    let custom = |ttl, disappearing, flags, method, url: &str| {
+        CeremonyMetadata::with_all(
+            ttl,
+            disappearing,
+            NotificationFlags::from_bits(flags),
+            method,
+            url.to_string(),
+        )
+        .expect("vector fits")
+    };
+
+    vec![
+        ("default", CeremonyMetadata::default()),
+        (
+            "custom_lt",
+            custom(
+                86_400,
+                30,
+                NotificationFlags::NOTIFY_NEW_MESSAGE,
+                TransferMethod::LT,
+                "https://relay.example.com",
+            ),
+        ),
+        (
+            "sequential_no_notifications",
+            custom(
+                604_800,
+                0,
+                0,
+                TransferMethod::Sequential,
+                "https://relay.example.com/a/much/longer/path?with=query",
+            ),
+        ),
+        (
+            "raptor_all_flags",
+            custom(
+                3_600,
+                300,
+                0xffff,
+                TransferMethod::Raptor,
+                "http://10.0.0.1:8080",
+            ),
+        ),
+    ]
+}
+
+fn metadata_json(metadata: &CeremonyMetadata) -> Vec<(&'static str, Json)> {
+    vec![
+        ("version", Json::Number(u64::from(metadata.version))),
+        ("ttl_seconds", Json::Number(metadata.ttl_seconds)),
+        (
+            "disappearing_messages_seconds",
+            Json::Number(u64::from(metadata.disappearing_messages_seconds)),
+        ),
+        (
+            "notification_flags",
+            Json::Number(u64::from(metadata.notification_flags.bits())),
+        ),
+        (
+            "transfer_method",
+            Json::Number(u64::from(metadata.transfer_method.to_byte())),
+        ),
+        ("relay_url", Json::string(&metadata.relay_url)),
+    ]
+}
+
+fn metadata_vectors() -> Vec<Json> {
+    metadata_cases()
+        .into_iter()
+        .map(|(name, metadata)| {
+            let mut fields = vec![("name", Json::string(name))];
+            fields.extend(metadata_json(&metadata));
+            fields.push(("encoded", Json::hex(&metadata.encode())));
+            Json::Object(fields)
+        })
+        .collect()
+}
+
+fn auth_vectors() -> Vec<Json> {
+    let cases: [(&str, Vec<u8>); 3] = [
+        (
+            "minimum_pad",
+            test_bytes(200, auth::MIN_PAD_SIZE_FOR_TOKENS),
+        ),
+        ("larger_pad", test_bytes(201, 512)),
+        ("zero_pad", vec![0; auth::MIN_PAD_SIZE_FOR_TOKENS]),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, pad)| {
+            let (conversation_id, auth_token, burn_token) =
+                auth::derive_all_tokens(&pad).expect("vector pad is large enough");
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("pad", Json::hex(&pad)),
+                ("conversation_id", Json::string(&conversation_id)),
+                ("auth_token", Json::string(&auth_token)),
+                ("burn_token", Json::string(&burn_token)),
+            ])
+        })
+        .collect()
+}
+
+fn registration_vectors() -> Vec<Json> {
+    let cases: [(&str, Vec<u8>); 3] = [
+        (
+            "minimum_pad",
+            test_bytes(210, auth::MIN_PAD_SIZE_FOR_REGISTRATION),
+        ),
+        ("larger_pad", test_bytes(211, 512)),
+        ("zero_pad", vec![0; auth::MIN_PAD_SIZE_FOR_REGISTRATION]),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, pad)| {
+            let (_, auth_token, burn_token) =
+                auth::derive_all_tokens(&pad).expect("vector pad is large enough");
+            let conversation_id = auth::derive_registered_conversation_id(&pad)
+                .expect("vector pad is large enough");
+            let proof = auth::derive_registration_proof(&pad).expect("vector pad is large enough");
+            let auth_token_hash = auth::hash_token(&auth_token);
+            let burn_token_hash = auth::hash_token(&burn_token);
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("pad", Json::hex(&pad)),
+                ("auth_token_hash", Json::string(&auth_token_hash)),
+                ("burn_token_hash", Json::string(&burn_token_hash)),
+                ("registration_proof", Json::string(&proof)),
+                ("conversation_id", Json::string(&conversation_id)),
+            ])
+        })
+        .collect()
+}
+
+fn mnemonic_vectors() -> Vec<Json> {
+    let cases: [(&str, Vec<u8>, usize); 4] = [
+        (
+            "default_words",
+            test_bytes(300, 256),
+            mnemonic::DEFAULT_WORD_COUNT,
+        ),
+        ("four_words", test_bytes(301, 256), 4),
+        ("twelve_words", test_bytes(302, 1024), 12),
+        ("zero_pad", vec![0; 64], mnemonic::DEFAULT_WORD_COUNT),
+    ];
+
+    cases
+        .into_iter()
+        .map(|(name, pad, word_count)| {
+            let words = mnemonic::generate(&pad, word_count);
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                ("pad", Json::hex(&pad)),
+                ("word_count", Json::Number(word_count as u64)),
+                (
+                    "words",
+                    Json::Array(words.into_iter().map(Json::string).collect()),
+                ),
+            ])
+        })
+        .collect()
+}
+
+fn fountain_vectors() -> Vec<Json> {
+    let metadata = CeremonyMetadata::with_all(
+        3_600,
+        60,
+        NotificationFlags::default_flags(),
+        TransferMethod::Raptor,
+        "https://relay.example.com".to_string(),
+    )
+    .expect("vector fits");
+
+    let cases = [
+        ("raptor", TransferMethod::Raptor, 400),
+        ("lt", TransferMethod::LT, 401),
+        ("sequential", TransferMethod::Sequential, 402),
+    ];
+    let block_size = 256;
+    let pad_len = 1000;
+
+    cases
+        .into_iter()
+        .map(|(name, method, seed)| {
+            let metadata = CeremonyMetadata {
+                transfer_method: method,
+                ..metadata.clone()
+            };
+            let pad = test_bytes(seed, pad_len);
+            let generator = frame::create_fountain_ceremony(
+                &metadata,
+                &pad,
+                block_size,
+                Some(FOUNTAIN_PASSPHRASE),
+                method,
+            )
+            .expect("vector pad is not empty");
+
+            let source_count = generator.source_count() as u32;
+            let frame_count = match method {
+                TransferMethod::Sequential => source_count,
+                TransferMethod::Raptor | TransferMethod::LT => source_count + REPAIR_FRAMES,
+            };
+            let frames = (0..frame_count)
+                .map(|index| Json::hex(&generator.generate_frame(index)))
+                .collect();
+
+            Json::Object(vec![
+                ("name", Json::string(name)),
+                (
+                    "metadata_version",
+                    Json::Number(u64::from(METADATA_VERSION)),
+                ),
+                ("transfer_method", Json::Number(u64::from(method.to_byte()))),
+                ("block_size", Json::Number(block_size as u64)),
+                ("passphrase", Json::string(FOUNTAIN_PASSPHRASE)),
+                ("metadata", Json::Object(metadata_json(&metadata))),
+                ("pad", Json::hex(&pad)),
+                ("source_count", Json::Number(u64::from(source_count))),
+                ("frames", Json::Array(frames)),
+            ])
+        })
+        .collect()
+}
+
+/// Lowercase hex, as used for every byte string in the vectors.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Deterministic filler bytes (xorshift32), so vectors need no randomness.
+fn test_bytes(seed: u32, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9e37_79b9) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 24) as u8
+        })
+        .collect()
+}
+
+/// Minimal JSON value, enough to write the vector file without dependencies.
+enum Json {
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn string(s: &str) -> Self {
+        Json::String(s.to_string())
+    }
+
+    fn hex(bytes: &[u8]) -> Self {
+        Json::String(to_hex(bytes))
+    }
+
+    fn write(&self, out: &mut String, indent: usize) {
+        let pad = |out: &mut String, level: usize| out.push_str(&"  ".repeat(level));
+
+        match self {
+            Json::Number(n) => out.push_str(&n.to_string()),
+            Json::String(s) => write_string(out, s),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    pad(out, indent + 1);
+                    item.write(out, indent + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push(']');
+            }
+            Json::Object(fields) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    pad(out, indent + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                    out.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMITTED: &str = include_str!("../vectors/ash_core_vectors.json");
+
+    #[test]
+    fn committed_vectors_are_current() {
+        // On failure, check whether the format change is intended. If so,
+        // regenerate the file (see module docs) and bump the affected
+        // format version.
+        assert!(
+            generate() == COMMITTED,
+            "generated vectors differ from core/vectors/ash_core_vectors.json"
+        );
+    }
+
+    #[test]
+    fn generation_is_deterministic() {
+        assert_eq!(generate(), generate());
+    }
+
+    #[test]
+    fn fountain_vectors_decode() {
+        let metadata = CeremonyMetadata::default();
+        let pad = test_bytes(1, 1000);
+        for method in [
+            TransferMethod::Raptor,
+            TransferMethod::LT,
+            TransferMethod::Sequential,
+        ] {
+            let generator =
+                frame::create_fountain_ceremony(&metadata, &pad, 256, Some("pass"), method)
+                    .unwrap();
+            let mut receiver = frame::FountainFrameReceiver::new(Some("pass"));
+            for index in 0..generator.source_count() as u32 {
+                receiver
+                    .add_frame(&generator.generate_frame(index))
+                    .unwrap();
+            }
+            assert_eq!(receiver.get_result().unwrap().pad, pad, "{:?}", method);
+        }
+    }
+
+    #[test]
+    fn string_escaping() {
+        let mut out = String::new();
+        write_string(&mut out, "a\"b\\c\n");
+        assert_eq!(out, r#""a\"b\\c\u000a""#);
+    }
+}
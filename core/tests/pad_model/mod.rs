@@ -0,0 +1,196 @@
+//! Model-based property suite for bidirectional pad consumption.
+//!
+//! Random interleavings of consume, peer updates, serialize/restore and
+//! zeroing are applied both to a pad implementation and to a plain
+//! reference model. After every step the two must agree, and:
+//!
+//! - the front and back consumption never overlap
+//! - peer updates never move a counter backwards, and claims that would
+//!   overlap our own end are rejected without moving either counter
+//! - zeroing never moves a counter
+//! - `next_send_offset` is where the bytes returned by `consume` start
+//!   (Initiator) or end (Responder)
+//!
+//! The suite is shared by ash-core and the bindings `Pad` wrapper through
+//! [`PadUnderTest`].
+
+use proptest::prelude::*;
+
+/// Consumption direction, independent of the implementation's role type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Initiator,
+    Responder,
+}
+
+/// Operations the suite drives a pad with.
+#[derive(Debug, Clone)]
+pub enum Op {
+    Consume(Side, usize),
+    PeerUpdate(Side, usize),
+    Zero(usize, usize),
+    Restore,
+}
+
+/// A pad implementation checked against the model.
+pub trait PadUnderTest: Sized {
+    fn from_bytes(bytes: Vec<u8>) -> Self;
+    /// Serialize the state and restore it into a new pad.
+    fn restore(&self) -> Self;
+    fn consume(&mut self, n: usize, side: Side) -> Option<Vec<u8>>;
+    /// Returns whether the claim was accepted.
+    fn update_peer_consumption(&mut self, peer: Side, consumed: usize) -> bool;
+    fn zero_bytes_at(&mut self, offset: usize, len: usize) -> bool;
+    fn can_send(&self, n: usize, side: Side) -> bool;
+    fn next_send_offset(&self, side: Side) -> usize;
+    fn consumed_front(&self) -> usize;
+    fn consumed_back(&self) -> usize;
+    fn remaining(&self) -> usize;
+    fn total_size(&self) -> usize;
+    fn bytes(&self) -> Vec<u8>;
+}
+
+/// Reference model: the pad as plain counters over a byte vector.
+#[derive(Debug, Clone)]
+struct Model {
+    bytes: Vec<u8>,
+    front: usize,
+    back: usize,
+}
+
+impl Model {
+    fn gap(&self) -> usize {
+        self.bytes.len() - self.front - self.back
+    }
+
+    fn consume(&mut self, n: usize, side: Side) -> Option<Vec<u8>> {
+        if n > self.gap() {
+            return None;
+        }
+        let range = match side {
+            Side::Initiator => self.front..self.front + n,
+            Side::Responder => self.bytes.len() - self.back - n..self.bytes.len() - self.back,
+        };
+        match side {
+            Side::Initiator => self.front += n,
+            Side::Responder => self.back += n,
+        }
+        Some(self.bytes[range].to_vec())
+    }
+
+    /// Claims reaching into our own end are rejected without a change.
+    fn update_peer_consumption(&mut self, peer: Side, consumed: usize) -> bool {
+        let len = self.bytes.len();
+        match peer {
+            Side::Initiator if consumed <= len - self.back => {
+                self.front = self.front.max(consumed);
+            }
+            Side::Responder if consumed <= len - self.front => {
+                self.back = self.back.max(consumed);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn zero(&mut self, offset: usize, len: usize) -> bool {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => {
+                self.bytes[offset..end].fill(0);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn next_send_offset(&self, side: Side) -> usize {
+        match side {
+            Side::Initiator => self.front,
+            Side::Responder => self.bytes.len() - self.back,
+        }
+    }
+}
+
+fn side() -> impl Strategy<Value = Side> {
+    prop_oneof![Just(Side::Initiator), Just(Side::Responder)]
+}
+
+/// Sizes are biased towards the pad length so exhaustion is reached.
+fn op(max: usize) -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => (side(), 0..=max / 4 + 1).prop_map(|(s, n)| Op::Consume(s, n)),
+        2 => (side(), 0..=max + 8).prop_map(|(s, n)| Op::PeerUpdate(s, n)),
+        2 => (0..=max + 8, 0..=max / 2 + 1).prop_map(|(o, n)| Op::Zero(o, n)),
+        1 => Just(Op::Restore),
+    ]
+}
+
+/// A random pad and a random sequence of operations on it.
+pub fn scenario() -> impl Strategy<Value = (Vec<u8>, Vec<Op>)> {
+    (0usize..512).prop_flat_map(|len| {
+        (
+            proptest::collection::vec(any::<u8>(), len),
+            proptest::collection::vec(op(len), 0..64),
+        )
+    })
+}
+
+fn check_state<P: PadUnderTest>(pad: &P, model: &Model) -> Result<(), TestCaseError> {
+    prop_assert_eq!(pad.total_size(), model.bytes.len());
+    prop_assert_eq!(pad.consumed_front(), model.front);
+    prop_assert_eq!(pad.consumed_back(), model.back);
+    prop_assert!(pad.consumed_front() + pad.consumed_back() <= pad.total_size());
+    prop_assert_eq!(pad.remaining(), model.gap());
+    for side in [Side::Initiator, Side::Responder] {
+        prop_assert_eq!(pad.next_send_offset(side), model.next_send_offset(side));
+    }
+    prop_assert_eq!(pad.bytes(), model.bytes.clone());
+    Ok(())
+}
+
+/// Run `ops` against a pad of `bytes` and the model, checking every step.
+pub fn run<P: PadUnderTest>(bytes: Vec<u8>, ops: &[Op]) -> Result<(), TestCaseError> {
+    let mut pad = P::from_bytes(bytes.clone());
+    let mut model = Model {
+        bytes,
+        front: 0,
+        back: 0,
+    };
+    check_state(&pad, &model)?;
+
+    for op in ops {
+        let (front, back) = (model.front, model.back);
+        match *op {
+            Op::Consume(side, n) => {
+                let offset = pad.next_send_offset(side);
+                prop_assert_eq!(pad.can_send(n, side), n <= model.gap());
+                let got = pad.consume(n, side);
+                let expected = model.consume(n, side);
+                prop_assert_eq!(&got, &expected);
+                if let Some(key) = got {
+                    let start = match side {
+                        Side::Initiator => offset,
+                        Side::Responder => offset - n,
+                    };
+                    prop_assert_eq!(key, pad.bytes()[start..start + n].to_vec());
+                }
+            }
+            Op::PeerUpdate(peer, consumed) => {
+                prop_assert_eq!(
+                    pad.update_peer_consumption(peer, consumed),
+                    model.update_peer_consumption(peer, consumed)
+                );
+            }
+            Op::Zero(offset, len) => {
+                prop_assert_eq!(pad.zero_bytes_at(offset, len), model.zero(offset, len));
+                prop_assert_eq!((pad.consumed_front(), pad.consumed_back()), (front, back));
+            }
+            Op::Restore => pad = pad.restore(),
+        }
+
+        // Counters only ever move forward
+        prop_assert!(model.front >= front && model.back >= back);
+        check_state(&pad, &model)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Model-based properties of [`Pad`] consumption (see `pad_model`).
+
+mod pad_model;
+
+use ash_core::pad::{Pad, Role};
+use pad_model::{PadUnderTest, Side};
+use proptest::prelude::*;
+
+fn role(side: Side) -> Role {
+    match side {
+        Side::Initiator => Role::Initiator,
+        Side::Responder => Role::Responder,
+    }
+}
+
+impl PadUnderTest for Pad {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Pad::from_bytes(bytes)
+    }
+
+    fn restore(&self) -> Self {
+        let (bytes, front, back) = self.serialize_state();
+        Pad::from_bytes_with_state(bytes, front, back)
+    }
+
+    fn consume(&mut self, n: usize, side: Side) -> Option<Vec<u8>> {
+        Pad::consume(self, n, role(side))
+            .ok()
+            .map(|key| key.into_vec())
+    }
+
+    fn update_peer_consumption(&mut self, peer: Side, consumed: usize) -> bool {
+        Pad::update_peer_consumption(self, role(peer), consumed).is_ok()
+    }
+
+    fn zero_bytes_at(&mut self, offset: usize, len: usize) -> bool {
+        Pad::zero_bytes_at(self, offset, len)
+    }
+
+    fn can_send(&self, n: usize, side: Side) -> bool {
+        Pad::can_send(self, n, role(side))
+    }
+
+    fn next_send_offset(&self, side: Side) -> usize {
+        Pad::next_send_offset(self, role(side))
+    }
+
+    fn consumed_front(&self) -> usize {
+        Pad::consumed_front(self)
+    }
+
+    fn consumed_back(&self) -> usize {
+        Pad::consumed_back(self)
+    }
+
+    fn remaining(&self) -> usize {
+        Pad::remaining(self)
+    }
+
+    fn total_size(&self) -> usize {
+        Pad::total_size(self)
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn pad_matches_model((bytes, ops) in pad_model::scenario()) {
+        pad_model::run::<Pad>(bytes, &ops)?;
+    }
+}
@@ -56,7 +56,11 @@ pub fn otp_decrypt(key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, JsError> {
 /// # Returns
 /// 32-byte authentication tag.
 #[wasm_bindgen]
-pub fn compute_auth_tag(auth_key: &[u8], header: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, JsError> {
+pub fn compute_auth_tag(
+    auth_key: &[u8],
+    header: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, JsError> {
     if auth_key.len() != AUTH_KEY_SIZE {
         return Err(JsError::new(&format!(
             "Auth key must be {} bytes, got {}",
@@ -81,7 +85,12 @@ pub fn compute_auth_tag(auth_key: &[u8], header: &[u8], ciphertext: &[u8]) -> Re
 /// # Returns
 /// true if valid, false otherwise.
 #[wasm_bindgen]
-pub fn verify_auth_tag(auth_key: &[u8], header: &[u8], ciphertext: &[u8], tag: &[u8]) -> Result<bool, JsError> {
+pub fn verify_auth_tag(
+    auth_key: &[u8],
+    header: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Result<bool, JsError> {
     if auth_key.len() != AUTH_KEY_SIZE {
         return Err(JsError::new(&format!(
             "Auth key must be {} bytes, got {}",
@@ -99,7 +108,9 @@ pub fn verify_auth_tag(auth_key: &[u8], header: &[u8], ciphertext: &[u8], tag: &
 
     let key = AuthKey::from_slice(auth_key);
     let tag_arr: [u8; TAG_SIZE] = tag.try_into().unwrap();
-    Ok(ash_core::mac::verify_tag(&key, header, ciphertext, &tag_arr))
+    Ok(ash_core::mac::verify_tag(
+        &key, header, ciphertext, &tag_arr,
+    ))
 }
 
 /// Pad a message to minimum 32 bytes.
@@ -125,7 +136,9 @@ pub fn pad_message(message: &[u8]) -> Result<Vec<u8>, JsError> {
 /// Original message bytes.
 #[wasm_bindgen]
 pub fn unpad_message(padded: &[u8]) -> Result<Vec<u8>, JsError> {
-    ash_core::message::unpad_message(padded).map_err(|e| JsError::new(&e.to_string()))
+    ash_core::message::unpad_message(padded)
+        .map(ash_core::SecretBytes::into_vec)
+        .map_err(|e| JsError::new(&e.to_string()))
 }
 
 /// Get the minimum padded message size (32 bytes).
@@ -155,7 +168,7 @@ pub fn bytes_to_hex(bytes: &[u8]) -> String {
 /// Convert hex string to bytes.
 #[wasm_bindgen]
 pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, JsError> {
-    if hex.len() % 2 != 0 {
+    if !hex.len().is_multiple_of(2) {
         return Err(JsError::new("Hex string must have even length"));
     }
 
@@ -200,7 +213,16 @@ pub fn get_min_pad_size_for_tokens() -> usize {
 /// Returns a 64-character hex string identifying the conversation.
 #[wasm_bindgen]
 pub fn derive_conversation_id(pad_bytes: &[u8]) -> Result<String, JsError> {
-    ash_core::auth::derive_conversation_id(pad_bytes)
+    ash_core::auth::derive_conversation_id(pad_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Derive the conversation ID for a registration that carries a proof.
+///
+/// Returns a 64-character hex string committing to the token hashes and
+/// the registration proof. It differs from `derive_conversation_id`.
+#[wasm_bindgen]
+pub fn derive_registered_conversation_id(pad_bytes: &[u8]) -> Result<String, JsError> {
+    ash_core::auth::derive_registered_conversation_id(pad_bytes)
         .map_err(|e| JsError::new(&e.to_string()))
 }
 
@@ -209,8 +231,7 @@ pub fn derive_conversation_id(pad_bytes: &[u8]) -> Result<String, JsError> {
 /// Returns a 64-character hex string used for API authentication.
 #[wasm_bindgen]
 pub fn derive_auth_token(pad_bytes: &[u8]) -> Result<String, JsError> {
-    ash_core::auth::derive_auth_token(pad_bytes)
-        .map_err(|e| JsError::new(&e.to_string()))
+    ash_core::auth::derive_auth_token(pad_bytes).map_err(|e| JsError::new(&e.to_string()))
 }
 
 /// Derive burn token from pad bytes.
@@ -218,8 +239,23 @@ pub fn derive_auth_token(pad_bytes: &[u8]) -> Result<String, JsError> {
 /// Returns a 64-character hex string required for burning conversations.
 #[wasm_bindgen]
 pub fn derive_burn_token(pad_bytes: &[u8]) -> Result<String, JsError> {
-    ash_core::auth::derive_burn_token(pad_bytes)
-        .map_err(|e| JsError::new(&e.to_string()))
+    ash_core::auth::derive_burn_token(pad_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Derive the registration proof from pad bytes.
+///
+/// Returns a 64-character hex string sent when registering a conversation.
+#[wasm_bindgen]
+pub fn derive_registration_proof(pad_bytes: &[u8]) -> Result<String, JsError> {
+    ash_core::auth::derive_registration_proof(pad_bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Hash a token as sent to the relay at registration.
+///
+/// Returns the SHA-256 of the token as a 64-character hex string.
+#[wasm_bindgen]
+pub fn hash_token(token: &str) -> String {
+    ash_core::auth::hash_token(token)
 }
 
 // === CRC-32 ===
@@ -244,3 +280,259 @@ pub fn derive_passphrase_key(passphrase: &str, frame_index: u16, length: usize)
     ash_core::passphrase::derive_key(passphrase, frame_index, length)
 }
 
+// === Ceremony State Machines ===
+
+fn to_js_error(e: ash_core::Error) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+fn pad_size_from_bytes(bytes: usize) -> Result<ash_core::PadSize, JsError> {
+    use ash_core::PadSize;
+
+    [
+        PadSize::Tiny,
+        PadSize::Small,
+        PadSize::Medium,
+        PadSize::Large,
+        PadSize::Huge,
+    ]
+    .into_iter()
+    .find(|size| size.bytes() == bytes)
+    .ok_or_else(|| JsError::new(&format!("Unsupported pad size: {} bytes", bytes)))
+}
+
+fn role_name(role: ash_core::Role) -> String {
+    match role {
+        ash_core::Role::Initiator => "initiator".to_string(),
+        ash_core::Role::Responder => "responder".to_string(),
+    }
+}
+
+/// Ceremony driver for the initiator (creates the pad, displays QR codes).
+///
+/// States run in order: configuring, transferring, verifying_mnemonic,
+/// confirmed, finished. Tokens are only derived after the mnemonic is confirmed.
+#[wasm_bindgen]
+pub struct InitiatorCeremony {
+    inner: ash_core::InitiatorCeremony,
+}
+
+#[wasm_bindgen]
+impl InitiatorCeremony {
+    /// Create a new ceremony for a pad of the given size in bytes (32 KB to 1 MB).
+    #[wasm_bindgen(constructor)]
+    pub fn new(pad_size_bytes: usize) -> Result<InitiatorCeremony, JsError> {
+        Ok(Self {
+            inner: ash_core::InitiatorCeremony::new(pad_size_from_bytes(pad_size_bytes)?),
+        })
+    }
+
+    /// Current state name (e.g. "transferring").
+    pub fn state(&self) -> String {
+        self.inner.state().name().to_string()
+    }
+
+    /// Set ceremony metadata (TTL, disappearing messages, notification flags, relay URL).
+    pub fn set_metadata(
+        &mut self,
+        ttl_seconds: u64,
+        disappearing_messages_seconds: u32,
+        notification_flags: u16,
+        relay_url: String,
+    ) -> Result<(), JsError> {
+        let metadata = ash_core::CeremonyMetadata::with_flags(
+            ttl_seconds,
+            disappearing_messages_seconds,
+            ash_core::NotificationFlags::from_bits(notification_flags),
+            relay_url,
+        )
+        .map_err(to_js_error)?;
+        self.inner.set_metadata(metadata).map_err(to_js_error)
+    }
+
+    /// Set the passphrase used to encrypt QR frames.
+    pub fn set_passphrase(&mut self, passphrase: &str) -> Result<(), JsError> {
+        self.inner
+            .set_passphrase(Some(passphrase))
+            .map_err(to_js_error)
+    }
+
+    /// Set the QR block size in bytes.
+    pub fn set_block_size(&mut self, block_size: usize) -> Result<(), JsError> {
+        self.inner.set_block_size(block_size).map_err(to_js_error)
+    }
+
+    /// Create the pad from entropy and start generating frames.
+    pub fn start_transfer(&mut self, entropy: &[u8]) -> Result<(), JsError> {
+        self.inner.start_transfer(entropy).map_err(to_js_error)
+    }
+
+    /// Generate the next QR frame.
+    pub fn next_frame(&mut self) -> Result<Vec<u8>, JsError> {
+        self.inner.next_frame().map_err(to_js_error)
+    }
+
+    /// Number of source blocks (0 unless transferring).
+    pub fn source_count(&self) -> usize {
+        self.inner.source_count()
+    }
+
+    /// Stop displaying frames and move on to mnemonic verification.
+    pub fn finish_transfer(&mut self) -> Result<(), JsError> {
+        self.inner.finish_transfer().map_err(to_js_error)
+    }
+
+    /// Mnemonic to compare with the responder (space-separated words).
+    pub fn mnemonic(&self) -> Result<String, JsError> {
+        Ok(self.inner.mnemonic().map_err(to_js_error)?.join(" "))
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    pub fn confirm_mnemonic(&mut self) -> Result<(), JsError> {
+        self.inner.confirm_mnemonic().map_err(to_js_error)
+    }
+
+    /// Record that the mnemonics differ. Aborts the ceremony.
+    pub fn reject_mnemonic(&mut self) -> Result<(), JsError> {
+        self.inner.reject_mnemonic().map_err(to_js_error)
+    }
+
+    /// Abort the ceremony and wipe the pad.
+    pub fn abort(&mut self) {
+        self.inner.abort();
+    }
+
+    /// Derive tokens and hand out the conversation bundle.
+    pub fn finish(&mut self) -> Result<ConversationBundle, JsError> {
+        let inner = self.inner.finish().map_err(to_js_error)?;
+        Ok(ConversationBundle { inner })
+    }
+}
+
+/// Ceremony driver for the responder (scans QR codes, receives the pad).
+#[wasm_bindgen]
+pub struct ResponderCeremony {
+    inner: ash_core::ResponderCeremony,
+}
+
+#[wasm_bindgen]
+impl ResponderCeremony {
+    /// Create a new ceremony in the configuring state.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ResponderCeremony {
+        Self {
+            inner: ash_core::ResponderCeremony::new(),
+        }
+    }
+
+    /// Current state name (e.g. "verifying_mnemonic").
+    pub fn state(&self) -> String {
+        self.inner.state().name().to_string()
+    }
+
+    /// Set the passphrase used to decrypt QR frames.
+    pub fn set_passphrase(&mut self, passphrase: &str) -> Result<(), JsError> {
+        self.inner
+            .set_passphrase(Some(passphrase))
+            .map_err(to_js_error)
+    }
+
+    /// Start accepting scanned frames.
+    pub fn start_transfer(&mut self) -> Result<(), JsError> {
+        self.inner.start_transfer().map_err(to_js_error)
+    }
+
+    /// Add a scanned QR frame. Returns true once the pad is decoded.
+    pub fn add_frame(&mut self, frame_bytes: &[u8]) -> Result<bool, JsError> {
+        self.inner.add_frame(frame_bytes).map_err(to_js_error)
+    }
+
+    /// Decoding progress (0.0 to 1.0).
+    pub fn progress(&self) -> f64 {
+        self.inner.progress()
+    }
+
+    /// Mnemonic to compare with the initiator (space-separated words).
+    pub fn mnemonic(&self) -> Result<String, JsError> {
+        Ok(self.inner.mnemonic().map_err(to_js_error)?.join(" "))
+    }
+
+    /// Record that both parties saw the same mnemonic.
+    pub fn confirm_mnemonic(&mut self) -> Result<(), JsError> {
+        self.inner.confirm_mnemonic().map_err(to_js_error)
+    }
+
+    /// Record that the mnemonics differ. Aborts the ceremony.
+    pub fn reject_mnemonic(&mut self) -> Result<(), JsError> {
+        self.inner.reject_mnemonic().map_err(to_js_error)
+    }
+
+    /// Abort the ceremony and wipe the received pad.
+    pub fn abort(&mut self) {
+        self.inner.abort();
+    }
+
+    /// Derive tokens and hand out the conversation bundle.
+    pub fn finish(&mut self) -> Result<ConversationBundle, JsError> {
+        let inner = self.inner.finish().map_err(to_js_error)?;
+        Ok(ConversationBundle { inner })
+    }
+}
+
+impl Default for ResponderCeremony {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of a completed ceremony: pad, settings and relay credentials.
+#[wasm_bindgen]
+pub struct ConversationBundle {
+    inner: ash_core::ConversationBundle,
+}
+
+#[wasm_bindgen]
+impl ConversationBundle {
+    /// Our role: "initiator" or "responder".
+    pub fn role(&self) -> String {
+        role_name(self.inner.role)
+    }
+
+    /// Raw pad bytes.
+    ///
+    /// This is a copy in JS memory that the bundle cannot wipe; overwrite it
+    /// once it has been stored.
+    pub fn pad_bytes(&self) -> Vec<u8> {
+        self.inner.pad.as_bytes().to_vec()
+    }
+
+    /// Conversation ID (64-character hex).
+    pub fn conversation_id(&self) -> String {
+        self.inner.conversation_id.clone()
+    }
+
+    /// Auth token for API operations (64-character hex).
+    pub fn auth_token(&self) -> String {
+        self.inner.auth_token.clone()
+    }
+
+    /// Burn token for burn operations (64-character hex).
+    pub fn burn_token(&self) -> String {
+        self.inner.burn_token.clone()
+    }
+
+    /// Message TTL on the relay in seconds.
+    pub fn ttl_seconds(&self) -> u64 {
+        self.inner.metadata.ttl_seconds
+    }
+
+    /// Disappearing messages timeout in seconds (0 = off).
+    pub fn disappearing_messages_seconds(&self) -> u32 {
+        self.inner.metadata.disappearing_messages_seconds
+    }
+
+    /// Relay server URL.
+    pub fn relay_url(&self) -> String {
+        self.inner.metadata.relay_url.clone()
+    }
+}
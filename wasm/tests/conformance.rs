@@ -0,0 +1,233 @@
+//! Conformance of the WASM surface against the ash-core test vectors.
+//!
+//! The vectors live in `core/vectors/ash_core_vectors.json`. These tests
+//! run natively, so they only take success paths: `JsError` cannot be
+//! constructed outside a JS host.
+
+use ash_wasm::{
+    compute_auth_tag, derive_auth_token, derive_burn_token, derive_conversation_id,
+    derive_registered_conversation_id, derive_registration_proof, generate_mnemonic_n, hash_token,
+    otp_decrypt, otp_encrypt, pad_message, unpad_message, verify_auth_tag, ResponderCeremony,
+};
+use serde_json::Value;
+
+const VECTORS: &str = include_str!("../../core/vectors/ash_core_vectors.json");
+
+fn vectors() -> Value {
+    serde_json::from_str(VECTORS).expect("vector file is valid JSON")
+}
+
+fn section(name: &str) -> Vec<Value> {
+    vectors()[name]
+        .as_array()
+        .expect("section is an array")
+        .clone()
+}
+
+fn hex(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("hex string");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("valid hex"))
+        .collect()
+}
+
+fn text(value: &Value) -> String {
+    value.as_str().expect("string").to_string()
+}
+
+fn number(value: &Value) -> u64 {
+    value.as_u64().expect("number")
+}
+
+fn ok<T, E>(result: Result<T, E>, context: &str) -> T {
+    result.ok().unwrap_or_else(|| panic!("{} failed", context))
+}
+
+#[test]
+fn vectors_version() {
+    assert_eq!(number(&vectors()["version"]), 1);
+}
+
+#[test]
+fn mac() {
+    for v in section("mac") {
+        let name = text(&v["name"]);
+        let (key, header, data, tag) = (
+            hex(&v["key"]),
+            hex(&v["header"]),
+            hex(&v["data"]),
+            hex(&v["tag"]),
+        );
+        assert_eq!(
+            ok(compute_auth_tag(&key, &header, &data), &name),
+            tag,
+            "{}",
+            name
+        );
+        assert!(
+            ok(verify_auth_tag(&key, &header, &data, &tag), &name),
+            "{}",
+            name
+        );
+
+        let mut forged = tag.clone();
+        forged[0] ^= 1;
+        assert!(
+            !ok(verify_auth_tag(&key, &header, &data, &forged), &name),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn padding() {
+    for v in section("padding") {
+        let name = text(&v["name"]);
+        let padded = ok(pad_message(&hex(&v["message"])), &name);
+        assert_eq!(padded, hex(&v["padded"]), "{}", name);
+        assert_eq!(
+            ok(unpad_message(&padded), &name),
+            hex(&v["message"]),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn message_frame() {
+    // The WASM surface has no frame type; build the frame from its parts
+    for v in section("message_frame") {
+        let name = text(&v["name"]);
+        let plaintext = hex(&v["plaintext"]);
+        let key = hex(&v["encryption_key"]);
+        let auth_key = hex(&v["auth_key"]);
+
+        let ciphertext = ok(otp_encrypt(&key, &plaintext), &name);
+        let mut header = vec![
+            number(&v["frame_version"]) as u8,
+            number(&v["msg_type"]) as u8,
+        ];
+        header.extend_from_slice(&(ciphertext.len() as u16).to_be_bytes());
+        let tag = ok(compute_auth_tag(&auth_key, &header, &ciphertext), &name);
+
+        let mut encoded = header.clone();
+        encoded.extend_from_slice(&ciphertext);
+        encoded.extend_from_slice(&tag);
+        assert_eq!(encoded, hex(&v["encoded"]), "{}", name);
+
+        assert_eq!(
+            ok(otp_decrypt(&key, &ciphertext), &name),
+            plaintext,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn auth_tokens() {
+    for v in section("auth") {
+        let name = text(&v["name"]);
+        let pad = hex(&v["pad"]);
+        assert_eq!(
+            ok(derive_conversation_id(&pad), &name),
+            text(&v["conversation_id"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            ok(derive_auth_token(&pad), &name),
+            text(&v["auth_token"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            ok(derive_burn_token(&pad), &name),
+            text(&v["burn_token"]),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn registration_proofs() {
+    for v in section("registration") {
+        let name = text(&v["name"]);
+        let pad = hex(&v["pad"]);
+        let auth_token = ok(derive_auth_token(&pad), &name);
+        let burn_token = ok(derive_burn_token(&pad), &name);
+        assert_eq!(
+            hash_token(&auth_token),
+            text(&v["auth_token_hash"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            hash_token(&burn_token),
+            text(&v["burn_token_hash"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            ok(derive_registered_conversation_id(&pad), &name),
+            text(&v["conversation_id"]),
+            "{}",
+            name
+        );
+        let proof = derive_registration_proof(&pad);
+        assert_eq!(ok(proof, &name), text(&v["registration_proof"]), "{}", name);
+    }
+}
+
+#[test]
+fn mnemonic() {
+    for v in section("mnemonic") {
+        let words: Vec<String> = v["words"].as_array().unwrap().iter().map(text).collect();
+        let generated = generate_mnemonic_n(&hex(&v["pad"]), number(&v["word_count"]) as usize);
+        assert_eq!(generated, words.join(" "), "{}", text(&v["name"]));
+    }
+}
+
+#[test]
+fn fountain_frames() {
+    for v in section("fountain") {
+        let name = text(&v["name"]);
+        let mut responder = ResponderCeremony::new();
+        ok(responder.set_passphrase(&text(&v["passphrase"])), &name);
+        ok(responder.start_transfer(), &name);
+
+        // Decode newest first so repair frames (and the Raptor pre-code)
+        // take part in recovery
+        let frames: Vec<Vec<u8>> = v["frames"].as_array().unwrap().iter().map(hex).collect();
+        let mut complete = false;
+        for frame in frames.iter().rev() {
+            if ok(responder.add_frame(frame), &name) {
+                complete = true;
+                break;
+            }
+        }
+        assert!(complete, "{}: frames did not decode", name);
+
+        ok(responder.confirm_mnemonic(), &name);
+        let bundle = ok(responder.finish(), &name);
+        let metadata = &v["metadata"];
+        assert_eq!(bundle.pad_bytes(), hex(&v["pad"]), "{}", name);
+        assert_eq!(
+            bundle.ttl_seconds(),
+            number(&metadata["ttl_seconds"]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            u64::from(bundle.disappearing_messages_seconds()),
+            number(&metadata["disappearing_messages_seconds"]),
+            "{}",
+            name
+        );
+        assert_eq!(bundle.relay_url(), text(&metadata["relay_url"]), "{}", name);
+    }
+}
@@ -2,7 +2,8 @@
  * TokenDemo - Interactive token derivation demonstration
  *
  * Shows how ASH derives authorization tokens from pad bytes:
- * - Conversation ID (bytes 0-31)
+ * - Conversation ID (hash over bytes 32-223, committing to the tokens
+ *   and the registration proof)
  * - Auth Token (bytes 32-95)
  * - Burn Token (bytes 96-159)
  */
@@ -34,7 +35,7 @@ export default function TokenDemo() {
   const [wasmModule, setWasmModule] = useState<WasmModule | null>(null);
   const [wasmReady, setWasmReady] = useState(false);
   const [padBytes, setPadBytes] = useState<Uint8Array | null>(null);
-  const [minPadSize, setMinPadSize] = useState(160);
+  const [minPadSize, setMinPadSize] = useState(224);
   const [tokens, setTokens] = useState<TokenInfo[]>([]);
   const [highlightedRange, setHighlightedRange] = useState<[number, number] | null>(null);
 
@@ -70,7 +71,7 @@ export default function TokenDemo() {
         {
           name: 'Conversation ID',
           value: convId,
-          range: '0-31',
+          range: '32-223',
           purpose: 'Identifies conversation on relay',
           color: 'brand',
         },